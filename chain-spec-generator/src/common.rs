@@ -19,9 +19,9 @@ use crate::{
 	relay_chain_specs::{KusamaChainSpec, PolkadotChainSpec},
 	system_parachains_specs::{
 		AssetHubKusamaChainSpec, AssetHubPolkadotChainSpec, BridgeHubKusamaChainSpec,
-		BridgeHubPolkadotChainSpec, CollectivesPolkadotChainSpec, CoretimeKusamaChainSpec,
-		CoretimePolkadotChainSpec, EncointerKusamaChainSpec, GluttonKusamaChainSpec,
-		PeopleKusamaChainSpec, PeoplePolkadotChainSpec,
+		BridgeHubPolkadotChainSpec, BulletinPolkadotChainSpec, CollectivesPolkadotChainSpec,
+		CoretimeKusamaChainSpec, CoretimePolkadotChainSpec, EncointerKusamaChainSpec,
+		GluttonKusamaChainSpec, PeopleKusamaChainSpec, PeoplePolkadotChainSpec,
 	},
 	ChainSpec,
 };
@@ -32,38 +32,85 @@ struct EmptyChainSpecWithId {
 }
 
 pub fn from_json_file(filepath: &str, supported: String) -> Result<Box<dyn ChainSpec>, String> {
-	let path = std::path::PathBuf::from(&filepath);
-	let file = std::fs::File::open(filepath).expect("Failed to open file");
-	let reader = std::io::BufReader::new(file);
-	let chain_spec: EmptyChainSpecWithId = serde_json::from_reader(reader)
+	let json = std::fs::read(filepath).expect("Failed to open file");
+	let chain_spec: EmptyChainSpecWithId = serde_json::from_slice(&json)
 		.expect("Failed to read 'json' file with ChainSpec configuration");
-	match &chain_spec.id {
+	from_json_bytes(&chain_spec.id, json, supported)
+}
+
+/// Loads the chain spec `json` as the chain spec type of the chain with the given `id`.
+pub fn from_json_bytes(
+	id: &str,
+	json: Vec<u8>,
+	supported: String,
+) -> Result<Box<dyn ChainSpec>, String> {
+	match id {
 		x if x.starts_with("polkadot") | x.starts_with("dot") =>
-			Ok(Box::new(PolkadotChainSpec::from_json_file(path)?)),
+			Ok(Box::new(PolkadotChainSpec::from_json_bytes(json)?)),
 		x if x.starts_with("kusama") | x.starts_with("ksm") =>
-			Ok(Box::new(KusamaChainSpec::from_json_file(path)?)),
+			Ok(Box::new(KusamaChainSpec::from_json_bytes(json)?)),
 		x if x.starts_with("asset-hub-polkadot") =>
-			Ok(Box::new(AssetHubPolkadotChainSpec::from_json_file(path)?)),
+			Ok(Box::new(AssetHubPolkadotChainSpec::from_json_bytes(json)?)),
 		x if x.starts_with("asset-hub-kusama") =>
-			Ok(Box::new(AssetHubKusamaChainSpec::from_json_file(path)?)),
+			Ok(Box::new(AssetHubKusamaChainSpec::from_json_bytes(json)?)),
 		x if x.starts_with("collectives-polkadot") =>
-			Ok(Box::new(CollectivesPolkadotChainSpec::from_json_file(path)?)),
+			Ok(Box::new(CollectivesPolkadotChainSpec::from_json_bytes(json)?)),
 		x if x.starts_with("bridge-hub-polkadot") =>
-			Ok(Box::new(BridgeHubPolkadotChainSpec::from_json_file(path)?)),
+			Ok(Box::new(BridgeHubPolkadotChainSpec::from_json_bytes(json)?)),
 		x if x.starts_with("bridge-hub-kusama") =>
-			Ok(Box::new(BridgeHubKusamaChainSpec::from_json_file(path)?)),
+			Ok(Box::new(BridgeHubKusamaChainSpec::from_json_bytes(json)?)),
 		x if x.starts_with("coretime-kusama") =>
-			Ok(Box::new(CoretimeKusamaChainSpec::from_json_file(path)?)),
+			Ok(Box::new(CoretimeKusamaChainSpec::from_json_bytes(json)?)),
 		x if x.starts_with("coretime-polkadot") =>
-			Ok(Box::new(CoretimePolkadotChainSpec::from_json_file(path)?)),
+			Ok(Box::new(CoretimePolkadotChainSpec::from_json_bytes(json)?)),
 		x if x.starts_with("glutton-kusama") =>
-			Ok(Box::new(GluttonKusamaChainSpec::from_json_file(path)?)),
+			Ok(Box::new(GluttonKusamaChainSpec::from_json_bytes(json)?)),
 		x if x.starts_with("encointer-kusama") =>
-			Ok(Box::new(EncointerKusamaChainSpec::from_json_file(path)?)),
+			Ok(Box::new(EncointerKusamaChainSpec::from_json_bytes(json)?)),
 		x if x.starts_with("people-kusama") =>
-			Ok(Box::new(PeopleKusamaChainSpec::from_json_file(path)?)),
+			Ok(Box::new(PeopleKusamaChainSpec::from_json_bytes(json)?)),
 		x if x.starts_with("people-polkadot") =>
-			Ok(Box::new(PeoplePolkadotChainSpec::from_json_file(path)?)),
+			Ok(Box::new(PeoplePolkadotChainSpec::from_json_bytes(json)?)),
+		x if x.starts_with("bulletin-polkadot") =>
+			Ok(Box::new(BulletinPolkadotChainSpec::from_json_bytes(json)?)),
 		_ => Err(format!("Unknown chain 'id' in json file. Only supported: {supported}'")),
 	}
 }
+
+/// Returns the metadata of the runtime of the chain with the given `id`, if it is compiled in.
+pub fn runtime_metadata(id: &str) -> Option<frame_metadata::RuntimeMetadataPrefixed> {
+	match id {
+		#[cfg(feature = "polkadot")]
+		x if x.starts_with("polkadot") | x.starts_with("dot") =>
+			Some(polkadot_runtime::Runtime::metadata()),
+		#[cfg(feature = "kusama")]
+		x if x.starts_with("kusama") | x.starts_with("ksm") => Some(kusama_runtime::Runtime::metadata()),
+		#[cfg(feature = "asset-hub-polkadot")]
+		x if x.starts_with("asset-hub-polkadot") => Some(asset_hub_polkadot_runtime::Runtime::metadata()),
+		#[cfg(feature = "asset-hub-kusama")]
+		x if x.starts_with("asset-hub-kusama") => Some(asset_hub_kusama_runtime::Runtime::metadata()),
+		#[cfg(feature = "collectives-polkadot")]
+		x if x.starts_with("collectives-polkadot") =>
+			Some(collectives_polkadot_runtime::Runtime::metadata()),
+		#[cfg(feature = "bridge-hub-polkadot")]
+		x if x.starts_with("bridge-hub-polkadot") =>
+			Some(bridge_hub_polkadot_runtime::Runtime::metadata()),
+		#[cfg(feature = "bridge-hub-kusama")]
+		x if x.starts_with("bridge-hub-kusama") => Some(bridge_hub_kusama_runtime::Runtime::metadata()),
+		#[cfg(feature = "coretime-kusama")]
+		x if x.starts_with("coretime-kusama") => Some(coretime_kusama_runtime::Runtime::metadata()),
+		#[cfg(feature = "coretime-polkadot")]
+		x if x.starts_with("coretime-polkadot") => Some(coretime_polkadot_runtime::Runtime::metadata()),
+		#[cfg(feature = "glutton-kusama")]
+		x if x.starts_with("glutton-kusama") => Some(glutton_kusama_runtime::Runtime::metadata()),
+		#[cfg(feature = "encointer-kusama")]
+		x if x.starts_with("encointer-kusama") => Some(encointer_kusama_runtime::Runtime::metadata()),
+		#[cfg(feature = "people-kusama")]
+		x if x.starts_with("people-kusama") => Some(people_kusama_runtime::Runtime::metadata()),
+		#[cfg(feature = "people-polkadot")]
+		x if x.starts_with("people-polkadot") => Some(people_polkadot_runtime::Runtime::metadata()),
+		#[cfg(feature = "bulletin-polkadot")]
+		x if x.starts_with("bulletin-polkadot") => Some(bulletin_polkadot_runtime::Runtime::metadata()),
+		_ => None,
+	}
+}
@@ -0,0 +1,304 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Inspection of the genesis storage of raw chain specs.
+//!
+//! The storage keys are grouped by pallet and storage item using the metadata of the runtime that
+//! is compiled into the generator. The values, and the map keys stored after a transparent hasher,
+//! are decoded with the type registry of the metadata. Anything that can't be decoded is kept SCALE
+//! encoded.
+
+use frame_metadata::{
+	v14::{StorageEntryType, StorageHasher},
+	RuntimeMetadata, RuntimeMetadataPrefixed,
+};
+use scale_info::{form::PortableForm, PortableRegistry, TypeDef, TypeDefPrimitive};
+use scale_value::{Composite, Primitive, ValueDef};
+use serde_json::{Map, Value};
+use sp_core::hashing::twox_128;
+use std::{collections::BTreeMap, path::Path};
+
+/// Length of the `twox_128(pallet) ++ twox_128(item)` prefix of a storage key.
+const PREFIX_LEN: usize = 32;
+
+/// A storage item of the runtime metadata.
+struct StorageItem {
+	pallet: String,
+	name: String,
+	/// The hashers and the type ids of the keys, empty for plain storage values.
+	keys: Vec<(StorageHasher, u32)>,
+	/// The type id of the value.
+	value: u32,
+}
+
+/// Decodes the genesis storage of the raw chain spec at `path` into pallet level JSON.
+pub fn inspect(path: &Path) -> Result<String, String> {
+	let file = std::fs::File::open(path)
+		.map_err(|e| format!("Failed to open '{}': {e}", path.display()))?;
+	let chain_spec: Value = serde_json::from_reader(std::io::BufReader::new(file))
+		.map_err(|e| format!("Failed to parse '{}': {e}", path.display()))?;
+	let output = inspect_chain_spec(&chain_spec)?;
+	serde_json::to_string_pretty(&output).map_err(|e| e.to_string())
+}
+
+/// Decodes the genesis storage of the raw `chain_spec`.
+fn inspect_chain_spec(chain_spec: &Value) -> Result<Value, String> {
+	let id = chain_spec.get("id").and_then(Value::as_str).ok_or("Chain spec has no 'id'")?;
+	let top = chain_spec
+		.pointer("/genesis/raw/top")
+		.and_then(Value::as_object)
+		.ok_or("Chain spec is not raw, generate it with '--raw'")?;
+
+	let (items, types) = match crate::common::runtime_metadata(id) {
+		Some(metadata) => storage_items(metadata)?,
+		None => {
+			eprintln!("Warning: runtime of '{id}' is not compiled in, storage is not decoded");
+			(BTreeMap::new(), PortableRegistry { types: Vec::new() })
+		},
+	};
+
+	let mut pallets = Map::new();
+	let mut well_known = Map::new();
+	let mut unknown = Map::new();
+	for (key, value) in top {
+		let key_bytes =
+			array_bytes::hex2bytes(key).map_err(|e| format!("Invalid key '{key}': {e:?}"))?;
+		let Some(item) = key_bytes.get(..PREFIX_LEN).and_then(|prefix| items.get(prefix)) else {
+			if key_bytes.starts_with(b":") {
+				let name = String::from_utf8_lossy(&key_bytes).into_owned();
+				// The runtime code is too large to be useful in the output.
+				let value = if name == ":code" { code_summary(value) } else { value.clone() };
+				well_known.insert(name, value);
+			} else {
+				unknown.insert(key.clone(), value.clone());
+			}
+			continue
+		};
+		let entries = pallets
+			.entry(item.pallet.clone())
+			.or_insert_with(|| Value::Object(Map::new()))
+			.as_object_mut()
+			.expect("Only objects are inserted; qed");
+		let value = decode_value(item, value, &types);
+		if item.keys.is_empty() {
+			entries.insert(item.name.clone(), value);
+		} else {
+			let key = decode_key(item, &key_bytes[PREFIX_LEN..], &types);
+			entries
+				.entry(item.name.clone())
+				.or_insert_with(|| Value::Object(Map::new()))
+				.as_object_mut()
+				.expect("Only objects are inserted for maps; qed")
+				.insert(key, value);
+		}
+	}
+
+	Ok(serde_json::json!({
+		"id": id,
+		"pallets": pallets,
+		"wellKnown": well_known,
+		"unknown": unknown,
+	}))
+}
+
+/// Maps the storage key prefix of every storage item to the item, and returns the type registry.
+fn storage_items(
+	metadata: RuntimeMetadataPrefixed,
+) -> Result<(BTreeMap<Vec<u8>, StorageItem>, PortableRegistry), String> {
+	let RuntimeMetadata::V14(metadata) = metadata.1 else {
+		return Err("Only metadata V14 is supported".into())
+	};
+	let mut items = BTreeMap::new();
+	for storage in metadata.pallets.into_iter().filter_map(|pallet| pallet.storage) {
+		for entry in storage.entries {
+			let key =
+				[twox_128(storage.prefix.as_bytes()), twox_128(entry.name.as_bytes())].concat();
+			let (keys, value) = match entry.ty {
+				StorageEntryType::Plain(value) => (Vec::new(), value.id),
+				StorageEntryType::Map { hashers, key, value } => {
+					// A map with several hashers has a tuple of the keys as key type.
+					let key_ids = match hashers.len() {
+						1 => vec![key.id],
+						_ => match metadata.types.resolve(key.id).map(|ty| &ty.type_def) {
+							Some(TypeDef::Tuple(tuple)) =>
+								tuple.fields.iter().map(|field| field.id).collect(),
+							_ => return Err(format!("Invalid key type of '{}'", entry.name)),
+						},
+					};
+					(hashers.into_iter().zip(key_ids).collect(), value.id)
+				},
+			};
+			let item =
+				StorageItem { pallet: storage.prefix.clone(), name: entry.name, keys, value };
+			items.insert(key, item);
+		}
+	}
+	Ok((items, metadata.types))
+}
+
+/// Decodes the hex encoded `value` of `item`, or keeps it encoded if it can't be decoded.
+fn decode_value(item: &StorageItem, value: &Value, types: &PortableRegistry) -> Value {
+	let decoded = value
+		.as_str()
+		.and_then(|value| array_bytes::hex2bytes(value).ok())
+		.and_then(|bytes| decode_exact(&bytes, item.value, types));
+	decoded.unwrap_or_else(|| {
+		eprintln!("Warning: failed to decode the value of '{}::{}'", item.pallet, item.name);
+		value.clone()
+	})
+}
+
+/// Decodes the map keys in the `suffix` of a storage key of `item`.
+///
+/// Keys behind a hasher that doesn't append the key are shown as the hex encoded suffix.
+fn decode_key(item: &StorageItem, mut suffix: &[u8], types: &PortableRegistry) -> String {
+	let hex_suffix = array_bytes::bytes2hex("0x", suffix);
+	let mut keys = Vec::with_capacity(item.keys.len());
+	for (hasher, type_id) in &item.keys {
+		let hash_len = match hasher {
+			StorageHasher::Blake2_128Concat => 16,
+			StorageHasher::Twox64Concat => 8,
+			StorageHasher::Identity => 0,
+			_ => return hex_suffix,
+		};
+		let Some(rest) = suffix.get(hash_len..) else { return hex_suffix };
+		suffix = rest;
+		let Ok(key) = scale_value::scale::decode_as_type(&mut suffix, *type_id, types) else {
+			return hex_suffix
+		};
+		keys.push(to_json(key, types));
+	}
+	if !suffix.is_empty() {
+		return hex_suffix
+	}
+	let key = if keys.len() == 1 { keys.remove(0) } else { Value::Array(keys) };
+	match key {
+		Value::String(key) => key,
+		key => key.to_string(),
+	}
+}
+
+/// Decodes `bytes` as a value of the type `type_id`, failing if not all bytes are used.
+fn decode_exact(mut bytes: &[u8], type_id: u32, types: &PortableRegistry) -> Option<Value> {
+	let value = scale_value::scale::decode_as_type(&mut bytes, type_id, types).ok()?;
+	bytes.is_empty().then(|| to_json(value, types))
+}
+
+/// Converts a decoded value to JSON.
+///
+/// Byte sequences are shown hex encoded, single field tuple structs as their field and integers
+/// that don't fit into a JSON number as strings.
+fn to_json(value: scale_value::Value<u32>, types: &PortableRegistry) -> Value {
+	let type_def = types.resolve(value.context).map(|ty| &ty.type_def);
+	match value.value {
+		ValueDef::Composite(composite) => match type_def {
+			Some(TypeDef::Sequence(_) | TypeDef::Array(_)) if is_bytes(type_def, types) => {
+				let bytes = composite
+					.into_values()
+					.map(|byte| match byte.value {
+						ValueDef::Primitive(Primitive::U128(byte)) => byte as u8,
+						_ => unreachable!("Only bytes are decoded for a byte sequence; qed"),
+					})
+					.collect::<Vec<_>>();
+				Value::String(array_bytes::bytes2hex("0x", bytes))
+			},
+			Some(TypeDef::Composite(def))
+				if def.fields.len() == 1 && def.fields[0].name.is_none() =>
+				composite
+					.into_values()
+					.next()
+					.map_or(Value::Null, |field| to_json(field, types)),
+			_ => composite_to_json(composite, types),
+		},
+		ValueDef::Variant(variant) => match variant.values {
+			Composite::Unnamed(values) if values.is_empty() => Value::String(variant.name),
+			values =>
+				Value::Object(Map::from_iter([(variant.name, composite_to_json(values, types))])),
+		},
+		ValueDef::BitSequence(bits) =>
+			Value::String(bits.iter().map(|bit| if bit { '1' } else { '0' }).collect()),
+		ValueDef::Primitive(primitive) => match primitive {
+			Primitive::Bool(value) => Value::Bool(value),
+			Primitive::Char(value) => Value::String(value.into()),
+			Primitive::String(value) => Value::String(value),
+			Primitive::U128(value) =>
+				u64::try_from(value).map_or_else(|_| Value::String(value.to_string()), Value::from),
+			Primitive::I128(value) =>
+				i64::try_from(value).map_or_else(|_| Value::String(value.to_string()), Value::from),
+			Primitive::U256(value) | Primitive::I256(value) =>
+				Value::String(array_bytes::bytes2hex("0x", value)),
+		},
+	}
+}
+
+/// Converts decoded struct or tuple fields to JSON, see [`to_json`].
+fn composite_to_json(composite: Composite<u32>, types: &PortableRegistry) -> Value {
+	match composite {
+		Composite::Named(fields) => Value::Object(
+			fields.into_iter().map(|(name, value)| (name, to_json(value, types))).collect(),
+		),
+		Composite::Unnamed(values) =>
+			Value::Array(values.into_iter().map(|value| to_json(value, types)).collect()),
+	}
+}
+
+/// Returns `true` if `type_def` is a sequence or an array of bytes.
+fn is_bytes(type_def: Option<&TypeDef<PortableForm>>, types: &PortableRegistry) -> bool {
+	let element = match type_def {
+		Some(TypeDef::Sequence(sequence)) => sequence.type_param.id,
+		Some(TypeDef::Array(array)) => array.type_param.id,
+		_ => return false,
+	};
+	matches!(
+		types.resolve(element).map(|ty| &ty.type_def),
+		Some(TypeDef::Primitive(TypeDefPrimitive::U8))
+	)
+}
+
+#[cfg(all(test, feature = "coretime-kusama"))]
+mod tests {
+	use super::*;
+	use sp_core::Pair;
+
+	#[test]
+	fn inspect_decodes_generated_raw_chain_spec() {
+		let chain_spec = crate::system_parachains_specs::coretime_kusama_local_testnet_config()
+			.unwrap()
+			.as_json(true)
+			.unwrap();
+		let output = inspect_chain_spec(&serde_json::from_str(&chain_spec).unwrap()).unwrap();
+
+		assert_eq!(output["id"], "coretime-kusama-local");
+		assert!(output["wellKnown"][":code"]["length"].as_u64().unwrap() > 0);
+		assert_eq!(output["pallets"]["ParachainInfo"]["ParachainId"], 1005);
+
+		// Alice is a collator of the local testnet and is endowed.
+		let alice = array_bytes::bytes2hex(
+			"0x",
+			sp_core::crypto::AccountId32::from(
+				sp_core::sr25519::Pair::from_string("//Alice", None).unwrap().public(),
+			),
+		);
+		let account = &output["pallets"]["System"]["Account"][&alice];
+		assert!(account["providers"].as_u64().unwrap() > 0);
+		assert!(account["data"]["free"].is_number() || account["data"]["free"].is_string());
+		assert!(output["pallets"]["CollatorSelection"]["Invulnerables"]
+			.as_array()
+			.unwrap()
+			.contains(&Value::String(alice)));
+	}
+}
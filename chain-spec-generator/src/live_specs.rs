@@ -0,0 +1,176 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Chain specs of the live networks.
+//!
+//! The metadata of every live network (name, id, boot nodes, telemetry endpoints, properties and
+//! the genesis preset or patch) is kept in the `live-specs/<chain>.json` data files, so that it
+//! can be reviewed and updated without touching the code.
+//!
+//! A data file of a [`ChainType::Live`] network must list its boot nodes and name a genesis preset
+//! of the runtime. Networks are only added once their boot nodes and genesis preset are known.
+
+use crate::system_parachains_specs::Extensions;
+use sc_chain_spec::{ChainSpec, ChainSpecBuilder, ChainType};
+use serde::Deserialize;
+use std::str::FromStr;
+
+/// The version of the `live-specs/*.json` data format understood by this generator.
+pub const LIVE_SPEC_DATA_VERSION: u32 = 1;
+
+/// The genesis of a live network.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+enum Genesis {
+	/// Name of the genesis config preset provided by the runtime.
+	Preset(String),
+	/// Patch applied on top of the default genesis config of the runtime.
+	Patch(serde_json::Value),
+}
+
+/// The content of a `live-specs/<chain>.json` data file.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct LiveSpecData {
+	version: u32,
+	name: String,
+	id: String,
+	chain_type: ChainType,
+	#[serde(default)]
+	protocol_id: Option<String>,
+	boot_nodes: Vec<String>,
+	telemetry_endpoints: Vec<(String, u8)>,
+	properties: sc_chain_spec::Properties,
+	/// The relay chain, only set for parachains.
+	#[serde(default)]
+	relay_chain: Option<String>,
+	/// The para id, only set for parachains.
+	#[serde(default)]
+	para_id: Option<u32>,
+	genesis: Genesis,
+}
+
+impl LiveSpecData {
+	fn load(json: &str) -> Result<Self, String> {
+		let data: Self = serde_json::from_str(json)
+			.map_err(|e| format!("Failed to parse live spec data: {e}"))?;
+		if data.version != LIVE_SPEC_DATA_VERSION {
+			return Err(format!(
+				"Live spec data of '{}' has unsupported version {}, expected {}",
+				data.id, data.version, LIVE_SPEC_DATA_VERSION,
+			));
+		}
+		if data.chain_type == ChainType::Live {
+			if data.boot_nodes.is_empty() {
+				return Err(format!("Live spec data of '{}' has no boot nodes", data.id));
+			}
+			if !matches!(data.genesis, Genesis::Preset(_)) {
+				return Err(format!("Live spec data of '{}' has no genesis preset", data.id));
+			}
+		}
+		Ok(data)
+	}
+
+	fn parachain_extensions(&self) -> Result<Extensions, String> {
+		match (&self.relay_chain, self.para_id) {
+			(Some(relay_chain), Some(para_id)) =>
+				Ok(Extensions { relay_chain: relay_chain.clone(), para_id }),
+			_ => Err(format!("Live spec data of '{}' misses 'relayChain' or 'paraId'", self.id)),
+		}
+	}
+
+	fn apply<E>(self, builder: ChainSpecBuilder<E>) -> Result<ChainSpecBuilder<E>, String> {
+		let boot_nodes = self
+			.boot_nodes
+			.iter()
+			.map(|addr| {
+				sc_network::config::MultiaddrWithPeerId::from_str(addr)
+					.map_err(|e| format!("Invalid boot node '{addr}': {e}"))
+			})
+			.collect::<Result<Vec<_>, _>>()?;
+		let telemetry_endpoints = sc_telemetry::TelemetryEndpoints::new(self.telemetry_endpoints)
+			.map_err(|e| format!("Invalid telemetry endpoint: {e}"))?;
+
+		let mut builder = builder
+			.with_name(&self.name)
+			.with_id(&self.id)
+			.with_chain_type(self.chain_type)
+			.with_boot_nodes(boot_nodes)
+			.with_telemetry_endpoints(telemetry_endpoints)
+			.with_properties(self.properties);
+		if let Some(protocol_id) = &self.protocol_id {
+			builder = builder.with_protocol_id(protocol_id);
+		}
+		Ok(match self.genesis {
+			Genesis::Preset(preset) => builder.with_genesis_config_preset_name(&preset),
+			Genesis::Patch(patch) => builder.with_genesis_config_patch(patch),
+		})
+	}
+}
+
+/// Kusama Coretime live config.
+#[cfg(feature = "coretime-kusama")]
+pub fn coretime_kusama_config() -> Result<Box<dyn ChainSpec>, String> {
+	let data = LiveSpecData::load(include_str!("../live-specs/coretime-kusama.json"))?;
+	let builder = crate::system_parachains_specs::CoretimeKusamaChainSpec::builder(
+		coretime_kusama_runtime::WASM_BINARY.ok_or("Kusama Coretime wasm not available")?,
+		data.parachain_extensions()?,
+	);
+	Ok(Box::new(data.apply(builder)?.build()))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn live_spec_data_files_are_valid() {
+		let data = LiveSpecData::load(include_str!("../live-specs/coretime-kusama.json")).unwrap();
+		assert_eq!(data.chain_type, ChainType::Live);
+		data.parachain_extensions().unwrap();
+	}
+
+	#[test]
+	fn live_spec_data_needs_boot_nodes_and_genesis_preset() {
+		let data = |boot_nodes: &str, genesis: &str| {
+			format!(
+				r#"{{
+					"version": 1,
+					"name": "Test",
+					"id": "test",
+					"chainType": "Live",
+					"bootNodes": {boot_nodes},
+					"telemetryEndpoints": [],
+					"properties": {{}},
+					"genesis": {genesis}
+				}}"#
+			)
+		};
+		let boot_node =
+			r#"["/dns/test/tcp/30333/p2p/12D3KooWR7Biy6nPgQFhk2eYP62pAkcFA6he9RUFURTDh7ewTjpo"]"#;
+
+		assert!(LiveSpecData::load(&data(boot_node, r#"{"preset": "live"}"#)).is_ok());
+		assert_eq!(
+			LiveSpecData::load(&data("[]", r#"{"preset": "live"}"#)).unwrap_err(),
+			"Live spec data of 'test' has no boot nodes",
+		);
+		assert_eq!(
+			LiveSpecData::load(&data(boot_node, r#"{"patch": {}}"#)).unwrap_err(),
+			"Live spec data of 'test' has no genesis preset",
+		);
+	}
+}
@@ -15,128 +15,236 @@
 // You should have received a copy of the GNU General Public License
 // along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
 
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use sc_chain_spec::ChainSpec;
-use std::collections::HashMap;
+use std::{collections::BTreeMap, path::PathBuf};
 
 mod common;
+mod inspect;
+mod live_specs;
+mod overrides;
 mod relay_chain_specs;
 mod system_parachains_specs;
 
 #[derive(Parser)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 struct Cli {
+	#[command(subcommand)]
+	command: Option<Command>,
+
 	/// The chain spec to generate.
-	chain: String,
+	#[arg(required = true)]
+	chain: Option<String>,
 
 	/// Generate the chain spec as raw?
 	#[arg(long)]
 	raw: bool,
+
+	#[command(flatten)]
+	overrides: overrides::Overrides,
+}
+
+#[derive(Subcommand)]
+enum Command {
+	/// List the supported chains with the cargo feature that enables them.
+	List,
+	/// Decode the genesis storage of a raw chain spec into pallet level JSON.
+	Inspect {
+		/// Path to the raw chain spec.
+		path: PathBuf,
+	},
+}
+
+/// A chain spec that can be generated by name.
+struct SupportedChain {
+	/// The cargo feature the chain spec is gated by.
+	feature: &'static str,
+	/// Generates the chain spec.
+	generate: fn() -> Result<Box<dyn ChainSpec>, String>,
+}
+
+fn supported_chains() -> BTreeMap<&'static str, SupportedChain> {
+	BTreeMap::from([
+		#[cfg(feature = "polkadot")]
+		(
+			"polkadot-dev",
+			SupportedChain {
+				feature: "polkadot",
+				generate: relay_chain_specs::polkadot_development_config,
+			},
+		),
+		#[cfg(feature = "polkadot")]
+		(
+			"polkadot-local",
+			SupportedChain {
+				feature: "polkadot",
+				generate: relay_chain_specs::polkadot_local_testnet_config,
+			},
+		),
+		#[cfg(feature = "kusama")]
+		(
+			"kusama-dev",
+			SupportedChain {
+				feature: "kusama",
+				generate: relay_chain_specs::kusama_development_config,
+			},
+		),
+		#[cfg(feature = "kusama")]
+		(
+			"kusama-local",
+			SupportedChain {
+				feature: "kusama",
+				generate: relay_chain_specs::kusama_local_testnet_config,
+			},
+		),
+		#[cfg(feature = "asset-hub-kusama")]
+		(
+			"asset-hub-kusama-local",
+			SupportedChain {
+				feature: "asset-hub-kusama",
+				generate: system_parachains_specs::asset_hub_kusama_local_testnet_config,
+			},
+		),
+		#[cfg(feature = "asset-hub-polkadot")]
+		(
+			"asset-hub-polkadot-local",
+			SupportedChain {
+				feature: "asset-hub-polkadot",
+				generate: system_parachains_specs::asset_hub_polkadot_local_testnet_config,
+			},
+		),
+		#[cfg(feature = "collectives-polkadot")]
+		(
+			"collectives-polkadot-local",
+			SupportedChain {
+				feature: "collectives-polkadot",
+				generate: system_parachains_specs::collectives_polkadot_local_testnet_config,
+			},
+		),
+		#[cfg(feature = "bridge-hub-polkadot")]
+		(
+			"bridge-hub-polkadot-local",
+			SupportedChain {
+				feature: "bridge-hub-polkadot",
+				generate: system_parachains_specs::bridge_hub_polkadot_local_testnet_config,
+			},
+		),
+		#[cfg(feature = "bridge-hub-kusama")]
+		(
+			"bridge-hub-kusama-local",
+			SupportedChain {
+				feature: "bridge-hub-kusama",
+				generate: system_parachains_specs::bridge_hub_kusama_local_testnet_config,
+			},
+		),
+		#[cfg(feature = "glutton-kusama")]
+		(
+			"glutton-kusama-local",
+			SupportedChain {
+				feature: "glutton-kusama",
+				generate: system_parachains_specs::glutton_kusama_local_testnet_config,
+			},
+		),
+		#[cfg(feature = "encointer-kusama")]
+		(
+			"encointer-kusama-local",
+			SupportedChain {
+				feature: "encointer-kusama",
+				generate: system_parachains_specs::encointer_kusama_local_testnet_config,
+			},
+		),
+		#[cfg(feature = "coretime-kusama")]
+		(
+			"coretime-kusama-local",
+			SupportedChain {
+				feature: "coretime-kusama",
+				generate: system_parachains_specs::coretime_kusama_local_testnet_config,
+			},
+		),
+		#[cfg(feature = "coretime-polkadot")]
+		(
+			"coretime-polkadot-local",
+			SupportedChain {
+				feature: "coretime-polkadot",
+				generate: system_parachains_specs::coretime_polkadot_local_testnet_config,
+			},
+		),
+		#[cfg(feature = "people-kusama")]
+		(
+			"people-kusama-local",
+			SupportedChain {
+				feature: "people-kusama",
+				generate: system_parachains_specs::people_kusama_local_testnet_config,
+			},
+		),
+		#[cfg(feature = "people-polkadot")]
+		(
+			"people-polkadot-local",
+			SupportedChain {
+				feature: "people-polkadot",
+				generate: system_parachains_specs::people_polkadot_local_testnet_config,
+			},
+		),
+		#[cfg(feature = "bulletin-polkadot")]
+		(
+			"bulletin-polkadot-local",
+			SupportedChain {
+				feature: "bulletin-polkadot",
+				generate: system_parachains_specs::bulletin_polkadot_local_testnet_config,
+			},
+		),
+		#[cfg(feature = "coretime-kusama")]
+		(
+			"coretime-kusama",
+			SupportedChain {
+				feature: "coretime-kusama",
+				generate: live_specs::coretime_kusama_config,
+			},
+		),
+	])
 }
 
 fn main() -> Result<(), String> {
 	let cli = Cli::parse();
+	let supported_chains = supported_chains();
+	let supported = supported_chains.keys().copied().collect::<Vec<_>>().join(", ");
 
-	let supported_chains =
-		HashMap::<&str, Box<dyn Fn() -> Result<Box<dyn ChainSpec>, String>>>::from([
-			#[cfg(feature = "polkadot")]
-			("polkadot-dev", Box::new(relay_chain_specs::polkadot_development_config) as Box<_>),
-			#[cfg(feature = "polkadot")]
-			(
-				"polkadot-local",
-				Box::new(relay_chain_specs::polkadot_local_testnet_config) as Box<_>,
-			),
-			#[cfg(feature = "kusama")]
-			("kusama-dev", Box::new(relay_chain_specs::kusama_development_config) as Box<_>),
-			#[cfg(feature = "kusama")]
-			("kusama-local", Box::new(relay_chain_specs::kusama_local_testnet_config) as Box<_>),
-			#[cfg(feature = "asset-hub-kusama")]
-			(
-				"asset-hub-kusama-local",
-				Box::new(system_parachains_specs::asset_hub_kusama_local_testnet_config) as Box<_>,
-			),
-			#[cfg(feature = "asset-hub-polkadot")]
-			(
-				"asset-hub-polkadot-local",
-				Box::new(system_parachains_specs::asset_hub_polkadot_local_testnet_config)
-					as Box<_>,
-			),
-			#[cfg(feature = "collectives-polkadot")]
-			(
-				"collectives-polkadot-local",
-				Box::new(system_parachains_specs::collectives_polkadot_local_testnet_config)
-					as Box<_>,
-			),
-			#[cfg(feature = "bridge-hub-polkadot")]
-			(
-				"bridge-hub-polkadot-local",
-				Box::new(system_parachains_specs::bridge_hub_polkadot_local_testnet_config)
-					as Box<_>,
-			),
-			#[cfg(feature = "bridge-hub-kusama")]
-			(
-				"bridge-hub-kusama-local",
-				Box::new(system_parachains_specs::bridge_hub_kusama_local_testnet_config) as Box<_>,
-			),
-			#[cfg(feature = "glutton-kusama")]
-			(
-				"glutton-kusama-local",
-				Box::new(system_parachains_specs::glutton_kusama_local_testnet_config) as Box<_>,
-			),
-			#[cfg(feature = "encointer-kusama")]
-			(
-				"encointer-kusama-local",
-				Box::new(system_parachains_specs::encointer_kusama_local_testnet_config) as Box<_>,
-			),
-			#[cfg(feature = "coretime-kusama")]
-			(
-				"coretime-kusama",
-				Box::new(system_parachains_specs::coretime_kusama_config) as Box<_>,
-			),
-			#[cfg(feature = "coretime-kusama")]
-			(
-				"coretime-kusama-local",
-				Box::new(system_parachains_specs::coretime_kusama_local_testnet_config) as Box<_>,
-			),
-			#[cfg(feature = "coretime-polkadot")]
-			(
-				"coretime-polkadot-local",
-				Box::new(system_parachains_specs::coretime_polkadot_local_testnet_config) as Box<_>,
-			),
-			#[cfg(feature = "people-kusama")]
-			(
-				"people-kusama-local",
-				Box::new(system_parachains_specs::people_kusama_local_testnet_config) as Box<_>,
-			),
-			#[cfg(feature = "people-polkadot")]
-			(
-				"people-polkadot-local",
-				Box::new(system_parachains_specs::people_polkadot_local_testnet_config) as Box<_>,
-			),
-			#[cfg(feature = "bulletin-polkadot")]
-			(
-				"bulletin-polkadot-local",
-				Box::new(system_parachains_specs::bulletin_polkadot_local_testnet_config) as Box<_>,
-			),
-		]);
-
-	if let Some(function) = supported_chains.get(&*cli.chain) {
-		let chain_spec = (*function)()?.as_json(cli.raw)?;
-		print!("{chain_spec}");
-		Ok(())
-	} else {
-		let supported = supported_chains.keys().enumerate().fold(String::new(), |c, (n, k)| {
-			let extra = if n + 1 < supported_chains.len() { ", " } else { "" };
-			format!("{c}{k}{extra}")
-		});
-		if cli.chain.ends_with(".json") {
-			let chain_spec = common::from_json_file(&cli.chain, supported)?.as_json(cli.raw)?;
-			print!("{chain_spec}");
-			Ok(())
-		} else {
-			Err(format!(
-				"Unknown chain: '{}', only supported: '{supported}' or a json file",
-				cli.chain,
-			))
-		}
+	match cli.command {
+		Some(Command::List) => {
+			for (name, chain) in &supported_chains {
+				println!("{name} (feature: {})", chain.feature);
+			}
+			return Ok(())
+		},
+		Some(Command::Inspect { path }) => {
+			print!("{}", inspect::inspect(&path)?);
+			return Ok(())
+		},
+		None => {},
 	}
+	let chain = cli.chain.expect("`chain` is required without a subcommand; qed");
+
+	let chain_spec = if let Some(supported_chain) = supported_chains.get(&*chain) {
+		(supported_chain.generate)()?
+	} else if chain.ends_with(".json") {
+		common::from_json_file(&chain, supported.clone())?
+	} else {
+		return Err(format!(
+			"Unknown chain: '{chain}', only supported: '{supported}' or a json file"
+		))
+	};
+
+	let chain_spec = if cli.overrides.is_empty() {
+		chain_spec
+	} else {
+		let mut json: serde_json::Value = serde_json::from_str(&chain_spec.as_json(false)?)
+			.map_err(|e| format!("Failed to parse generated chain spec: {e}"))?;
+		cli.overrides.apply(&mut json)?;
+		let json = serde_json::to_vec(&json).map_err(|e| e.to_string())?;
+		// The chain spec type is selected by the original id, the `--id` override may be anything.
+		common::from_json_bytes(chain_spec.id(), json, supported)?
+	};
+
+	print!("{}", chain_spec.as_json(cli.raw)?);
+	Ok(())
 }
@@ -0,0 +1,115 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Command line overrides of a generated chain spec.
+
+use sc_chain_spec::GenesisConfigBuilderRuntimeCaller;
+use serde_json::Value;
+use std::path::PathBuf;
+
+/// Overrides that are applied on top of the chain spec of the selected chain.
+#[derive(clap::Args, Debug)]
+pub struct Overrides {
+	/// Use the named genesis config preset of the runtime instead of the genesis of the chain.
+	#[arg(long)]
+	pub preset: Option<String>,
+
+	/// JSON file with a patch that is merged into the genesis config (e.g. extra endowed
+	/// accounts, invulnerables, para id or sudo key).
+	#[arg(long)]
+	pub patch: Option<PathBuf>,
+
+	/// Override the name of the chain.
+	#[arg(long)]
+	pub name: Option<String>,
+
+	/// Override the id of the chain.
+	#[arg(long)]
+	pub id: Option<String>,
+
+	/// Override the network protocol id.
+	#[arg(long)]
+	pub protocol_id: Option<String>,
+
+	/// Override the chain properties with the given JSON object.
+	#[arg(long)]
+	pub properties: Option<String>,
+}
+
+impl Overrides {
+	/// Returns `true` if no override is set.
+	pub fn is_empty(&self) -> bool {
+		self.preset.is_none() &&
+			self.patch.is_none() &&
+			self.name.is_none() &&
+			self.id.is_none() &&
+			self.protocol_id.is_none() &&
+			self.properties.is_none()
+	}
+
+	/// Applies the overrides to the (non-raw) JSON of a chain spec.
+	pub fn apply(&self, chain_spec: &mut Value) -> Result<(), String> {
+		let genesis = chain_spec
+			.pointer_mut("/genesis/runtimeGenesis")
+			.and_then(Value::as_object_mut)
+			.ok_or("Chain spec has no runtime genesis")?;
+
+		if let Some(preset) = &self.preset {
+			let code = genesis
+				.get("code")
+				.and_then(Value::as_str)
+				.ok_or("Chain spec has no runtime code")?;
+			let code = array_bytes::hex2bytes(code)
+				.map_err(|e| format!("Invalid runtime code in chain spec: {e:?}"))?;
+			let patch = GenesisConfigBuilderRuntimeCaller::<()>::new(&code)
+				.get_named_preset(Some(preset))
+				.map_err(|e| format!("Failed to get genesis preset '{preset}': {e}"))?;
+			genesis.remove("config");
+			genesis.insert("patch".into(), patch);
+		}
+
+		if let Some(path) = &self.patch {
+			let file = std::fs::File::open(path)
+				.map_err(|e| format!("Failed to open patch '{}': {e}", path.display()))?;
+			let patch: Value = serde_json::from_reader(std::io::BufReader::new(file))
+				.map_err(|e| format!("Failed to parse patch '{}': {e}", path.display()))?;
+			let key = if genesis.contains_key("patch") { "patch" } else { "config" };
+			let target = genesis.get_mut(key).ok_or("Chain spec has no genesis config")?;
+			sc_chain_spec::json_merge(target, patch);
+		}
+
+		if let Some(name) = &self.name {
+			chain_spec["name"] = name.as_str().into();
+		}
+		if let Some(id) = &self.id {
+			chain_spec["id"] = id.as_str().into();
+		}
+		if let Some(protocol_id) = &self.protocol_id {
+			chain_spec["protocolId"] = protocol_id.as_str().into();
+		}
+		if let Some(properties) = &self.properties {
+			let properties: Value = serde_json::from_str(properties)
+				.map_err(|e| format!("Failed to parse properties: {e}"))?;
+			if !properties.is_object() {
+				return Err("Properties must be a JSON object".into());
+			}
+			chain_spec["properties"] = properties;
+		}
+
+		Ok(())
+	}
+}
@@ -226,44 +226,6 @@ pub fn coretime_kusama_local_testnet_config() -> Result<Box<dyn sc_chain_spec::C
 	))
 }
 
-#[cfg(feature = "coretime-kusama")]
-pub fn coretime_kusama_config() -> Result<Box<dyn sc_chain_spec::ChainSpec>, String> {
-	let mut properties = sc_chain_spec::Properties::new();
-	properties.insert("ss58Format".into(), 2.into());
-	properties.insert("tokenSymbol".into(), "KSM".into());
-	properties.insert("tokenDecimals".into(), 12.into());
-
-	let boot_nodes = [
-		"/dns/kusama-coretime-connect-a-0.polkadot.io/tcp/30334/p2p/12D3KooWR7Biy6nPgQFhk2eYP62pAkcFA6he9RUFURTDh7ewTjpo",
-		"/dns/kusama-coretime-connect-a-1.polkadot.io/tcp/30334/p2p/12D3KooWAGFiMZDF9RxdacrkenzGdo8nhfSe9EXofHc5mHeJ9vGX",
-		"/dns/kusama-coretime-connect-a-0.polkadot.io/tcp/443/wss/p2p/12D3KooWR7Biy6nPgQFhk2eYP62pAkcFA6he9RUFURTDh7ewTjpo",
-		"/dns/kusama-coretime-connect-a-1.polkadot.io/tcp/443/wss/p2p/12D3KooWAGFiMZDF9RxdacrkenzGdo8nhfSe9EXofHc5mHeJ9vGX",
-	];
-
-	Ok(Box::new(
-		CoretimeKusamaChainSpec::builder(
-			coretime_kusama_runtime::WASM_BINARY.expect("Kusama Coretime wasm not available!"),
-			Extensions { relay_chain: "kusama".into(), para_id: 1005 },
-		)
-		.with_name("Kusama Coretime")
-		.with_id("coretime-kusama")
-		.with_chain_type(sc_chain_spec::ChainType::Live)
-		.with_genesis_config_preset_name("live")
-		.with_properties(properties)
-		.with_boot_nodes(
-			boot_nodes
-				.iter()
-				.map(|addr| {
-					use std::str::FromStr;
-					sc_network::config::MultiaddrWithPeerId::from_str(addr)
-						.expect("Boot node address is incorrect.")
-				})
-				.collect(),
-		)
-		.build(),
-	))
-}
-
 #[cfg(feature = "coretime-polkadot")]
 pub fn coretime_polkadot_local_testnet_config() -> Result<Box<dyn sc_chain_spec::ChainSpec>, String>
 {
@@ -0,0 +1,634 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The chains supported by the dry-run and how their runtimes are driven.
+
+use crate::{Execution, Ext, Proposal};
+use codec::{Decode, DecodeLimit};
+use frame_support::{
+	storage::{storage_prefix, unhashed},
+	storage_alias,
+	traits::{
+		schedule::{v3::Anon, DispatchTime, HIGHEST_PRIORITY},
+		Get, OnInitialize, QueryPreimage, StorePreimage,
+	},
+	weights::Weight,
+	Blake2_128Concat, Twox64Concat,
+};
+use remote_externalities::{Builder, Mode, OfflineConfig, OnlineConfig, SnapshotConfig};
+use scale_info::{TypeDef, TypeInfo};
+use sp_runtime::traits::BlockNumberProvider;
+use xcm::{latest::prelude::*, VersionedXcm, MAX_XCM_DECODE_DEPTH};
+use xcm_executor::XcmExecutor;
+
+/// The outbound DMP queues of a relay chain, as `(sent_at, message)` per parachain.
+#[storage_alias]
+type DownwardMessageQueues = StorageMap<Dmp, Twox64Concat, u32, Vec<(u32, Vec<u8>)>, ValueQuery>;
+
+/// The outbound UMP queue of a parachain.
+#[storage_alias]
+type PendingUpwardMessages = StorageValue<ParachainSystem, Vec<Vec<u8>>, ValueQuery>;
+
+/// The outbound XCMP queues of a parachain, in pages per recipient.
+#[storage_alias]
+type OutboundXcmpMessages =
+	StorageDoubleMap<XcmpQueue, Blake2_128Concat, u32, Twox64Concat, u16, Vec<u8>, ValueQuery>;
+
+/// Where the state of a chain is loaded from.
+#[derive(Clone, Debug)]
+pub enum StateSource {
+	/// A snapshot file, e.g. created with `try-runtime create-snapshot`.
+	Snapshot(std::path::PathBuf),
+	/// A node the state is downloaded from.
+	Uri(String),
+	/// The genesis of the chain in the emulated integration tests.
+	Genesis,
+}
+
+/// The chains whose runtimes are part of the dry-run.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, clap::ValueEnum)]
+pub enum Chain {
+	Polkadot,
+	Kusama,
+	AssetHubPolkadot,
+	AssetHubKusama,
+	CollectivesPolkadot,
+	BridgeHubPolkadot,
+	BridgeHubKusama,
+	CoretimePolkadot,
+	CoretimeKusama,
+	PeoplePolkadot,
+	PeopleKusama,
+	BulletinPolkadot,
+	EncointerKusama,
+}
+
+/// Runs `$body` with `$module` being the module of `$chain`.
+macro_rules! with_chain {
+	($chain:expr, $module:ident => $body:expr) => {
+		match $chain {
+			Chain::Polkadot => {
+				use polkadot as $module;
+				$body
+			},
+			Chain::Kusama => {
+				use kusama as $module;
+				$body
+			},
+			Chain::AssetHubPolkadot => {
+				use asset_hub_polkadot as $module;
+				$body
+			},
+			Chain::AssetHubKusama => {
+				use asset_hub_kusama as $module;
+				$body
+			},
+			Chain::CollectivesPolkadot => {
+				use collectives_polkadot as $module;
+				$body
+			},
+			Chain::BridgeHubPolkadot => {
+				use bridge_hub_polkadot as $module;
+				$body
+			},
+			Chain::BridgeHubKusama => {
+				use bridge_hub_kusama as $module;
+				$body
+			},
+			Chain::CoretimePolkadot => {
+				use coretime_polkadot as $module;
+				$body
+			},
+			Chain::CoretimeKusama => {
+				use coretime_kusama as $module;
+				$body
+			},
+			Chain::PeoplePolkadot => {
+				use people_polkadot as $module;
+				$body
+			},
+			Chain::PeopleKusama => {
+				use people_kusama as $module;
+				$body
+			},
+			Chain::BulletinPolkadot => {
+				use bulletin_polkadot as $module;
+				$body
+			},
+			Chain::EncointerKusama => {
+				use encointer_kusama as $module;
+				$body
+			},
+		}
+	};
+}
+
+impl Chain {
+	/// The name of the chain on the command line.
+	pub fn name(self) -> String {
+		use clap::ValueEnum;
+		self.to_possible_value().expect("No variant is skipped; qed").get_name().into()
+	}
+
+	/// The global consensus and the para id of the chain.
+	fn location(self) -> (NetworkId, Option<u32>) {
+		match self {
+			Chain::Polkadot => (NetworkId::Polkadot, None),
+			Chain::Kusama => (NetworkId::Kusama, None),
+			Chain::AssetHubPolkadot => (NetworkId::Polkadot, Some(1000)),
+			Chain::AssetHubKusama => (NetworkId::Kusama, Some(1000)),
+			Chain::CollectivesPolkadot => (NetworkId::Polkadot, Some(1001)),
+			Chain::EncointerKusama => (NetworkId::Kusama, Some(1001)),
+			Chain::BridgeHubPolkadot => (NetworkId::Polkadot, Some(1002)),
+			Chain::BridgeHubKusama => (NetworkId::Kusama, Some(1002)),
+			Chain::PeoplePolkadot => (NetworkId::Polkadot, Some(1004)),
+			Chain::PeopleKusama => (NetworkId::Kusama, Some(1004)),
+			Chain::CoretimePolkadot => (NetworkId::Polkadot, Some(1005)),
+			Chain::CoretimeKusama => (NetworkId::Kusama, Some(1005)),
+			Chain::BulletinPolkadot => (NetworkId::Polkadot, Some(1010)),
+		}
+	}
+
+	/// Resolves the `destination` of a message sent by this chain.
+	///
+	/// Returns the receiving chain and the origin of the message as seen by the receiver.
+	pub fn resolve(self, destination: &Location) -> Option<(Chain, Location)> {
+		let universal = with_chain!(self, chain => chain::UniversalLocation::get());
+		let origin = universal.invert_target(destination).ok()?;
+
+		let mut absolute = universal.into_iter().collect::<Vec<_>>();
+		let parents = destination.parent_count() as usize;
+		// Leaving the global consensus is not supported, e.g. bridged messages.
+		if parents >= absolute.len() {
+			return None
+		}
+		absolute.truncate(absolute.len() - parents);
+		absolute.extend(destination.interior().iter().cloned());
+
+		let location = match absolute.as_slice() {
+			[GlobalConsensus(network)] => (*network, None),
+			[GlobalConsensus(network), Parachain(para_id)] => (*network, Some(*para_id)),
+			_ => return None,
+		};
+		let receiver = <Chain as clap::ValueEnum>::value_variants()
+			.iter()
+			.find(|chain| chain.location() == location)?;
+		Some((*receiver, origin))
+	}
+
+	/// Loads the state of the chain.
+	pub async fn load(self, source: &StateSource) -> Result<Ext, String> {
+		with_chain!(self, chain => chain::load(source).await)
+	}
+
+	/// Dispatches `proposal` with `origin` through the scheduler of the chain.
+	pub fn dispatch_proposal(self, proposal: &Proposal, origin: &str) -> Result<Execution, String> {
+		match self {
+			Chain::Polkadot => polkadot::dispatch_proposal(proposal, origin),
+			Chain::Kusama => kusama::dispatch_proposal(proposal, origin),
+			Chain::AssetHubPolkadot => asset_hub_polkadot::dispatch_proposal(proposal, origin),
+			Chain::AssetHubKusama => asset_hub_kusama::dispatch_proposal(proposal, origin),
+			Chain::CollectivesPolkadot => collectives_polkadot::dispatch_proposal(proposal, origin),
+			chain => Err(format!("{} has no governance to dispatch proposals with", chain.name())),
+		}
+	}
+
+	/// Executes an XCM `message` received from `origin`.
+	pub fn execute_xcm(self, origin: Location, message: &[u8]) -> Execution {
+		with_chain!(self, chain => chain::execute_xcm(origin, message))
+	}
+
+	/// The metadata of the runtime of the chain.
+	pub fn metadata(self) -> frame_metadata::RuntimeMetadataPrefixed {
+		with_chain!(self, chain => chain::Runtime::metadata())
+	}
+}
+
+/// Takes the messages of the outbound UMP, DMP and XCMP queues, like the emulator does after
+/// every block.
+///
+/// Returns the destination relative to the sending chain and the encoded `VersionedXcm` of every
+/// message. Relay chains only have DMP queues and parachains only UMP and XCMP queues, the others
+/// are empty.
+fn take_outbound_messages() -> Vec<(Location, Vec<u8>)> {
+	let mut messages = PendingUpwardMessages::take()
+		.into_iter()
+		.map(|message| (Location::parent(), message))
+		.collect::<Vec<_>>();
+
+	let mut queues = DownwardMessageQueues::drain().collect::<Vec<_>>();
+	queues.sort_by_key(|(para, _)| *para);
+	for (para, queue) in queues {
+		messages.extend(
+			queue
+				.into_iter()
+				.map(|(_, message)| (Location::new(0, [Parachain(para)]), message)),
+		);
+	}
+
+	let mut pages = OutboundXcmpMessages::drain().collect::<Vec<_>>();
+	pages.sort_by_key(|(para, index, _)| (*para, *index));
+	// The channels are reset together with their pages.
+	unhashed::kill(&storage_prefix(b"XcmpQueue", b"OutboundXcmpStatus"));
+	for (para, _, page) in pages {
+		messages.extend(
+			split_xcmp_page(&page)
+				.into_iter()
+				.map(|message| (Location::new(1, [Parachain(para)]), message)),
+		);
+	}
+	messages
+}
+
+/// Splits an XCMP page into the encoded `VersionedXcm` it is made of.
+///
+/// Only pages of the `ConcatenatedVersionedXcm` format carry XCM. The rest of a page that can't be
+/// decoded is returned as a single message, so that it is reported when it is executed.
+fn split_xcmp_page(page: &[u8]) -> Vec<Vec<u8>> {
+	// The index of `XcmpMessageFormat::ConcatenatedVersionedXcm`.
+	let Some((0, mut data)) = page.split_first() else { return Vec::new() };
+	let mut messages = Vec::new();
+	while !data.is_empty() {
+		let remaining = data;
+		if VersionedXcm::<()>::decode_with_depth_limit(MAX_XCM_DECODE_DEPTH, &mut data).is_err() {
+			messages.push(remaining.to_vec());
+			break
+		}
+		messages.push(remaining[..remaining.len() - data.len()].to_vec());
+	}
+	messages
+}
+
+/// Returns the destinations of the messages reported as sent by `pallet-xcm` that are not among
+/// the `queued` messages, e.g. messages that are exported over a bridge.
+fn not_queued(sent: Vec<Location>, queued: &[(Location, Vec<u8>)]) -> Vec<Location> {
+	let mut queued = queued.iter().map(|(destination, _)| destination).collect::<Vec<_>>();
+	sent.into_iter()
+		.filter(|destination| {
+			let Some(index) = queued.iter().position(|queued| *queued == destination) else {
+				return true
+			};
+			queued.swap_remove(index);
+			false
+		})
+		.collect()
+}
+
+/// Finds the unit variant called `name` of the origin enum `O`.
+fn origin_from_name<O: TypeInfo + Decode>(name: &str) -> Option<O> {
+	let TypeDef::Variant(def) = O::type_info().type_def else { return None };
+	let variant = def.variants.iter().find(|v| v.name == name && v.fields.is_empty())?;
+	O::decode(&mut &[variant.index][..]).ok()
+}
+
+/// Implements the module of a chain.
+///
+/// Chains with a `governance` section can dispatch proposals through their scheduler with the
+/// given origins. `relay_block_number` is set if the scheduler uses the relay chain block number.
+macro_rules! impl_chain {
+	(
+		$module:ident, $runtime:ident, $emulated:ident, $xcm_pallet:ident
+		$(, governance { origins: [$($origin:ty),*], relay_block_number: $relay:literal })?
+	) => {
+		pub mod $module {
+			use super::*;
+			pub use $runtime::{
+				xcm_config::{UniversalLocation, XcmConfig},
+				Block, Runtime, RuntimeCall, RuntimeEvent,
+			};
+
+			pub fn genesis() -> sp_core::storage::Storage {
+				$emulated::genesis::genesis()
+			}
+
+			pub async fn load(source: &StateSource) -> Result<Ext, String> {
+				let mode = match source {
+					StateSource::Snapshot(path) => Mode::Offline(OfflineConfig {
+						state_snapshot: SnapshotConfig::new(path.to_string_lossy()),
+					}),
+					StateSource::Uri(uri) => Mode::Online(OnlineConfig {
+						transport_uris: vec![uri.clone()],
+						..Default::default()
+					}),
+					StateSource::Genesis => {
+						let mut ext = Ext::new(genesis());
+						// Events are not deposited in the genesis block.
+						ext.execute_with(|| frame_system::Pallet::<Runtime>::set_block_number(1));
+						return Ok(ext)
+					},
+				};
+				let ext = Builder::<Block>::default()
+					.mode(mode)
+					.build()
+					.await
+					.map_err(|e| format!("Failed to load the state: {e:?}"))?;
+				Ok(ext.inner_ext)
+			}
+
+			pub fn execute_xcm(origin: Location, message_data: &[u8]) -> Execution {
+				frame_system::Pallet::<Runtime>::reset_events();
+				let message = match VersionedXcm::<RuntimeCall>::decode_all_with_depth_limit(
+					MAX_XCM_DECODE_DEPTH,
+					&mut &message_data[..],
+				)
+				.map_err(|e| format!("{e:?}"))
+				.and_then(|message| {
+					Xcm::try_from(message).map_err(|_| "Unsupported XCM version".into())
+				}) {
+					Ok(message) => message,
+					Err(e) => return Execution::failed(format!("Failed to decode XCM: {e}")),
+				};
+				let mut hash = sp_io::hashing::blake2_256(message_data);
+				let outcome = XcmExecutor::<XcmConfig>::prepare_and_execute(
+					origin,
+					message,
+					&mut hash,
+					Weight::MAX,
+					Weight::zero(),
+				);
+				let result = outcome.clone().ensure_complete().map_err(|e| format!("{e:?}"));
+				collect(result, outcome.weight_used())
+			}
+
+			/// Collects the events and takes the sent XCM from the outbound queues of the current
+			/// block.
+			fn collect(result: Result<(), String>, weight: Weight) -> Execution {
+				let sent = take_outbound_messages();
+				let mut reported = Vec::new();
+				let mut events = Vec::new();
+				for record in frame_system::Pallet::<Runtime>::events() {
+					if let RuntimeEvent::$xcm_pallet(pallet_xcm::Event::Sent {
+						destination, ..
+					}) = &record.event
+					{
+						reported.push(destination.clone());
+					}
+					events.push(format!("{:?}", record.event));
+				}
+				let not_queued = not_queued(reported, &sent);
+				Execution { result, weight, events, sent, not_queued }
+			}
+
+			$(
+				pub fn dispatch_proposal(
+					proposal: &Proposal,
+					origin: &str,
+				) -> Result<Execution, String> {
+					type Preimages = <Runtime as pallet_scheduler::Config>::Preimages;
+					type Scheduler = pallet_scheduler::Pallet<Runtime>;
+
+					let call = match proposal {
+						Proposal::Call(data) => {
+							let call = RuntimeCall::decode(&mut &data[..])
+								.map_err(|e| format!("Failed to decode call: {e:?}"))?;
+							Preimages::bound(call)
+								.map_err(|e| format!("Failed to note preimage: {e:?}"))?
+						},
+						Proposal::Preimage(hash) => {
+							let hash = (*hash).into();
+							let len = Preimages::len(&hash).ok_or("Preimage not found")?;
+							frame_support::traits::Bounded::Lookup { hash, len }
+						},
+					};
+					type AccountId = <Runtime as frame_system::Config>::AccountId;
+					type PalletsOrigin = <Runtime as pallet_scheduler::Config>::PalletsOrigin;
+
+					let origin: PalletsOrigin = if origin == "Root" {
+						frame_system::RawOrigin::<AccountId>::Root.into()
+					} else {
+						None$(.or_else(|| origin_from_name::<$origin>(origin).map(Into::into)))*
+							.ok_or_else(|| format!("Unknown origin '{origin}'"))?
+					};
+
+					type Provider = <Runtime as pallet_scheduler::Config>::BlockNumberProvider;
+					let when = Provider::current_block_number() + 1;
+					<Scheduler as Anon<_, RuntimeCall, PalletsOrigin>>::schedule(
+						DispatchTime::At(when),
+						None,
+						HIGHEST_PRIORITY,
+						origin,
+						call,
+					)
+					.map_err(|e| format!("Failed to schedule the proposal: {e:?}"))?;
+
+					// Advance to the block the proposal is scheduled for.
+					let block_number = frame_system::Pallet::<Runtime>::block_number() + 1;
+					frame_system::Pallet::<Runtime>::set_block_number(block_number);
+					if $relay {
+						// What the parachain system does for a new block with a new relay parent.
+						unhashed::kill(&storage_prefix(b"ParachainSystem", b"ValidationData"));
+						unhashed::put(
+							&storage_prefix(b"ParachainSystem", b"LastRelayChainBlockNumber"),
+							&when,
+						);
+					}
+					frame_system::Pallet::<Runtime>::reset_events();
+					let weight = Scheduler::on_initialize(block_number);
+
+					let mut execution = collect(Ok(()), weight);
+					execution.result = frame_system::Pallet::<Runtime>::events()
+						.into_iter()
+						.find_map(|record| match record.event {
+							RuntimeEvent::Scheduler(pallet_scheduler::Event::Dispatched {
+								result,
+								..
+							}) => Some(result.map_err(|e| format!("{e:?}"))),
+							_ => None,
+						})
+						.unwrap_or_else(|| Err("The proposal was not dispatched".into()));
+					Ok(execution)
+				}
+			)?
+		}
+	};
+}
+
+impl_chain!(
+	polkadot,
+	polkadot_runtime,
+	polkadot_emulated_chain,
+	XcmPallet,
+	governance {
+		origins: [polkadot_runtime::governance::pallet_custom_origins::Origin],
+		relay_block_number: false
+	}
+);
+impl_chain!(
+	kusama,
+	kusama_runtime,
+	kusama_emulated_chain,
+	XcmPallet,
+	governance {
+		origins: [kusama_runtime::governance::pallet_custom_origins::Origin],
+		relay_block_number: false
+	}
+);
+impl_chain!(
+	asset_hub_polkadot,
+	asset_hub_polkadot_runtime,
+	asset_hub_polkadot_emulated_chain,
+	PolkadotXcm,
+	governance {
+		origins: [asset_hub_polkadot_runtime::governance::pallet_custom_origins::Origin],
+		relay_block_number: true
+	}
+);
+impl_chain!(
+	asset_hub_kusama,
+	asset_hub_kusama_runtime,
+	asset_hub_kusama_emulated_chain,
+	PolkadotXcm,
+	governance {
+		origins: [asset_hub_kusama_runtime::governance::pallet_custom_origins::Origin],
+		relay_block_number: true
+	}
+);
+impl_chain!(
+	collectives_polkadot,
+	collectives_polkadot_runtime,
+	collectives_polkadot_emulated_chain,
+	PolkadotXcm,
+	governance {
+		origins: [collectives_polkadot_runtime::fellowship::pallet_fellowship_origins::Origin],
+		relay_block_number: false
+	}
+);
+impl_chain!(
+	bridge_hub_polkadot,
+	bridge_hub_polkadot_runtime,
+	bridge_hub_polkadot_emulated_chain,
+	PolkadotXcm
+);
+impl_chain!(
+	bridge_hub_kusama,
+	bridge_hub_kusama_runtime,
+	bridge_hub_kusama_emulated_chain,
+	PolkadotXcm
+);
+impl_chain!(
+	coretime_polkadot,
+	coretime_polkadot_runtime,
+	coretime_polkadot_emulated_chain,
+	PolkadotXcm
+);
+impl_chain!(coretime_kusama, coretime_kusama_runtime, coretime_kusama_emulated_chain, PolkadotXcm);
+impl_chain!(people_polkadot, people_polkadot_runtime, people_polkadot_emulated_chain, PolkadotXcm);
+impl_chain!(people_kusama, people_kusama_runtime, people_kusama_emulated_chain, PolkadotXcm);
+impl_chain!(
+	bulletin_polkadot,
+	bulletin_polkadot_runtime,
+	bulletin_polkadot_emulated_chain,
+	PolkadotXcm
+);
+impl_chain!(
+	encointer_kusama,
+	encointer_kusama_runtime,
+	encointer_kusama_emulated_chain,
+	PolkadotXcm
+);
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use codec::Encode;
+
+	#[test]
+	fn resolve_relay_to_para() {
+		assert_eq!(
+			Chain::Polkadot.resolve(&Location::new(0, [Parachain(1000)])),
+			Some((Chain::AssetHubPolkadot, Location::parent())),
+		);
+		assert_eq!(
+			Chain::Kusama.resolve(&Location::new(0, [Parachain(1005)])),
+			Some((Chain::CoretimeKusama, Location::parent())),
+		);
+		assert_eq!(Chain::Polkadot.resolve(&Location::new(0, [Parachain(2000)])), None);
+	}
+
+	#[test]
+	fn resolve_para_to_relay_and_para() {
+		assert_eq!(
+			Chain::CollectivesPolkadot.resolve(&Location::new(1, [Parachain(1000)])),
+			Some((Chain::AssetHubPolkadot, Location::new(1, [Parachain(1001)]))),
+		);
+		assert_eq!(
+			Chain::AssetHubKusama.resolve(&Location::new(1, [Parachain(1001)])),
+			Some((Chain::EncointerKusama, Location::new(1, [Parachain(1000)]))),
+		);
+		assert_eq!(
+			Chain::AssetHubPolkadot.resolve(&Location::parent()),
+			Some((Chain::Polkadot, Location::new(0, [Parachain(1000)]))),
+		);
+		// Bridged destinations are not followed.
+		assert_eq!(
+			Chain::BridgeHubPolkadot
+				.resolve(&Location::new(2, [GlobalConsensus(NetworkId::Kusama), Parachain(1000)])),
+			None,
+		);
+	}
+
+	#[test]
+	fn xcmp_pages_are_split_into_messages() {
+		let message = |data: &[u8]| {
+			VersionedXcm::<()>::from(Xcm::<()>(vec![ClearTopic, SetTopic([data[0]; 32])])).encode()
+		};
+		let (first, second) = (message(&[1]), message(&[2]));
+
+		assert_eq!(
+			split_xcmp_page(&[&[0][..], &first, &second].concat()),
+			vec![first.clone(), second.clone()],
+		);
+		// Pages of other formats carry no XCM.
+		assert!(split_xcmp_page(&[&[2][..], &first].concat()).is_empty());
+		assert!(split_xcmp_page(&[]).is_empty());
+		// The undecodable rest of a page is kept.
+		assert_eq!(
+			split_xcmp_page(&[&[0][..], &first, &[0xff, 0xff]].concat()),
+			vec![first, vec![0xff, 0xff]],
+		);
+	}
+
+	#[test]
+	fn messages_that_are_not_queued_are_found() {
+		let sibling = Location::new(1, [Parachain(1000)]);
+		let bridged = Location::new(2, [GlobalConsensus(NetworkId::Kusama), Parachain(1000)]);
+		let queued = [(sibling.clone(), vec![1]), (Location::parent(), vec![2])];
+
+		assert_eq!(
+			not_queued(vec![sibling.clone(), bridged.clone(), Location::parent()], &queued),
+			vec![bridged],
+		);
+		// Every queued message matches only one reported message.
+		assert_eq!(not_queued(vec![sibling.clone(), sibling.clone()], &queued), vec![sibling]);
+	}
+
+	#[test]
+	fn origin_from_name_finds_unit_variants() {
+		use collectives_polkadot_runtime::fellowship::pallet_fellowship_origins::Origin as FellowshipOrigin;
+		use polkadot_runtime::governance::pallet_custom_origins::Origin as PolkadotOrigin;
+
+		assert_eq!(
+			origin_from_name::<FellowshipOrigin>("Fellows"),
+			Some(FellowshipOrigin::Fellows)
+		);
+		assert_eq!(
+			origin_from_name::<PolkadotOrigin>("WhitelistedCaller"),
+			Some(PolkadotOrigin::WhitelistedCaller),
+		);
+		assert_eq!(origin_from_name::<PolkadotOrigin>("Fellows"), None);
+		assert_eq!(origin_from_name::<FellowshipOrigin>("Root"), None);
+	}
+}
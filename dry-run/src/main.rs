@@ -0,0 +1,286 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Dry-run of governance proposals.
+//!
+//! Loads the state of a chain from a snapshot (or a node), schedules the proposal with the given
+//! origin, runs the scheduler and follows the XCM in the outbound UMP, DMP and XCMP queues into the
+//! receiving chains. Receiving chains are loaded from their own snapshots or start from the genesis
+//! of the emulated integration tests. Everything is executed natively, no node is required.
+
+use chains::{Chain, StateSource};
+use clap::Parser;
+use frame_support::weights::Weight;
+use report::{Report, Step};
+use std::{
+	collections::{BTreeMap, VecDeque},
+	path::PathBuf,
+};
+use xcm::latest::Location;
+
+mod chains;
+mod report;
+
+/// The externalities all chains are executed in.
+pub type Ext = sp_state_machine::TestExternalities<sp_runtime::traits::BlakeTwo256>;
+
+/// The proposal to dispatch.
+pub enum Proposal {
+	/// A SCALE encoded call, noted as preimage if it is too large to be inlined.
+	Call(Vec<u8>),
+	/// The hash of a preimage that is noted on the chain.
+	Preimage([u8; 32]),
+}
+
+/// The outcome of executing a proposal or an XCM on a chain.
+pub struct Execution {
+	/// Whether the proposal or the XCM was executed successfully.
+	pub result: Result<(), String>,
+	/// The weight used by the execution.
+	pub weight: Weight,
+	/// The events that were deposited.
+	pub events: Vec<String>,
+	/// The destination and the encoded `VersionedXcm` of every message that was taken from the
+	/// outbound UMP, DMP and XCMP queues.
+	pub sent: Vec<(Location, Vec<u8>)>,
+	/// The destinations of the XCM that `pallet-xcm` reported as sent, but that are not in the
+	/// outbound queues, e.g. messages exported over a bridge.
+	pub not_queued: Vec<Location>,
+}
+
+impl Execution {
+	fn failed(error: String) -> Self {
+		Execution {
+			result: Err(error),
+			weight: Weight::zero(),
+			events: Vec::new(),
+			sent: Vec::new(),
+			not_queued: Vec::new(),
+		}
+	}
+}
+
+#[derive(Parser)]
+struct Cli {
+	/// The chain that enacts the proposal.
+	#[arg(long, value_enum)]
+	chain: Chain,
+
+	/// Snapshot of the state of the chain, e.g. created with `try-runtime create-snapshot`.
+	#[arg(long, required_unless_present = "uri", conflicts_with = "uri")]
+	snapshot: Option<PathBuf>,
+
+	/// Node to download the state of the chain from instead of a snapshot.
+	#[arg(long)]
+	uri: Option<String>,
+
+	/// Hex encoded call to dispatch.
+	#[arg(long, required_unless_present = "preimage_hash", conflicts_with = "preimage_hash")]
+	call: Option<String>,
+
+	/// Hash of a preimage noted on the chain to dispatch.
+	#[arg(long)]
+	preimage_hash: Option<String>,
+
+	/// Origin the scheduler dispatches the proposal with, e.g. `Root`, `WhitelistedCaller`,
+	/// `Treasurer` or `Fellows`.
+	#[arg(long, default_value = "Root")]
+	origin: String,
+
+	/// Snapshot of a chain that receives XCM, as `<chain>=<path>`. Chains without a snapshot start
+	/// from the genesis of the emulated integration tests.
+	#[arg(long = "follow-snapshot", value_parser = parse_follow_snapshot)]
+	follow_snapshots: Vec<(Chain, PathBuf)>,
+
+	/// The maximum number of XCM hops that are followed.
+	#[arg(long, default_value_t = 4)]
+	max_hops: u32,
+
+	/// Print the report as JSON.
+	#[arg(long)]
+	json: bool,
+}
+
+fn parse_follow_snapshot(arg: &str) -> Result<(Chain, PathBuf), String> {
+	let (chain, path) = arg.split_once('=').ok_or("Expected `<chain>=<path>`")?;
+	let chain = <Chain as clap::ValueEnum>::from_str(chain, true)?;
+	Ok((chain, path.into()))
+}
+
+/// Dispatches `proposal` with `origin` on `chain` and follows the sent XCM up to `max_hops` hops.
+async fn dry_run(
+	chain: Chain,
+	source: &StateSource,
+	proposal: &Proposal,
+	origin: &str,
+	follow_snapshots: &BTreeMap<Chain, PathBuf>,
+	max_hops: u32,
+) -> Result<Report, String> {
+	let mut exts = BTreeMap::from([(chain, chain.load(source).await?)]);
+	let execution = exts
+		.get_mut(&chain)
+		.expect("Inserted above; qed")
+		.execute_with(|| chain.dispatch_proposal(proposal, origin))?;
+
+	let mut report = Report::default();
+	let mut pending = execution
+		.sent
+		.iter()
+		.map(|(destination, message)| (chain, destination.clone(), message.clone(), 1))
+		.collect::<VecDeque<_>>();
+	report.not_queued(chain, &execution);
+	report.steps.push(Step { chain, origin: origin.into(), execution });
+
+	while let Some((sender, destination, message, hop)) = pending.pop_front() {
+		let Some((receiver, origin)) = sender.resolve(&destination) else {
+			report.not_followed.push((sender, destination, "unknown destination"));
+			continue
+		};
+		if hop > max_hops {
+			report.not_followed.push((sender, destination, "too many hops"));
+			continue
+		}
+
+		if !exts.contains_key(&receiver) {
+			let source = follow_snapshots
+				.get(&receiver)
+				.map_or(StateSource::Genesis, |path| StateSource::Snapshot(path.clone()));
+			exts.insert(receiver, receiver.load(&source).await?);
+		}
+		let execution = exts
+			.get_mut(&receiver)
+			.expect("Inserted above; qed")
+			.execute_with(|| receiver.execute_xcm(origin.clone(), &message));
+		pending.extend(execution.sent.iter().map(|(destination, message)| {
+			(receiver, destination.clone(), message.clone(), hop + 1)
+		}));
+		report.not_queued(receiver, &execution);
+		report
+			.steps
+			.push(Step { chain: receiver, origin: format!("{origin:?}"), execution });
+	}
+
+	for (chain, ext) in &exts {
+		report.storage.push((*chain, report::storage_diff(*chain, ext)));
+	}
+	Ok(report)
+}
+
+#[tokio::main]
+async fn main() -> Result<(), String> {
+	let cli = Cli::parse();
+
+	let proposal = match (&cli.call, &cli.preimage_hash) {
+		(Some(call), _) => Proposal::Call(
+			array_bytes::hex2bytes(call).map_err(|e| format!("Invalid call: {e:?}"))?,
+		),
+		(None, Some(hash)) => Proposal::Preimage(
+			array_bytes::hex2array(hash).map_err(|e| format!("Invalid preimage hash: {e:?}"))?,
+		),
+		(None, None) => return Err("Either `--call` or `--preimage-hash` is required".into()),
+	};
+	let source = match (&cli.snapshot, &cli.uri) {
+		(Some(path), _) => StateSource::Snapshot(path.clone()),
+		(None, Some(uri)) => StateSource::Uri(uri.clone()),
+		(None, None) => return Err("Either `--snapshot` or `--uri` is required".into()),
+	};
+	let follow_snapshots = cli.follow_snapshots.into_iter().collect::<BTreeMap<_, _>>();
+
+	let report =
+		dry_run(cli.chain, &source, &proposal, &cli.origin, &follow_snapshots, cli.max_hops)
+			.await?;
+
+	if cli.json {
+		println!("{}", report.to_json());
+	} else {
+		print!("{report}");
+	}
+
+	if report.steps.iter().any(|step| step.execution.result.is_err()) {
+		return Err("The proposal or one of the resulting XCM failed".into())
+	}
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use codec::Encode;
+	use xcm::latest::prelude::*;
+
+	#[tokio::test]
+	async fn root_remark_is_dispatched_on_genesis_state() {
+		let remark = polkadot_runtime::RuntimeCall::System(frame_system::Call::remark {
+			remark: b"dry-run".to_vec(),
+		});
+
+		let report = dry_run(
+			Chain::Polkadot,
+			&StateSource::Genesis,
+			&Proposal::Call(remark.encode()),
+			"Root",
+			&BTreeMap::new(),
+			4,
+		)
+		.await
+		.unwrap();
+
+		assert_eq!(report.steps.len(), 1);
+		assert_eq!(report.steps[0].chain, Chain::Polkadot);
+		assert_eq!(report.steps[0].execution.result, Ok(()));
+		assert!(report.steps[0].execution.sent.is_empty());
+		assert!(report.not_followed.is_empty());
+	}
+
+	#[tokio::test]
+	async fn sent_xcm_is_followed_into_the_receiving_chain() {
+		let remark = polkadot_runtime::RuntimeCall::System(frame_system::Call::remark_with_event {
+			remark: b"dry-run".to_vec(),
+		});
+		let send = asset_hub_polkadot_runtime::RuntimeCall::PolkadotXcm(pallet_xcm::Call::send {
+			dest: Box::new(Location::parent().into()),
+			message: Box::new(xcm::VersionedXcm::from(Xcm::<()>(vec![
+				UnpaidExecution { weight_limit: Unlimited, check_origin: None },
+				Transact {
+					origin_kind: OriginKind::SovereignAccount,
+					fallback_max_weight: None,
+					call: remark.encode().into(),
+				},
+			]))),
+		});
+
+		let report = dry_run(
+			Chain::AssetHubPolkadot,
+			&StateSource::Genesis,
+			&Proposal::Call(send.encode()),
+			"Root",
+			&BTreeMap::new(),
+			1,
+		)
+		.await
+		.unwrap();
+
+		assert_eq!(report.steps.len(), 2);
+		assert_eq!(report.steps[0].execution.result, Ok(()));
+		assert_eq!(report.steps[0].execution.sent.len(), 1);
+		let hop = &report.steps[1];
+		assert_eq!(hop.chain, Chain::Polkadot);
+		assert_eq!(hop.origin, format!("{:?}", Location::new(0, [Parachain(1000)])));
+		assert_eq!(hop.execution.result, Ok(()));
+		assert!(hop.execution.events.iter().any(|event| event.contains("Remarked")));
+		assert!(report.not_followed.is_empty());
+	}
+}
@@ -0,0 +1,207 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The report of a dry-run.
+
+use crate::{chains::Chain, Execution, Ext};
+use frame_metadata::{RuntimeMetadata, RuntimeMetadataPrefixed};
+use serde_json::{json, Value};
+use sp_core::hashing::twox_128;
+use sp_state_machine::Backend;
+use std::{collections::BTreeMap, fmt};
+use xcm::latest::Location;
+
+/// Storage items that are not part of the storage diff, the events are reported separately.
+const IGNORED_ITEMS: &[&str] = &["System::Events", "System::EventCount", "System::EventTopics"];
+
+/// The execution of the proposal or of an XCM on a chain.
+pub struct Step {
+	/// The chain the step was executed on.
+	pub chain: Chain,
+	/// The origin of the proposal or of the XCM.
+	pub origin: String,
+	/// The outcome of the step.
+	pub execution: Execution,
+}
+
+/// A storage value that was changed by the dry-run.
+pub struct StorageChange {
+	/// The hex encoded key.
+	pub key: String,
+	/// The `Pallet::Item` the key belongs to, if known.
+	pub item: Option<String>,
+	/// The hex encoded value before the dry-run.
+	pub old: Option<String>,
+	/// The hex encoded value after the dry-run.
+	pub new: Option<String>,
+}
+
+#[derive(Default)]
+pub struct Report {
+	/// The proposal followed by every executed XCM.
+	pub steps: Vec<Step>,
+	/// XCM that were not followed, with their sender, destination and the reason.
+	pub not_followed: Vec<(Chain, Location, &'static str)>,
+	/// The storage changes of every chain.
+	pub storage: Vec<(Chain, Vec<StorageChange>)>,
+}
+
+impl Report {
+	/// Adds the XCM that `sender` reported as sent in `execution`, but that are not in its
+	/// outbound queues, to the XCM that were not followed.
+	pub fn not_queued(&mut self, sender: Chain, execution: &Execution) {
+		self.not_followed.extend(
+			execution
+				.not_queued
+				.iter()
+				.map(|destination| (sender, destination.clone(), "not in an outbound queue")),
+		);
+	}
+
+	pub fn to_json(&self) -> Value {
+		let steps = self
+			.steps
+			.iter()
+			.map(|step| {
+				json!({
+					"chain": step.chain.name(),
+					"origin": step.origin,
+					"result": match &step.execution.result {
+						Ok(()) => Value::String("Ok".into()),
+						Err(e) => json!({ "Err": e }),
+					},
+					"weight": {
+						"refTime": step.execution.weight.ref_time(),
+						"proofSize": step.execution.weight.proof_size(),
+					},
+					"events": step.execution.events,
+					"sent": step
+						.execution
+						.sent
+						.iter()
+						.map(|(destination, _)| format!("{destination:?}"))
+						.collect::<Vec<_>>(),
+				})
+			})
+			.collect::<Vec<_>>();
+		let not_followed = self
+			.not_followed
+			.iter()
+			.map(|(sender, destination, reason)| {
+				json!({
+					"sender": sender.name(),
+					"destination": format!("{destination:?}"),
+					"reason": reason,
+				})
+			})
+			.collect::<Vec<_>>();
+		let storage = self
+			.storage
+			.iter()
+			.map(|(chain, changes)| {
+				let changes = changes
+					.iter()
+					.map(|change| {
+						json!({
+							"key": change.key,
+							"item": change.item,
+							"old": change.old,
+							"new": change.new,
+						})
+					})
+					.collect::<Vec<_>>();
+				(chain.name(), Value::Array(changes))
+			})
+			.collect::<serde_json::Map<_, _>>();
+
+		json!({ "steps": steps, "notFollowed": not_followed, "storage": storage })
+	}
+}
+
+impl fmt::Display for Report {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		for step in &self.steps {
+			writeln!(f, "=== {} (origin: {}) ===", step.chain.name(), step.origin)?;
+			writeln!(f, "Result: {:?}", step.execution.result)?;
+			writeln!(
+				f,
+				"Weight: ref_time {}, proof_size {}",
+				step.execution.weight.ref_time(),
+				step.execution.weight.proof_size()
+			)?;
+			writeln!(f, "Events:")?;
+			for event in &step.execution.events {
+				writeln!(f, "  {event}")?;
+			}
+			for (destination, _) in &step.execution.sent {
+				writeln!(f, "Sent XCM to {destination:?}")?;
+			}
+			writeln!(f)?;
+		}
+		for (sender, destination, reason) in &self.not_followed {
+			writeln!(f, "Not followed: XCM from {} to {destination:?} ({reason})", sender.name())?;
+		}
+		for (chain, changes) in &self.storage {
+			writeln!(f, "=== Storage changes of {} ===", chain.name())?;
+			for change in changes {
+				let item = change.item.as_deref().unwrap_or("unknown");
+				writeln!(f, "  {item} {}", change.key)?;
+				writeln!(f, "    old: {}", change.old.as_deref().unwrap_or("none"))?;
+				writeln!(f, "    new: {}", change.new.as_deref().unwrap_or("none"))?;
+			}
+		}
+		Ok(())
+	}
+}
+
+/// Returns the storage changes of `ext` compared to the state it was loaded with.
+pub fn storage_diff(chain: Chain, ext: &Ext) -> Vec<StorageChange> {
+	let items = storage_items(chain.metadata());
+	let mut overlay = ext.overlayed_changes().clone();
+	let mut changes = overlay
+		.changes_mut()
+		.filter_map(|(key, value)| {
+			let new = value.value().cloned();
+			let old = ext.backend.storage(key).ok().flatten();
+			let item = key.get(..32).and_then(|prefix| items.get(prefix)).cloned();
+			if old == new || item.as_deref().is_some_and(|item| IGNORED_ITEMS.contains(&item)) {
+				return None
+			}
+			Some(StorageChange {
+				key: array_bytes::bytes2hex("0x", key),
+				item,
+				old: old.map(|value| array_bytes::bytes2hex("0x", value)),
+				new: new.map(|value| array_bytes::bytes2hex("0x", value)),
+			})
+		})
+		.collect::<Vec<_>>();
+	changes.sort_by(|a, b| a.key.cmp(&b.key));
+	changes
+}
+
+/// Maps the key prefix of every storage item to its `Pallet::Item` name.
+fn storage_items(metadata: RuntimeMetadataPrefixed) -> BTreeMap<Vec<u8>, String> {
+	let RuntimeMetadata::V14(metadata) = metadata.1 else { return BTreeMap::new() };
+	let mut items = BTreeMap::new();
+	for storage in metadata.pallets.into_iter().filter_map(|pallet| pallet.storage) {
+		for entry in storage.entries {
+			let key =
+				[twox_128(storage.prefix.as_bytes()), twox_128(entry.name.as_bytes())].concat();
+			items.insert(key, format!("{}::{}", storage.prefix, entry.name));
+		}
+	}
+	items
+}
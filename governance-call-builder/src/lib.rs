@@ -0,0 +1,334 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Builder for whitelisted governance proposals of the Polkadot system chains.
+//!
+//! Takes the calls that should be dispatched as Root on the relay chain and the system parachains
+//! and builds:
+//! - the call that is whitelisted on Asset Hub. Calls for Asset Hub are dispatched directly, the
+//!   others are sent with an XCM `Transact` from Asset Hub;
+//! - the Fellowship referendum on Collectives that whitelists this call on Asset Hub;
+//! - the public `WhitelistedCaller` referendum on Asset Hub that dispatches it.
+//!
+//! The calls are decoded with the types of the runtimes compiled into the builder, which also
+//! provide the weights used as `fallback_max_weight` of the `Transact` instructions.
+
+use asset_hub_polkadot_runtime as asset_hub;
+use codec::{DecodeAll, Encode};
+use collectives_polkadot_runtime as collectives;
+use frame_support::{
+	dispatch::GetDispatchInfo,
+	traits::{schedule::DispatchTime, Bounded},
+	weights::Weight,
+};
+use polkadot_runtime_constants::system_parachain::{
+	ASSET_HUB_ID, BRIDGE_HUB_ID, BROKER_ID, BULLETIN_ID, COLLECTIVES_ID, PEOPLE_ID,
+};
+use sp_core::{hashing::blake2_256, H256};
+use std::{fmt, str::FromStr};
+use xcm::{latest::prelude::*, VersionedLocation, VersionedXcm};
+
+/// A chain that calls can be dispatched on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Chain {
+	Polkadot,
+	AssetHubPolkadot,
+	BridgeHubPolkadot,
+	CollectivesPolkadot,
+	CoretimePolkadot,
+	PeoplePolkadot,
+	BulletinPolkadot,
+}
+
+/// Runs `$body` with `$runtime` being the runtime crate of `$chain`.
+macro_rules! with_runtime {
+	($chain:expr, $runtime:ident => $body:expr) => {
+		match $chain {
+			Chain::Polkadot => {
+				use polkadot_runtime as $runtime;
+				$body
+			},
+			Chain::AssetHubPolkadot => {
+				use asset_hub_polkadot_runtime as $runtime;
+				$body
+			},
+			Chain::BridgeHubPolkadot => {
+				use bridge_hub_polkadot_runtime as $runtime;
+				$body
+			},
+			Chain::CollectivesPolkadot => {
+				use collectives_polkadot_runtime as $runtime;
+				$body
+			},
+			Chain::CoretimePolkadot => {
+				use coretime_polkadot_runtime as $runtime;
+				$body
+			},
+			Chain::PeoplePolkadot => {
+				use people_polkadot_runtime as $runtime;
+				$body
+			},
+			Chain::BulletinPolkadot => {
+				use bulletin_polkadot_runtime as $runtime;
+				$body
+			},
+		}
+	};
+}
+
+impl Chain {
+	/// All supported chains.
+	pub const ALL: [Chain; 7] = [
+		Chain::Polkadot,
+		Chain::AssetHubPolkadot,
+		Chain::BridgeHubPolkadot,
+		Chain::CollectivesPolkadot,
+		Chain::CoretimePolkadot,
+		Chain::PeoplePolkadot,
+		Chain::BulletinPolkadot,
+	];
+
+	pub fn name(&self) -> &'static str {
+		match self {
+			Chain::Polkadot => "polkadot",
+			Chain::AssetHubPolkadot => "asset-hub-polkadot",
+			Chain::BridgeHubPolkadot => "bridge-hub-polkadot",
+			Chain::CollectivesPolkadot => "collectives-polkadot",
+			Chain::CoretimePolkadot => "coretime-polkadot",
+			Chain::PeoplePolkadot => "people-polkadot",
+			Chain::BulletinPolkadot => "bulletin-polkadot",
+		}
+	}
+
+	/// The location of the chain as seen from Asset Hub, `None` for Asset Hub itself.
+	fn location_from_asset_hub(&self) -> Option<Location> {
+		let para_id = match self {
+			Chain::Polkadot => return Some(Location::parent()),
+			Chain::AssetHubPolkadot => return None,
+			Chain::BridgeHubPolkadot => BRIDGE_HUB_ID,
+			Chain::CollectivesPolkadot => COLLECTIVES_ID,
+			Chain::CoretimePolkadot => BROKER_ID,
+			Chain::PeoplePolkadot => PEOPLE_ID,
+			Chain::BulletinPolkadot => BULLETIN_ID,
+		};
+		Some(Location::new(1, [Parachain(para_id)]))
+	}
+
+	/// Decodes `call` with the types of the chain and returns its weight.
+	pub fn call_weight(&self, call: &[u8]) -> Result<Weight, String> {
+		with_runtime!(self, runtime => {
+			let call = runtime::RuntimeCall::decode_all(&mut &call[..])
+				.map_err(|e| format!("Invalid {} call: {e}", self.name()))?;
+			Ok(call.get_dispatch_info().call_weight)
+		})
+	}
+
+	/// Returns the encoded `System::authorize_upgrade` call for the runtime with `code_hash`.
+	pub fn authorize_upgrade(&self, code_hash: H256) -> Vec<u8> {
+		with_runtime!(self, runtime => {
+			runtime::RuntimeCall::System(
+				frame_system::Call::<runtime::Runtime>::authorize_upgrade { code_hash },
+			)
+			.encode()
+		})
+	}
+}
+
+impl fmt::Display for Chain {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str(self.name())
+	}
+}
+
+impl FromStr for Chain {
+	type Err = String;
+
+	fn from_str(name: &str) -> Result<Self, Self::Err> {
+		Chain::ALL.into_iter().find(|chain| chain.name() == name).ok_or_else(|| {
+			let supported = Chain::ALL.map(|chain| chain.name()).join(", ");
+			format!("Unknown chain '{name}', supported are: {supported}")
+		})
+	}
+}
+
+/// A call that has to be noted as preimage.
+pub struct Preimage {
+	/// The encoded call.
+	pub call: Vec<u8>,
+	/// The hash the preimage is referenced by.
+	pub hash: H256,
+	/// The length of the encoded call.
+	pub len: u32,
+	/// The encoded `Preimage::note_preimage` call that notes the preimage.
+	pub note: Vec<u8>,
+}
+
+impl Preimage {
+	fn on_asset_hub(call: asset_hub::RuntimeCall) -> Self {
+		let call = call.encode();
+		let note = asset_hub::RuntimeCall::Preimage(pallet_preimage::Call::note_preimage {
+			bytes: call.clone(),
+		});
+		Self::new(call, note.encode())
+	}
+
+	fn on_collectives(call: collectives::RuntimeCall) -> Self {
+		let call = call.encode();
+		let note = collectives::RuntimeCall::Preimage(pallet_preimage::Call::note_preimage {
+			bytes: call.clone(),
+		});
+		Self::new(call, note.encode())
+	}
+
+	fn new(call: Vec<u8>, note: Vec<u8>) -> Self {
+		let hash = blake2_256(&call).into();
+		let len = call.len() as u32;
+		Preimage { call, hash, len, note }
+	}
+}
+
+/// The Fellowship and public referenda of a whitelisted proposal.
+pub struct Proposal {
+	/// The call that is whitelisted and dispatched on Asset Hub.
+	pub whitelisted: Preimage,
+	/// The call on Collectives that whitelists [`Self::whitelisted`] on Asset Hub.
+	pub fellowship: Preimage,
+	/// The encoded `FellowshipReferenda::submit` call on Collectives.
+	pub fellowship_referendum: Vec<u8>,
+	/// The `Whitelist::dispatch_whitelisted_call_with_preimage` call on Asset Hub.
+	pub public: Preimage,
+	/// The encoded `Referenda::submit` call on Asset Hub.
+	pub public_referendum: Vec<u8>,
+}
+
+/// Builds the proposal that dispatches `calls` as Root on their chains, in the given order.
+///
+/// Both referenda are enacted `enactment_after` blocks after they are confirmed.
+pub fn build(calls: &[(Chain, Vec<u8>)], enactment_after: u32) -> Result<Proposal, String> {
+	let mut batch = Vec::with_capacity(calls.len());
+	for (chain, call) in calls {
+		let weight = chain.call_weight(call)?;
+		let call = match chain.location_from_asset_hub() {
+			None => asset_hub::RuntimeCall::decode_all(&mut &call[..])
+				.expect("Decoded by `call_weight`; qed"),
+			Some(dest) => asset_hub::RuntimeCall::PolkadotXcm(pallet_xcm::Call::send {
+				dest: Box::new(VersionedLocation::from(dest)),
+				message: Box::new(VersionedXcm::from(Xcm(vec![
+					UnpaidExecution { weight_limit: Unlimited, check_origin: None },
+					Transact {
+						origin_kind: OriginKind::Superuser,
+						fallback_max_weight: Some(weight),
+						call: call.clone().into(),
+					},
+				]))),
+			}),
+		};
+		batch.push(call);
+	}
+	let whitelisted = match batch.len() {
+		0 => return Err("No calls to dispatch".into()),
+		1 => batch.remove(0),
+		_ => asset_hub::RuntimeCall::Utility(pallet_utility::Call::force_batch { calls: batch }),
+	};
+
+	let whitelist_call =
+		asset_hub::RuntimeCall::Whitelist(pallet_whitelist::Call::whitelist_call {
+			call_hash: blake2_256(&whitelisted.encode()).into(),
+		});
+	let fellowship =
+		Preimage::on_collectives(collectives::RuntimeCall::PolkadotXcm(pallet_xcm::Call::send {
+			dest: Box::new(VersionedLocation::from(Location::new(1, [Parachain(ASSET_HUB_ID)]))),
+			message: Box::new(VersionedXcm::from(Xcm(vec![
+				UnpaidExecution { weight_limit: Unlimited, check_origin: None },
+				Transact {
+					origin_kind: OriginKind::Xcm,
+					fallback_max_weight: Some(whitelist_call.get_dispatch_info().call_weight),
+					call: whitelist_call.encode().into(),
+				},
+			]))),
+		}));
+	let fellowship_referendum =
+		collectives::RuntimeCall::FellowshipReferenda(pallet_referenda::Call::submit {
+			proposal_origin: Box::new(collectives::OriginCaller::FellowshipOrigins(
+				collectives::fellowship::pallet_fellowship_origins::Origin::Fellows,
+			)),
+			proposal: Bounded::Lookup { hash: fellowship.hash, len: fellowship.len },
+			enactment_moment: DispatchTime::After(enactment_after),
+		})
+		.encode();
+
+	let public = Preimage::on_asset_hub(asset_hub::RuntimeCall::Whitelist(
+		pallet_whitelist::Call::dispatch_whitelisted_call_with_preimage {
+			call: Box::new(whitelisted.clone()),
+		},
+	));
+	let public_referendum = asset_hub::RuntimeCall::Referenda(pallet_referenda::Call::submit {
+		proposal_origin: Box::new(asset_hub::OriginCaller::Origins(
+			asset_hub::governance::pallet_custom_origins::Origin::WhitelistedCaller,
+		)),
+		proposal: Bounded::Lookup { hash: public.hash, len: public.len },
+		enactment_moment: DispatchTime::After(enactment_after),
+	})
+	.encode();
+
+	Ok(Proposal {
+		whitelisted: Preimage::on_asset_hub(whitelisted),
+		fellowship,
+		fellowship_referendum,
+		public,
+		public_referendum,
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn builds_whitelisted_upgrade_of_relay_and_asset_hub() {
+		let calls = [Chain::Polkadot, Chain::AssetHubPolkadot]
+			.map(|chain| (chain, chain.authorize_upgrade([1u8; 32].into())));
+		let proposal = build(&calls, 10).unwrap();
+
+		let asset_hub::RuntimeCall::Utility(pallet_utility::Call::force_batch { calls: batch }) =
+			asset_hub::RuntimeCall::decode_all(&mut &proposal.whitelisted.call[..]).unwrap()
+		else {
+			panic!("Expected a batch of both calls");
+		};
+		let asset_hub::RuntimeCall::PolkadotXcm(pallet_xcm::Call::send { dest, .. }) = &batch[0]
+		else {
+			panic!("Expected the relay chain call to be sent with XCM");
+		};
+		assert_eq!(**dest, VersionedLocation::from(Location::parent()));
+		assert!(matches!(
+			&batch[1],
+			asset_hub::RuntimeCall::System(frame_system::Call::authorize_upgrade { .. })
+		));
+
+		let whitelist = asset_hub::RuntimeCall::Whitelist(pallet_whitelist::Call::whitelist_call {
+			call_hash: proposal.whitelisted.hash,
+		});
+		let fellowship_call = array_bytes::bytes2hex("", &proposal.fellowship.call);
+		assert!(fellowship_call.contains(&array_bytes::bytes2hex("", whitelist.encode())));
+		assert_eq!(proposal.public.hash, H256::from(blake2_256(&proposal.public.call)));
+	}
+
+	#[test]
+	fn rejects_invalid_calls() {
+		assert!(build(&[(Chain::BulletinPolkadot, vec![0xff, 0])], 10).is_err());
+		assert!(build(&[], 10).is_err());
+	}
+}
@@ -0,0 +1,84 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use clap::Parser;
+use governance_call_builder::{build, Chain, Preimage};
+use serde_json::{json, Value};
+use std::path::PathBuf;
+
+/// Builds the Fellowship whitelist referendum on Collectives and the public `WhitelistedCaller`
+/// referendum on Asset Hub that dispatch the given calls as Root.
+#[derive(Parser)]
+struct Cli {
+	/// Hex encoded call to dispatch, as `<chain>=<call>`. Can be given multiple times, the calls
+	/// are dispatched in the given order.
+	#[arg(long = "call", value_parser = parse_call)]
+	calls: Vec<(Chain, Vec<u8>)>,
+
+	/// Runtime to authorize as upgrade, as `<chain>=<path to the wasm blob>`. Can be given
+	/// multiple times, the upgrades are authorized after the `--call`s.
+	#[arg(long = "authorize-upgrade", value_parser = parse_upgrade)]
+	upgrades: Vec<(Chain, Vec<u8>)>,
+
+	/// Number of blocks after confirmation before the referenda are enacted.
+	#[arg(long, default_value_t = 10)]
+	enactment_after: u32,
+}
+
+fn parse_call(arg: &str) -> Result<(Chain, Vec<u8>), String> {
+	let (chain, call) = arg.split_once('=').ok_or("Expected `<chain>=<call>`")?;
+	let call = array_bytes::hex2bytes(call).map_err(|e| format!("Invalid call: {e:?}"))?;
+	Ok((chain.parse()?, call))
+}
+
+fn parse_upgrade(arg: &str) -> Result<(Chain, Vec<u8>), String> {
+	let (chain, path) = arg.split_once('=').ok_or("Expected `<chain>=<path>`")?;
+	let chain: Chain = chain.parse()?;
+	let code =
+		std::fs::read(PathBuf::from(path)).map_err(|e| format!("Failed to read '{path}': {e}"))?;
+	Ok((chain, chain.authorize_upgrade(sp_core::hashing::blake2_256(&code).into())))
+}
+
+fn preimage_json(preimage: &Preimage) -> Value {
+	json!({
+		"call": array_bytes::bytes2hex("0x", &preimage.call),
+		"hash": format!("{:?}", preimage.hash),
+		"len": preimage.len,
+		"notePreimage": array_bytes::bytes2hex("0x", &preimage.note),
+	})
+}
+
+fn main() -> Result<(), String> {
+	let cli = Cli::parse();
+	let calls = cli.calls.into_iter().chain(cli.upgrades).collect::<Vec<_>>();
+	let proposal = build(&calls, cli.enactment_after)?;
+
+	let output = json!({
+		"whitelistedCall": preimage_json(&proposal.whitelisted),
+		"fellowship": {
+			"chain": Chain::CollectivesPolkadot.name(),
+			"preimage": preimage_json(&proposal.fellowship),
+			"referendum": array_bytes::bytes2hex("0x", &proposal.fellowship_referendum),
+		},
+		"public": {
+			"chain": Chain::AssetHubPolkadot.name(),
+			"preimage": preimage_json(&proposal.public),
+			"referendum": array_bytes::bytes2hex("0x", &proposal.public_referendum),
+		},
+	});
+	println!("{}", serde_json::to_string_pretty(&output).map_err(|e| e.to_string())?);
+	Ok(())
+}
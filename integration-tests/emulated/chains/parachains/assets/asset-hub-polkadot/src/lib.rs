@@ -55,6 +55,7 @@ decl_test_parachains! {
 			Preimage: asset_hub_polkadot_runtime::Preimage,
 			Treasury: asset_hub_polkadot_runtime::Treasury,
 			AssetRate: asset_hub_polkadot_runtime::AssetRate,
+			RemoteCoretime: asset_hub_polkadot_runtime::RemoteCoretime,
 		}
 	},
 }
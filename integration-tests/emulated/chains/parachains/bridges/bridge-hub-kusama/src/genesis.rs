@@ -70,6 +70,11 @@ pub fn genesis() -> sp_core::storage::Storage {
 			owner: Some(Keyring::Bob.to_account_id()),
 			..Default::default()
 		},
+		bridge_polkadot_dynamic_messages:
+			bridge_hub_kusama_runtime::BridgePolkadotDynamicMessagesConfig {
+				owner: Some(Keyring::Bob.to_account_id()),
+				..Default::default()
+			},
 		xcm_over_bridge_hub_polkadot: bridge_hub_kusama_runtime::XcmOverBridgeHubPolkadotConfig {
 			opened_bridges: vec![
 				// open KAH -> PAH bridge
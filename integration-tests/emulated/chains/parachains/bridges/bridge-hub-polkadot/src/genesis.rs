@@ -70,6 +70,11 @@ pub fn genesis() -> sp_core::storage::Storage {
 			owner: Some(Keyring::Bob.to_account_id()),
 			..Default::default()
 		},
+		bridge_kusama_dynamic_messages:
+			bridge_hub_polkadot_runtime::BridgeKusamaDynamicMessagesConfig {
+				owner: Some(Keyring::Bob.to_account_id()),
+				..Default::default()
+			},
 		ethereum_system: bridge_hub_polkadot_runtime::EthereumSystemConfig {
 			para_id: PARA_ID.into(),
 			asset_hub_para_id: ASSET_HUB_PARA_ID.into(),
@@ -0,0 +1,268 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests related to coretime orders that are sent from Asset Hub.
+
+use crate::*;
+use pallet_broker::{
+	CompletionStatus, ConfigRecord, CoreAssignment, CoreMask, PotentialRenewalId,
+	PotentialRenewalRecord, RCBlockNumberProviderOf, SaleInfoRecordOf, Schedule, ScheduleItem,
+};
+use polkadot_runtime_constants::currency::UNITS;
+use sp_runtime::{traits::BlockNumberProvider, Perbill};
+
+type AssetHubEvent = <AssetHubPolkadot as Chain>::RuntimeEvent;
+type CoretimeEvent = <CoretimePolkadot as Chain>::RuntimeEvent;
+type CoretimeRuntime = <CoretimePolkadot as Chain>::Runtime;
+
+/// The parachain that the purchased cores are assigned to.
+const TASK: u32 = 2000;
+
+/// Start the bulk sales with one core on the Coretime chain and wait until the lead-in is over, so
+/// the core is sold at the end price of the sale.
+fn start_sales() -> SaleInfoRecordOf<CoretimeRuntime> {
+	CoretimePolkadot::execute_with(|| {
+		let coretime_root_origin = <CoretimePolkadot as Chain>::RuntimeOrigin::root();
+		let config = ConfigRecord {
+			advance_notice: 2,
+			interlude_length: 1,
+			leadin_length: 1,
+			region_length: 1,
+			ideal_bulk_proportion: Perbill::from_percent(100),
+			limit_cores_offered: None,
+			renewal_bump: Perbill::from_percent(2),
+			contribution_timeout: 1,
+		};
+		assert_ok!(<CoretimePolkadot as CoretimePolkadotPallet>::Broker::configure(
+			coretime_root_origin.clone(),
+			config
+		));
+		assert_ok!(<CoretimePolkadot as CoretimePolkadotPallet>::Broker::start_sales(
+			coretime_root_origin,
+			100 * UNITS,
+			1
+		));
+	});
+
+	for _ in 0..10 {
+		// The relay chain block number of the Coretime chain follows the blocks of the relay chain.
+		Polkadot::execute_with(|| {});
+		let sale = CoretimePolkadot::execute_with(|| {
+			let sale = pallet_broker::SaleInfo::<CoretimeRuntime>::get().expect("sales started");
+			let now = RCBlockNumberProviderOf::<
+				<CoretimeRuntime as pallet_broker::Config>::Coretime,
+			>::current_block_number();
+			(now > sale.sale_start + sale.leadin_length).then_some(sale)
+		});
+		if let Some(sale) = sale {
+			return sale;
+		}
+	}
+	panic!("the lead-in of the sale didn't end");
+}
+
+fn asset_hub_balance(who: &AccountId) -> Balance {
+	AssetHubPolkadot::execute_with(|| {
+		<AssetHubPolkadot as AssetHubPolkadotPallet>::Balances::free_balance(who)
+	})
+}
+
+fn coretime_balance(who: &AccountId) -> Balance {
+	CoretimePolkadot::execute_with(|| {
+		<CoretimePolkadot as CoretimePolkadotPallet>::Balances::free_balance(who)
+	})
+}
+
+/// Send an order with `send` from the sender on Asset Hub and return its id.
+fn send_order(
+	send: impl FnOnce(<AssetHubPolkadot as Chain>::RuntimeOrigin) -> DispatchResult,
+) -> u64 {
+	AssetHubPolkadot::execute_with(|| {
+		assert_ok!(send(<AssetHubPolkadot as Chain>::RuntimeOrigin::signed(
+			AssetHubPolkadotSender::get()
+		)));
+		<AssetHubPolkadot as Chain>::System::events()
+			.iter()
+			.find_map(|record| match record.event {
+				AssetHubEvent::RemoteCoretime(pallet_remote_coretime::Event::OrderSent {
+					id,
+					..
+				}) => Some(id),
+				_ => None,
+			})
+			.expect("the order was sent")
+	})
+}
+
+/// Conclude the order `id` on Asset Hub with the outcome that was reported back.
+fn check_order(id: u64) {
+	AssetHubPolkadot::execute_with(|| {
+		assert_ok!(<AssetHubPolkadot as AssetHubPolkadotPallet>::RemoteCoretime::check_order(
+			<AssetHubPolkadot as Chain>::RuntimeOrigin::signed(AssetHubPolkadotReceiver::get()),
+			id
+		));
+	});
+}
+
+#[test]
+fn purchase_from_asset_hub_assigns_core() {
+	let sender = AssetHubPolkadotSender::get();
+	let sale = start_sales();
+	let max_price = sale.end_price * 2;
+	let asset_hub_balance_before = asset_hub_balance(&sender);
+	let coretime_balance_before = coretime_balance(&sender);
+
+	let id = send_order(|origin| {
+		<AssetHubPolkadot as AssetHubPolkadotPallet>::RemoteCoretime::purchase(
+			origin, TASK, max_price,
+		)
+	});
+
+	CoretimePolkadot::execute_with(|| {
+		assert_expected_events!(
+			CoretimePolkadot,
+			vec![
+				CoretimeEvent::Broker(pallet_broker::Event::Purchased { who, price, .. }) => {
+					who: *who == sender,
+					price: *price == sale.end_price,
+				},
+				CoretimeEvent::Broker(pallet_broker::Event::Assigned { task, .. }) => {
+					task: *task == TASK,
+				},
+			]
+		);
+	});
+	// Nothing of the payment stays on the Coretime chain.
+	assert_eq!(coretime_balance(&sender), coretime_balance_before);
+
+	check_order(id);
+	AssetHubPolkadot::execute_with(|| {
+		assert_expected_events!(
+			AssetHubPolkadot,
+			vec![
+				AssetHubEvent::RemoteCoretime(
+					pallet_remote_coretime::Event::OrderCompleted { id: order_id, who, .. }
+				) => {
+					order_id: *order_id == id,
+					who: *who == sender,
+				},
+			]
+		);
+	});
+	// The difference to the maximum price was teleported back, only the price and the fees of the
+	// order were spent.
+	let spent = asset_hub_balance_before - asset_hub_balance(&sender);
+	assert!(spent > sale.end_price && spent < sale.end_price + UNITS);
+}
+
+#[test]
+fn purchase_above_max_price_fails_and_returns_payment() {
+	let sender = AssetHubPolkadotSender::get();
+	let sale = start_sales();
+	let max_price = sale.end_price - 1;
+	let asset_hub_balance_before = asset_hub_balance(&sender);
+	let coretime_balance_before = coretime_balance(&sender);
+
+	let id = send_order(|origin| {
+		<AssetHubPolkadot as AssetHubPolkadotPallet>::RemoteCoretime::purchase(
+			origin, TASK, max_price,
+		)
+	});
+
+	CoretimePolkadot::execute_with(|| {
+		assert!(!<CoretimePolkadot as Chain>::System::events().iter().any(|record| matches!(
+			record.event,
+			CoretimeEvent::Broker(pallet_broker::Event::Purchased { .. })
+		)));
+		assert_eq!(pallet_broker::SaleInfo::<CoretimeRuntime>::get().unwrap().cores_sold, 0);
+	});
+	// Only the fees that weren't used may be left on the Coretime chain.
+	assert!(coretime_balance(&sender) < coretime_balance_before + max_price);
+
+	check_order(id);
+	AssetHubPolkadot::execute_with(|| {
+		assert_expected_events!(
+			AssetHubPolkadot,
+			vec![
+				AssetHubEvent::RemoteCoretime(
+					pallet_remote_coretime::Event::OrderFailed { id: order_id, who, .. }
+				) => {
+					order_id: *order_id == id,
+					who: *who == sender,
+				},
+			]
+		);
+	});
+	// The payment was teleported back, only the fees of the order were spent.
+	assert!(asset_hub_balance(&sender) > asset_hub_balance_before - UNITS);
+}
+
+#[test]
+fn renewal_from_asset_hub_renews_core() {
+	let sender = AssetHubPolkadotSender::get();
+	let sale = start_sales();
+	let renewal_price = 20 * UNITS;
+	CoretimePolkadot::execute_with(|| {
+		let mut workload = Schedule::new();
+		workload
+			.try_push(ScheduleItem {
+				mask: CoreMask::complete(),
+				assignment: CoreAssignment::Task(TASK),
+			})
+			.unwrap();
+		pallet_broker::PotentialRenewals::<CoretimeRuntime>::insert(
+			PotentialRenewalId { core: sale.first_core, when: sale.region_begin },
+			PotentialRenewalRecord {
+				price: renewal_price,
+				completion: CompletionStatus::Complete(workload),
+			},
+		);
+	});
+
+	let id = send_order(|origin| {
+		<AssetHubPolkadot as AssetHubPolkadotPallet>::RemoteCoretime::renew(
+			origin,
+			sale.first_core,
+			renewal_price,
+		)
+	});
+
+	CoretimePolkadot::execute_with(|| {
+		assert_expected_events!(
+			CoretimePolkadot,
+			vec![
+				CoretimeEvent::Broker(pallet_broker::Event::Renewed { who, price, old_core, .. }) => {
+					who: *who == sender,
+					price: *price == renewal_price,
+					old_core: *old_core == sale.first_core,
+				},
+			]
+		);
+	});
+
+	check_order(id);
+	AssetHubPolkadot::execute_with(|| {
+		assert_expected_events!(
+			AssetHubPolkadot,
+			vec![
+				AssetHubEvent::RemoteCoretime(
+					pallet_remote_coretime::Event::OrderCompleted { id: order_id, .. }
+				) => {
+					order_id: *order_id == id,
+				},
+			]
+		);
+	});
+}
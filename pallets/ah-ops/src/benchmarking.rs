@@ -13,11 +13,69 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::*;
+use crate::{translate::*, *};
 use frame_benchmarking::{account, v2::*};
-use frame_support::{dispatch::RawOrigin, traits::Currency};
+use frame_support::{
+	dispatch::RawOrigin,
+	traits::{Currency, Incrementable, VestingSchedule},
+};
+use sp_runtime::traits::{Hash, StaticLookup, Zero};
 
-#[benchmarks]
+/// The pallets that are used by the translators of `translate_para_sovereign_derived`.
+pub trait TranslatorsConfig:
+	Config
+	+ pallet_vesting::Config<Currency = <Self as Config>::Currency>
+	+ pallet_proxy::Config<Currency = <Self as Config>::Currency>
+	+ pallet_multisig::Config<Currency = <Self as Config>::Currency>
+	+ pallet_nomination_pools::Config<Currency = <Self as Config>::Currency>
+	+ pallet_uniques::Config
+	+ pallet_nfts::Config
+{
+}
+
+impl<T> TranslatorsConfig for T where
+	T: Config
+		+ pallet_vesting::Config<Currency = <T as Config>::Currency>
+		+ pallet_proxy::Config<Currency = <T as Config>::Currency>
+		+ pallet_multisig::Config<Currency = <T as Config>::Currency>
+		+ pallet_nomination_pools::Config<Currency = <T as Config>::Currency>
+		+ pallet_uniques::Config
+		+ pallet_nfts::Config
+{
+}
+
+fn signed<T: Config>(who: &T::AccountId) -> T::RuntimeOrigin {
+	RawOrigin::Signed(who.clone()).into()
+}
+
+fn funded<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+	let who = account(name, index, 0);
+	let ed = <<T as crate::Config>::Currency as Currency<_>>::minimum_balance();
+	let _ = <T as crate::Config>::Currency::deposit_creating(&who, ed * 1_000_000_000);
+	who
+}
+
+fn join_pool<T: TranslatorsConfig>(member: &T::AccountId) {
+	let ed = <<T as crate::Config>::Currency as Currency<_>>::minimum_balance();
+	let depositor = funded::<T>("depositor", 0);
+	let depositor_lookup = <T as frame_system::Config>::Lookup::unlookup(depositor.clone());
+	pallet_nomination_pools::Pallet::<T>::create(
+		signed::<T>(&depositor),
+		pallet_nomination_pools::Pallet::<T>::depositor_min_bond() * 2,
+		depositor_lookup.clone(),
+		depositor_lookup.clone(),
+		depositor_lookup,
+	)
+	.expect("pool can be created");
+	pallet_nomination_pools::Pallet::<T>::join(
+		signed::<T>(member),
+		pallet_nomination_pools::MinJoinBond::<T>::get().max(ed),
+		pallet_nomination_pools::LastPoolId::<T>::get(),
+	)
+	.expect("member can join");
+}
+
+#[benchmarks(where T: TranslatorsConfig)]
 pub mod benchmarks {
 	use super::*;
 
@@ -82,18 +140,310 @@ pub mod benchmarks {
 		assert_eq!(RcCrowdloanReserve::<T>::get((block, para_id, &sender)), None);
 	}
 
+	#[benchmark]
+	fn unreserve_lease_deposits(n: Linear<1, { T::MaxBatchSize::get() }>) {
+		let ed = <<T as crate::Config>::Currency as Currency<_>>::minimum_balance();
+		let block = T::RcBlockNumberProvider::current_block_number();
+		let para_id = ParaId::from(1u32);
+		let mut items = BoundedVec::new();
+		for i in 0..n {
+			let sender: T::AccountId = account("sender", i, 0);
+			let _ = <T as crate::Config>::Currency::deposit_creating(&sender, ed + ed);
+			let _ = <T as crate::Config>::Currency::reserve(&sender, ed);
+			RcLeaseReserve::<T>::insert((block, para_id, &sender), ed);
+			items.try_push((block, para_id, sender)).expect("within bounds");
+		}
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), items);
+
+		assert_eq!(RcLeaseReserve::<T>::iter_keys().count(), 0);
+	}
+
+	#[benchmark]
+	fn withdraw_crowdloan_contributions(n: Linear<1, { T::MaxBatchSize::get() }>) {
+		let pot: T::AccountId = account("pot", 0, 0);
+		let ed = <<T as crate::Config>::Currency as Currency<_>>::minimum_balance();
+		let block = T::RcBlockNumberProvider::current_block_number();
+		let para_id = ParaId::from(1u32);
+		let _ = <T as crate::Config>::Currency::deposit_creating(&pot, ed * (n + 1).into());
+		let _ = <T as crate::Config>::Currency::reserve(&pot, ed * n.into());
+		RcLeaseReserve::<T>::insert((block, para_id, &pot), ed * n.into());
+
+		let mut items = BoundedVec::new();
+		for i in 0..n {
+			let sender: T::AccountId = account("sender", i, 0);
+			RcCrowdloanContribution::<T>::insert((block, para_id, &sender), (pot.clone(), ed));
+			items.try_push((block, para_id, sender)).expect("within bounds");
+		}
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), items);
+
+		assert_eq!(RcCrowdloanContribution::<T>::iter_keys().count(), 0);
+		assert_eq!(<T as crate::Config>::Currency::free_balance(&pot), ed);
+	}
+
+	#[benchmark]
+	fn unreserve_crowdloan_reserves(n: Linear<1, { T::MaxBatchSize::get() }>) {
+		let ed = <<T as crate::Config>::Currency as Currency<_>>::minimum_balance();
+		let block = T::RcBlockNumberProvider::current_block_number();
+		let mut items = BoundedVec::new();
+		for i in 0..n {
+			let sender: T::AccountId = account("sender", i, 0);
+			let para_id = ParaId::from(i);
+			let _ = <T as crate::Config>::Currency::deposit_creating(&sender, ed + ed);
+			let _ = <T as crate::Config>::Currency::reserve(&sender, ed);
+			RcCrowdloanReserve::<T>::insert((block, para_id, &sender), ed);
+			items.try_push((block, para_id, sender)).expect("within bounds");
+		}
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), items);
+
+		assert_eq!(RcCrowdloanReserve::<T>::iter_keys().count(), 0);
+	}
+
+	#[benchmark]
+	fn translate_vesting() {
+		let (from, to) = (funded::<T>("from", 0), funded::<T>("to", 0));
+		let ed = <<T as crate::Config>::Currency as Currency<_>>::minimum_balance();
+		let max = <T as pallet_vesting::Config>::MAX_VESTING_SCHEDULES;
+		for _ in 0..max {
+			<pallet_vesting::Pallet<T> as VestingSchedule<_>>::add_vesting_schedule(
+				&from,
+				ed * 100,
+				ed,
+				100u32.into(),
+			)
+			.expect("below the maximal number of schedules");
+		}
+		let mut report = Vec::new();
+
+		#[block]
+		{
+			VestingTranslator::<T>::translate(&from, &to, &Default::default(), &mut report);
+		}
+
+		assert_eq!(report, vec![ItemReport::new(TranslatedItem::Vesting, max, 0)]);
+	}
+
+	#[benchmark]
+	fn translate_proxies() {
+		let (from, to) = (funded::<T>("from", 0), funded::<T>("to", 0));
+		let max = <T as pallet_proxy::Config>::MaxProxies::get();
+		for i in 0..max {
+			pallet_proxy::Pallet::<T>::add_proxy(
+				signed::<T>(&from),
+				<T as frame_system::Config>::Lookup::unlookup(account("delegate", i, 0)),
+				Default::default(),
+				Zero::zero(),
+			)
+			.expect("below the maximal number of proxies");
+		}
+		// The old account is also the delegate of pending announcements.
+		let real = funded::<T>("real", 0);
+		pallet_proxy::Pallet::<T>::add_proxy(
+			signed::<T>(&real),
+			<T as frame_system::Config>::Lookup::unlookup(from.clone()),
+			Default::default(),
+			Zero::zero(),
+		)
+		.expect("first proxy of the real account");
+		for i in 0..<T as pallet_proxy::Config>::MaxPending::get() {
+			pallet_proxy::Pallet::<T>::announce(
+				signed::<T>(&from),
+				<T as frame_system::Config>::Lookup::unlookup(real.clone()),
+				<T as pallet_proxy::Config>::CallHasher::hash_of(&i),
+			)
+			.expect("below the maximal number of announcements");
+		}
+		let mut report = Vec::new();
+
+		#[block]
+		{
+			ProxyTranslator::<T>::translate(&from, &to, &Default::default(), &mut report);
+		}
+
+		assert_eq!(report, vec![ItemReport::new(TranslatedItem::Proxies, max, 0)]);
+		assert!(pallet_proxy::Announcements::<T>::get(&from).0.is_empty());
+	}
+
+	#[benchmark]
+	fn translate_multisigs(n: Linear<1, { MaxMultisigWitnesses::get() }>) {
+		let (from, to) = (funded::<T>("from", 0), funded::<T>("to", 0));
+		let signatories = <T as pallet_multisig::Config>::MaxSignatories::get()
+			.min(MaxMultisigSignatories::get() + 1);
+		let mut others: Vec<T::AccountId> =
+			(1..signatories).map(|i| account("signatory", i, 0)).collect();
+		others.sort();
+		let mut witness = TranslationWitness::default();
+		for i in 0..n {
+			let call_hash = sp_core::blake2_256(&i.encode());
+			pallet_multisig::Pallet::<T>::approve_as_multi(
+				signed::<T>(&from),
+				2,
+				others.clone(),
+				None,
+				call_hash,
+				Weight::zero(),
+			)
+			.expect("new multisig operation");
+			witness
+				.multisigs
+				.try_push(MultisigWitness {
+					threshold: 2,
+					other_signatories: others.clone().try_into().expect("within bounds"),
+					call_hash,
+				})
+				.expect("within bounds");
+		}
+		let mut report = Vec::new();
+
+		#[block]
+		{
+			MultisigTranslator::<T>::translate(&from, &to, &witness, &mut report);
+		}
+
+		assert_eq!(report, vec![ItemReport::new(TranslatedItem::MultisigsCancelled, n, 0)]);
+	}
+
+	#[benchmark]
+	fn translate_pool_member_unbond() {
+		let (from, to) = (funded::<T>("from", 0), funded::<T>("to", 0));
+		join_pool::<T>(&from);
+		let mut report = Vec::new();
+
+		#[block]
+		{
+			PoolMemberTranslator::<T>::translate(&from, &to, &Default::default(), &mut report);
+		}
+
+		// The member can only be withdrawn right away if the bonding duration is zero.
+		let member = pallet_nomination_pools::PoolMembers::<T>::get(&from);
+		assert!(member.map_or(true, |m| m.active_points().is_zero()));
+	}
+
+	#[benchmark]
+	fn translate_pool_member_withdraw() {
+		let (from, to) = (funded::<T>("from", 0), funded::<T>("to", 0));
+		join_pool::<T>(&from);
+		let points = pallet_nomination_pools::PoolMembers::<T>::get(&from)
+			.expect("member of the pool")
+			.active_points();
+		pallet_nomination_pools::Pallet::<T>::unbond(
+			signed::<T>(&from),
+			<T as frame_system::Config>::Lookup::unlookup(from.clone()),
+			points,
+		)
+		.expect("member can unbond");
+		let era = pallet_staking_async::CurrentEra::<T>::get().unwrap_or_default();
+		pallet_staking_async::CurrentEra::<T>::put(
+			era + <T as pallet_staking_async::Config>::BondingDuration::get(),
+		);
+		let mut report = Vec::new();
+
+		#[block]
+		{
+			PoolMemberTranslator::<T>::translate(&from, &to, &Default::default(), &mut report);
+		}
+
+		assert!(!pallet_nomination_pools::PoolMembers::<T>::contains_key(&from));
+		assert_eq!(report, vec![ItemReport::new(TranslatedItem::NominationPoolUnbonded, 1, 0)]);
+	}
+
+	#[benchmark]
+	fn translate_uniques() {
+		use pallet_uniques::BenchmarkHelper as _;
+		let (from, to) = (funded::<T>("from", 0), funded::<T>("to", 0));
+		let owner = <T as frame_system::Config>::Lookup::unlookup(from.clone());
+		for i in 0..MAX_NFTS_PER_TRANSLATION as u16 {
+			let collection = <T as pallet_uniques::Config>::Helper::collection(i);
+			pallet_uniques::Pallet::<T>::create(signed::<T>(&from), collection, owner.clone())
+				.expect("new collection");
+		}
+		let collection = <T as pallet_uniques::Config>::Helper::collection(0);
+		for i in 0..MAX_NFTS_PER_TRANSLATION as u16 {
+			pallet_uniques::Pallet::<T>::mint(
+				signed::<T>(&from),
+				collection.clone(),
+				<T as pallet_uniques::Config>::Helper::item(i),
+				owner.clone(),
+			)
+			.expect("new item");
+		}
+		let mut report = Vec::new();
+
+		#[block]
+		{
+			UniquesTranslator::<T>::translate(&from, &to, &Default::default(), &mut report);
+		}
+
+		let moved = 2 * MAX_NFTS_PER_TRANSLATION;
+		assert_eq!(report, vec![ItemReport::new(TranslatedItem::Uniques, moved, 0)]);
+	}
+
+	#[benchmark]
+	fn translate_nfts() {
+		let (from, to) = (funded::<T>("from", 0), funded::<T>("to", 0));
+		let owner = <T as frame_system::Config>::Lookup::unlookup(from.clone());
+		let collection = pallet_nfts::NextCollectionId::<T>::get()
+			.or(<T as pallet_nfts::Config>::CollectionId::initial_value())
+			.expect("collection ids can be incremented");
+		for _ in 0..MAX_NFTS_PER_TRANSLATION {
+			pallet_nfts::Pallet::<T>::create(
+				signed::<T>(&from),
+				owner.clone(),
+				pallet_nfts::CollectionConfig {
+					settings: pallet_nfts::CollectionSettings::all_enabled(),
+					max_supply: None,
+					mint_settings: Default::default(),
+				},
+			)
+			.expect("new collection");
+		}
+		for i in 0..MAX_NFTS_PER_TRANSLATION as u16 {
+			pallet_nfts::Pallet::<T>::mint(
+				signed::<T>(&from),
+				collection.clone(),
+				<<T as pallet_nfts::Config>::Helper as pallet_nfts::BenchmarkHelper<
+					_,
+					_,
+					_,
+					_,
+					_,
+				>>::item(i),
+				Some(owner.clone()),
+				None,
+			)
+			.expect("new item");
+		}
+		let mut report = Vec::new();
+
+		#[block]
+		{
+			NftsTranslator::<T>::translate(&from, &to, &Default::default(), &mut report);
+		}
+
+		let moved = 2 * MAX_NFTS_PER_TRANSLATION;
+		assert_eq!(report, vec![ItemReport::new(TranslatedItem::Nfts, moved, 0)]);
+	}
+
 	#[cfg(feature = "std")]
-	pub fn test_unreserve_lease_deposit<T: Config>() {
+	pub fn test_unreserve_lease_deposit<T: TranslatorsConfig>() {
 		_unreserve_lease_deposit::<T>(true)
 	}
 
 	#[cfg(feature = "std")]
-	pub fn test_withdraw_crowdloan_contribution<T: Config>() {
+	pub fn test_withdraw_crowdloan_contribution<T: TranslatorsConfig>() {
 		_withdraw_crowdloan_contribution::<T>(true)
 	}
 
 	#[cfg(feature = "std")]
-	pub fn test_unreserve_crowdloan_reserve<T: Config>() {
+	pub fn test_unreserve_crowdloan_reserve<T: TranslatorsConfig>() {
 		_unreserve_crowdloan_reserve::<T>(true)
 	}
 
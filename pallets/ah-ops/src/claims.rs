@@ -0,0 +1,84 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Lookup of the lease and crowdloan funds that an account can claim.
+//!
+//! The crowdloan and lease maps are keyed by the unlock block first, so finding the entries of a
+//! single account requires iterating all of them. This is only meant to be used off-chain through
+//! the view functions or the [`crate::runtime_api::AhOpsApi`].
+
+use crate::*;
+
+/// The kind of funds that can be claimed.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo)]
+pub enum ClaimKind {
+	/// A lease deposit in [`RcLeaseReserve`], claimed with `unreserve_lease_deposit`.
+	LeaseDeposit,
+	/// A crowdloan contribution in [`RcCrowdloanContribution`], claimed with
+	/// `withdraw_crowdloan_contribution`.
+	CrowdloanContribution,
+	/// A crowdloan reserve in [`RcCrowdloanReserve`], claimed with `unreserve_crowdloan_reserve`.
+	CrowdloanReserve,
+}
+
+/// Funds that an account can claim once the Relay Chain reached `unlock_block`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct Claim<BlockNumber, Balance> {
+	/// The kind of the funds and therefore the call to claim them.
+	pub kind: ClaimKind,
+	/// The Relay Chain block number after which the funds can be claimed.
+	pub unlock_block: BlockNumber,
+	/// The para id of the lease or crowdloan.
+	pub para_id: ParaId,
+	/// The amount that is unreserved or transferred to the account.
+	pub amount: Balance,
+}
+
+/// [`Claim`] of the runtime.
+pub type ClaimOf<T> = Claim<BlockNumberFor<T>, BalanceOf<T>>;
+
+impl<T: Config> Pallet<T> {
+	/// All lease deposits, crowdloan contributions and crowdloan reserves of `who`.
+	///
+	/// Iterates over all entries of the crowdloan and lease maps.
+	pub fn claims_of(who: &T::AccountId) -> Vec<ClaimOf<T>> {
+		let lease_deposits = RcLeaseReserve::<T>::iter()
+			.filter(|((_, _, depositor), _)| depositor == who)
+			.map(|((unlock_block, para_id, _), amount)| Claim {
+				kind: ClaimKind::LeaseDeposit,
+				unlock_block,
+				para_id,
+				amount,
+			});
+		let contributions = RcCrowdloanContribution::<T>::iter()
+			.filter(|((_, _, contributor), _)| contributor == who)
+			.map(|((unlock_block, para_id, _), (_, amount))| Claim {
+				kind: ClaimKind::CrowdloanContribution,
+				unlock_block,
+				para_id,
+				amount,
+			});
+		let crowdloan_reserves = RcCrowdloanReserve::<T>::iter()
+			.filter(|((_, _, depositor), _)| depositor == who)
+			.map(|((unlock_block, para_id, _), amount)| Claim {
+				kind: ClaimKind::CrowdloanReserve,
+				unlock_block,
+				para_id,
+				amount,
+			});
+
+		lease_deposits.chain(contributions).chain(crowdloan_reserves).collect()
+	}
+}
@@ -31,19 +31,26 @@
 
 #[cfg(feature = "runtime-benchmarks")]
 pub mod benchmarking;
+pub mod claims;
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
 mod tests;
+pub mod translate;
 pub mod weights;
 
+pub use claims::{Claim, ClaimKind, ClaimOf};
 pub use pallet::*;
+pub use translate::{
+	ItemReport, MultisigWitness, TranslateAccount, TranslatedItem, TranslationWitness,
+};
 pub use weights::WeightInfo;
 
 use codec::DecodeAll;
 use cumulus_primitives_core::ParaId;
 use frame_support::{
 	pallet_prelude::*,
+	storage::with_storage_layer,
 	traits::{
 		fungible::{
 			Inspect as FungibleInspect, InspectFreeze, Mutate as FungibleMutate, MutateFreeze,
@@ -54,13 +61,15 @@ use frame_support::{
 		Currency, Defensive, LockableCurrency, ReservableCurrency,
 		WithdrawReasons as LockWithdrawReasons,
 	},
+	weights::WeightMeter,
 };
 use frame_system::pallet_prelude::*;
 use pallet_balances::{AccountData, Reasons as LockReasons};
+use pallet_rc_migrator::types::{PortableFreezeReason, PortableHoldReason, RcAccount};
 use sp_application_crypto::ByteArray;
 use sp_core::blake2_256;
 use sp_runtime::{
-	traits::{BlockNumberProvider, TrailingZeroInput},
+	traits::{BlockNumberProvider, Convert, TrailingZeroInput},
 	AccountId32,
 };
 use sp_std::prelude::*;
@@ -71,6 +80,64 @@ pub const LOG_TARGET: &str = "runtime::ah-ops";
 pub type BalanceOf<T> = <T as pallet_balances::Config>::Balance;
 pub type DerivationIndex = u16;
 
+/// An account that is sent from the Relay Chain with `receive_accounts`.
+pub type RcAccountOf<T> = RcAccount<
+	<T as frame_system::Config>::AccountId,
+	u128,
+	PortableHoldReason,
+	PortableFreezeReason,
+>;
+
+/// A `(block, para_id, depositor)` entry of one of the crowdloan or lease maps.
+pub type BatchItemOf<T> = (BlockNumberFor<T>, ParaId, <T as frame_system::Config>::AccountId);
+
+/// The maximal length of a raw storage key of the crowdloan and lease maps.
+pub type MaxRawKeyLen = ConstU32<128>;
+
+/// The map that is currently swept by the `on_idle` sweeper.
+#[derive(
+	Encode,
+	DecodeWithMemTracking,
+	Decode,
+	Clone,
+	Copy,
+	Default,
+	PartialEq,
+	Eq,
+	Debug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub enum SweepStage {
+	/// Withdrawing matured `RcCrowdloanContribution`s.
+	#[default]
+	Contributions,
+	/// Unreserving matured `RcLeaseReserve`s of solo bidders.
+	LeaseReserves,
+	/// Unreserving matured `RcCrowdloanReserve`s.
+	CrowdloanReserves,
+}
+
+/// The position of the `on_idle` sweeper.
+#[derive(
+	Encode,
+	DecodeWithMemTracking,
+	Decode,
+	Clone,
+	Default,
+	PartialEq,
+	Eq,
+	Debug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub struct SweepCursor {
+	/// The map that is currently swept.
+	pub stage: SweepStage,
+	/// The raw storage key of the last visited entry of that map.
+	pub last_key: Option<BoundedVec<u8, MaxRawKeyLen>>,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -130,6 +197,29 @@ pub mod pallet {
 		/// The block number when the migration ended.
 		type MigrationEndBlock: Get<BlockNumberFor<Self>>;
 
+		/// The maximal number of entries that can be processed by a single batch call.
+		#[pallet::constant]
+		type MaxBatchSize: Get<u32>;
+
+		/// Convert a hold reason of the Relay Chain into the local hold reason.
+		///
+		/// Holds that have no local reason are received as reserve without a reason.
+		type PortableToAhHoldReason: Convert<
+			PortableHoldReason,
+			Option<<Self as pallet_balances::Config>::RuntimeHoldReason>,
+		>;
+
+		/// Convert a freeze reason of the Relay Chain into the local freeze reason.
+		///
+		/// Accounts with freezes that have no local reason are rejected by `receive_accounts`.
+		type PortableToAhFreezeReason: Convert<PortableFreezeReason, Option<Self::FreezeIdentifier>>;
+
+		/// Moves the state of other pallets when translating a sovereign account with
+		/// `translate_para_sovereign_derived`.
+		///
+		/// See [`translate`] for the available translators.
+		type AccountTranslator: TranslateAccount<Self::AccountId>;
+
 		/// The Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -205,6 +295,10 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// The position of the `on_idle` sweeper that processes matured crowdloans and leases.
+	#[pallet::storage]
+	pub type Sweeper<T: Config> = StorageValue<_, SweepCursor, ValueQuery>;
+
 	#[pallet::error]
 	#[derive(PartialEq, Eq)]
 	pub enum Error<T> {
@@ -238,6 +332,12 @@ pub mod pallet {
 		TooLongDerivationPath,
 		/// Failed to force unstake.
 		FailedToForceUnstake,
+		/// The origin is neither the sibling sovereign account of the para nor the new account.
+		NotParaSovereign,
+		/// The account to translate does not exist.
+		AccountNotFound,
+		/// An account from the Relay Chain could not be received.
+		FailedToReceiveAccount,
 	}
 
 	#[pallet::event]
@@ -271,11 +371,70 @@ pub mod pallet {
 		},
 		/// Failed to re-bond some migrated funds.
 		FailedToBond { account: T::AccountId, amount: BalanceOf<T> },
+
+		/// Report of a `translate_para_sovereign_derived` call.
+		///
+		/// Lists for every kind of state how many entries were moved and how many remain on the
+		/// old account.
+		SovereignMigrationReport {
+			/// The parachain ID that had its account migrated.
+			para_id: u16,
+			/// The old account that was migrated out of.
+			from: T::AccountId,
+			/// The new account that was migrated into.
+			to: T::AccountId,
+			/// The per-item outcome.
+			items: Vec<ItemReport>,
+		},
+
+		/// The Relay Chain started to migrate its accounts to Asset Hub.
+		AssetHubMigrationStarted,
+		/// The Relay Chain finished to migrate its accounts to Asset Hub.
+		AssetHubMigrationFinished,
+		/// A number of accounts were received from the Relay Chain.
+		AccountsReceived {
+			/// The number of received accounts.
+			count: u32,
+		},
+
+		/// An entry of a batch call could not be processed and was skipped.
+		BatchItemFailed {
+			/// The index of the entry in the batch.
+			index: u32,
+			/// The error that occurred.
+			error: DispatchError,
+		},
 	}
 
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let mut meter = WeightMeter::with_limit(remaining_weight);
+			// The Relay Chain block number and the sweeper cursor.
+			if meter.try_consume(T::DbWeight::get().reads_writes(3, 1)).is_err() {
+				return meter.consumed();
+			}
+			if !T::MigrationCompletion::get() {
+				return meter.consumed();
+			}
+
+			Self::sweep(&mut meter);
+			meter.consumed()
+		}
+	}
+
+	#[pallet::view_functions]
+	impl<T: Config> Pallet<T> {
+		/// The lease deposits, crowdloan contributions and crowdloan reserves that `who` can
+		/// claim, together with their unlock block and para id.
+		pub fn claimable_funds(who: T::AccountId) -> Vec<ClaimOf<T>> {
+			Self::claims_of(&who)
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Unreserve the deposit that was taken for creating a crowdloan.
@@ -389,6 +548,7 @@ pub mod pallet {
 		/// - Staked balances
 		///
 		/// Things like non-relevant assets or vested transfers may remain on the old account.
+		/// Use `translate_para_sovereign_derived` to move them as well.
 		#[pallet::call_index(4)]
 		#[pallet::weight(Weight::from_parts(100_000_000, 9000)
 				.saturating_add(T::DbWeight::get().reads_writes(20, 20)))]
@@ -409,9 +569,188 @@ pub mod pallet {
 			)
 			.map_err(Into::into)
 		}
+
+		/// Batch version of `unreserve_lease_deposit`.
+		///
+		/// Every entry is a `(block, para_id, depositor)` tuple. Entries that fail are skipped and
+		/// reported with a `BatchItemFailed` event. Can be called by any signed origin.
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::unreserve_lease_deposits(items.len() as u32))]
+		pub fn unreserve_lease_deposits(
+			origin: OriginFor<T>,
+			items: BoundedVec<BatchItemOf<T>, T::MaxBatchSize>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			Self::do_batch(items, Self::do_unreserve_lease_deposit);
+			Ok(())
+		}
+
+		/// Batch version of `withdraw_crowdloan_contribution`.
+		///
+		/// Every entry is a `(block, para_id, depositor)` tuple. Entries that fail are skipped and
+		/// reported with a `BatchItemFailed` event. Can be called by any signed origin.
+		#[pallet::call_index(6)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::withdraw_crowdloan_contributions(items.len() as u32)
+		)]
+		pub fn withdraw_crowdloan_contributions(
+			origin: OriginFor<T>,
+			items: BoundedVec<BatchItemOf<T>, T::MaxBatchSize>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			Self::do_batch(items, Self::do_withdraw_crowdloan_contribution);
+			Ok(())
+		}
+
+		/// Batch version of `unreserve_crowdloan_reserve`.
+		///
+		/// Every entry is a `(block, para_id, depositor)` tuple. Entries that fail are skipped and
+		/// reported with a `BatchItemFailed` event. Can be called by any signed origin.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::unreserve_crowdloan_reserves(items.len() as u32))]
+		pub fn unreserve_crowdloan_reserves(
+			origin: OriginFor<T>,
+			items: BoundedVec<BatchItemOf<T>, T::MaxBatchSize>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			Self::do_batch(items, Self::do_unreserve_crowdloan_reserve);
+			Ok(())
+		}
+
+		/// Permissionless and complete version of
+		/// `translate_para_sovereign_child_to_sibling_derived`.
+		///
+		/// Can be called by the sibling sovereign account of `para_id` or by the new derived
+		/// account itself, for example through an XCM `Transact` from the parachain. The old and
+		/// new accounts are derived from `para_id` and `derivation_path`.
+		///
+		/// Besides the native balance, the relevant assets and the staked balance, this also moves
+		/// everything that is supported by `T::AccountTranslator`, like vesting schedules, proxies
+		/// and NFTs. Multisig operations and nomination pool memberships are not moved: the
+		/// multisig operations in `witness` are cancelled to release their deposits, and the pool
+		/// membership is unbonded so that its stake follows with the free balance once withdrawn.
+		/// A `SovereignMigrationReport` event lists what was moved, cancelled or unbonded and what
+		/// remains. The call can be repeated to move what remained.
+		#[pallet::call_index(8)]
+		#[pallet::weight(Weight::from_parts(100_000_000, 9000)
+				.saturating_add(T::DbWeight::get().reads_writes(20, 20))
+				.saturating_add(T::AccountTranslator::weight()))]
+		pub fn translate_para_sovereign_derived(
+			origin: OriginFor<T>,
+			para_id: u16,
+			derivation_path: Vec<u16>,
+			witness: TranslationWitness<T::AccountId>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			ensure!(derivation_path.len() <= 10, Error::<T>::TooLongDerivationPath);
+
+			let from =
+				derivative_account_id_recursive(Self::para_sov_child(para_id), &derivation_path);
+			let to =
+				derivative_account_id_recursive(Self::para_sov_sibling(para_id), &derivation_path);
+			ensure!(
+				sender == Self::para_sov_sibling(para_id) || sender == to,
+				Error::<T>::NotParaSovereign
+			);
+			ensure!(frame_system::Account::<T>::contains_key(&from), Error::<T>::AccountNotFound);
+
+			let items =
+				Self::translate_sovereign(para_id, derivation_path, &from, &to, Some(&witness))?;
+			Self::deposit_event(Event::SovereignMigrationReport { para_id, from, to, items });
+			Ok(())
+		}
+
+		/// Receive a batch of accounts that were withdrawn on the Relay Chain.
+		///
+		/// Mints the migrated balance and re-applies the holds, the unnamed reserve, the freezes
+		/// and the locks of every account. If any account can't be received, the whole batch fails
+		/// and the Relay Chain restores the accounts. Can only be called by the Relay Chain with a
+		/// `Transact` as `Superuser`.
+		#[pallet::call_index(9)]
+		#[pallet::weight(Weight::from_parts(100_000_000, 9000)
+				.saturating_add(T::DbWeight::get().reads_writes(10, 10))
+				.saturating_mul(accounts.len() as u64))]
+		pub fn receive_accounts(
+			origin: OriginFor<T>,
+			accounts: Vec<RcAccountOf<T>>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			let count = accounts.len() as u32;
+			for account in accounts {
+				Self::do_receive_account(account)?;
+			}
+			Self::deposit_event(Event::AccountsReceived { count });
+			Ok(())
+		}
+
+		/// Note that the Relay Chain started to migrate its accounts.
+		///
+		/// Can only be called by the Relay Chain with a `Transact` as `Superuser`.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::DbWeight::get().writes(1))]
+		pub fn start_migration(origin: OriginFor<T>) -> DispatchResult {
+			ensure_root(origin)?;
+
+			Self::deposit_event(Event::AssetHubMigrationStarted);
+			Ok(())
+		}
+
+		/// Note that the Relay Chain finished to migrate its accounts.
+		///
+		/// Can only be called by the Relay Chain with a `Transact` as `Superuser`.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::DbWeight::get().writes(1))]
+		pub fn finish_migration(origin: OriginFor<T>) -> DispatchResult {
+			ensure_root(origin)?;
+
+			Self::deposit_event(Event::AssetHubMigrationFinished);
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
+		/// Mint the balance of an account from the Relay Chain and re-apply its holds, unnamed
+		/// reserve, freezes and locks.
+		///
+		/// Holds without a local reason are added to the unnamed reserve.
+		pub fn do_receive_account(account: RcAccountOf<T>) -> Result<(), Error<T>> {
+			let who = account.who;
+			<T as Config>::Currency::mint_into(&who, account.free.saturating_add(account.reserved))
+				.map_err(|_| Error::<T>::FailedToReceiveAccount)?;
+
+			let mut unnamed_reserve = account.unnamed_reserve;
+			for hold in account.holds.into_iter() {
+				match T::PortableToAhHoldReason::convert(hold.reason) {
+					Some(reason) => <T as Config>::Currency::hold(&reason, &who, hold.amount)
+						.map_err(|_| Error::<T>::FailedToReceiveAccount)?,
+					None => unnamed_reserve = unnamed_reserve.saturating_add(hold.amount),
+				}
+			}
+			<T as Config>::Currency::reserve(&who, unnamed_reserve)
+				.map_err(|_| Error::<T>::FailedToReceiveAccount)?;
+
+			for freeze in account.freezes.into_iter() {
+				let id = T::PortableToAhFreezeReason::convert(freeze.reason)
+					.ok_or(Error::<T>::FailedToReceiveAccount)?;
+				<T as Config>::Currency::set_freeze(&id, &who, freeze.amount)
+					.map_err(|_| Error::<T>::FailedToReceiveAccount)?;
+			}
+			for lock in account.locks.into_iter() {
+				<T as Config>::Currency::set_lock(
+					lock.id,
+					&who,
+					lock.amount,
+					map_lock_reason(lock.reasons),
+				);
+			}
+
+			Ok(())
+		}
+
 		pub fn do_unreserve_lease_deposit(
 			block: BlockNumberFor<T>,
 			depositor: T::AccountId,
@@ -493,6 +832,129 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Process every entry of a batch in its own storage layer, skipping the failing ones.
+		fn do_batch(
+			items: BoundedVec<BatchItemOf<T>, T::MaxBatchSize>,
+			f: impl Fn(BlockNumberFor<T>, T::AccountId, ParaId) -> Result<(), Error<T>>,
+		) {
+			for (index, (block, para_id, depositor)) in items.into_iter().enumerate() {
+				let res = with_storage_layer::<(), DispatchError, _>(|| {
+					f(block, depositor, para_id).map_err(Into::into)
+				});
+				if let Err(error) = res {
+					Self::deposit_event(Event::BatchItemFailed { index: index as u32, error });
+				}
+			}
+		}
+
+		/// Process matured crowdloan contributions, lease reserves and crowdloan reserves until
+		/// the `meter` is exhausted.
+		///
+		/// The sweeper walks the maps in this order and continues where it stopped in the
+		/// previous block. Entries that are not matured yet are skipped and visited again in the
+		/// next round.
+		pub(crate) fn sweep(meter: &mut WeightMeter) {
+			let now = T::RcBlockNumberProvider::current_block_number();
+			let mut cursor = Sweeper::<T>::get();
+			let start_stage = cursor.stage;
+
+			loop {
+				let done = match cursor.stage {
+					SweepStage::Contributions => Self::sweep_map(
+						meter,
+						&mut cursor,
+						RcCrowdloanContribution::<T>::iter_keys(),
+						RcCrowdloanContribution::<T>::iter_keys_from,
+						<T as Config>::WeightInfo::withdraw_crowdloan_contribution(),
+						now,
+						Self::do_withdraw_crowdloan_contribution,
+					),
+					SweepStage::LeaseReserves => Self::sweep_map(
+						meter,
+						&mut cursor,
+						RcLeaseReserve::<T>::iter_keys(),
+						RcLeaseReserve::<T>::iter_keys_from,
+						<T as Config>::WeightInfo::unreserve_lease_deposit(),
+						now,
+						Self::do_unreserve_lease_deposit,
+					),
+					SweepStage::CrowdloanReserves => Self::sweep_map(
+						meter,
+						&mut cursor,
+						RcCrowdloanReserve::<T>::iter_keys(),
+						RcCrowdloanReserve::<T>::iter_keys_from,
+						<T as Config>::WeightInfo::unreserve_crowdloan_reserve(),
+						now,
+						Self::do_unreserve_crowdloan_reserve,
+					),
+				};
+				if !done {
+					break;
+				}
+
+				cursor.stage = match cursor.stage {
+					SweepStage::Contributions => SweepStage::LeaseReserves,
+					SweepStage::LeaseReserves => SweepStage::CrowdloanReserves,
+					SweepStage::CrowdloanReserves => SweepStage::Contributions,
+				};
+				cursor.last_key = None;
+				// Visit every map at most once per block.
+				if cursor.stage == start_stage {
+					break;
+				}
+			}
+
+			Sweeper::<T>::put(cursor);
+		}
+
+		/// Sweep a single map starting after `cursor.last_key`.
+		///
+		/// Returns `true` if the end of the map was reached.
+		fn sweep_map(
+			meter: &mut WeightMeter,
+			cursor: &mut SweepCursor,
+			iter_all: frame_support::storage::KeyPrefixIterator<BatchItemOf<T>>,
+			iter_from: impl Fn(Vec<u8>) -> frame_support::storage::KeyPrefixIterator<BatchItemOf<T>>,
+			item_weight: Weight,
+			now: BlockNumberFor<T>,
+			process: impl Fn(BlockNumberFor<T>, T::AccountId, ParaId) -> Result<(), Error<T>>,
+		) -> bool {
+			let mut iter = match cursor.last_key.clone() {
+				Some(last_key) => iter_from(last_key.into_inner()),
+				None => iter_all,
+			};
+			let read_weight = T::DbWeight::get().reads(1);
+
+			loop {
+				if !meter.can_consume(item_weight.saturating_add(read_weight)) {
+					return false;
+				}
+				let Some((block, para_id, depositor)) = iter.next() else {
+					return true;
+				};
+				meter.consume(read_weight);
+				cursor.last_key = BoundedVec::try_from(iter.last_raw_key().to_vec()).ok();
+
+				if block > now {
+					continue;
+				}
+				meter.consume(item_weight);
+				let res = with_storage_layer::<(), DispatchError, _>(|| {
+					process(block, depositor.clone(), para_id).map_err(Into::into)
+				});
+				if let Err(e) = res {
+					log::debug!(
+						target: LOG_TARGET,
+						"Sweeper skipped {:?} of para {:?} at block {:?}: {:?}",
+						depositor,
+						para_id,
+						block,
+						e
+					);
+				}
+			}
+		}
+
 		fn contributions_withdrawn(block: BlockNumberFor<T>, para_id: ParaId) -> bool {
 			let mut contrib_iter = RcCrowdloanContribution::<T>::iter_prefix((block, para_id));
 			contrib_iter.next().is_none()
@@ -583,7 +1045,29 @@ pub mod pallet {
 				// Nothing to do if the account does not exist
 				return Ok(());
 			}
-			pallet_balances::Pallet::<T>::ensure_upgraded(&from); // prevent future headache
+
+			Self::translate_sovereign(para_id, derivation_path, &from, &to, None)?;
+			Ok(())
+		}
+
+		/// Move the balances and staking of `from` to `to`.
+		///
+		/// If a `witness` is given, the state of other pallets is moved with
+		/// `T::AccountTranslator` as well.
+		///
+		/// Returns the per-item report.
+		pub fn translate_sovereign(
+			para_id: u16,
+			derivation_path: Vec<u16>,
+			from: &T::AccountId,
+			to: &T::AccountId,
+			witness: Option<&TranslationWitness<T::AccountId>>,
+		) -> Result<Vec<ItemReport>, Error<T>> {
+			let (from, to) = (from.clone(), to.clone());
+			let mut report = Vec::new();
+			if frame_system::Account::<T>::contains_key(&from) {
+				pallet_balances::Pallet::<T>::ensure_upgraded(&from); // prevent future headache
+			}
 
 			// Get the bonded amount that we will force-unstake.
 			let active_bonded =
@@ -621,6 +1105,7 @@ pub mod pallet {
 
 			// Transfer all assets to the new account. This must not create or reap an account since
 			// that could fail, depending on whether all assets are sufficient.
+			let mut assets = 0;
 			for id in T::RelevantAssets::get() {
 				let amount = <T as Config>::Fungibles::reducible_balance(
 					id.clone(),
@@ -642,6 +1127,16 @@ pub mod pallet {
 				)
 				.defensive()
 				.map_err(|_| Error::<T>::FailedToTransfer)?;
+				assets += 1;
+			}
+			if assets > 0 {
+				report.push(ItemReport::new(TranslatedItem::Assets, assets, 0));
+			}
+
+			// Move the state of other pallets. Deposits that are released on the old account are
+			// transferred along with the remaining DOT.
+			if let Some(witness) = witness {
+				T::AccountTranslator::translate(&from, &to, witness, &mut report);
 			}
 
 			// Now transfer the remaining DOT to the new account.
@@ -660,6 +1155,10 @@ pub mod pallet {
 				.defensive()
 				.map_err(|_| Error::<T>::FailedToTransfer)?;
 			}
+			if reducible_dot >= ed || remaining_dot > 0 {
+				let left = <<T as Config>::Currency as FungibleInspect<_>>::total_balance(&from);
+				report.push(ItemReport::new(TranslatedItem::Balance, 1, (left > 0) as u32));
+			}
 
 			// Re-stake the new account:
 			if active_bonded > 0 {
@@ -678,6 +1177,7 @@ pub mod pallet {
 						amount: active_bonded,
 					});
 				}
+				report.push(ItemReport::new(TranslatedItem::Staking, res.is_ok() as u32, 0));
 			}
 
 			Self::deposit_event(Event::SovereignMigrated {
@@ -687,7 +1187,7 @@ pub mod pallet {
 				derivation_path,
 			});
 
-			Ok(())
+			Ok(report)
 		}
 
 		/// Sovereign child account of a parachain (normally on the relay chain).
@@ -708,6 +1208,24 @@ pub mod pallet {
 	}
 }
 
+pub mod runtime_api {
+	use crate::claims::Claim;
+	use sp_std::vec::Vec;
+
+	sp_api::decl_runtime_apis! {
+		/// API to query the lease and crowdloan funds that are managed by `pallet_ah_ops`.
+		pub trait AhOpsApi<AccountId, BlockNumber, Balance> where
+			AccountId: codec::Codec,
+			BlockNumber: codec::Codec,
+			Balance: codec::Codec,
+		{
+			/// Returns the lease deposits, crowdloan contributions and crowdloan reserves that
+			/// `who` can claim, together with their unlock block and para id.
+			fn claimable_funds(who: AccountId) -> Vec<Claim<BlockNumber, Balance>>;
+		}
+	}
+}
+
 // Copied from https://github.com/paritytech/polkadot-sdk/blob/436b4935b52562f79a83b6ecadeac7dcbc1c2367/substrate/frame/utility/src/lib.rs#L627-L639
 /// Derive a derivative account ID from the owner account and the sub-account index.
 ///
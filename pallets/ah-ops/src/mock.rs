@@ -17,11 +17,19 @@
 use crate as pallet_ah_ops;
 use crate::*;
 use frame_election_provider_support::BoundedSupportsOf;
-use frame_support::{derive_impl, traits::ConstBool, PalletId};
+use frame_support::{
+	derive_impl,
+	traits::{AsEnsureOriginWithArg, ConstBool, ConstU128, ConstU64, Nothing, WithdrawReasons},
+	PalletId,
+};
 use frame_system::{EnsureRoot, EnsureSigned};
 use pallet_election_provider_multi_block::PageIndex;
-use sp_core::H256;
-use sp_runtime::traits::{parameter_types, BlakeTwo256, IdentityLookup};
+use pallet_nfts::PalletFeatures;
+use sp_core::{H256, U256};
+use sp_runtime::{
+	traits::{parameter_types, BlakeTwo256, Convert, ConvertInto, IdentityLookup, Verify},
+	FixedU128, MultiSignature, Perbill,
+};
 
 type Block = frame_system::mocking::MockBlock<Runtime>;
 
@@ -34,6 +42,13 @@ frame_support::construct_runtime!(
 		AhOps: pallet_ah_ops,
 		Timestamp: pallet_timestamp,
 		Staking: pallet_staking_async,
+		Vesting: pallet_vesting,
+		Proxy: pallet_proxy,
+		Multisig: pallet_multisig,
+		NominationPools: pallet_nomination_pools,
+		DelegatedStaking: pallet_delegated_staking,
+		Uniques: pallet_uniques,
+		Nfts: pallet_nfts,
 	}
 );
 
@@ -67,6 +82,8 @@ impl frame_system::Config for Runtime {
 impl pallet_balances::Config for Runtime {
 	type Balance = u128;
 	type AccountStore = System;
+	type MaxLocks = ConstU32<50>;
+	type MaxFreezes = frame_support::traits::VariantCountOf<RuntimeFreezeReason>;
 }
 
 #[derive_impl(pallet_assets::config_preludes::TestDefaultConfig)]
@@ -108,7 +125,7 @@ impl pallet_staking_async::Config for Runtime {
 	type TargetList = Self;
 	type MaxValidatorSet = ();
 	type NominationsQuota = pallet_staking_async::FixedNominationsQuota<100>;
-	type MaxUnlockingChunks = ();
+	type MaxUnlockingChunks = ConstU32<32>;
 	type HistoryDepth = ();
 	type MaxControllersInDeprecationBatch = ();
 	type EventListeners = ();
@@ -239,6 +256,154 @@ impl frame_election_provider_support::ElectionProvider for Runtime {
 	}
 }
 
+parameter_types! {
+	pub UnvestedFundsAllowedWithdrawReasons: WithdrawReasons =
+		WithdrawReasons::except(WithdrawReasons::TRANSFER | WithdrawReasons::RESERVE);
+}
+
+impl pallet_vesting::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type BlockNumberToBalance = ConvertInto;
+	type MinVestedTransfer = ConstU128<1>;
+	type WeightInfo = ();
+	type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
+	type BlockNumberProvider = System;
+	const MAX_VESTING_SCHEDULES: u32 = 3;
+}
+
+impl pallet_proxy::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type Currency = Balances;
+	type ProxyType = ();
+	type ProxyDepositBase = ConstU128<10>;
+	type ProxyDepositFactor = ConstU128<1>;
+	type MaxProxies = ConstU32<4>;
+	type WeightInfo = ();
+	type MaxPending = ConstU32<2>;
+	type CallHasher = BlakeTwo256;
+	type AnnouncementDepositBase = ConstU128<10>;
+	type AnnouncementDepositFactor = ConstU128<1>;
+	type BlockNumberProvider = System;
+}
+
+impl pallet_multisig::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type Currency = Balances;
+	type DepositBase = ConstU128<10>;
+	type DepositFactor = ConstU128<1>;
+	type MaxSignatories = ConstU32<4>;
+	type WeightInfo = ();
+	type BlockNumberProvider = System;
+}
+
+pub struct BalanceToU256;
+impl Convert<u128, U256> for BalanceToU256 {
+	fn convert(n: u128) -> U256 {
+		n.into()
+	}
+}
+
+pub struct U256ToBalance;
+impl Convert<U256, u128> for U256ToBalance {
+	fn convert(n: U256) -> u128 {
+		n.try_into().unwrap_or(u128::MAX)
+	}
+}
+
+parameter_types! {
+	pub const PoolsPalletId: PalletId = PalletId(*b"py/nopls");
+	pub const DelegatedStakingPalletId: PalletId = PalletId(*b"py/dlstk");
+	pub const SlashRewardFraction: Perbill = Perbill::from_percent(1);
+}
+
+impl pallet_nomination_pools::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type RewardCounter = FixedU128;
+	type BalanceToU256 = BalanceToU256;
+	type U256ToBalance = U256ToBalance;
+	type StakeAdapter =
+		pallet_nomination_pools::adapter::DelegateStake<Self, Staking, DelegatedStaking>;
+	type PostUnbondingPoolsWindow = ConstU32<2>;
+	type MaxMetadataLen = ConstU32<256>;
+	type MaxUnbonding = ConstU32<8>;
+	type PalletId = PoolsPalletId;
+	type MaxPointsToBalance = frame_support::traits::ConstU8<10>;
+	type WeightInfo = ();
+	type AdminOrigin = EnsureRoot<AccountId32>;
+	type Filter = Nothing;
+	type BlockNumberProvider = System;
+}
+
+impl pallet_delegated_staking::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type PalletId = DelegatedStakingPalletId;
+	type Currency = Balances;
+	type OnSlash = ();
+	type SlashRewardFraction = SlashRewardFraction;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type CoreStaking = Staking;
+}
+
+impl pallet_uniques::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type CollectionId = u32;
+	type ItemId = u32;
+	type Currency = Balances;
+	type ForceOrigin = EnsureRoot<AccountId32>;
+	type CollectionDeposit = ConstU128<2>;
+	type ItemDeposit = ConstU128<1>;
+	type MetadataDepositBase = ConstU128<1>;
+	type AttributeDepositBase = ConstU128<1>;
+	type DepositPerByte = ConstU128<1>;
+	type StringLimit = ConstU32<50>;
+	type KeyLimit = ConstU32<50>;
+	type ValueLimit = ConstU32<50>;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type Helper = ();
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId32>>;
+	type Locker = ();
+}
+
+parameter_types! {
+	pub NftsPalletFeatures: PalletFeatures = PalletFeatures::all_enabled();
+}
+
+impl pallet_nfts::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type CollectionId = u32;
+	type ItemId = u32;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId32>>;
+	type ForceOrigin = EnsureRoot<AccountId32>;
+	type Locker = ();
+	type CollectionDeposit = ConstU128<2>;
+	type ItemDeposit = ConstU128<1>;
+	type MetadataDepositBase = ConstU128<1>;
+	type AttributeDepositBase = ConstU128<1>;
+	type DepositPerByte = ConstU128<1>;
+	type StringLimit = ConstU32<50>;
+	type KeyLimit = ConstU32<50>;
+	type ValueLimit = ConstU32<50>;
+	type ApprovalsLimit = ConstU32<10>;
+	type ItemAttributesApprovalsLimit = ConstU32<2>;
+	type MaxTips = ConstU32<10>;
+	type MaxDeadlineDuration = ConstU64<10_000>;
+	type MaxAttributesPerCall = ConstU32<2>;
+	type Features = NftsPalletFeatures;
+	type OffchainSignature = MultiSignature;
+	type OffchainPublic = <MultiSignature as Verify>::Signer;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type Helper = ();
+	type BlockNumberProvider = System;
+}
+
 parameter_types! {
 	pub const MigrationCompletion: bool = true;
 	pub TreasuryPreMigrationAccount: AccountId32 = AccountId32::from([1; 32]);
@@ -247,6 +412,30 @@ parameter_types! {
 	pub MigrationEndBlock: BlockNumberFor<Runtime> = 456u64;
 }
 
+/// Converts the hold reasons of the Relay Chain into the hold reasons of the mock.
+pub struct PortableToAhHoldReason;
+impl Convert<PortableHoldReason, Option<RuntimeHoldReason>> for PortableToAhHoldReason {
+	fn convert(reason: PortableHoldReason) -> Option<RuntimeHoldReason> {
+		match reason {
+			PortableHoldReason::Staking(_) =>
+				Some(RuntimeHoldReason::Staking(pallet_staking_async::HoldReason::Staking)),
+			PortableHoldReason::DelegatedStaking(r) => Some(RuntimeHoldReason::DelegatedStaking(r)),
+			_ => None,
+		}
+	}
+}
+
+/// Converts the freeze reasons of the Relay Chain into the freeze reasons of the mock.
+pub struct PortableToAhFreezeReason;
+impl Convert<PortableFreezeReason, Option<RuntimeFreezeReason>> for PortableToAhFreezeReason {
+	fn convert(reason: PortableFreezeReason) -> Option<RuntimeFreezeReason> {
+		match reason {
+			PortableFreezeReason::NominationPools(r) =>
+				Some(RuntimeFreezeReason::NominationPools(r)),
+		}
+	}
+}
+
 impl Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
@@ -261,6 +450,17 @@ impl Config for Runtime {
 	type TreasuryPostMigrationAccount = TreasuryPostMigrationAccount;
 	type MigrationStartBlock = MigrationStartBlock;
 	type MigrationEndBlock = MigrationEndBlock;
+	type MaxBatchSize = ConstU32<8>;
+	type PortableToAhHoldReason = PortableToAhHoldReason;
+	type PortableToAhFreezeReason = PortableToAhFreezeReason;
+	type AccountTranslator = (
+		translate::VestingTranslator<Runtime>,
+		translate::ProxyTranslator<Runtime>,
+		translate::MultisigTranslator<Runtime>,
+		translate::PoolMemberTranslator<Runtime>,
+		translate::UniquesTranslator<Runtime>,
+		translate::NftsTranslator<Runtime>,
+	);
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -15,10 +15,26 @@
 // limitations under the License.
 
 use crate::{
-	mock::{new_test_ext, Runtime as AssetHub, RuntimeOrigin},
-	Error,
+	derivative_account_id_recursive,
+	mock::{
+		new_test_ext, Balances, Multisig, Nfts, NominationPools, Proxy, Runtime as AssetHub,
+		RuntimeEvent, RuntimeOrigin, System, Uniques, Vesting,
+	},
+	Claim, ClaimKind, Error, Event, ItemReport, MultisigWitness, RcAccountOf,
+	RcCrowdloanContribution, RcCrowdloanReserve, RcLeaseReserve, TranslatedItem,
+	TranslationWitness,
 };
-use frame_support::{assert_noop, assert_ok};
+use cumulus_primitives_core::ParaId;
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{
+		fungible::{InspectFreeze, InspectHold},
+		Currency, Hooks, ReservableCurrency, VestingSchedule,
+	},
+	weights::Weight,
+};
+use pallet_balances::{BalanceLock, Reasons};
+use pallet_rc_migrator::types::{PortableFreezeReason, PortableHoldReason, ReasonAmount};
 use sp_runtime::AccountId32;
 use std::str::FromStr;
 
@@ -222,3 +238,480 @@ fn moonbeam_stellaswap_double_derived_translation() {
 		));
 	});
 }
+
+#[test]
+fn batch_skips_failing_items() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(10);
+		let para_id = ParaId::from(2000);
+		let alice = AccountId32::from([10; 32]);
+		let bob = AccountId32::from([11; 32]);
+		Balances::make_free_balance_be(&alice, 100);
+		assert_ok!(Balances::reserve(&alice, 50));
+		RcLeaseReserve::<AssetHub>::insert((5, para_id, &alice), 50);
+
+		assert_ok!(crate::Pallet::<AssetHub>::unreserve_lease_deposits(
+			RuntimeOrigin::signed(alice.clone()),
+			vec![(5, para_id, bob), (5, para_id, alice.clone())].try_into().unwrap(),
+		));
+
+		System::assert_has_event(
+			Event::<AssetHub>::BatchItemFailed {
+				index: 0,
+				error: Error::<AssetHub>::NoLeaseReserve.into(),
+			}
+			.into(),
+		);
+		assert_eq!(Balances::reserved_balance(&alice), 0);
+		assert!(RcLeaseReserve::<AssetHub>::get((5, para_id, &alice)).is_none());
+	});
+}
+
+#[test]
+fn on_idle_sweeps_matured_entries() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(10);
+		let para_id = ParaId::from(2000);
+		let pot = AccountId32::from([20; 32]);
+		let contributor = AccountId32::from([21; 32]);
+		let depositor = AccountId32::from([22; 32]);
+		Balances::make_free_balance_be(&pot, 101);
+		assert_ok!(Balances::reserve(&pot, 100));
+		Balances::make_free_balance_be(&depositor, 100);
+		assert_ok!(Balances::reserve(&depositor, 10));
+		RcLeaseReserve::<AssetHub>::insert((5, para_id, &pot), 100);
+		RcCrowdloanContribution::<AssetHub>::insert((5, para_id, &contributor), (pot.clone(), 100));
+		RcCrowdloanReserve::<AssetHub>::insert((5, para_id, &depositor), 10);
+		// Not matured yet.
+		RcCrowdloanReserve::<AssetHub>::insert((20, para_id, &depositor), 10);
+
+		crate::Pallet::<AssetHub>::on_idle(10, Weight::MAX);
+
+		assert_eq!(Balances::free_balance(&contributor), 100);
+		assert_eq!(Balances::free_balance(&pot), 1);
+		assert_eq!(Balances::free_balance(&depositor), 100);
+		assert!(RcCrowdloanContribution::<AssetHub>::iter_keys().next().is_none());
+		assert!(RcLeaseReserve::<AssetHub>::iter_keys().next().is_none());
+		assert_eq!(RcCrowdloanReserve::<AssetHub>::iter_keys().collect::<Vec<_>>().len(), 1);
+	});
+}
+
+#[test]
+fn claimable_funds_lists_all_claims_of_account() {
+	new_test_ext().execute_with(|| {
+		let para_id = ParaId::from(2000);
+		let pot = AccountId32::from([30; 32]);
+		let alice = AccountId32::from([31; 32]);
+		let bob = AccountId32::from([32; 32]);
+		RcLeaseReserve::<AssetHub>::insert((5, para_id, &alice), 10);
+		RcLeaseReserve::<AssetHub>::insert((5, para_id, &pot), 30);
+		RcCrowdloanContribution::<AssetHub>::insert((7, para_id, &alice), (pot.clone(), 20));
+		RcCrowdloanContribution::<AssetHub>::insert((7, para_id, &bob), (pot.clone(), 10));
+		RcCrowdloanReserve::<AssetHub>::insert((9, para_id, &alice), 5);
+
+		let claims = crate::Pallet::<AssetHub>::claimable_funds(alice);
+		assert_eq!(
+			claims,
+			vec![
+				Claim { kind: ClaimKind::LeaseDeposit, unlock_block: 5, para_id, amount: 10 },
+				Claim {
+					kind: ClaimKind::CrowdloanContribution,
+					unlock_block: 7,
+					para_id,
+					amount: 20
+				},
+				Claim { kind: ClaimKind::CrowdloanReserve, unlock_block: 9, para_id, amount: 5 },
+			]
+		);
+		assert_eq!(crate::Pallet::<AssetHub>::claimable_funds(bob).len(), 1);
+	});
+}
+
+#[test]
+fn permissionless_translation_checks_origin_and_reports() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let para_id = 2004;
+		let path = vec![5, 2];
+		let sibling = crate::Pallet::<AssetHub>::para_sov_sibling(para_id);
+		let from = derivative_account_id_recursive(
+			crate::Pallet::<AssetHub>::para_sov_child(para_id),
+			&path,
+		);
+		let to = derivative_account_id_recursive(sibling.clone(), &path);
+		Balances::make_free_balance_be(&from, 1_000);
+
+		assert_noop!(
+			crate::Pallet::<AssetHub>::translate_para_sovereign_derived(
+				RuntimeOrigin::signed(from.clone()),
+				para_id,
+				path.clone(),
+				Default::default(),
+			),
+			Error::<AssetHub>::NotParaSovereign
+		);
+
+		assert_ok!(crate::Pallet::<AssetHub>::translate_para_sovereign_derived(
+			RuntimeOrigin::signed(sibling),
+			para_id,
+			path,
+			Default::default(),
+		));
+
+		assert_eq!(Balances::free_balance(&from), 0);
+		assert_eq!(Balances::free_balance(&to), 1_000);
+		System::assert_last_event(
+			Event::<AssetHub>::SovereignMigrationReport {
+				para_id,
+				from,
+				to,
+				items: vec![ItemReport::new(TranslatedItem::Balance, 1, 0)],
+			}
+			.into(),
+		);
+	});
+}
+
+/// The sibling sovereign account of `para_id` and the old and new account of `path`.
+fn derived_accounts(para_id: u16, path: &[u16]) -> (AccountId32, AccountId32, AccountId32) {
+	let sibling = crate::Pallet::<AssetHub>::para_sov_sibling(para_id);
+	let from =
+		derivative_account_id_recursive(crate::Pallet::<AssetHub>::para_sov_child(para_id), path);
+	let to = derivative_account_id_recursive(sibling.clone(), path);
+	(sibling, from, to)
+}
+
+fn translate(
+	para_id: u16,
+	path: Vec<u16>,
+	witness: TranslationWitness<AccountId32>,
+) -> Vec<ItemReport> {
+	let (sibling, ..) = derived_accounts(para_id, &path);
+	assert_ok!(crate::Pallet::<AssetHub>::translate_para_sovereign_derived(
+		RuntimeOrigin::signed(sibling),
+		para_id,
+		path,
+		witness,
+	));
+	System::events()
+		.into_iter()
+		.rev()
+		.find_map(|record| match record.event {
+			RuntimeEvent::AhOps(Event::SovereignMigrationReport { items, .. }) => Some(items),
+			_ => None,
+		})
+		.expect("report was emitted")
+}
+
+#[test]
+fn permissionless_translation_requires_existing_account() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (sibling, ..) = derived_accounts(2004, &[5]);
+
+		assert_noop!(
+			crate::Pallet::<AssetHub>::translate_para_sovereign_derived(
+				RuntimeOrigin::signed(sibling),
+				2004,
+				vec![5],
+				Default::default(),
+			),
+			Error::<AssetHub>::AccountNotFound
+		);
+	});
+}
+
+#[test]
+fn permissionless_translation_moves_vesting_schedules() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (_, from, to) = derived_accounts(2004, &[1]);
+		Balances::make_free_balance_be(&from, 1_000);
+		assert_ok!(<Vesting as VestingSchedule<_>>::add_vesting_schedule(&from, 500, 10, 10));
+		assert_ok!(<Vesting as VestingSchedule<_>>::add_vesting_schedule(&from, 200, 5, 20));
+
+		assert_eq!(
+			translate(2004, vec![1], Default::default()),
+			vec![
+				ItemReport::new(TranslatedItem::Vesting, 2, 0),
+				ItemReport::new(TranslatedItem::Balance, 1, 0),
+			]
+		);
+
+		assert_eq!(pallet_vesting::Vesting::<AssetHub>::get(&from), None);
+		let schedules = pallet_vesting::Vesting::<AssetHub>::get(&to).unwrap();
+		assert_eq!(schedules.len(), 2);
+		assert_eq!(<Vesting as VestingSchedule<_>>::vesting_balance(&to), Some(700));
+		assert_eq!(Balances::total_balance(&to), 1_000);
+	});
+}
+
+#[test]
+fn permissionless_translation_moves_proxies() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (_, from, to) = derived_accounts(2004, &[2]);
+		let delegate = AccountId32::from([9; 32]);
+		Balances::make_free_balance_be(&from, 1_000);
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(from.clone()), delegate.clone(), (), 0));
+
+		assert_eq!(
+			translate(2004, vec![2], Default::default()),
+			vec![
+				ItemReport::new(TranslatedItem::Proxies, 1, 0),
+				ItemReport::new(TranslatedItem::Balance, 1, 0),
+			]
+		);
+
+		assert!(pallet_proxy::Proxies::<AssetHub>::get(&from).0.is_empty());
+		let (proxies, deposit) = pallet_proxy::Proxies::<AssetHub>::get(&to);
+		assert_eq!(proxies.len(), 1);
+		assert_eq!(proxies[0].delegate, delegate);
+		assert_eq!(Balances::reserved_balance(&to), deposit);
+		assert_eq!(Balances::total_balance(&to), 1_000);
+	});
+}
+
+#[test]
+fn permissionless_translation_cancels_witnessed_multisigs() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (_, from, to) = derived_accounts(2004, &[3]);
+		Balances::make_free_balance_be(&from, 1_000);
+		let mut others = vec![AccountId32::from([7; 32]), AccountId32::from([8; 32])];
+		others.sort();
+		let call_hash = [1; 32];
+		assert_ok!(Multisig::approve_as_multi(
+			RuntimeOrigin::signed(from.clone()),
+			2,
+			others.clone(),
+			None,
+			call_hash,
+			Weight::zero(),
+		));
+		let mut signatories = others.clone();
+		signatories.push(from.clone());
+		signatories.sort();
+		let multisig = Multisig::multi_account_id(&signatories, 2);
+		assert!(Balances::reserved_balance(&from) > 0);
+
+		let witness = TranslationWitness {
+			multisigs: vec![
+				MultisigWitness {
+					threshold: 2,
+					other_signatories: others.clone().try_into().unwrap(),
+					call_hash,
+				},
+				// Not deposited by the old account and ignored.
+				MultisigWitness {
+					threshold: 2,
+					other_signatories: others.try_into().unwrap(),
+					call_hash: [2; 32],
+				},
+			]
+			.try_into()
+			.unwrap(),
+		};
+		assert_eq!(
+			translate(2004, vec![3], witness),
+			vec![
+				ItemReport::new(TranslatedItem::MultisigsCancelled, 1, 0),
+				ItemReport::new(TranslatedItem::Balance, 1, 0),
+			]
+		);
+
+		assert!(pallet_multisig::Multisigs::<AssetHub>::get(&multisig, call_hash).is_none());
+		System::assert_has_event(
+			pallet_multisig::Event::<AssetHub>::MultisigCancelled {
+				cancelling: from.clone(),
+				timepoint: Multisig::timepoint(),
+				multisig,
+				call_hash,
+			}
+			.into(),
+		);
+		assert_eq!(Balances::total_balance(&from), 0);
+		assert_eq!(Balances::free_balance(&to), 1_000);
+	});
+}
+
+#[test]
+fn permissionless_translation_leaves_nomination_pool() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (_, from, to) = derived_accounts(2004, &[4]);
+		let depositor = AccountId32::from([7; 32]);
+		Balances::make_free_balance_be(&depositor, 1_000);
+		Balances::make_free_balance_be(&from, 1_000);
+		assert_ok!(NominationPools::create(
+			RuntimeOrigin::signed(depositor.clone()),
+			100,
+			depositor.clone(),
+			depositor.clone(),
+			depositor,
+		));
+		assert_ok!(NominationPools::join(RuntimeOrigin::signed(from.clone()), 200, 1));
+
+		// The bonding duration of the mock is zero, so the stake is withdrawn right away.
+		assert_eq!(
+			translate(2004, vec![4], Default::default()),
+			vec![
+				ItemReport::new(TranslatedItem::NominationPoolUnbonded, 1, 0),
+				ItemReport::new(TranslatedItem::Balance, 1, 0),
+			]
+		);
+
+		assert!(!pallet_nomination_pools::PoolMembers::<AssetHub>::contains_key(&from));
+		assert_eq!(Balances::total_balance(&from), 0);
+		assert_eq!(Balances::free_balance(&to), 1_000);
+	});
+}
+
+#[test]
+fn permissionless_translation_moves_uniques() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (_, from, to) = derived_accounts(2004, &[6]);
+		Balances::make_free_balance_be(&from, 1_000);
+		assert_ok!(Uniques::create(RuntimeOrigin::signed(from.clone()), 0, from.clone()));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(from.clone()), 0, 42, from.clone()));
+
+		assert_eq!(
+			translate(2004, vec![6], Default::default()),
+			vec![
+				ItemReport::new(TranslatedItem::Uniques, 2, 0),
+				ItemReport::new(TranslatedItem::Balance, 1, 0),
+			]
+		);
+
+		assert_eq!(Uniques::owner(0, 42), Some(to.clone()));
+		assert_eq!(Uniques::collection_owner(0), Some(to.clone()));
+		assert_eq!(Balances::total_balance(&from), 0);
+	});
+}
+
+#[test]
+fn permissionless_translation_moves_nfts() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (_, from, to) = derived_accounts(2004, &[7]);
+		Balances::make_free_balance_be(&from, 1_000);
+		assert_ok!(Nfts::create(
+			RuntimeOrigin::signed(from.clone()),
+			from.clone(),
+			pallet_nfts::CollectionConfig {
+				settings: pallet_nfts::CollectionSettings::all_enabled(),
+				max_supply: None,
+				mint_settings: Default::default(),
+			},
+		));
+		assert_ok!(Nfts::mint(
+			RuntimeOrigin::signed(from.clone()),
+			0,
+			42,
+			Some(from.clone()),
+			None
+		));
+
+		assert_eq!(
+			translate(2004, vec![7], Default::default()),
+			vec![
+				ItemReport::new(TranslatedItem::Nfts, 2, 0),
+				ItemReport::new(TranslatedItem::Balance, 1, 0),
+			]
+		);
+
+		assert_eq!(Nfts::owner(0, 42), Some(to.clone()));
+		assert_eq!(Nfts::collection_owner(0), Some(to));
+	});
+}
+
+fn rc_account(id: u8, amount: u128) -> RcAccountOf<AssetHub> {
+	RcAccountOf::<AssetHub> {
+		who: AccountId32::new([id; 32]),
+		free: amount - 300,
+		reserved: 300,
+		frozen: 400,
+		holds: vec![ReasonAmount {
+			reason: PortableHoldReason::DelegatedStaking(
+				pallet_delegated_staking::HoldReason::StakingDelegation,
+			),
+			amount: 200,
+		}]
+		.try_into()
+		.unwrap(),
+		freezes: vec![ReasonAmount {
+			reason: PortableFreezeReason::NominationPools(
+				pallet_nomination_pools::FreezeReason::PoolMinBalance,
+			),
+			amount: 400,
+		}]
+		.try_into()
+		.unwrap(),
+		locks: vec![BalanceLock { id: *b"vesting ", amount: 300, reasons: Reasons::All }]
+			.try_into()
+			.unwrap(),
+		unnamed_reserve: 100,
+		consumers: 3,
+		providers: 1,
+	}
+}
+
+#[test]
+fn receive_accounts_mints_balance_and_reapplies_holds_freezes_and_locks() {
+	new_test_ext().execute_with(|| {
+		let account = rc_account(1, 1_000);
+		let who = account.who.clone();
+		let issuance = Balances::total_issuance();
+
+		assert_noop!(
+			crate::Pallet::<AssetHub>::receive_accounts(
+				RuntimeOrigin::signed(who.clone()),
+				vec![account.clone()]
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(crate::Pallet::<AssetHub>::receive_accounts(
+			RuntimeOrigin::root(),
+			vec![account]
+		));
+
+		assert_eq!(Balances::total_issuance(), issuance + 1_000);
+		assert_eq!(Balances::free_balance(&who), 700);
+		assert_eq!(Balances::reserved_balance(&who), 300);
+		assert_eq!(
+			Balances::balance_on_hold(
+				&pallet_delegated_staking::HoldReason::StakingDelegation.into(),
+				&who
+			),
+			200
+		);
+		assert_eq!(
+			Balances::balance_frozen(
+				&pallet_nomination_pools::FreezeReason::PoolMinBalance.into(),
+				&who
+			),
+			400
+		);
+		assert_eq!(pallet_balances::Locks::<AssetHub>::get(&who)[0].amount, 300);
+		System::assert_last_event(RuntimeEvent::AhOps(Event::AccountsReceived { count: 1 }));
+	});
+}
+
+#[test]
+fn receive_accounts_fails_for_the_whole_batch() {
+	new_test_ext().execute_with(|| {
+		let valid = rc_account(1, 1_000);
+		// Not enough balance to back the hold.
+		let invalid = RcAccountOf::<AssetHub> { free: 0, reserved: 100, ..rc_account(2, 1_000) };
+
+		assert_noop!(
+			crate::Pallet::<AssetHub>::receive_accounts(
+				RuntimeOrigin::root(),
+				vec![valid.clone(), invalid]
+			),
+			Error::<AssetHub>::FailedToReceiveAccount
+		);
+		assert_eq!(Balances::total_balance(&valid.who), 0);
+	});
+}
@@ -0,0 +1,541 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Translation of the pallet state of a parachain sovereign account besides its balances.
+//!
+//! Every translator moves the state of one pallet from the old Relay Chain derived sovereign
+//! account to the new sibling derived account. They run after the new account was created and
+//! before the remaining free balance is transferred, so any deposit that they release on the old
+//! account is transferred along with the free balance.
+//!
+//! Nothing that a translator can not move is lost. It stays on the old account and is reported in
+//! the [`ItemReport`] of its [`TranslatedItem`].
+
+use crate::*;
+use frame_support::traits::VestingSchedule;
+use sp_runtime::traits::{StaticLookup, Zero};
+
+/// The maximal number of pending multisig operations that can be passed as witness.
+pub type MaxMultisigWitnesses = ConstU32<16>;
+
+/// The maximal number of other signatories of a multisig operation that is passed as witness.
+pub type MaxMultisigSignatories = ConstU32<100>;
+
+/// The maximal number of NFTs or collections that are moved by a single translation.
+///
+/// Left-over items are reported and can be moved by calling the translation again.
+pub const MAX_NFTS_PER_TRANSLATION: u32 = 64;
+
+/// The kind of state that is moved to the new sovereign account.
+#[derive(
+	Encode,
+	DecodeWithMemTracking,
+	Decode,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	Debug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub enum TranslatedItem {
+	/// The free native balance.
+	Balance,
+	/// The balances of the assets in `T::RelevantAssets`.
+	Assets,
+	/// The bonded balance in `pallet_staking_async`.
+	Staking,
+	/// The vesting schedules.
+	Vesting,
+	/// The proxy definitions and their deposit. Pending announcements are dropped.
+	Proxies,
+	/// The pending multisig operations that were passed as witness. They are cancelled to
+	/// release their deposit.
+	MultisigsCancelled,
+	/// The membership in a nomination pool. It is not moved: the old account unbonds and leaves
+	/// the pool, and its stake is transferred with the free balance once it is withdrawn.
+	NominationPoolUnbonded,
+	/// The items and collections in `pallet_uniques`.
+	Uniques,
+	/// The items and collections in `pallet_nfts`.
+	Nfts,
+}
+
+/// How much of one [`TranslatedItem`] was moved to the new account.
+#[derive(
+	Encode,
+	DecodeWithMemTracking,
+	Decode,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	Debug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub struct ItemReport {
+	/// The kind of state.
+	pub item: TranslatedItem,
+	/// The number of entries that were moved, or cancelled and unbonded for
+	/// [`TranslatedItem::MultisigsCancelled`] and [`TranslatedItem::NominationPoolUnbonded`].
+	pub migrated: u32,
+	/// The number of entries that remain on the old account.
+	pub remaining: u32,
+}
+
+impl ItemReport {
+	pub fn new(item: TranslatedItem, migrated: u32, remaining: u32) -> Self {
+		Self { item, migrated, remaining }
+	}
+}
+
+/// A pending multisig operation that was deposited by the old account.
+#[derive(
+	Encode, DecodeWithMemTracking, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen,
+)]
+pub struct MultisigWitness<AccountId> {
+	/// The threshold of the multisig account.
+	pub threshold: u16,
+	/// The sorted signatories of the multisig account without the old account.
+	pub other_signatories: BoundedVec<AccountId, MaxMultisigSignatories>,
+	/// The hash of the call of the operation.
+	pub call_hash: [u8; 32],
+}
+
+/// Witness data for the state that can not be found from the old account alone.
+#[derive(
+	Encode, DecodeWithMemTracking, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen,
+)]
+pub struct TranslationWitness<AccountId> {
+	/// The pending multisig operations that were deposited by the old account.
+	pub multisigs: BoundedVec<MultisigWitness<AccountId>, MaxMultisigWitnesses>,
+}
+
+impl<AccountId> Default for TranslationWitness<AccountId> {
+	fn default() -> Self {
+		Self { multisigs: Default::default() }
+	}
+}
+
+/// Moves the state of a pallet from an old to a new sovereign account.
+pub trait TranslateAccount<AccountId> {
+	/// Move the state of `from` to `to` and push an [`ItemReport`] to `report`.
+	///
+	/// Must not fail. Whatever can not be moved stays on `from` and is reported as remaining.
+	fn translate(
+		from: &AccountId,
+		to: &AccountId,
+		witness: &TranslationWitness<AccountId>,
+		report: &mut Vec<ItemReport>,
+	);
+
+	/// The maximal weight of [`Self::translate`].
+	fn weight() -> Weight;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(10)]
+impl<AccountId> TranslateAccount<AccountId> for Tuple {
+	fn translate(
+		from: &AccountId,
+		to: &AccountId,
+		witness: &TranslationWitness<AccountId>,
+		report: &mut Vec<ItemReport>,
+	) {
+		for_tuples!( #( Tuple::translate(from, to, witness, report); )* );
+	}
+
+	fn weight() -> Weight {
+		let mut weight = Weight::zero();
+		for_tuples!( #( weight.saturating_accrue(Tuple::weight()); )* );
+		weight
+	}
+}
+
+fn signed<T: frame_system::Config>(who: &T::AccountId) -> T::RuntimeOrigin {
+	frame_system::RawOrigin::Signed(who.clone()).into()
+}
+
+/// Moves the vesting schedules of `pallet_vesting`.
+///
+/// The schedules are removed from the old account, which also removes its vesting lock, and added
+/// with the same parameters to the new account.
+pub struct VestingTranslator<T>(core::marker::PhantomData<T>);
+
+impl<T> TranslateAccount<T::AccountId> for VestingTranslator<T>
+where
+	T: Config + pallet_vesting::Config<Currency = <T as Config>::Currency>,
+{
+	fn translate(
+		from: &T::AccountId,
+		to: &T::AccountId,
+		_witness: &TranslationWitness<T::AccountId>,
+		report: &mut Vec<ItemReport>,
+	) {
+		let schedules = pallet_vesting::Vesting::<T>::get(from).unwrap_or_default();
+		if schedules.is_empty() {
+			return;
+		}
+
+		let mut migrated = 0;
+		for (index, schedule) in schedules.iter().enumerate().rev() {
+			let moved = with_storage_layer::<(), DispatchError, _>(|| {
+				<pallet_vesting::Pallet<T> as VestingSchedule<_>>::remove_vesting_schedule(
+					from,
+					index as u32,
+				)?;
+				<pallet_vesting::Pallet<T> as VestingSchedule<_>>::add_vesting_schedule(
+					to,
+					schedule.locked(),
+					schedule.per_block(),
+					schedule.starting_block(),
+				)
+			});
+			match moved {
+				Ok(()) => migrated += 1,
+				Err(e) => log::warn!(
+					target: LOG_TARGET,
+					"Failed to move vesting schedule {} of {:?}: {:?}",
+					index,
+					from,
+					e
+				),
+			}
+		}
+
+		let remaining = schedules.len() as u32 - migrated;
+		report.push(ItemReport::new(TranslatedItem::Vesting, migrated, remaining));
+	}
+
+	fn weight() -> Weight {
+		<T as Config>::WeightInfo::translate_vesting()
+	}
+}
+
+/// Moves the proxy definitions of `pallet_proxy`.
+///
+/// The proxies of the old account are removed, their deposit is transferred and the same proxies
+/// are added to the new account. Announcements are removed and their deposit is released.
+pub struct ProxyTranslator<T>(core::marker::PhantomData<T>);
+
+impl<T> TranslateAccount<T::AccountId> for ProxyTranslator<T>
+where
+	T: Config + pallet_proxy::Config<Currency = <T as Config>::Currency>,
+{
+	fn translate(
+		from: &T::AccountId,
+		to: &T::AccountId,
+		_witness: &TranslationWitness<T::AccountId>,
+		report: &mut Vec<ItemReport>,
+	) {
+		let (proxies, deposit) = pallet_proxy::Proxies::<T>::get(from);
+		let (_, announcement_deposit) = pallet_proxy::Announcements::<T>::take(from);
+		<T as Config>::Currency::unreserve(from, announcement_deposit);
+		if proxies.is_empty() {
+			return;
+		}
+
+		let moved = with_storage_layer::<(), DispatchError, _>(|| {
+			pallet_proxy::Pallet::<T>::remove_proxies(signed::<T>(from))?;
+			// The new account needs the deposit before the proxies can be added.
+			<<T as Config>::Currency as FungibleMutate<_>>::transfer(
+				from,
+				to,
+				deposit,
+				Preservation::Expendable,
+			)?;
+			for proxy in proxies.iter() {
+				pallet_proxy::Pallet::<T>::add_proxy(
+					signed::<T>(to),
+					<T as frame_system::Config>::Lookup::unlookup(proxy.delegate.clone()),
+					proxy.proxy_type.clone(),
+					proxy.delay,
+				)?;
+			}
+			Ok(())
+		});
+
+		let count = proxies.len() as u32;
+		let item = match moved {
+			Ok(()) => ItemReport::new(TranslatedItem::Proxies, count, 0),
+			Err(e) => {
+				log::warn!(target: LOG_TARGET, "Failed to move proxies of {:?}: {:?}", from, e);
+				ItemReport::new(TranslatedItem::Proxies, 0, count)
+			},
+		};
+		report.push(item);
+	}
+
+	fn weight() -> Weight {
+		<T as Config>::WeightInfo::translate_proxies()
+	}
+}
+
+/// Cancels the pending multisig operations that were passed as witness.
+///
+/// A multisig operation can not be moved since the multisig account is derived from its
+/// signatories. The operations are cancelled through `pallet_multisig`, which emits a
+/// `MultisigCancelled` event, and their deposit is transferred with the free balance. Operations
+/// that were not deposited by the old account are ignored.
+pub struct MultisigTranslator<T>(core::marker::PhantomData<T>);
+
+impl<T> TranslateAccount<T::AccountId> for MultisigTranslator<T>
+where
+	T: Config + pallet_multisig::Config<Currency = <T as Config>::Currency>,
+{
+	fn translate(
+		from: &T::AccountId,
+		_to: &T::AccountId,
+		witness: &TranslationWitness<T::AccountId>,
+		report: &mut Vec<ItemReport>,
+	) {
+		let (mut cancelled, mut remaining) = (0u32, 0u32);
+		for op in witness.multisigs.iter() {
+			let mut signatories = op.other_signatories.to_vec();
+			signatories.push(from.clone());
+			signatories.sort();
+			let multisig =
+				pallet_multisig::Pallet::<T>::multi_account_id(&signatories, op.threshold);
+			let Some(pending) = pallet_multisig::Multisigs::<T>::get(&multisig, op.call_hash)
+			else {
+				continue;
+			};
+			if pending.depositor != *from {
+				continue;
+			}
+
+			match pallet_multisig::Pallet::<T>::cancel_as_multi(
+				signed::<T>(from),
+				op.threshold,
+				op.other_signatories.to_vec(),
+				pending.when,
+				op.call_hash,
+			) {
+				Ok(()) => cancelled += 1,
+				Err(e) => {
+					log::warn!(
+						target: LOG_TARGET,
+						"Failed to cancel multisig operation of {:?}: {:?}",
+						from,
+						e
+					);
+					remaining += 1;
+				},
+			}
+		}
+
+		if cancelled > 0 || remaining > 0 {
+			report.push(ItemReport::new(TranslatedItem::MultisigsCancelled, cancelled, remaining));
+		}
+	}
+
+	fn weight() -> Weight {
+		<T as Config>::WeightInfo::translate_multisigs(MaxMultisigWitnesses::get())
+	}
+}
+
+/// Removes the old account from its nomination pool.
+///
+/// The pool membership can not be moved to another account through the API of
+/// `pallet_nomination_pools`. Instead, the old account unbonds all of its points and withdraws
+/// them once the bonding duration passed, which releases the delegated balance so that it is
+/// transferred with the free balance. Until then, the membership is reported as remaining and the
+/// translation can be repeated to withdraw it. Fails for the depositor of a pool.
+pub struct PoolMemberTranslator<T>(core::marker::PhantomData<T>);
+
+impl<T> TranslateAccount<T::AccountId> for PoolMemberTranslator<T>
+where
+	T: Config + pallet_nomination_pools::Config,
+{
+	fn translate(
+		from: &T::AccountId,
+		_to: &T::AccountId,
+		_witness: &TranslationWitness<T::AccountId>,
+		report: &mut Vec<ItemReport>,
+	) {
+		let Some(member) = pallet_nomination_pools::PoolMembers::<T>::get(from) else {
+			return;
+		};
+
+		let who = <T as frame_system::Config>::Lookup::unlookup(from.clone());
+		let points = member.active_points();
+		if !points.is_zero() {
+			if let Err(e) =
+				pallet_nomination_pools::Pallet::<T>::unbond(signed::<T>(from), who.clone(), points)
+			{
+				log::warn!(target: LOG_TARGET, "Failed to unbond pool member {:?}: {:?}", from, e);
+			}
+		}
+		// Fails until the unbonding period of the points passed.
+		let _ = pallet_nomination_pools::Pallet::<T>::withdraw_unbonded(signed::<T>(from), who, 0);
+
+		let item = if pallet_nomination_pools::PoolMembers::<T>::contains_key(from) {
+			ItemReport::new(TranslatedItem::NominationPoolUnbonded, 0, 1)
+		} else {
+			ItemReport::new(TranslatedItem::NominationPoolUnbonded, 1, 0)
+		};
+		report.push(item);
+	}
+
+	fn weight() -> Weight {
+		<T as Config>::WeightInfo::translate_pool_member_unbond()
+			.saturating_add(<T as Config>::WeightInfo::translate_pool_member_withdraw())
+	}
+}
+
+/// Moves the items and collections of `pallet_uniques`.
+///
+/// At most [`MAX_NFTS_PER_TRANSLATION`] items and collections are moved at once.
+pub struct UniquesTranslator<T>(core::marker::PhantomData<T>);
+
+impl<T> TranslateAccount<T::AccountId> for UniquesTranslator<T>
+where
+	T: Config + pallet_uniques::Config,
+{
+	fn translate(
+		from: &T::AccountId,
+		to: &T::AccountId,
+		_witness: &TranslationWitness<T::AccountId>,
+		report: &mut Vec<ItemReport>,
+	) {
+		let items: Vec<_> = pallet_uniques::Account::<T>::iter_key_prefix((from.clone(),))
+			.take(MAX_NFTS_PER_TRANSLATION as usize + 1)
+			.collect();
+		let collections: Vec<_> = pallet_uniques::ClassAccount::<T>::iter_key_prefix(from)
+			.take(MAX_NFTS_PER_TRANSLATION as usize + 1)
+			.collect();
+		if items.is_empty() && collections.is_empty() {
+			return;
+		}
+
+		let dest = <T as frame_system::Config>::Lookup::unlookup(to.clone());
+		let (mut migrated, mut remaining) = (0u32, 0u32);
+		for (index, (collection, item)) in items.into_iter().enumerate() {
+			if index as u32 >= MAX_NFTS_PER_TRANSLATION {
+				remaining += 1;
+				continue;
+			}
+			match pallet_uniques::Pallet::<T>::transfer(
+				signed::<T>(from),
+				collection,
+				item,
+				dest.clone(),
+			) {
+				Ok(()) => migrated += 1,
+				Err(_) => remaining += 1,
+			}
+		}
+		for (index, collection) in collections.into_iter().enumerate() {
+			if index as u32 >= MAX_NFTS_PER_TRANSLATION {
+				remaining += 1;
+				continue;
+			}
+			let moved = with_storage_layer::<(), DispatchError, _>(|| {
+				pallet_uniques::Pallet::<T>::set_accept_ownership(
+					signed::<T>(to),
+					Some(collection.clone()),
+				)?;
+				pallet_uniques::Pallet::<T>::transfer_ownership(
+					signed::<T>(from),
+					collection,
+					dest.clone(),
+				)
+			});
+			match moved {
+				Ok(()) => migrated += 1,
+				Err(_) => remaining += 1,
+			}
+		}
+
+		report.push(ItemReport::new(TranslatedItem::Uniques, migrated, remaining));
+	}
+
+	fn weight() -> Weight {
+		<T as Config>::WeightInfo::translate_uniques()
+	}
+}
+
+/// Moves the items and collections of `pallet_nfts`.
+///
+/// At most [`MAX_NFTS_PER_TRANSLATION`] items and collections are moved at once.
+pub struct NftsTranslator<T>(core::marker::PhantomData<T>);
+
+impl<T> TranslateAccount<T::AccountId> for NftsTranslator<T>
+where
+	T: Config + pallet_nfts::Config,
+{
+	fn translate(
+		from: &T::AccountId,
+		to: &T::AccountId,
+		_witness: &TranslationWitness<T::AccountId>,
+		report: &mut Vec<ItemReport>,
+	) {
+		let items: Vec<_> = pallet_nfts::Account::<T>::iter_key_prefix((from.clone(),))
+			.take(MAX_NFTS_PER_TRANSLATION as usize + 1)
+			.collect();
+		let collections: Vec<_> = pallet_nfts::CollectionAccount::<T>::iter_key_prefix(from)
+			.take(MAX_NFTS_PER_TRANSLATION as usize + 1)
+			.collect();
+		if items.is_empty() && collections.is_empty() {
+			return;
+		}
+
+		let dest = <T as frame_system::Config>::Lookup::unlookup(to.clone());
+		let (mut migrated, mut remaining) = (0u32, 0u32);
+		for (index, (collection, item)) in items.into_iter().enumerate() {
+			if index as u32 >= MAX_NFTS_PER_TRANSLATION {
+				remaining += 1;
+				continue;
+			}
+			match pallet_nfts::Pallet::<T>::transfer(
+				signed::<T>(from),
+				collection,
+				item,
+				dest.clone(),
+			) {
+				Ok(()) => migrated += 1,
+				Err(_) => remaining += 1,
+			}
+		}
+		for (index, collection) in collections.into_iter().enumerate() {
+			if index as u32 >= MAX_NFTS_PER_TRANSLATION {
+				remaining += 1;
+				continue;
+			}
+			let moved = with_storage_layer::<(), DispatchError, _>(|| {
+				pallet_nfts::Pallet::<T>::set_accept_ownership(
+					signed::<T>(to),
+					Some(collection.clone()),
+				)?;
+				pallet_nfts::Pallet::<T>::transfer_ownership(
+					signed::<T>(from),
+					collection,
+					dest.clone(),
+				)
+			});
+			match moved {
+				Ok(()) => migrated += 1,
+				Err(_) => remaining += 1,
+			}
+		}
+
+		report.push(ItemReport::new(TranslatedItem::Nfts, migrated, remaining));
+	}
+
+	fn weight() -> Weight {
+		<T as Config>::WeightInfo::translate_nfts()
+	}
+}
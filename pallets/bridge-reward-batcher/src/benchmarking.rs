@@ -0,0 +1,41 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Benchmarks for the bridge reward batcher pallet.
+
+use crate::*;
+use frame_benchmarking::{v2::*, whitelisted_caller};
+use frame_system::RawOrigin;
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn pay_pending() {
+		let payer: T::AccountId = whitelisted_caller();
+		let (beneficiary, kind, amount) = T::BenchmarkHelper::reward();
+		T::BenchmarkHelper::prepare_payment(&payer);
+		Pallet::<T>::queue_reward(beneficiary.clone(), kind, amount);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(payer), Box::new(beneficiary.clone()), kind);
+
+		assert!(!PendingRewards::<T>::contains_key(&beneficiary, kind));
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
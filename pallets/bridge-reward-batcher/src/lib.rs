@@ -0,0 +1,200 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Bridge reward batcher pallet
+//!
+//! Bridge relayers can claim their rewards to an account on Asset Hub. Sending one XCM message
+//! per claim means that the delivery and execution fees are paid for every single claim. This
+//! pallet queues the rewards per beneficiary and kind instead. Anyone can then pay all queued
+//! rewards of a beneficiary with one message by calling [`Pallet::pay_pending`]. The caller pays
+//! the delivery fees.
+//!
+//! The runtime is responsible for moving the funds of a queued reward into custody when calling
+//! [`Pallet::queue_reward`] and for sending them in its [`PayBatch`] implementation.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+use alloc::boxed::Box;
+use frame_support::{pallet_prelude::*, Parameter};
+use frame_system::pallet_prelude::*;
+use sp_runtime::traits::{AtLeast32BitUnsigned, Zero};
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+/// Pays a batch of queued rewards.
+pub trait PayBatch<AccountId, RewardKind, Balance, Beneficiary> {
+	/// Pay `amount` of the given reward `kind` to `beneficiary`.
+	///
+	/// Any fees for delivering the payment are charged from `payer`.
+	fn pay_batch(
+		payer: &AccountId,
+		kind: RewardKind,
+		amount: Balance,
+		beneficiary: Beneficiary,
+	) -> DispatchResult;
+}
+
+/// Helper for setting up the benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AccountId, RewardKind, Balance, Beneficiary> {
+	/// The reward that is queued and paid in the benchmark.
+	fn reward() -> (Beneficiary, RewardKind, Balance);
+
+	/// Prepare everything required by [`PayBatch::pay_batch`] for `payer` to pay the
+	/// [`Self::reward`].
+	fn prepare_payment(payer: &AccountId);
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The kinds of rewards that are batched, for example the native token.
+		type RewardKind: Parameter + MaxEncodedLen + Copy;
+
+		/// The balance type of the rewards.
+		type Balance: Parameter + MaxEncodedLen + AtLeast32BitUnsigned + Copy + Default;
+
+		/// The beneficiary of the rewards.
+		type Beneficiary: Parameter + MaxEncodedLen;
+
+		/// Pays the queued rewards.
+		type Payer: PayBatch<Self::AccountId, Self::RewardKind, Self::Balance, Self::Beneficiary>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// Helper for setting up the benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<
+			Self::AccountId,
+			Self::RewardKind,
+			Self::Balance,
+			Self::Beneficiary,
+		>;
+	}
+
+	/// The queued rewards per beneficiary and reward kind.
+	#[pallet::storage]
+	pub type PendingRewards<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::Beneficiary,
+		Twox64Concat,
+		T::RewardKind,
+		T::Balance,
+		ValueQuery,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A reward was queued for `beneficiary`.
+		RewardQueued {
+			beneficiary: T::Beneficiary,
+			kind: T::RewardKind,
+			amount: T::Balance,
+			pending: T::Balance,
+		},
+		/// The queued rewards of `beneficiary` were paid.
+		BatchPaid {
+			payer: T::AccountId,
+			beneficiary: T::Beneficiary,
+			kind: T::RewardKind,
+			amount: T::Balance,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// There are no queued rewards for the beneficiary and reward kind.
+		NothingPending,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Pay all queued rewards of `kind` to `beneficiary` at once.
+		///
+		/// The dispatch origin for this call must be _Signed_. The sender pays the delivery fees
+		/// of the payment. Any account can call this, for example the relayer that claimed the
+		/// rewards.
+		///
+		/// Parameters:
+		/// - `beneficiary`: The beneficiary of the queued rewards.
+		/// - `kind`: The kind of the queued rewards.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::pay_pending())]
+		pub fn pay_pending(
+			origin: OriginFor<T>,
+			beneficiary: Box<T::Beneficiary>,
+			kind: T::RewardKind,
+		) -> DispatchResult {
+			let payer = ensure_signed(origin)?;
+
+			let amount = PendingRewards::<T>::get(&*beneficiary, kind);
+			ensure!(!amount.is_zero(), Error::<T>::NothingPending);
+
+			T::Payer::pay_batch(&payer, kind, amount, (*beneficiary).clone())?;
+			PendingRewards::<T>::remove(&*beneficiary, kind);
+
+			Self::deposit_event(Event::BatchPaid {
+				payer,
+				beneficiary: *beneficiary,
+				kind,
+				amount,
+			});
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Queue `amount` of the reward `kind` for `beneficiary`.
+		///
+		/// The caller must make sure that the funds can be paid by [`Config::Payer`] later on.
+		pub fn queue_reward(beneficiary: T::Beneficiary, kind: T::RewardKind, amount: T::Balance) {
+			if amount.is_zero() {
+				return;
+			}
+
+			let pending = PendingRewards::<T>::mutate(&beneficiary, kind, |pending| {
+				*pending = pending.saturating_add(amount);
+				*pending
+			});
+
+			Self::deposit_event(Event::RewardQueued { beneficiary, kind, amount, pending });
+		}
+	}
+}
@@ -0,0 +1,84 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Mock runtime for the bridge reward batcher pallet.
+
+use crate as pallet_bridge_reward_batcher;
+use crate::*;
+use frame_support::{derive_impl, parameter_types};
+use sp_runtime::{BuildStorage, DispatchError};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test {
+		System: frame_system,
+		RewardBatcher: pallet_bridge_reward_batcher,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+}
+
+/// Beneficiary for which [`TestPayer`] fails.
+pub const FAILING_BENEFICIARY: u64 = 99;
+
+parameter_types! {
+	/// The batches paid by [`TestPayer`] as `(payer, kind, amount, beneficiary)`.
+	pub static PaidBatches: Vec<(u64, u8, u64, u64)> = Vec::new();
+}
+
+pub struct TestPayer;
+impl PayBatch<u64, u8, u64, u64> for TestPayer {
+	fn pay_batch(payer: &u64, kind: u8, amount: u64, beneficiary: u64) -> DispatchResult {
+		if beneficiary == FAILING_BENEFICIARY {
+			return Err(DispatchError::Other("failed to deliver"));
+		}
+		PaidBatches::mutate(|paid| paid.push((*payer, kind, amount, beneficiary)));
+		Ok(())
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct TestBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl BenchmarkHelper<u64, u8, u64, u64> for TestBenchmarkHelper {
+	fn reward() -> (u64, u8, u64) {
+		(1, 0, 100)
+	}
+
+	fn prepare_payment(_payer: &u64) {}
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RewardKind = u8;
+	type Balance = u64;
+	type Beneficiary = u64;
+	type Payer = TestPayer;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = TestBenchmarkHelper;
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
@@ -0,0 +1,66 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Tests for the bridge reward batcher pallet.
+
+use crate::{mock::*, *};
+use frame_support::{assert_noop, assert_ok};
+
+#[test]
+fn rewards_are_queued_and_paid_at_once() {
+	new_test_ext().execute_with(|| {
+		RewardBatcher::queue_reward(1, 0, 10);
+		RewardBatcher::queue_reward(1, 0, 5);
+		RewardBatcher::queue_reward(1, 1, 7);
+		// Zero rewards are ignored.
+		RewardBatcher::queue_reward(2, 0, 0);
+
+		System::assert_last_event(
+			Event::<Test>::RewardQueued { beneficiary: 1, kind: 1, amount: 7, pending: 7 }.into(),
+		);
+		assert_eq!(PendingRewards::<Test>::get(1, 0), 15);
+		assert!(!PendingRewards::<Test>::contains_key(2, 0));
+
+		assert_ok!(RewardBatcher::pay_pending(RuntimeOrigin::signed(3), Box::new(1), 0));
+		System::assert_last_event(
+			Event::<Test>::BatchPaid { payer: 3, beneficiary: 1, kind: 0, amount: 15 }.into(),
+		);
+		assert_eq!(PaidBatches::get(), vec![(3, 0, 15, 1)]);
+		assert!(!PendingRewards::<Test>::contains_key(1, 0));
+		assert_eq!(PendingRewards::<Test>::get(1, 1), 7);
+
+		assert_noop!(
+			RewardBatcher::pay_pending(RuntimeOrigin::signed(3), Box::new(1), 0),
+			Error::<Test>::NothingPending
+		);
+	});
+}
+
+#[test]
+fn failed_payment_keeps_rewards_queued() {
+	new_test_ext().execute_with(|| {
+		RewardBatcher::queue_reward(FAILING_BENEFICIARY, 0, 10);
+
+		assert!(RewardBatcher::pay_pending(
+			RuntimeOrigin::signed(3),
+			Box::new(FAILING_BENEFICIARY),
+			0
+		)
+		.is_err());
+		assert_eq!(PendingRewards::<Test>::get(FAILING_BENEFICIARY, 0), 10);
+		assert!(PaidBatches::get().is_empty());
+	});
+}
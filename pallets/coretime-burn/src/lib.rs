@@ -0,0 +1,222 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Coretime burn pallet
+//!
+//! The revenue of coretime sales is collected in a burn account on the Coretime chain and burnt
+//! periodically, see [RFC-010](https://polkadot-fellows.github.io/RFCs/approved/0010-burn-coretime-revenue.html).
+//! This pallet schedules these burns. Every [`Config::BurnPeriod`] relay chain blocks the whole
+//! reducible balance of [`Config::BurnAccount`] is handed to [`Config::Burner`], which burns it at
+//! the Relay Chain. Every burn cycle handles a single burn, so the weight of the cycle is bounded.
+//!
+//! The burnt amounts are accounted in [`TotalBurnt`] and [`LastBurn`], so the burn rate can be
+//! audited from the chain state. A failed burn is rolled back and retried after
+//! [`Config::RetryPeriod`], up to [`Config::MaxRetries`] times before the pallet falls back to the
+//! regular schedule.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+use frame_support::{
+	pallet_prelude::*,
+	storage::with_storage_layer,
+	traits::{
+		fungible::Inspect,
+		tokens::{Fortitude, Preservation},
+	},
+};
+use frame_system::pallet_prelude::*;
+use sp_runtime::traits::{BlockNumberProvider, Saturating, Zero};
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// Burns funds of the burn account.
+pub trait BurnRevenue<AccountId, Balance> {
+	/// Burn `amount` from `stash`.
+	///
+	/// The changes of a failed burn are rolled back by the pallet.
+	fn burn(stash: &AccountId, amount: Balance) -> DispatchResult;
+}
+
+/// Helper for setting up the benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AccountId, Balance> {
+	/// Fund `stash` and prepare everything required by [`BurnRevenue::burn`] to burn the funds.
+	///
+	/// Returns the amount that is burnt.
+	fn prepare_burn(stash: &AccountId) -> Balance;
+}
+
+/// A successful burn.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+	Clone,
+	PartialEq,
+	Eq,
+	RuntimeDebug,
+)]
+pub struct BurnRecord<BlockNumber, Balance> {
+	/// The relay chain block number of the burn.
+	pub at: BlockNumber,
+	/// The burnt amount.
+	pub amount: Balance,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The currency of the burn account.
+		type Currency: Inspect<Self::AccountId>;
+
+		/// The account into which the revenue is moved until it is burnt.
+		type BurnAccount: Get<Self::AccountId>;
+
+		/// Burns the funds of [`Config::BurnAccount`].
+		type Burner: BurnRevenue<Self::AccountId, BalanceOf<Self>>;
+
+		/// Provider of the relay chain block number that the burns are scheduled with.
+		type RcBlockNumberProvider: BlockNumberProvider<BlockNumber = BlockNumberFor<Self>>;
+
+		/// The number of relay chain blocks between two burns.
+		#[pallet::constant]
+		type BurnPeriod: Get<BlockNumberFor<Self>>;
+
+		/// The number of relay chain blocks after which a failed burn is retried.
+		#[pallet::constant]
+		type RetryPeriod: Get<BlockNumberFor<Self>>;
+
+		/// The number of retries of a failed burn before waiting for the next
+		/// [`Config::BurnPeriod`].
+		#[pallet::constant]
+		type MaxRetries: Get<u32>;
+
+		/// Weight information for the burn cycle of this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// Helper for setting up the benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::AccountId, BalanceOf<Self>>;
+	}
+
+	/// The total amount that was burnt by this pallet.
+	#[pallet::storage]
+	pub type TotalBurnt<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+	/// The last successful burn.
+	#[pallet::storage]
+	pub type LastBurn<T: Config> =
+		StorageValue<_, BurnRecord<BlockNumberFor<T>, BalanceOf<T>>, OptionQuery>;
+
+	/// The relay chain block number from which on the next burn is attempted.
+	///
+	/// The burn is attempted in the first block if not set.
+	#[pallet::storage]
+	pub type NextBurnAt<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+	/// The number of failed burns since the last successful one.
+	#[pallet::storage]
+	pub type ConsecutiveFailures<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// `amount` was burnt, `total` is the total amount burnt so far.
+		Burnt { amount: BalanceOf<T>, total: BalanceOf<T> },
+		/// Burning `amount` failed. The burn is attempted again at `next_attempt`.
+		BurnFailed { amount: BalanceOf<T>, error: DispatchError, next_attempt: BlockNumberFor<T> },
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_: BlockNumberFor<T>) -> Weight {
+			let now = T::RcBlockNumberProvider::current_block_number();
+			if NextBurnAt::<T>::get().is_some_and(|next| next > now) {
+				return T::WeightInfo::on_initialize_idle();
+			}
+			Self::burn_cycle(now);
+			T::WeightInfo::burn()
+		}
+
+		fn integrity_test() {
+			assert!(!T::BurnPeriod::get().is_zero(), "`BurnPeriod` must not be zero");
+			assert!(!T::RetryPeriod::get().is_zero(), "`RetryPeriod` must not be zero");
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Burn the reducible balance of the burn account and schedule the next burn.
+		pub(crate) fn burn_cycle(now: BlockNumberFor<T>) {
+			let stash = T::BurnAccount::get();
+			let amount =
+				T::Currency::reducible_balance(&stash, Preservation::Expendable, Fortitude::Polite);
+			if amount.is_zero() {
+				NextBurnAt::<T>::put(now.saturating_add(T::BurnPeriod::get()));
+				return;
+			}
+
+			match with_storage_layer(|| T::Burner::burn(&stash, amount)) {
+				Ok(()) => {
+					let total = TotalBurnt::<T>::mutate(|total| {
+						*total = total.saturating_add(amount);
+						*total
+					});
+					LastBurn::<T>::put(BurnRecord { at: now, amount });
+					ConsecutiveFailures::<T>::kill();
+					NextBurnAt::<T>::put(now.saturating_add(T::BurnPeriod::get()));
+					Self::deposit_event(Event::Burnt { amount, total });
+				},
+				Err(error) => {
+					let failures = ConsecutiveFailures::<T>::mutate(|failures| {
+						*failures = failures.saturating_add(1);
+						*failures
+					});
+					let next_attempt = if failures > T::MaxRetries::get() {
+						ConsecutiveFailures::<T>::kill();
+						now.saturating_add(T::BurnPeriod::get())
+					} else {
+						now.saturating_add(T::RetryPeriod::get())
+					};
+					NextBurnAt::<T>::put(next_attempt);
+					Self::deposit_event(Event::BurnFailed { amount, error, next_attempt });
+				},
+			}
+		}
+	}
+}
@@ -0,0 +1,107 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Mock runtime for the coretime burn pallet.
+
+use crate as pallet_coretime_burn;
+use crate::*;
+use frame_support::{
+	derive_impl, parameter_types,
+	traits::{
+		fungible::Mutate,
+		tokens::{Fortitude, Precision, Preservation},
+		ConstU32, ConstU64,
+	},
+};
+use sp_runtime::{BuildStorage, DispatchError};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test {
+		System: frame_system,
+		Balances: pallet_balances,
+		CoretimeBurn: pallet_coretime_burn,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+	type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type AccountStore = System;
+}
+
+/// The account into which the revenue is moved.
+pub const BURN_ACCOUNT: u64 = 42;
+
+parameter_types! {
+	pub const BurnAccount: u64 = BURN_ACCOUNT;
+	/// Whether [`TestBurner`] fails after burning the funds locally.
+	pub static BurnFails: bool = false;
+}
+
+pub struct TestBurner;
+impl BurnRevenue<u64, u64> for TestBurner {
+	fn burn(stash: &u64, amount: u64) -> DispatchResult {
+		Balances::burn_from(
+			stash,
+			amount,
+			Preservation::Expendable,
+			Precision::Exact,
+			Fortitude::Polite,
+		)?;
+		if BurnFails::get() {
+			return Err(DispatchError::Other("failed to send"));
+		}
+		Ok(())
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct TestBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl BenchmarkHelper<u64, u64> for TestBenchmarkHelper {
+	fn prepare_burn(stash: &u64) -> u64 {
+		Balances::set_balance(stash, 100);
+		100
+	}
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type BurnAccount = BurnAccount;
+	type Burner = TestBurner;
+	type RcBlockNumberProvider = System;
+	type BurnPeriod = ConstU64<10>;
+	type RetryPeriod = ConstU64<2>;
+	type MaxRetries = ConstU32<2>;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = TestBenchmarkHelper;
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
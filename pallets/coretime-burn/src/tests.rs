@@ -0,0 +1,87 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Tests for the coretime burn pallet.
+
+use crate::{mock::*, *};
+use frame_support::traits::{
+	fungible::{Inspect, Mutate},
+	Hooks,
+};
+use sp_runtime::DispatchError;
+
+fn run_to_block(n: u64) {
+	System::set_block_number(n);
+	CoretimeBurn::on_initialize(n);
+}
+
+#[test]
+fn revenue_is_burnt_periodically() {
+	new_test_ext().execute_with(|| {
+		Balances::set_balance(&BURN_ACCOUNT, 100);
+		run_to_block(1);
+		System::assert_last_event(Event::<Test>::Burnt { amount: 100, total: 100 }.into());
+		assert_eq!(Balances::total_balance(&BURN_ACCOUNT), 0);
+		assert_eq!(LastBurn::<Test>::get(), Some(BurnRecord { at: 1, amount: 100 }));
+		assert_eq!(NextBurnAt::<Test>::get(), Some(11));
+
+		// Revenue is collected until the next burn.
+		Balances::set_balance(&BURN_ACCOUNT, 50);
+		run_to_block(10);
+		assert_eq!(Balances::total_balance(&BURN_ACCOUNT), 50);
+
+		run_to_block(11);
+		System::assert_last_event(Event::<Test>::Burnt { amount: 50, total: 150 }.into());
+		assert_eq!(TotalBurnt::<Test>::get(), 150);
+		assert_eq!(LastBurn::<Test>::get(), Some(BurnRecord { at: 11, amount: 50 }));
+
+		// Nothing to burn.
+		System::reset_events();
+		run_to_block(21);
+		assert!(System::events().is_empty());
+		assert_eq!(NextBurnAt::<Test>::get(), Some(31));
+	});
+}
+
+#[test]
+fn failed_burns_are_rolled_back_and_retried() {
+	new_test_ext().execute_with(|| {
+		Balances::set_balance(&BURN_ACCOUNT, 100);
+		BurnFails::set(true);
+
+		// The burn is attempted once and retried `MaxRetries` times.
+		for (block, next_attempt) in [(1, 3), (3, 5), (5, 15)] {
+			run_to_block(block);
+			System::assert_last_event(
+				Event::<Test>::BurnFailed {
+					amount: 100,
+					error: DispatchError::Other("failed to send"),
+					next_attempt,
+				}
+				.into(),
+			);
+			assert_eq!(Balances::total_balance(&BURN_ACCOUNT), 100);
+			assert_eq!(NextBurnAt::<Test>::get(), Some(next_attempt));
+		}
+		assert_eq!(ConsecutiveFailures::<Test>::get(), 0);
+		assert_eq!(TotalBurnt::<Test>::get(), 0);
+
+		BurnFails::set(false);
+		run_to_block(15);
+		System::assert_last_event(Event::<Test>::Burnt { amount: 100, total: 100 }.into());
+		assert_eq!(Balances::total_balance(&BURN_ACCOUNT), 0);
+	});
+}
@@ -0,0 +1,167 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Ethereum congestion pallet
+//!
+//! The bridge router of Asset Hub raises the delivery fee of messages to Ethereum while Bridge Hub
+//! reports that the bridge is congested. This pallet keeps the state that was last reported. The
+//! runtime calls [`Pallet::update`] whenever the backlog of undelivered messages may have changed,
+//! for example when new messages are committed or a delivery receipt is submitted. The
+//! (un)congestion signal is sent with [`Config::Reporter`] when the state changes.
+//!
+//! If a signal was lost, the state of Bridge Hub and Asset Hub may disagree and the flag may be
+//! stuck. [`Pallet::force_report`] lets [`Config::AdminOrigin`] send the signal again and reset
+//! the flag.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+use frame_support::pallet_prelude::*;
+use frame_system::pallet_prelude::*;
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+const LOG_TARGET: &str = "runtime::ethereum-congestion";
+
+/// The backlog of messages to Ethereum that wait for their delivery receipt.
+pub trait OutboundBacklog {
+	/// Returns `true` if at least `backlog` messages wait for their delivery receipt.
+	fn has_backlog(backlog: u64) -> bool;
+}
+
+/// Reports the congestion state of the bridge to the bridge router of Asset Hub.
+pub trait ReportCongestion {
+	/// Tell the bridge router whether the bridge `is_congested`.
+	fn report(is_congested: bool) -> DispatchResult;
+}
+
+/// Helper for setting up the benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper {
+	/// Prepare everything required by [`ReportCongestion::report`].
+	fn prepare_report();
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The backlog of messages to Ethereum.
+		type Backlog: OutboundBacklog;
+
+		/// Sends the (un)congestion signal.
+		type Reporter: ReportCongestion;
+
+		/// Number of messages waiting for their delivery receipt, at which the bridge is reported
+		/// as congested.
+		#[pallet::constant]
+		type CongestedBacklog: Get<u64>;
+
+		/// Number of messages waiting for their delivery receipt, below which the bridge is
+		/// reported as no longer congested.
+		#[pallet::constant]
+		type UncongestedBacklog: Get<u64>;
+
+		/// The origin that may send the signal again with [`Pallet::force_report`].
+		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// Helper for setting up the benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper;
+	}
+
+	/// Whether Asset Hub has been told that the Ethereum bridge is congested.
+	#[pallet::storage]
+	pub type IsCongested<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Asset Hub was told whether the bridge `is_congested`.
+		CongestionReported { is_congested: bool },
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Send the (un)congestion signal to Asset Hub and set the state of the bridge.
+		///
+		/// The dispatch origin for this call must be [`Config::AdminOrigin`]. This resets a stuck
+		/// state, for example after a signal was lost. The state is updated as usual again with
+		/// the next change of the backlog.
+		///
+		/// Parameters:
+		/// - `is_congested`: Whether the bridge is reported as congested.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::force_report())]
+		pub fn force_report(origin: OriginFor<T>, is_congested: bool) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			Self::report(is_congested)
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Send the (un)congestion signal if the state of the backlog has changed.
+		///
+		/// If the signal can't be sent, the state is kept and the signal is sent again with the
+		/// next update.
+		pub fn update() {
+			let was_congested = IsCongested::<T>::get();
+			let is_congested = if was_congested {
+				T::Backlog::has_backlog(T::UncongestedBacklog::get())
+			} else {
+				T::Backlog::has_backlog(T::CongestedBacklog::get())
+			};
+			if is_congested == was_congested {
+				return;
+			}
+
+			if let Err(e) = Self::report(is_congested) {
+				log::error!(
+					target: LOG_TARGET,
+					"Failed to report Ethereum bridge status is_congested={is_congested}: {e:?}",
+				);
+			}
+		}
+
+		fn report(is_congested: bool) -> DispatchResult {
+			T::Reporter::report(is_congested)?;
+			IsCongested::<T>::put(is_congested);
+
+			Self::deposit_event(Event::CongestionReported { is_congested });
+			Ok(())
+		}
+	}
+}
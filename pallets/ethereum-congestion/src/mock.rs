@@ -0,0 +1,90 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Mock runtime for the Ethereum congestion pallet.
+
+use crate as pallet_ethereum_congestion;
+use crate::*;
+use frame_support::{derive_impl, parameter_types};
+use frame_system::EnsureRoot;
+use sp_runtime::{BuildStorage, DispatchError};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test {
+		System: frame_system,
+		EthereumCongestion: pallet_ethereum_congestion,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+}
+
+parameter_types! {
+	/// Number of messages that wait for their delivery receipt.
+	pub static Backlog: u64 = 0;
+	/// Whether [`TestReporter`] fails to send the signal.
+	pub static ReportFails: bool = false;
+	/// The signals sent by [`TestReporter`].
+	pub static Reports: Vec<bool> = Vec::new();
+	pub const CongestedBacklog: u64 = 10;
+	pub const UncongestedBacklog: u64 = 2;
+}
+
+pub struct TestBacklog;
+impl OutboundBacklog for TestBacklog {
+	fn has_backlog(backlog: u64) -> bool {
+		Backlog::get() >= backlog
+	}
+}
+
+pub struct TestReporter;
+impl ReportCongestion for TestReporter {
+	fn report(is_congested: bool) -> DispatchResult {
+		if ReportFails::get() {
+			return Err(DispatchError::Other("failed to deliver"));
+		}
+		Reports::mutate(|reports| reports.push(is_congested));
+		Ok(())
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl BenchmarkHelper for TestReporter {
+	fn prepare_report() {}
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Backlog = TestBacklog;
+	type Reporter = TestReporter;
+	type CongestedBacklog = CongestedBacklog;
+	type UncongestedBacklog = UncongestedBacklog;
+	type AdminOrigin = EnsureRoot<u64>;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = TestReporter;
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
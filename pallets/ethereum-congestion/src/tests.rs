@@ -0,0 +1,89 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Tests for the Ethereum congestion pallet.
+
+use crate::{mock::*, *};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError;
+
+#[test]
+fn congestion_is_reported_when_the_backlog_crosses_the_thresholds() {
+	new_test_ext().execute_with(|| {
+		// Nothing to report below the congested backlog.
+		Backlog::set(9);
+		EthereumCongestion::update();
+		assert!(!IsCongested::<Test>::get());
+		assert!(Reports::get().is_empty());
+
+		Backlog::set(10);
+		EthereumCongestion::update();
+		assert!(IsCongested::<Test>::get());
+		assert_eq!(Reports::get(), vec![true]);
+		System::assert_last_event(Event::CongestionReported { is_congested: true }.into());
+
+		// The bridge stays congested until the backlog is below the uncongested backlog.
+		Backlog::set(2);
+		EthereumCongestion::update();
+		assert!(IsCongested::<Test>::get());
+		assert_eq!(Reports::get(), vec![true]);
+
+		Backlog::set(1);
+		EthereumCongestion::update();
+		assert!(!IsCongested::<Test>::get());
+		assert_eq!(Reports::get(), vec![true, false]);
+	});
+}
+
+#[test]
+fn state_is_kept_while_the_signal_cannot_be_sent() {
+	new_test_ext().execute_with(|| {
+		Backlog::set(10);
+		ReportFails::set(true);
+		EthereumCongestion::update();
+		assert!(!IsCongested::<Test>::get());
+
+		// The signal is sent with the next update.
+		ReportFails::set(false);
+		EthereumCongestion::update();
+		assert!(IsCongested::<Test>::get());
+		assert_eq!(Reports::get(), vec![true]);
+	});
+}
+
+#[test]
+fn force_report_resets_a_stuck_state() {
+	new_test_ext().execute_with(|| {
+		IsCongested::<Test>::put(true);
+
+		assert_noop!(
+			EthereumCongestion::force_report(RuntimeOrigin::signed(1), false),
+			DispatchError::BadOrigin
+		);
+
+		ReportFails::set(true);
+		assert_noop!(
+			EthereumCongestion::force_report(RuntimeOrigin::root(), false),
+			DispatchError::Other("failed to deliver")
+		);
+
+		ReportFails::set(false);
+		assert_ok!(EthereumCongestion::force_report(RuntimeOrigin::root(), false));
+		assert!(!IsCongested::<Test>::get());
+		assert_eq!(Reports::get(), vec![false]);
+		System::assert_last_event(Event::CongestionReported { is_congested: false }.into());
+	});
+}
@@ -0,0 +1,300 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Account balance migration from the Relay Chain to Asset Hub.
+//!
+//! Accounts are processed in order of their `frame_system::Account` storage key. For every account
+//! the holds, freezes, locks and the unnamed reserve are removed, the migrated balance is burned on
+//! the Relay Chain and an [`RcAccount`] is created that Asset Hub uses to mint the same balance and
+//! re-apply the holds, freezes and locks.
+//!
+//! Accounts that are listed in [`RcAccounts`] are kept on the Relay Chain either fully
+//! ([`AccountState::Preserve`]) or partially ([`AccountState::Part`]).
+//!
+//! If Asset Hub fails to receive a message, the accounts of the message are restored on the Relay
+//! Chain with [`AccountsMigrator::restore_account`].
+
+use crate::{types::*, *};
+use frame_support::{
+	storage::{with_transaction, TransactionOutcome},
+	traits::{
+		tokens::{Fortitude, Precision, Preservation},
+		WithdrawReasons,
+	},
+	weights::WeightMeter,
+};
+use pallet_balances::Reasons;
+use sp_runtime::traits::{Convert, Zero};
+
+pub struct AccountsMigrator<T> {
+	_phantom: core::marker::PhantomData<T>,
+}
+
+impl<T: Config> AccountsMigrator<T> {
+	/// Migrate accounts starting after `last_key` until the `weight_counter` is exhausted.
+	///
+	/// Returns the key of the last processed account or `None` if all accounts are migrated.
+	///
+	/// The withdrawals and the messages that send them to Asset Hub are applied in one storage
+	/// transaction. If a message can't be sent, all withdrawals of the block are rolled back and
+	/// the accounts are migrated again from `last_key` in the next block.
+	pub fn migrate_many(
+		last_key: Option<T::AccountId>,
+		weight_counter: &mut WeightMeter,
+	) -> Result<Option<T::AccountId>, DispatchError> {
+		with_transaction(|| match Self::migrate_batches(last_key, weight_counter) {
+			Ok(last_key) => TransactionOutcome::Commit(Ok(last_key)),
+			Err(e) => TransactionOutcome::Rollback(Err(e.into())),
+		})
+	}
+
+	fn migrate_batches(
+		last_key: Option<T::AccountId>,
+		weight_counter: &mut WeightMeter,
+	) -> Result<Option<T::AccountId>, Error<T>> {
+		let mut iter = match last_key.clone() {
+			Some(last_key) => frame_system::Account::<T>::iter_keys_from(
+				frame_system::Account::<T>::hashed_key_for(last_key),
+			),
+			None => frame_system::Account::<T>::iter_keys(),
+		};
+
+		let mut batch = Vec::new();
+		let mut last_key = last_key;
+		let mut done = false;
+
+		let account_weight = <T as Config>::WeightInfo::withdraw_account();
+		let send_weight = <T as Config>::WeightInfo::send_accounts();
+		loop {
+			// The weight for sending a batch is consumed along with its first account.
+			let weight = if batch.is_empty() {
+				account_weight.saturating_add(send_weight)
+			} else {
+				account_weight
+			};
+			if weight_counter.try_consume(weight).is_err() {
+				log::info!(target: LOG_TARGET, "RC weight limit reached at account {:?}", last_key);
+				break;
+			}
+
+			let Some(who) = iter.next() else {
+				done = true;
+				break;
+			};
+
+			let withdrawn = with_transaction(|| match Self::withdraw_account(who.clone()) {
+				Ok(account) => TransactionOutcome::Commit(Ok(account)),
+				Err(e) => TransactionOutcome::Rollback(Err(DispatchError::from(e))),
+			});
+			match withdrawn {
+				Ok(Some(account)) => batch.push(account),
+				Ok(None) => (),
+				Err(e) => {
+					log::error!(
+						target: LOG_TARGET,
+						"Failed to withdraw account {:?}: {:?}, keeping it on the Relay Chain",
+						who,
+						e
+					);
+					Pallet::<T>::deposit_event(Event::AccountWithdrawFailed { who: who.clone() });
+				},
+			}
+			last_key = Some(who);
+
+			if batch.len() as u32 >= T::MaxAccountsPerMessage::get() {
+				Pallet::<T>::send_accounts(core::mem::take(&mut batch))?;
+			}
+		}
+
+		if !batch.is_empty() {
+			Pallet::<T>::send_accounts(batch)?;
+		}
+
+		if done {
+			Ok(None)
+		} else {
+			Ok(last_key)
+		}
+	}
+
+	/// Withdraw the balance of `who` from the Relay Chain.
+	///
+	/// Returns `None` if the account is preserved on the Relay Chain or has nothing to migrate.
+	///
+	/// Must be called within a storage transaction since it may fail half-way.
+	pub fn withdraw_account(who: T::AccountId) -> Result<Option<RcAccountFor<T>>, Error<T>> {
+		let state = RcAccounts::<T>::get(&who);
+		if let Some(AccountState::Preserve) = state {
+			log::debug!(target: LOG_TARGET, "Preserving account {:?} on the Relay Chain", who);
+			return Ok(None);
+		}
+		let (keep_free, keep_reserved, keep_consumers) = match state {
+			Some(AccountState::Part { free, reserved, consumers }) => (free, reserved, consumers),
+			_ => (0, 0, 0),
+		};
+
+		pallet_balances::Pallet::<T>::ensure_upgraded(&who);
+		let account_info = frame_system::Account::<T>::get(&who);
+
+		// Release all holds.
+		let mut holds = BoundedVec::new();
+		for hold in pallet_balances::Holds::<T>::get(&who).into_iter() {
+			<T as Config>::Currency::release(&hold.id, &who, hold.amount, Precision::Exact)
+				.map_err(|_| Error::<T>::FailedToWithdrawAccount)?;
+			let reason = T::RcToPortableHoldReason::convert(hold.id);
+			holds
+				.try_push(ReasonAmount { reason, amount: hold.amount })
+				.map_err(|_| Error::<T>::FailedToWithdrawAccount)?;
+		}
+
+		// Thaw all freezes.
+		let mut freezes = BoundedVec::new();
+		for freeze in pallet_balances::Freezes::<T>::get(&who).into_iter() {
+			<T as Config>::Currency::thaw(&freeze.id, &who)
+				.map_err(|_| Error::<T>::FailedToWithdrawAccount)?;
+			let reason = T::RcToPortableFreezeReason::convert(freeze.id);
+			freezes
+				.try_push(ReasonAmount { reason, amount: freeze.amount })
+				.map_err(|_| Error::<T>::FailedToWithdrawAccount)?;
+		}
+
+		// Remove all locks.
+		let mut locks = BoundedVec::new();
+		for lock in pallet_balances::Locks::<T>::get(&who).into_iter() {
+			<T as Config>::Currency::remove_lock(lock.id, &who);
+			locks.try_push(lock).map_err(|_| Error::<T>::FailedToWithdrawAccount)?;
+		}
+
+		// What is still reserved now is not covered by any hold. Keep the part that must stay on
+		// the Relay Chain reserved and migrate the rest.
+		let reserved = <T as Config>::Currency::reserved_balance(&who);
+		let keep_reserved = keep_reserved.min(reserved);
+		let unnamed_reserve = reserved.saturating_sub(keep_reserved);
+		let remaining = <T as Config>::Currency::unreserve(&who, unnamed_reserve);
+		if remaining > 0 {
+			return Err(Error::<T>::FailedToWithdrawAccount);
+		}
+
+		let holds_total = holds.iter().fold(0u128, |acc, h| acc.saturating_add(h.amount));
+		let frozen = freezes
+			.iter()
+			.map(|f| f.amount)
+			.chain(locks.iter().map(|l| l.amount))
+			.max()
+			.unwrap_or_default();
+
+		// The free balance that is migrated. The account might not be allowed to die due to other
+		// consumer references, in which case the ED stays on the Relay Chain.
+		let reducible = <T as Config>::Currency::reducible_balance(
+			&who,
+			Preservation::Expendable,
+			Fortitude::Force,
+		);
+		let teleport = reducible.saturating_sub(keep_free);
+		let total_reserved = holds_total.saturating_add(unnamed_reserve);
+		if teleport.is_zero() {
+			if total_reserved.is_zero() && freezes.is_empty() && locks.is_empty() {
+				return Ok(None);
+			}
+			// Reserved balance cannot be migrated without moving the funds backing it.
+			return Err(Error::<T>::FailedToWithdrawAccount);
+		}
+		if teleport < total_reserved {
+			// Not enough balance to back the holds and reserves on Asset Hub.
+			return Err(Error::<T>::FailedToWithdrawAccount);
+		}
+
+		let burned = <T as Config>::Currency::burn_from(
+			&who,
+			teleport,
+			Preservation::Expendable,
+			Precision::Exact,
+			Fortitude::Force,
+		)
+		.map_err(|_| Error::<T>::FailedToWithdrawAccount)?;
+		defensive_assert!(burned == teleport);
+
+		// Consumers that are moved along with the account. Providers are moved when the account
+		// was fully drained.
+		let consumers = account_info.consumers.saturating_sub(keep_consumers);
+		let providers =
+			if frame_system::Account::<T>::contains_key(&who) { 0 } else { account_info.providers };
+
+		Ok(Some(RcAccount {
+			who,
+			free: teleport.saturating_sub(total_reserved),
+			reserved: total_reserved,
+			frozen,
+			holds,
+			freezes,
+			locks,
+			unnamed_reserve,
+			consumers: consumers.min(u8::MAX as u32) as u8,
+			providers: providers.min(u8::MAX as u32) as u8,
+		}))
+	}
+
+	/// Restore an account on the Relay Chain that Asset Hub failed to receive.
+	///
+	/// Mints the withdrawn balance again and re-applies the holds, the unnamed reserve, the freezes
+	/// and the locks. If that fails, all changes are rolled back and an `AccountRestoreFailed`
+	/// event is emitted.
+	pub fn restore_account(account: RcAccountFor<T>) {
+		let who = account.who.clone();
+		let amount = account.free.saturating_add(account.reserved);
+		let restored = with_transaction(|| match Self::do_restore_account(account) {
+			Ok(()) => TransactionOutcome::Commit(Ok(())),
+			Err(e) => TransactionOutcome::Rollback(Err(DispatchError::from(e))),
+		});
+		if let Err(e) = restored {
+			log::error!(target: LOG_TARGET, "Failed to restore account {:?}: {:?}", who, e);
+			Pallet::<T>::deposit_event(Event::AccountRestoreFailed { who, amount });
+		}
+	}
+
+	fn do_restore_account(account: RcAccountFor<T>) -> Result<(), Error<T>> {
+		let who = account.who;
+		<T as Config>::Currency::mint_into(&who, account.free.saturating_add(account.reserved))
+			.map_err(|_| Error::<T>::FailedToRestoreAccount)?;
+
+		for hold in account.holds.into_iter() {
+			let reason = T::PortableToRcHoldReason::convert(hold.reason)
+				.ok_or(Error::<T>::FailedToRestoreAccount)?;
+			<T as Config>::Currency::hold(&reason, &who, hold.amount)
+				.map_err(|_| Error::<T>::FailedToRestoreAccount)?;
+		}
+		<T as Config>::Currency::reserve(&who, account.unnamed_reserve)
+			.map_err(|_| Error::<T>::FailedToRestoreAccount)?;
+
+		for freeze in account.freezes.into_iter() {
+			let id = T::PortableToRcFreezeReason::convert(freeze.reason)
+				.ok_or(Error::<T>::FailedToRestoreAccount)?;
+			<T as Config>::Currency::set_freeze(&id, &who, freeze.amount)
+				.map_err(|_| Error::<T>::FailedToRestoreAccount)?;
+		}
+		for lock in account.locks.into_iter() {
+			let reasons = match lock.reasons {
+				Reasons::Fee => WithdrawReasons::TRANSACTION_PAYMENT,
+				Reasons::Misc => WithdrawReasons::except(WithdrawReasons::TRANSACTION_PAYMENT),
+				Reasons::All => WithdrawReasons::all(),
+			};
+			<T as Config>::Currency::set_lock(lock.id, &who, lock.amount, reasons);
+		}
+
+		Ok(())
+	}
+}
@@ -0,0 +1,244 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{classification::*, dry_run::*, progress::*, *};
+use frame_benchmarking::{account, v2::*};
+use frame_support::{
+	storage::{with_transaction, TransactionOutcome},
+	traits::{Currency, EnsureOrigin, WithdrawReasons},
+};
+use frame_system::RawOrigin;
+use polkadot_runtime_common::paras_registrar::{ParaInfo, Paras};
+
+#[benchmarks]
+pub mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn withdraw_account() {
+		let who: T::AccountId = account("who", 0, 0);
+		let hold_reasons = T::BenchmarkHelper::hold_reasons();
+		let freeze_reasons = T::BenchmarkHelper::freeze_reasons();
+		let locks = <T as pallet_balances::Config>::MaxLocks::get().min(MaxAccountItems::get());
+		let ed = <<T as Config>::Currency as Currency<_>>::minimum_balance();
+		let amount = ed * 10;
+		let _ = <<T as Config>::Currency as Currency<_>>::deposit_creating(
+			&who,
+			ed * 100 + amount * (hold_reasons.len() as u128 + 1),
+		);
+
+		// The worst case is an account with every hold, freeze and lock and an unnamed reserve.
+		for reason in hold_reasons.iter() {
+			<<T as Config>::Currency as MutateHold<_>>::hold(reason, &who, amount)
+				.expect("account is funded");
+		}
+		for id in freeze_reasons.iter() {
+			<<T as Config>::Currency as MutateFreeze<_>>::set_freeze(id, &who, amount)
+				.expect("account is funded");
+		}
+		for i in 0..locks {
+			<T as Config>::Currency::set_lock([i as u8; 8], &who, amount, WithdrawReasons::all());
+		}
+		<T as Config>::Currency::reserve(&who, amount).expect("account is funded");
+
+		let res;
+		#[block]
+		{
+			res = with_transaction(|| {
+				TransactionOutcome::Commit(Ok::<_, DispatchError>(
+					AccountsMigrator::<T>::withdraw_account(who.clone()),
+				))
+			});
+		}
+
+		let account = res.unwrap().expect("withdraw works").expect("account is migrated");
+		assert_eq!(account.holds.len(), hold_reasons.len());
+		assert_eq!(account.freezes.len(), freeze_reasons.len());
+		assert_eq!(account.locks.len() as u32, locks);
+		assert_eq!(account.unnamed_reserve, amount);
+		assert!(!frame_system::Account::<T>::contains_key(&who));
+	}
+
+	#[benchmark]
+	fn send_accounts() {
+		let hold_reasons = T::BenchmarkHelper::hold_reasons();
+		let freeze_reasons = T::BenchmarkHelper::freeze_reasons();
+		let locks = <T as pallet_balances::Config>::MaxLocks::get().min(MaxAccountItems::get());
+		let amount = u128::MAX;
+		let accounts = (0..T::MaxAccountsPerMessage::get())
+			.map(|i| RcAccount {
+				who: account("who", i, 0),
+				free: amount,
+				reserved: amount,
+				frozen: amount,
+				holds: hold_reasons
+					.iter()
+					.map(|reason| ReasonAmount {
+						reason: T::RcToPortableHoldReason::convert(reason.clone()),
+						amount,
+					})
+					.collect::<Vec<_>>()
+					.try_into()
+					.unwrap(),
+				freezes: freeze_reasons
+					.iter()
+					.map(|id| ReasonAmount {
+						reason: T::RcToPortableFreezeReason::convert(id.clone()),
+						amount,
+					})
+					.collect::<Vec<_>>()
+					.try_into()
+					.unwrap(),
+				locks: (0..locks)
+					.map(|l| pallet_balances::BalanceLock {
+						id: [l as u8; 8],
+						amount,
+						reasons: pallet_balances::Reasons::All,
+					})
+					.collect::<Vec<_>>()
+					.try_into()
+					.unwrap(),
+				unnamed_reserve: amount,
+				consumers: u8::MAX,
+				providers: u8::MAX,
+			})
+			.collect::<Vec<_>>();
+		T::SendXcm::ensure_successful_delivery(Some(T::AssetHubLocation::get()));
+
+		#[block]
+		{
+			Pallet::<T>::send_accounts(accounts).expect("sending works");
+		}
+
+		assert_eq!(
+			MigrationItemCounters::<T>::get(MigratedItem::Accounts).sent,
+			T::MaxAccountsPerMessage::get()
+		);
+	}
+
+	#[benchmark]
+	fn force_set_stage() -> Result<(), BenchmarkError> {
+		let origin =
+			T::ManagerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let stage = MigrationStage::AccountsMigrationOngoing { last_key: None };
+
+		#[extrinsic_call]
+		_(origin, Box::new(stage.clone()));
+
+		assert_eq!(RcMigrationStage::<T>::get(), stage);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn schedule_migration() -> Result<(), BenchmarkError> {
+		let origin =
+			T::ManagerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		let start = now + 10u32.into();
+
+		#[extrinsic_call]
+		_(origin, DispatchTime::At(start));
+
+		assert_eq!(RcMigrationStage::<T>::get(), MigrationStage::Scheduled { start });
+		Ok(())
+	}
+
+	#[benchmark]
+	fn receive_query_response() {
+		let origin: <T as Config>::RuntimeOrigin =
+			pallet_xcm::Origin::Response(T::AssetHubLocation::get()).into();
+		let origin: <T as frame_system::Config>::RuntimeOrigin = origin.into();
+		let query_id = Pallet::<T>::expect_acknowledgement(MigratedItem::Accounts, 100);
+		Pallet::<T>::note_sent(MigratedItem::Accounts, 100);
+
+		#[extrinsic_call]
+		_(origin, query_id, Response::DispatchResult(MaybeErrorCode::Success));
+
+		assert_eq!(
+			MigrationItemCounters::<T>::get(MigratedItem::Accounts),
+			ItemCounter { sent: 100, acknowledged: 100 }
+		);
+	}
+
+	#[benchmark]
+	fn start_dry_run() -> Result<(), BenchmarkError> {
+		let origin =
+			T::ManagerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin);
+
+		assert_eq!(RcMigrationStage::<T>::get(), MigrationStage::DryRunInit);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn dry_run_account() {
+		let who: T::AccountId = account("who", 0, 0);
+		let ed = <<T as Config>::Currency as Currency<_>>::minimum_balance();
+		let _ = <<T as Config>::Currency as Currency<_>>::deposit_creating(&who, ed * 100);
+		let _ = <T as Config>::Currency::reserve(&who, ed);
+		let mut report = DryRunReportOf::<T>::default();
+
+		#[block]
+		{
+			DryRunner::<T>::check_account(&who, &mut report);
+		}
+
+		assert_eq!(report.accounts_migrated, 1);
+		assert_eq!(report.mismatches, 0);
+		assert!(frame_system::Account::<T>::contains_key(&who));
+	}
+
+	#[benchmark]
+	fn dry_run_rc_account() {
+		let who: T::AccountId = account("who", 0, 0);
+		let ed = <<T as Config>::Currency as Currency<_>>::minimum_balance();
+		let _ = <<T as Config>::Currency as Currency<_>>::deposit_creating(&who, ed);
+		let state = AccountState::Part { free: ed * 2, reserved: 0, consumers: 0 };
+		let mut report = DryRunReportOf::<T>::default();
+
+		#[block]
+		{
+			DryRunner::<T>::check_rc_account(&who, state, &mut report);
+		}
+
+		assert_eq!(report.mismatches, 1);
+	}
+
+	#[benchmark]
+	fn classify_accounts(n: Linear<1, 100>) {
+		let caller: T::AccountId = whitelisted_caller();
+		for i in 0..n {
+			let info = ParaInfo {
+				manager: account("manager", i, 0),
+				deposit: 1_000_000_000u32.into(),
+				locked: None,
+			};
+			Paras::<T>::insert(polkadot_parachain_primitives::primitives::Id::from(2000 + i), info);
+		}
+		// Skip the transition out of `NotStarted`.
+		RcAccountsClassification::<T>::put(ClassificationCursor::Registrar { last: None });
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), n);
+
+		assert_eq!(RcAccounts::<T>::count(), n);
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
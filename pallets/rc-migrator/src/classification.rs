@@ -0,0 +1,262 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Automatic classification of the accounts that must be kept partially on the Relay Chain.
+//!
+//! The deposits of the following pallets stay on the Relay Chain since the pallets stay there as
+//! well:
+//! - `paras_registrar`: the deposit reserved by the manager of a para;
+//! - `hrmp`: the deposits reserved by the sovereign accounts of the channel sender and recipient,
+//!   for both open channels and open channel requests;
+//! - `session`: the consumer reference of the account that registered session keys.
+//!
+//! The classifier walks these pallets in this order and adds the deposits and consumer references
+//! to the [`RcAccounts`] entry of the account as [`AccountState::Part`]. Accounts that are already
+//! [`AccountState::Preserve`]d are not touched.
+
+use crate::*;
+use frame_support::traits::fungible::Inspect;
+use polkadot_parachain_primitives::primitives::{HrmpChannelId, Id as ParaId};
+use polkadot_runtime_common::paras_registrar;
+use runtime_parachains::{configuration, hrmp};
+use sp_runtime::traits::{AccountIdConversion, Saturating, UniqueSaturatedInto};
+
+/// The progress of the classification.
+#[derive(
+	Encode,
+	DecodeWithMemTracking,
+	Decode,
+	Clone,
+	DefaultNoBound,
+	PartialEq,
+	Eq,
+	Debug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub enum ClassificationCursor<AccountId> {
+	/// The classification did not start yet.
+	#[default]
+	NotStarted,
+	/// Classifying the para managers.
+	Registrar {
+		/// The last para that was classified.
+		last: Option<ParaId>,
+	},
+	/// Classifying the HRMP open channel requests.
+	HrmpRequests {
+		/// The `(sender, recipient)` of the last request that was classified.
+		last: Option<(ParaId, ParaId)>,
+	},
+	/// Classifying the HRMP channels.
+	HrmpChannels {
+		/// The `(sender, recipient)` of the last channel that was classified.
+		last: Option<(ParaId, ParaId)>,
+	},
+	/// Classifying the accounts with session keys.
+	Session {
+		/// The last account that was classified.
+		last: Option<AccountId>,
+	},
+	/// All accounts are classified.
+	Done,
+}
+
+/// [`ClassificationCursor`] of the runtime.
+pub type ClassificationCursorOf<T> = ClassificationCursor<<T as frame_system::Config>::AccountId>;
+
+pub struct AccountsClassifier<T> {
+	_phantom: core::marker::PhantomData<T>,
+}
+
+impl<T: Config> AccountsClassifier<T> {
+	/// Classify up to `limit` items, continuing from the stored cursor.
+	///
+	/// Returns the number of processed items.
+	pub fn classify(limit: u32) -> u32 {
+		let mut cursor = RcAccountsClassification::<T>::get();
+		let mut processed = 0;
+
+		while processed < limit {
+			let remaining = limit - processed;
+			let (next, count) = match cursor.clone() {
+				ClassificationCursor::NotStarted =>
+					(ClassificationCursor::Registrar { last: None }, 0),
+				ClassificationCursor::Registrar { last } => Self::classify_paras(last, remaining),
+				ClassificationCursor::HrmpRequests { last } =>
+					Self::classify_hrmp_requests(last, remaining),
+				ClassificationCursor::HrmpChannels { last } =>
+					Self::classify_hrmp_channels(last, remaining),
+				ClassificationCursor::Session { last } => Self::classify_session(last, remaining),
+				ClassificationCursor::Done => break,
+			};
+			cursor = next;
+			processed += count;
+		}
+
+		RcAccountsClassification::<T>::put(cursor);
+		processed
+	}
+
+	fn classify_paras(last: Option<ParaId>, limit: u32) -> (ClassificationCursorOf<T>, u32) {
+		let mut iter = match last {
+			Some(last) => paras_registrar::Paras::<T>::iter_from(
+				paras_registrar::Paras::<T>::hashed_key_for(last),
+			),
+			None => paras_registrar::Paras::<T>::iter(),
+		};
+
+		let mut count = 0;
+		let mut last = last;
+		while count < limit {
+			let Some((para_id, info)) = iter.next() else {
+				return (ClassificationCursor::HrmpRequests { last: None }, count);
+			};
+			Self::note_reserve(&info.manager, info.deposit.unique_saturated_into());
+			last = Some(para_id);
+			count += 1;
+		}
+
+		(ClassificationCursor::Registrar { last }, count)
+	}
+
+	fn classify_hrmp_requests(
+		last: Option<(ParaId, ParaId)>,
+		limit: u32,
+	) -> (ClassificationCursorOf<T>, u32) {
+		let mut iter = match last {
+			Some((sender, recipient)) => hrmp::HrmpOpenChannelRequests::<T>::iter_from(
+				hrmp::HrmpOpenChannelRequests::<T>::hashed_key_for(HrmpChannelId {
+					sender,
+					recipient,
+				}),
+			),
+			None => hrmp::HrmpOpenChannelRequests::<T>::iter(),
+		};
+		// The recipient deposit is not part of the request but taken from the active config when
+		// the request is accepted.
+		let recipient_deposit = configuration::ActiveConfig::<T>::get().hrmp_recipient_deposit;
+
+		let mut count = 0;
+		let mut last = last;
+		while count < limit {
+			let Some((id, request)) = iter.next() else {
+				return (ClassificationCursor::HrmpChannels { last: None }, count);
+			};
+			Self::note_reserve(&id.sender.into_account_truncating(), request.sender_deposit);
+			if request.confirmed {
+				Self::note_reserve(&id.recipient.into_account_truncating(), recipient_deposit);
+			}
+			last = Some((id.sender, id.recipient));
+			count += 1;
+		}
+
+		(ClassificationCursor::HrmpRequests { last }, count)
+	}
+
+	fn classify_hrmp_channels(
+		last: Option<(ParaId, ParaId)>,
+		limit: u32,
+	) -> (ClassificationCursorOf<T>, u32) {
+		let mut iter = match last {
+			Some((sender, recipient)) => hrmp::HrmpChannels::<T>::iter_from(
+				hrmp::HrmpChannels::<T>::hashed_key_for(HrmpChannelId { sender, recipient }),
+			),
+			None => hrmp::HrmpChannels::<T>::iter(),
+		};
+
+		let mut count = 0;
+		let mut last = last;
+		while count < limit {
+			let Some((id, channel)) = iter.next() else {
+				return (ClassificationCursor::Session { last: None }, count);
+			};
+			Self::note_reserve(&id.sender.into_account_truncating(), channel.sender_deposit);
+			Self::note_reserve(&id.recipient.into_account_truncating(), channel.recipient_deposit);
+			last = Some((id.sender, id.recipient));
+			count += 1;
+		}
+
+		(ClassificationCursor::HrmpChannels { last }, count)
+	}
+
+	fn classify_session(
+		last: Option<T::AccountId>,
+		limit: u32,
+	) -> (ClassificationCursorOf<T>, u32) {
+		let mut iter = match last.clone() {
+			Some(last) => pallet_session::NextKeys::<T>::iter_keys_from(
+				pallet_session::NextKeys::<T>::hashed_key_for(last),
+			),
+			None => pallet_session::NextKeys::<T>::iter_keys(),
+		};
+
+		let mut count = 0;
+		let mut last = last;
+		while count < limit {
+			let Some(who) = iter.next() else {
+				return (ClassificationCursor::Done, count);
+			};
+			Self::note_consumer(&who);
+			last = Some(who);
+			count += 1;
+		}
+
+		(ClassificationCursor::Session { last }, count)
+	}
+
+	/// Note that `amount` is reserved by `who` and must stay on the Relay Chain.
+	pub(crate) fn note_reserve(who: &T::AccountId, amount: u128) {
+		if amount == 0 {
+			return;
+		}
+		RcAccounts::<T>::mutate(who, |state| match state {
+			Some(AccountState::Preserve) => (),
+			Some(AccountState::Part { reserved, consumers, .. }) => {
+				// A single consumer reference is held for any amount of reserved balance.
+				if *reserved == 0 {
+					consumers.saturating_inc();
+				}
+				reserved.saturating_accrue(amount);
+			},
+			Some(AccountState::Migrate) | None =>
+				*state = Some(AccountState::Part {
+					free: Self::existential_deposit(),
+					reserved: amount,
+					consumers: 1,
+				}),
+		});
+	}
+
+	/// Note that `who` holds a consumer reference that must stay on the Relay Chain.
+	pub(crate) fn note_consumer(who: &T::AccountId) {
+		RcAccounts::<T>::mutate(who, |state| match state {
+			Some(AccountState::Preserve) => (),
+			Some(AccountState::Part { consumers, .. }) => consumers.saturating_inc(),
+			Some(AccountState::Migrate) | None =>
+				*state = Some(AccountState::Part {
+					free: Self::existential_deposit(),
+					reserved: 0,
+					consumers: 1,
+				}),
+		});
+	}
+
+	fn existential_deposit() -> u128 {
+		<<T as Config>::Currency as Inspect<_>>::minimum_balance()
+	}
+}
@@ -0,0 +1,303 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Dry-run of the account migration.
+//!
+//! The dry-run withdraws every account exactly like the real migration would, but within a storage
+//! transaction that is always rolled back. Nothing is sent to Asset Hub and no balance is moved.
+//! The outcome is aggregated in the [`DryRunReport`] and every account that would not be migrated
+//! correctly is recorded in [`DryRunMismatches`].
+
+use crate::{accounts::AccountsMigrator, *};
+use frame_support::{
+	storage::{with_transaction, TransactionOutcome},
+	traits::fungible::Inspect,
+};
+use sp_runtime::{traits::Saturating, DispatchError};
+
+/// The aggregated outcome of a dry-run.
+#[derive(
+	Encode,
+	DecodeWithMemTracking,
+	Decode,
+	Clone,
+	Default,
+	PartialEq,
+	Eq,
+	Debug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub struct DryRunReport<BlockNumber> {
+	/// The block number when the dry-run started.
+	pub started_at: BlockNumber,
+	/// The block number when the dry-run finished or `None` if it is still running.
+	pub finished_at: Option<BlockNumber>,
+	/// The total issuance when the dry-run started.
+	pub total_issuance: u128,
+	/// The number of accounts that were checked.
+	pub accounts_checked: u32,
+	/// The number of accounts that would be sent to Asset Hub.
+	pub accounts_migrated: u32,
+	/// The number of accounts that would be kept fully or partially on the Relay Chain.
+	pub accounts_preserved: u32,
+	/// The number of accounts that were recorded in [`DryRunMismatches`].
+	pub mismatches: u32,
+	/// The balance that would be sent to Asset Hub.
+	pub balance_migrated: u128,
+	/// The balance that would stay on the Relay Chain.
+	pub balance_preserved: u128,
+	/// The reduction of the total issuance on the Relay Chain that the migration would cause.
+	///
+	/// Must be equal to `balance_migrated`.
+	pub issuance_delta: u128,
+}
+
+/// [`DryRunReport`] of the runtime.
+pub type DryRunReportOf<T> = DryRunReport<BlockNumberFor<T>>;
+
+/// The reason why an account would not be migrated correctly.
+#[derive(
+	Encode, DecodeWithMemTracking, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen,
+)]
+pub enum DryRunMismatch<Balance> {
+	/// The balance of the account could not be withdrawn.
+	WithdrawFailed,
+	/// The balance before the migration does not equal the migrated plus the remaining balance.
+	BalanceMismatch {
+		/// The total balance before the migration.
+		before: Balance,
+		/// The balance that would be sent to Asset Hub.
+		migrated: Balance,
+		/// The balance that would stay on the Relay Chain.
+		remaining: Balance,
+	},
+	/// The reduction of the total issuance does not equal the migrated balance.
+	IssuanceMismatch {
+		/// The balance that would be sent to Asset Hub.
+		migrated: Balance,
+		/// The reduction of the total issuance.
+		burned: Balance,
+	},
+	/// The account is listed in [`RcAccounts`] but does not exist.
+	UnknownAccount,
+	/// The account must keep more balance on the Relay Chain than it has.
+	PartExceedsBalance {
+		/// The total balance of the account.
+		balance: Balance,
+		/// The balance that must be kept on the Relay Chain.
+		keep: Balance,
+	},
+}
+
+pub struct DryRunner<T> {
+	_phantom: core::marker::PhantomData<T>,
+}
+
+impl<T: Config> DryRunner<T> {
+	/// Clear the outcome of a previous dry-run.
+	///
+	/// Returns `true` once everything is cleared and the new report is initialized.
+	pub fn init(weight_counter: &mut WeightMeter) -> bool {
+		let limit = T::MaxAccountsPerMessage::get();
+		let weight = T::DbWeight::get().writes(limit as u64);
+		if weight_counter.try_consume(weight).is_err() {
+			return false;
+		}
+
+		let result = DryRunMismatches::<T>::clear(limit, None);
+		if result.maybe_cursor.is_some() {
+			return false;
+		}
+
+		LastDryRun::<T>::put(DryRunReportOf::<T> {
+			started_at: frame_system::Pallet::<T>::block_number(),
+			total_issuance: Self::total_issuance(),
+			..Default::default()
+		});
+		true
+	}
+
+	/// Dry-run the migration of the accounts after `last_key` until the `weight_counter` is
+	/// exhausted.
+	///
+	/// Returns the key of the last checked account or `None` if all accounts are checked.
+	pub fn check_accounts(
+		last_key: Option<T::AccountId>,
+		weight_counter: &mut WeightMeter,
+	) -> Option<T::AccountId> {
+		let mut iter = match last_key.clone() {
+			Some(last_key) => frame_system::Account::<T>::iter_keys_from(
+				frame_system::Account::<T>::hashed_key_for(last_key),
+			),
+			None => frame_system::Account::<T>::iter_keys(),
+		};
+
+		let mut report = LastDryRun::<T>::get().unwrap_or_default();
+		let mut last_key = last_key;
+		let mut done = false;
+
+		let account_weight = <T as Config>::WeightInfo::dry_run_account();
+		loop {
+			if weight_counter.try_consume(account_weight).is_err() {
+				break;
+			}
+
+			let Some(who) = iter.next() else {
+				done = true;
+				break;
+			};
+
+			Self::check_account(&who, &mut report);
+			last_key = Some(who);
+		}
+
+		LastDryRun::<T>::put(report);
+		if done {
+			None
+		} else {
+			last_key
+		}
+	}
+
+	/// Check the [`RcAccounts`] entries after `last_key` until the `weight_counter` is exhausted.
+	///
+	/// Returns the key of the last checked entry or `None` if all entries are checked.
+	pub fn check_rc_accounts(
+		last_key: Option<T::AccountId>,
+		weight_counter: &mut WeightMeter,
+	) -> Option<T::AccountId> {
+		let mut iter = match last_key.clone() {
+			Some(last_key) => RcAccounts::<T>::iter_from(RcAccounts::<T>::hashed_key_for(last_key)),
+			None => RcAccounts::<T>::iter(),
+		};
+
+		let mut report = LastDryRun::<T>::get().unwrap_or_default();
+		let mut last_key = last_key;
+		let mut done = false;
+
+		let entry_weight = <T as Config>::WeightInfo::dry_run_rc_account();
+		loop {
+			if weight_counter.try_consume(entry_weight).is_err() {
+				break;
+			}
+
+			let Some((who, state)) = iter.next() else {
+				done = true;
+				break;
+			};
+
+			Self::check_rc_account(&who, state, &mut report);
+			last_key = Some(who);
+		}
+
+		if done {
+			report.finished_at = Some(frame_system::Pallet::<T>::block_number());
+		}
+		LastDryRun::<T>::put(report);
+		if done {
+			None
+		} else {
+			last_key
+		}
+	}
+
+	/// Check that an [`RcAccounts`] entry refers to an existing account that can cover the balance
+	/// that must be kept on the Relay Chain.
+	pub(crate) fn check_rc_account(
+		who: &T::AccountId,
+		state: AccountState<u128>,
+		report: &mut DryRunReportOf<T>,
+	) {
+		if !frame_system::Account::<T>::contains_key(who) {
+			Self::record(who, DryRunMismatch::UnknownAccount, report);
+		} else if let AccountState::Part { free, reserved, .. } = state {
+			let balance = Self::total_balance(who);
+			let keep = free.saturating_add(reserved);
+			if keep > balance {
+				Self::record(who, DryRunMismatch::PartExceedsBalance { balance, keep }, report);
+			}
+		}
+	}
+
+	/// Withdraw the account within a storage transaction that is rolled back and compare the
+	/// outcome with the balance before.
+	pub(crate) fn check_account(who: &T::AccountId, report: &mut DryRunReportOf<T>) {
+		report.accounts_checked.saturating_inc();
+
+		let outcome = with_transaction(|| {
+			let before = Self::total_balance(who);
+			let issuance_before = Self::total_issuance();
+			let account = AccountsMigrator::<T>::withdraw_account(who.clone());
+			let remaining = Self::total_balance(who);
+			let burned = issuance_before.saturating_sub(Self::total_issuance());
+			TransactionOutcome::Rollback(Ok::<_, DispatchError>((
+				before, account, remaining, burned,
+			)))
+		});
+
+		let Ok((before, account, remaining, burned)) = outcome else {
+			Self::record(who, DryRunMismatch::WithdrawFailed, report);
+			return;
+		};
+		let migrated = match account {
+			Ok(Some(account)) => account.free.saturating_add(account.reserved),
+			Ok(None) => 0,
+			Err(_) => {
+				Self::record(who, DryRunMismatch::WithdrawFailed, report);
+				return;
+			},
+		};
+
+		if migrated > 0 {
+			report.accounts_migrated.saturating_inc();
+		}
+		if remaining > 0 {
+			report.accounts_preserved.saturating_inc();
+		}
+		report.balance_migrated.saturating_accrue(migrated);
+		report.balance_preserved.saturating_accrue(remaining);
+		report.issuance_delta.saturating_accrue(burned);
+
+		if before != migrated.saturating_add(remaining) {
+			Self::record(
+				who,
+				DryRunMismatch::BalanceMismatch { before, migrated, remaining },
+				report,
+			);
+		} else if burned != migrated {
+			Self::record(who, DryRunMismatch::IssuanceMismatch { migrated, burned }, report);
+		}
+	}
+
+	fn total_balance(who: &T::AccountId) -> u128 {
+		<<T as Config>::Currency as Inspect<_>>::total_balance(who)
+	}
+
+	fn total_issuance() -> u128 {
+		<<T as Config>::Currency as Inspect<_>>::total_issuance()
+	}
+
+	/// Record a mismatch for `who`.
+	fn record(who: &T::AccountId, mismatch: DryRunMismatch<u128>, report: &mut DryRunReportOf<T>) {
+		log::warn!(target: LOG_TARGET, "Dry-run mismatch for account {:?}: {:?}", who, mismatch);
+		if !DryRunMismatches::<T>::contains_key(who) {
+			report.mismatches.saturating_inc();
+		}
+		DryRunMismatches::<T>::insert(who, mismatch);
+	}
+}
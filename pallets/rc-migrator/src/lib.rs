@@ -17,8 +17,8 @@
 
 //! The operational pallet for the Relay Chain, designed to manage and facilitate the migration of
 //! subsystems such as Governance, Staking, Balances from the Relay Chain to the Asset Hub. This
-//! pallet works alongside its counterpart, `pallet_ah_migrator`, which handles migration
-//! processes on the Asset Hub side.
+//! pallet works alongside its counterpart, `pallet_ah_ops`, which handles migration processes on
+//! the Asset Hub side.
 //!
 //! This pallet is responsible for controlling the initiation, progression, and completion of the
 //! migration process, including managing its various stages and transferring the necessary data.
@@ -31,18 +31,47 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+pub mod accounts;
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+pub mod classification;
+pub mod dry_run;
+#[cfg(test)]
+mod mock;
+pub mod progress;
+#[cfg(test)]
+mod tests;
+pub mod types;
+pub mod weights;
+
 pub use pallet::*;
+pub use weights::WeightInfo;
 
+use accounts::AccountsMigrator;
+use classification::*;
+use dry_run::*;
 use frame_support::{
 	pallet_prelude::*,
 	traits::{
 		fungible::{InspectFreeze, Mutate, MutateFreeze, MutateHold},
+		schedule::DispatchTime,
 		LockableCurrency, ReservableCurrency,
 	},
+	weights::WeightMeter,
 };
-use frame_system::pallet_prelude::BlockNumberFor;
+use frame_system::pallet_prelude::*;
 use pallet_balances::AccountData;
-use sp_runtime::AccountId32;
+use progress::*;
+use sp_runtime::{traits::Convert, AccountId32};
+use sp_std::prelude::*;
+use types::*;
+use xcm::prelude::{
+	send_xcm, Instruction, Location, MaybeErrorCode, OriginKind, QueryId, QueryResponseInfo,
+	Response, SendXcm, WeightLimit, Xcm,
+};
+
+/// The log target of this pallet.
+pub const LOG_TARGET: &str = "runtime::rc-migrator";
 
 /// The state for the Relay Chain accounts.
 #[derive(
@@ -82,6 +111,104 @@ pub enum AccountState<Balance> {
 	},
 }
 
+/// The stages of the migration on the Relay Chain.
+///
+/// The stages are passed in order, see the `README.md` for a high-level overview.
+#[derive(
+	Encode,
+	DecodeWithMemTracking,
+	Decode,
+	Clone,
+	DefaultNoBound,
+	PartialEq,
+	Eq,
+	Debug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub enum MigrationStage<AccountId, BlockNumber> {
+	/// The migration has not been scheduled yet.
+	#[default]
+	Pending,
+	/// Clearing the outcome of the previous dry-run.
+	DryRunInit,
+	/// The migration of the accounts is being dry-run.
+	///
+	/// Nothing is sent to Asset Hub and all changes are rolled back.
+	DryRunAccountsOngoing {
+		/// The last account that was checked or `None` if none was checked yet.
+		last_key: Option<AccountId>,
+	},
+	/// The [`RcAccounts`] entries are being checked.
+	DryRunRcAccountsOngoing {
+		/// The last entry that was checked or `None` if none was checked yet.
+		last_key: Option<AccountId>,
+	},
+	/// The migration is scheduled to start at the given block number.
+	Scheduled {
+		/// The block number at which the migration starts.
+		start: BlockNumber,
+	},
+	/// The migration is starting and Asset Hub gets notified.
+	Starting,
+	/// Initializing the account migration.
+	AccountsMigrationInit,
+	/// Accounts are being migrated.
+	AccountsMigrationOngoing {
+		/// The last account that was processed or `None` if none was processed yet.
+		last_key: Option<AccountId>,
+	},
+	/// All accounts are migrated.
+	AccountsMigrationDone,
+	/// The migration is finished and Asset Hub got notified.
+	MigrationDone,
+}
+
+impl<AccountId, BlockNumber> MigrationStage<AccountId, BlockNumber> {
+	/// Whether the migration is ongoing.
+	///
+	/// This is the case between starting the migration and finishing it. Functionality that
+	/// interferes with the migration should be locked while this returns `true`. A dry-run does
+	/// not count as ongoing migration.
+	pub fn is_ongoing(&self) -> bool {
+		!matches!(
+			self,
+			MigrationStage::Pending |
+				MigrationStage::Scheduled { .. } |
+				MigrationStage::MigrationDone
+		) && !self.is_dry_run()
+	}
+
+	/// Whether a dry-run is in progress.
+	pub fn is_dry_run(&self) -> bool {
+		matches!(
+			self,
+			MigrationStage::DryRunInit |
+				MigrationStage::DryRunAccountsOngoing { .. } |
+				MigrationStage::DryRunRcAccountsOngoing { .. }
+		)
+	}
+
+	/// Whether the migration is finished.
+	pub fn is_finished(&self) -> bool {
+		matches!(self, MigrationStage::MigrationDone)
+	}
+}
+
+/// Helper for setting up the benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<HoldReason, FreezeReason> {
+	/// Distinct hold reasons of the runtime, the benchmarked account is held for each of them.
+	fn hold_reasons() -> Vec<HoldReason>;
+
+	/// Distinct freeze reasons of the runtime, the benchmarked account is frozen for each of them.
+	fn freeze_reasons() -> Vec<FreezeReason>;
+}
+
+/// [`MigrationStage`] of the runtime.
+pub type MigrationStageOf<T> =
+	MigrationStage<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -91,23 +218,199 @@ pub mod pallet {
 	#[pallet::config]
 	pub trait Config:
 		frame_system::Config<AccountData = AccountData<u128>, AccountId = AccountId32, Nonce = u32>
+		+ pallet_balances::Config<Balance = u128>
+		+ pallet_session::Config<ValidatorId = AccountId32>
+		+ polkadot_runtime_common::paras_registrar::Config
+		+ runtime_parachains::hrmp::Config
+		+ pallet_xcm::Config
 	{
+		/// The overarching event type.
+		#[allow(deprecated)]
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
 		/// The overall runtime origin type.
 		type RuntimeOrigin: Into<Result<pallet_xcm::Origin, <Self as Config>::RuntimeOrigin>>
 			+ IsType<<Self as frame_system::Config>::RuntimeOrigin>
-			+ From<frame_system::RawOrigin<Self::AccountId>>;
+			+ From<frame_system::RawOrigin<Self::AccountId>>
+			+ From<pallet_xcm::Origin>;
+
+		/// The overall runtime call type.
+		type RuntimeCall: From<Call<Self>> + IsType<<Self as pallet_xcm::Config>::RuntimeCall>;
+
+		/// Origin that can schedule the migration and force stage transitions.
+		type ManagerOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
 
 		/// Native asset registry type.
 		type Currency: Mutate<Self::AccountId, Balance = u128>
-			+ MutateHold<Self::AccountId>
-			+ InspectFreeze<Self::AccountId>
+			+ MutateHold<
+				Self::AccountId,
+				Reason = <Self as pallet_balances::Config>::RuntimeHoldReason,
+			> + InspectFreeze<Self::AccountId, Id = Self::FreezeIdentifier>
 			+ MutateFreeze<Self::AccountId>
 			+ ReservableCurrency<Self::AccountId, Balance = u128>
 			+ LockableCurrency<Self::AccountId, Balance = u128>;
+
+		/// Convert a Relay Chain hold reason into its portable representation.
+		type RcToPortableHoldReason: Convert<
+			<Self as pallet_balances::Config>::RuntimeHoldReason,
+			PortableHoldReason,
+		>;
+
+		/// Convert a Relay Chain freeze reason into its portable representation.
+		type RcToPortableFreezeReason: Convert<Self::FreezeIdentifier, PortableFreezeReason>;
+
+		/// Convert a portable hold reason back into the Relay Chain hold reason.
+		///
+		/// Used to restore the accounts that Asset Hub failed to receive.
+		type PortableToRcHoldReason: Convert<
+			PortableHoldReason,
+			Option<<Self as pallet_balances::Config>::RuntimeHoldReason>,
+		>;
+
+		/// Convert a portable freeze reason back into the Relay Chain freeze reason.
+		///
+		/// Used to restore the accounts that Asset Hub failed to receive.
+		type PortableToRcFreezeReason: Convert<PortableFreezeReason, Option<Self::FreezeIdentifier>>;
+
+		/// The XCM sender used to send the migration messages to Asset Hub.
+		type SendXcm: SendXcm;
+
+		/// The location of Asset Hub as seen from the Relay Chain.
+		type AssetHubLocation: Get<Location>;
+
+		/// The maximal weight that the migration consumes on the Relay Chain per block.
+		type MaxRcWeight: Get<Weight>;
+
+		/// The maximal number of accounts that are sent to Asset Hub in a single XCM message.
+		#[pallet::constant]
+		type MaxAccountsPerMessage: Get<u32>;
+
+		/// The Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// Helper for setting up the benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<
+			<Self as pallet_balances::Config>::RuntimeHoldReason,
+			Self::FreezeIdentifier,
+		>;
 	}
 
 	#[pallet::error]
-	pub enum Error<T> {}
+	pub enum Error<T> {
+		/// The migration is not in the expected stage.
+		WrongStage,
+		/// The given block number is in the past.
+		PastBlockNumber,
+		/// Failed to send an XCM message to Asset Hub.
+		XcmError,
+		/// Failed to withdraw the balance of an account.
+		FailedToWithdrawAccount,
+		/// Failed to restore the balance of an account that Asset Hub failed to receive.
+		FailedToRestoreAccount,
+		/// All accounts are already classified.
+		ClassificationDone,
+		/// No items are waiting for the acknowledgement with the given query id.
+		UnknownQuery,
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A stage transition has occurred.
+		StageTransition {
+			/// The old stage before the transition.
+			old: MigrationStageOf<T>,
+			/// The new stage after the transition.
+			new: MigrationStageOf<T>,
+		},
+		/// The Asset Hub migration started and is active until `AssetHubMigrationFinished` is
+		/// emitted.
+		///
+		/// This event is equivalent to `StageTransition { new: Starting, .. }` but is easier to
+		/// understand. The activation is immediate and affects all events happening afterwards.
+		AssetHubMigrationStarted,
+		/// The Asset Hub migration finished.
+		///
+		/// This event is equivalent to `StageTransition { new: MigrationDone, .. }` but is easier
+		/// to understand. The finishing is immediate and affects all events happening afterwards.
+		AssetHubMigrationFinished,
+		/// The balance of an account could not be withdrawn and it stays on the Relay Chain.
+		AccountWithdrawFailed { who: T::AccountId },
+		/// The balance of an account that Asset Hub failed to receive could not be restored.
+		///
+		/// The withdrawn balance is burned and needs to be restored manually.
+		AccountRestoreFailed {
+			/// The account that was sent to Asset Hub.
+			who: T::AccountId,
+			/// The balance that was withdrawn from the account.
+			amount: u128,
+		},
+		/// A dry-run of the migration finished.
+		///
+		/// The outcome is stored in `LastDryRun` and the affected accounts in `DryRunMismatches`.
+		DryRunFinished {
+			/// The number of accounts that would not be migrated correctly.
+			mismatches: u32,
+		},
+		/// All accounts that must be kept partially on the Relay Chain are classified in
+		/// `RcAccounts`.
+		RcAccountsClassified,
+		/// Asset Hub acknowledged to have processed a number of migrated items.
+		ItemsAcknowledged {
+			/// The kind of the acknowledged items.
+			item: MigratedItem,
+			/// The number of acknowledged items.
+			count: u32,
+		},
+		/// Asset Hub failed to process a number of migrated items.
+		///
+		/// The rejected accounts are restored on the Relay Chain.
+		ItemsRejected {
+			/// The kind of the rejected items.
+			item: MigratedItem,
+			/// The number of rejected items.
+			count: u32,
+			/// The response of Asset Hub.
+			response: Response,
+		},
+	}
+
+	/// The Relay Chain migration stage.
+	#[pallet::storage]
+	pub type RcMigrationStage<T: Config> = StorageValue<_, MigrationStageOf<T>, ValueQuery>;
+
+	/// The number of items of each kind that were sent to and acknowledged by Asset Hub.
+	#[pallet::storage]
+	pub type MigrationItemCounters<T: Config> =
+		StorageMap<_, Twox64Concat, MigratedItem, ItemCounter, ValueQuery>;
+
+	/// The items that wait for Asset Hub to acknowledge their processing, by `pallet_xcm` query id.
+	#[pallet::storage]
+	pub type PendingAcknowledgements<T: Config> =
+		StorageMap<_, Twox64Concat, QueryId, (MigratedItem, u32), OptionQuery>;
+
+	/// The accounts that wait for Asset Hub to acknowledge their processing, by `pallet_xcm` query
+	/// id.
+	///
+	/// They are restored on the Relay Chain if Asset Hub fails to receive them.
+	#[pallet::storage]
+	pub type PendingAccounts<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		QueryId,
+		BoundedVec<RcAccountFor<T>, T::MaxAccountsPerMessage>,
+		OptionQuery,
+	>;
+
+	/// The outcome of the last dry-run.
+	#[pallet::storage]
+	pub type LastDryRun<T: Config> = StorageValue<_, DryRunReportOf<T>, OptionQuery>;
+
+	/// The accounts that would not be migrated correctly according to the last dry-run.
+	#[pallet::storage]
+	pub type DryRunMismatches<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, DryRunMismatch<u128>, OptionQuery>;
 
 	/// Helper storage item to obtain and store the known accounts that should be kept partially or
 	/// fully on Relay Chain.
@@ -115,6 +418,11 @@ pub mod pallet {
 	pub type RcAccounts<T: Config> =
 		CountedStorageMap<_, Twox64Concat, T::AccountId, AccountState<u128>, OptionQuery>;
 
+	/// The progress of the automatic classification of `RcAccounts`.
+	#[pallet::storage]
+	pub type RcAccountsClassification<T: Config> =
+		StorageValue<_, ClassificationCursorOf<T>, ValueQuery>;
+
 	/// The block number when the migration started.
 	#[pallet::storage]
 	pub type MigrationStartBlock<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
@@ -125,17 +433,349 @@ pub mod pallet {
 
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the migration stage.
+		///
+		/// This call is intended for emergency use only and is guarded by the `ManagerOrigin`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::force_set_stage())]
+		pub fn force_set_stage(
+			origin: OriginFor<T>,
+			stage: Box<MigrationStageOf<T>>,
+		) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+			Self::transition(*stage);
+			Ok(())
+		}
+
+		/// Schedule the migration to start at the given moment.
+		///
+		/// Can only be called by the `ManagerOrigin` while the migration is `Pending` or already
+		/// `Scheduled`, in which case it re-schedules the start.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::schedule_migration())]
+		pub fn schedule_migration(
+			origin: OriginFor<T>,
+			start: DispatchTime<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				matches!(
+					RcMigrationStage::<T>::get(),
+					MigrationStage::Pending | MigrationStage::Scheduled { .. }
+				),
+				Error::<T>::WrongStage
+			);
+			let now = frame_system::Pallet::<T>::block_number();
+			let start = start.evaluate(now);
+			ensure!(start > now, Error::<T>::PastBlockNumber);
+
+			Self::transition(MigrationStage::Scheduled { start });
+			Ok(())
+		}
+
+		/// Receive the report of Asset Hub on processing a message with migrated items.
+		///
+		/// Dispatched by `pallet_xcm` with the `Transact` status that Asset Hub reports for the
+		/// `query_id` of the message. The items are acknowledged if the `Transact` succeeded. The
+		/// number of acknowledged items never exceeds the number of sent items.
+		///
+		/// If the `Transact` failed, nothing was received on Asset Hub and the accounts of the
+		/// message are restored on the Relay Chain.
+		#[pallet::call_index(2)]
+		#[pallet::weight(Pallet::<T>::receive_query_response_weight())]
+		pub fn receive_query_response(
+			origin: OriginFor<T>,
+			query_id: QueryId,
+			response: Response,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_asset_hub_response(origin)?;
+			let (item, count) =
+				PendingAcknowledgements::<T>::take(query_id).ok_or(Error::<T>::UnknownQuery)?;
+			let accounts = PendingAccounts::<T>::take(query_id).unwrap_or_default();
+
+			match response {
+				Response::DispatchResult(MaybeErrorCode::Success) => {
+					MigrationItemCounters::<T>::mutate(item, |counter| {
+						counter.acknowledged =
+							counter.acknowledged.saturating_add(count).min(counter.sent);
+					});
+					Self::deposit_event(Event::ItemsAcknowledged { item, count });
+					Ok(Some(<T as Config>::WeightInfo::receive_query_response()).into())
+				},
+				response => {
+					log::error!(
+						target: LOG_TARGET,
+						"Asset Hub failed to process {} items of {:?}: {:?}",
+						count,
+						item,
+						response
+					);
+					let restored = accounts.len() as u32;
+					for account in accounts {
+						AccountsMigrator::<T>::restore_account(account);
+					}
+					Self::deposit_event(Event::ItemsRejected { item, count, response });
+					Ok(Some(
+						<T as Config>::WeightInfo::receive_query_response().saturating_add(
+							<T as Config>::WeightInfo::withdraw_account()
+								.saturating_mul(restored.into()),
+						),
+					)
+					.into())
+				},
+			}
+		}
+
+		/// Start a dry-run of the migration.
+		///
+		/// The dry-run checks every account as it would be migrated and rolls back all changes.
+		/// The outcome is reported in `LastDryRun` and `DryRunMismatches`, after which the stage
+		/// returns to `Pending`. Can only be called by the `ManagerOrigin` while the migration is
+		/// `Pending`.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::start_dry_run())]
+		pub fn start_dry_run(origin: OriginFor<T>) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+			ensure!(
+				matches!(RcMigrationStage::<T>::get(), MigrationStage::Pending),
+				Error::<T>::WrongStage
+			);
+
+			Self::transition(MigrationStage::DryRunInit);
+			Ok(())
+		}
+
+		/// Classify up to `limit` accounts that must be kept partially on the Relay Chain.
+		///
+		/// Scans the `paras_registrar`, `hrmp` and `session` pallets for deposits and consumer
+		/// references and records them in `RcAccounts`, continuing where the previous call
+		/// stopped. Can be called by any signed origin while the migration is `Pending` or
+		/// `Scheduled`, and is free of charge as long as it makes progress.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::classify_accounts(*limit))]
+		pub fn classify_accounts(origin: OriginFor<T>, limit: u32) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			ensure!(
+				matches!(
+					RcMigrationStage::<T>::get(),
+					MigrationStage::Pending | MigrationStage::Scheduled { .. }
+				),
+				Error::<T>::WrongStage
+			);
+			ensure!(
+				!matches!(RcAccountsClassification::<T>::get(), ClassificationCursor::Done),
+				Error::<T>::ClassificationDone
+			);
+
+			let processed = AccountsClassifier::<T>::classify(limit);
+			if matches!(RcAccountsClassification::<T>::get(), ClassificationCursor::Done) {
+				Self::deposit_event(Event::RcAccountsClassified);
+			}
+
+			Ok(PostDispatchInfo {
+				actual_weight: Some(<T as Config>::WeightInfo::classify_accounts(processed)),
+				pays_fee: if processed > 0 { Pays::No } else { Pays::Yes },
+			})
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let mut weight_counter = WeightMeter::with_limit(T::MaxRcWeight::get());
+			weight_counter.consume(T::DbWeight::get().reads(1));
+
+			match RcMigrationStage::<T>::get() {
+				MigrationStage::Pending | MigrationStage::MigrationDone => (),
+				MigrationStage::DryRunInit =>
+					if DryRunner::<T>::init(&mut weight_counter) {
+						Self::transition(MigrationStage::DryRunAccountsOngoing { last_key: None });
+					},
+				MigrationStage::DryRunAccountsOngoing { last_key } =>
+					match DryRunner::<T>::check_accounts(last_key, &mut weight_counter) {
+						None => Self::transition(MigrationStage::DryRunRcAccountsOngoing {
+							last_key: None,
+						}),
+						Some(last_key) => Self::transition(MigrationStage::DryRunAccountsOngoing {
+							last_key: Some(last_key),
+						}),
+					},
+				MigrationStage::DryRunRcAccountsOngoing { last_key } =>
+					match DryRunner::<T>::check_rc_accounts(last_key, &mut weight_counter) {
+						None => {
+							let mismatches =
+								LastDryRun::<T>::get().map(|r| r.mismatches).unwrap_or_default();
+							Self::transition(MigrationStage::Pending);
+							Self::deposit_event(Event::DryRunFinished { mismatches });
+						},
+						Some(last_key) =>
+							Self::transition(MigrationStage::DryRunRcAccountsOngoing {
+								last_key: Some(last_key),
+							}),
+					},
+				MigrationStage::Scheduled { start } =>
+					if now >= start {
+						Self::transition(MigrationStage::Starting);
+					},
+				MigrationStage::Starting => {
+					if let Err(e) = Self::send_ah_call(AhOpsCall::StartMigration, None) {
+						log::error!(target: LOG_TARGET, "Failed to start the migration: {:?}", e);
+						// Try again in the next block.
+						return weight_counter.consumed();
+					}
+					MigrationStartBlock::<T>::put(now);
+					Self::deposit_event(Event::AssetHubMigrationStarted);
+					Self::transition(MigrationStage::AccountsMigrationInit);
+				},
+				MigrationStage::AccountsMigrationInit => {
+					Self::transition(MigrationStage::AccountsMigrationOngoing { last_key: None });
+				},
+				MigrationStage::AccountsMigrationOngoing { last_key } => {
+					match AccountsMigrator::<T>::migrate_many(last_key, &mut weight_counter) {
+						Ok(None) => Self::transition(MigrationStage::AccountsMigrationDone),
+						Ok(Some(last_key)) =>
+							Self::transition(MigrationStage::AccountsMigrationOngoing {
+								last_key: Some(last_key),
+							}),
+						Err(e) => {
+							// The withdrawals of the batch were rolled back and the stage is not
+							// updated, so the batch is retried in the next block.
+							log::error!(target: LOG_TARGET, "Error while migrating accounts: {:?}", e);
+						},
+					}
+				},
+				MigrationStage::AccountsMigrationDone => {
+					if let Err(e) = Self::send_ah_call(AhOpsCall::FinishMigration, None) {
+						log::error!(target: LOG_TARGET, "Failed to finish the migration: {:?}", e);
+						return weight_counter.consumed();
+					}
+					MigrationEndBlock::<T>::put(now);
+					Self::transition(MigrationStage::MigrationDone);
+					Self::deposit_event(Event::AssetHubMigrationFinished);
+				},
+			}
+
+			weight_counter.consumed()
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Execute a stage transition and log it.
+		fn transition(new: MigrationStageOf<T>) {
+			let old = RcMigrationStage::<T>::get();
+			RcMigrationStage::<T>::put(&new);
+			log::info!(target: LOG_TARGET, "[Block {:?}] Stage transition: {:?} -> {:?}",
+				frame_system::Pallet::<T>::block_number(), &old, &new);
+			Self::deposit_event(Event::StageTransition { old, new });
+		}
+
+		/// Whether the migration is ongoing.
+		pub fn is_ongoing() -> bool {
+			RcMigrationStage::<T>::get().is_ongoing()
+		}
+
+		/// Ensure that the `origin` is a query response from Asset Hub.
+		fn ensure_asset_hub_response(origin: OriginFor<T>) -> DispatchResult {
+			let origin: <T as Config>::RuntimeOrigin = origin.into();
+			match origin.into() {
+				Ok(pallet_xcm::Origin::Response(location))
+					if location == T::AssetHubLocation::get() =>
+					Ok(()),
+				_ => Err(DispatchError::BadOrigin),
+			}
+		}
+
+		/// The maximal weight of `receive_query_response`.
+		///
+		/// Restoring an account writes the same storage as withdrawing it, so the restoration of
+		/// a rejected message is accounted with the `withdraw_account` weight per account.
+		pub(crate) fn receive_query_response_weight() -> Weight {
+			<T as Config>::WeightInfo::receive_query_response().saturating_add(
+				<T as Config>::WeightInfo::withdraw_account()
+					.saturating_mul(T::MaxAccountsPerMessage::get().into()),
+			)
+		}
+
+		/// Send a batch of accounts to Asset Hub.
+		///
+		/// The accounts are kept until Asset Hub reports whether it received them.
+		pub(crate) fn send_accounts(accounts: Vec<RcAccountFor<T>>) -> Result<(), Error<T>> {
+			log::debug!(target: LOG_TARGET, "Sending {} accounts to Asset Hub", accounts.len());
+			let count = accounts.len() as u32;
+			let pending = BoundedVec::<_, T::MaxAccountsPerMessage>::try_from(accounts.clone())
+				.map_err(|_| {
+					defensive!("Batches never exceed `MaxAccountsPerMessage`");
+					Error::<T>::XcmError
+				})?;
+			let query_id = Self::expect_acknowledgement(MigratedItem::Accounts, count);
+			Self::send_ah_call(AhOpsCall::ReceiveAccounts { accounts }, Some(query_id))?;
+			PendingAccounts::<T>::insert(query_id, pending);
+			Self::note_sent(MigratedItem::Accounts, count);
+			Ok(())
+		}
+
+		/// Send a call to the `pallet_ah_ops` on Asset Hub.
+		///
+		/// If a `query_id` is given, Asset Hub reports the status of the call back to it. The
+		/// report is only sent if the call was executed, a message that fails before the call was
+		/// executed is not reported.
+		pub(crate) fn send_ah_call(
+			call: AhOpsCall<T>,
+			query_id: Option<QueryId>,
+		) -> Result<(), Error<T>> {
+			let call = AssetHubPalletConfig::<T>::AhOps(call);
+			let mut message = Xcm(vec![
+				Instruction::UnpaidExecution {
+					weight_limit: WeightLimit::Unlimited,
+					check_origin: None,
+				},
+				Instruction::Transact {
+					origin_kind: OriginKind::Superuser,
+					fallback_max_weight: None,
+					call: call.encode().into(),
+				},
+			]);
+			if let Some(query_id) = query_id {
+				message.0.push(Instruction::ReportTransactStatus(QueryResponseInfo {
+					destination: Location::parent(),
+					query_id,
+					max_weight: Self::receive_query_response_weight(),
+				}));
+			}
+
+			send_xcm::<T::SendXcm>(T::AssetHubLocation::get(), message).map_err(|e| {
+				log::error!(target: LOG_TARGET, "Failed to send XCM to Asset Hub: {:?}", e);
+				Error::<T>::XcmError
+			})?;
+
+			Ok(())
+		}
+	}
 }
 
 pub mod runtime_api {
+	use crate::progress::VersionedMigrationProgress;
+
 	sp_api::decl_runtime_apis! {
 		/// API to query information about the Asset Hub migration process.
+		#[api_version(2)]
 		pub trait AssetHubMigrationApi<BlockNumber> where BlockNumber: sp_runtime::traits::BlockNumber {
 			/// Returns the block number when the migration started.
 			fn migration_start_block() -> BlockNumber;
 
 			/// Returns the block number when the migration ended.
 			fn migration_end_block() -> BlockNumber;
+
+			/// Returns the current stage, the status of every kind of item and an estimate of the
+			/// remaining blocks of the migration.
+			///
+			/// Only implemented by the Relay Chain.
+			#[api_version(2)]
+			fn migration_progress() -> VersionedMigrationProgress<BlockNumber>;
 		}
 	}
 }
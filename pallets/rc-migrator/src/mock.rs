@@ -0,0 +1,435 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Mock runtime for the Relay Chain migrator pallet.
+
+use crate as pallet_rc_migrator;
+use crate::*;
+use frame_support::{
+	construct_runtime, derive_impl, parameter_types,
+	traits::{ConstU128, ConstU32, ConstUint, Disabled, Everything, Nothing, VariantCount},
+};
+use frame_system::EnsureRoot;
+use polkadot_runtime_common::paras_registrar;
+use runtime_parachains::{configuration, dmp, hrmp, origin, paras, shared};
+use sp_core::crypto::KeyTypeId;
+use sp_runtime::{
+	testing::UintAuthorityId,
+	traits::{ConvertInto, IdentityLookup, OpaqueKeys},
+	BuildStorage, RuntimeAppPublic,
+};
+use xcm::prelude::*;
+use xcm_builder::{EnsureXcmOrigin, FixedWeightBounds, SignedToAccountId32};
+use xcm_executor::XcmExecutor;
+
+pub type AccountId = AccountId32;
+pub type Balance = u128;
+type Block = frame_system::mocking::MockBlockU32<Test>;
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+
+construct_runtime!(
+	pub enum Test {
+		System: frame_system,
+		Balances: pallet_balances,
+		Session: pallet_session,
+		Configuration: configuration,
+		ParasShared: shared,
+		Paras: paras,
+		ParachainsOrigin: origin,
+		Registrar: paras_registrar,
+		Dmp: dmp,
+		Hrmp: hrmp,
+		XcmPallet: pallet_xcm,
+		RcMigrator: pallet_rc_migrator,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+	type Nonce = u32;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<AccountId>;
+	type AccountData = pallet_balances::AccountData<Balance>;
+}
+
+impl<C> frame_system::offchain::CreateTransactionBase<C> for Test
+where
+	RuntimeCall: From<C>,
+{
+	type Extrinsic = UncheckedExtrinsic;
+	type RuntimeCall = RuntimeCall;
+}
+
+impl<C> frame_system::offchain::CreateBare<C> for Test
+where
+	RuntimeCall: From<C>,
+{
+	fn create_bare(call: RuntimeCall) -> UncheckedExtrinsic {
+		UncheckedExtrinsic::new_bare(call)
+	}
+}
+
+/// The existential deposit of the mock.
+pub const ED: Balance = 10;
+
+/// The freeze reasons of the mock.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	Debug,
+)]
+pub enum TestFreezeReason {
+	PoolMinBalance,
+}
+
+impl VariantCount for TestFreezeReason {
+	const VARIANT_COUNT: u32 = 1;
+}
+
+impl pallet_balances::Config for Test {
+	type MaxLocks = ConstU32<16>;
+	type Balance = Balance;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU128<ED>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxReserves = ConstU32<4>;
+	type ReserveIdentifier = [u8; 8];
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type FreezeIdentifier = TestFreezeReason;
+	type MaxFreezes = ConstU32<1>;
+	type DoneSlashHandler = ();
+}
+
+pub struct TestSessionHandler;
+impl pallet_session::SessionHandler<AccountId> for TestSessionHandler {
+	const KEY_TYPE_IDS: &'static [KeyTypeId] = &[UintAuthorityId::ID];
+
+	fn on_genesis_session<Ks: OpaqueKeys>(_: &[(AccountId, Ks)]) {}
+
+	fn on_new_session<Ks: OpaqueKeys>(_: bool, _: &[(AccountId, Ks)], _: &[(AccountId, Ks)]) {}
+
+	fn on_disabled(_: u32) {}
+}
+
+type Sessions = pallet_session::PeriodicSessions<ConstU32<10>, ConstU32<0>>;
+
+impl pallet_session::Config for Test {
+	type Currency = Balances;
+	type RuntimeEvent = RuntimeEvent;
+	type ValidatorId = AccountId;
+	type ValidatorIdOf = ConvertInto;
+	type ShouldEndSession = Sessions;
+	type NextSessionRotation = Sessions;
+	type SessionManager = ();
+	type SessionHandler = TestSessionHandler;
+	type Keys = UintAuthorityId;
+	type WeightInfo = ();
+	type DisablingStrategy = ();
+	type KeyDeposit = ();
+}
+
+impl configuration::Config for Test {
+	type WeightInfo = configuration::TestWeightInfo;
+}
+
+impl shared::Config for Test {
+	type DisabledValidators = ();
+}
+
+parameter_types! {
+	pub const ParasUnsignedPriority: u64 = u64::MAX;
+}
+
+impl paras::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = paras::TestWeightInfo;
+	type UnsignedPriority = ParasUnsignedPriority;
+	type QueueFootprinter = ();
+	type NextSessionRotation = Sessions;
+	type OnNewHead = ();
+	type AssignCoretime = ();
+	type Fungible = Balances;
+	type CooldownRemovalMultiplier = ConstUint<1>;
+	type AuthorizeCurrentCodeOrigin = EnsureRoot<AccountId>;
+}
+
+impl origin::Config for Test {}
+
+impl paras_registrar::Config for Test {
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type OnSwap = ();
+	type ParaDeposit = ConstU128<100>;
+	type DataDepositPerByte = ConstU128<1>;
+	type WeightInfo = paras_registrar::TestWeightInfo;
+}
+
+impl dmp::Config for Test {}
+
+parameter_types! {
+	pub const DefaultChannelSizeAndCapacityWithSystem: (u32, u32) = (51200, 500);
+}
+
+impl hrmp::Config for Test {
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeEvent = RuntimeEvent;
+	type ChannelManager = EnsureRoot<AccountId>;
+	type Currency = Balances;
+	type DefaultChannelSizeAndCapacityWithSystem = DefaultChannelSizeAndCapacityWithSystem;
+	type VersionWrapper = XcmPallet;
+	type WeightInfo = hrmp::TestWeightInfo;
+}
+
+parameter_types! {
+	pub const AnyNetwork: Option<NetworkId> = None;
+	pub UniversalLocation: InteriorLocation = Here;
+	pub const BaseXcmWeight: Weight = Weight::from_parts(1_000, 1_000);
+	pub const MaxInstructions: u32 = 100;
+	pub const MaxAssetsIntoHolding: u32 = 64;
+}
+
+pub type LocalOriginToLocation = SignedToAccountId32<RuntimeOrigin, AccountId, AnyNetwork>;
+
+pub struct XcmConfig;
+impl xcm_executor::Config for XcmConfig {
+	type RuntimeCall = RuntimeCall;
+	type XcmSender = TestSendXcm;
+	type AssetTransactor = ();
+	type OriginConverter = pallet_xcm::XcmPassthrough<RuntimeOrigin>;
+	type IsReserve = ();
+	type IsTeleporter = ();
+	type UniversalLocation = UniversalLocation;
+	type Barrier = ();
+	type Weigher = FixedWeightBounds<BaseXcmWeight, RuntimeCall, MaxInstructions>;
+	type Trader = ();
+	type ResponseHandler = XcmPallet;
+	type AssetTrap = XcmPallet;
+	type AssetLocker = ();
+	type AssetExchanger = ();
+	type SubscriptionService = XcmPallet;
+	type PalletInstancesInfo = ();
+	type MaxAssetsIntoHolding = MaxAssetsIntoHolding;
+	type FeeManager = ();
+	type MessageExporter = ();
+	type UniversalAliases = Nothing;
+	type CallDispatcher = RuntimeCall;
+	type SafeCallFilter = Everything;
+	type Aliasers = Nothing;
+	type TransactionalProcessor = ();
+	type HrmpNewChannelOpenRequestHandler = ();
+	type HrmpChannelAcceptedHandler = ();
+	type HrmpChannelClosingHandler = ();
+	type XcmRecorder = XcmPallet;
+	type XcmEventEmitter = XcmPallet;
+}
+
+impl pallet_xcm::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type SendXcmOrigin = EnsureXcmOrigin<RuntimeOrigin, LocalOriginToLocation>;
+	type XcmRouter = TestSendXcm;
+	type ExecuteXcmOrigin = EnsureXcmOrigin<RuntimeOrigin, LocalOriginToLocation>;
+	type XcmExecuteFilter = Everything;
+	type XcmExecutor = XcmExecutor<XcmConfig>;
+	type XcmTeleportFilter = Everything;
+	type XcmReserveTransferFilter = Everything;
+	type Weigher = FixedWeightBounds<BaseXcmWeight, RuntimeCall, MaxInstructions>;
+	type UniversalLocation = UniversalLocation;
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	const VERSION_DISCOVERY_QUEUE_SIZE: u32 = 100;
+	type AdvertisedXcmVersion = pallet_xcm::CurrentXcmVersion;
+	type TrustedLockers = ();
+	type SovereignAccountOf = ();
+	type Currency = Balances;
+	type CurrencyMatcher = ();
+	type MaxLockers = ConstU32<8>;
+	type MaxRemoteLockConsumers = ConstU32<0>;
+	type RemoteLockConsumerIdentifier = ();
+	type WeightInfo = pallet_xcm::TestWeightInfo;
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type AuthorizedAliasConsideration = Disabled;
+}
+
+parameter_types! {
+	/// The messages that were sent by [`TestSendXcm`].
+	pub static SentXcm: Vec<(Location, Xcm<()>)> = vec![];
+	/// Whether [`TestSendXcm`] fails to send messages.
+	pub static SendFails: bool = false;
+}
+
+/// Records the sent messages in [`SentXcm`], or fails if [`SendFails`] is set.
+pub struct TestSendXcm;
+impl SendXcm for TestSendXcm {
+	type Ticket = (Location, Xcm<()>);
+
+	fn validate(
+		dest: &mut Option<Location>,
+		msg: &mut Option<Xcm<()>>,
+	) -> SendResult<Self::Ticket> {
+		if SendFails::get() {
+			return Err(SendError::Transport("mock failure"));
+		}
+		let ticket = (dest.take().ok_or(SendError::MissingArgument)?, msg.take().unwrap());
+		Ok((ticket, Assets::new()))
+	}
+
+	fn deliver(ticket: Self::Ticket) -> Result<XcmHash, SendError> {
+		SentXcm::mutate(|sent| sent.push(ticket));
+		Ok([0; 32])
+	}
+}
+
+/// Converts the hold reasons of the mock into their portable representation.
+pub struct ToPortableHoldReason;
+impl Convert<RuntimeHoldReason, PortableHoldReason> for ToPortableHoldReason {
+	fn convert(reason: RuntimeHoldReason) -> PortableHoldReason {
+		match reason {
+			RuntimeHoldReason::Session(r) => PortableHoldReason::Session(r),
+			RuntimeHoldReason::XcmPallet(r) => PortableHoldReason::XcmPallet(r),
+			#[allow(unreachable_patterns)]
+			_ => unreachable!("no other holds are placed in the mock"),
+		}
+	}
+}
+
+/// Converts the freeze reasons of the mock into their portable representation.
+pub struct ToPortableFreezeReason;
+impl Convert<TestFreezeReason, PortableFreezeReason> for ToPortableFreezeReason {
+	fn convert(reason: TestFreezeReason) -> PortableFreezeReason {
+		match reason {
+			TestFreezeReason::PoolMinBalance => PortableFreezeReason::NominationPools(
+				pallet_nomination_pools::FreezeReason::PoolMinBalance,
+			),
+		}
+	}
+}
+
+/// Converts the portable hold reasons back into the hold reasons of the mock.
+pub struct FromPortableHoldReason;
+impl Convert<PortableHoldReason, Option<RuntimeHoldReason>> for FromPortableHoldReason {
+	fn convert(reason: PortableHoldReason) -> Option<RuntimeHoldReason> {
+		match reason {
+			PortableHoldReason::Session(r) => Some(RuntimeHoldReason::Session(r)),
+			PortableHoldReason::XcmPallet(r) => Some(RuntimeHoldReason::XcmPallet(r)),
+			_ => None,
+		}
+	}
+}
+
+/// Converts the portable freeze reasons back into the freeze reasons of the mock.
+pub struct FromPortableFreezeReason;
+impl Convert<PortableFreezeReason, Option<TestFreezeReason>> for FromPortableFreezeReason {
+	fn convert(reason: PortableFreezeReason) -> Option<TestFreezeReason> {
+		match reason {
+			PortableFreezeReason::NominationPools(
+				pallet_nomination_pools::FreezeReason::PoolMinBalance,
+			) => Some(TestFreezeReason::PoolMinBalance),
+		}
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl BenchmarkHelper<RuntimeHoldReason, TestFreezeReason> for Test {
+	fn hold_reasons() -> Vec<RuntimeHoldReason> {
+		vec![
+			RuntimeHoldReason::Session(pallet_session::HoldReason::Keys),
+			RuntimeHoldReason::XcmPallet(pallet_xcm::HoldReason::AuthorizeAlias),
+		]
+	}
+
+	fn freeze_reasons() -> Vec<TestFreezeReason> {
+		vec![TestFreezeReason::PoolMinBalance]
+	}
+}
+
+parameter_types! {
+	pub AssetHubLocation: Location = Location::new(0, Parachain(1000));
+	/// The weight that the migration may consume per block.
+	pub static MaxRcWeight: Weight = Weight::MAX;
+}
+
+impl pallet_rc_migrator::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type ManagerOrigin = EnsureRoot<AccountId>;
+	type Currency = Balances;
+	type RcToPortableHoldReason = ToPortableHoldReason;
+	type RcToPortableFreezeReason = ToPortableFreezeReason;
+	type PortableToRcHoldReason = FromPortableHoldReason;
+	type PortableToRcFreezeReason = FromPortableFreezeReason;
+	type SendXcm = TestSendXcm;
+	type AssetHubLocation = AssetHubLocation;
+	type MaxRcWeight = MaxRcWeight;
+	type MaxAccountsPerMessage = ConstU32<2>;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = Test;
+}
+
+/// Account with the given `id`.
+pub fn account(id: u8) -> AccountId {
+	AccountId::new([id; 32])
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+/// Run the `on_initialize` of the migrator in the next block.
+pub fn next_block() {
+	System::set_block_number(System::block_number() + 1);
+	RcMigrator::on_initialize(System::block_number());
+}
+
+/// The accounts that were sent to Asset Hub, in order.
+pub fn sent_accounts() -> Vec<RcAccountFor<Test>> {
+	SentXcm::get()
+		.into_iter()
+		.flat_map(|(dest, message)| {
+			assert_eq!(dest, AssetHubLocation::get());
+			message.0.into_iter().filter_map(|instruction| match instruction {
+				Transact { call, .. } => {
+					let call = AssetHubPalletConfig::<Test>::decode(&mut &call.into_encoded()[..])
+						.expect("valid call");
+					match call {
+						AssetHubPalletConfig::AhOps(AhOpsCall::ReceiveAccounts { accounts }) =>
+							Some(accounts),
+						_ => None,
+					}
+				},
+				_ => None,
+			})
+		})
+		.flatten()
+		.collect()
+}
@@ -0,0 +1,238 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Progress reporting of the migration for off-chain tooling.
+//!
+//! The Relay Chain counts how many items of each kind were sent to Asset Hub and how many of them
+//! Asset Hub acknowledged to have processed. Every message with migrated items asks Asset Hub to
+//! report the status of its `Transact` back, which `pallet_xcm` forwards to
+//! [`Pallet::receive_query_response`]. Together with the current [`MigrationStage`] this is exposed
+//! through the [`crate::runtime_api::AssetHubMigrationApi`].
+//!
+//! Kinds of items that stay on the Relay Chain are reported as [`ItemStatus::NotMigrated`], so
+//! that tooling does not mistake them for items that were not sent yet.
+
+use crate::*;
+use sp_runtime::{
+	traits::{Bounded, Saturating, UniqueSaturatedInto},
+	SaturatedConversion,
+};
+use xcm::prelude::{QueryId, Response};
+
+/// The kind of items that are migrated to Asset Hub.
+#[derive(
+	Encode,
+	DecodeWithMemTracking,
+	Decode,
+	Copy,
+	Clone,
+	PartialEq,
+	Eq,
+	Debug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub enum MigratedItem {
+	/// Account balances together with their holds, freezes and locks.
+	Accounts,
+	/// Proxy definitions and announcements of `pallet_proxy`.
+	Proxies,
+	/// Pending multisig operations of `pallet_multisig`.
+	Multisigs,
+	/// Referenda of `pallet_referenda`.
+	Referenda,
+	/// Staking ledgers of `pallet_staking`.
+	StakingLedgers,
+}
+
+impl MigratedItem {
+	/// All item kinds in the order in which they are reported.
+	pub const ALL: [MigratedItem; 5] = [
+		MigratedItem::Accounts,
+		MigratedItem::Proxies,
+		MigratedItem::Multisigs,
+		MigratedItem::Referenda,
+		MigratedItem::StakingLedgers,
+	];
+
+	/// Whether items of this kind are migrated to Asset Hub.
+	///
+	/// Items of the other kinds stay on the Relay Chain.
+	pub fn is_migrated(&self) -> bool {
+		matches!(self, MigratedItem::Accounts)
+	}
+}
+
+/// The number of items of one kind that were sent to and acknowledged by Asset Hub.
+#[derive(
+	Encode,
+	DecodeWithMemTracking,
+	Decode,
+	Copy,
+	Clone,
+	Default,
+	PartialEq,
+	Eq,
+	Debug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub struct ItemCounter {
+	/// The number of items that were sent to Asset Hub.
+	pub sent: u32,
+	/// The number of items that Asset Hub acknowledged to have processed.
+	pub acknowledged: u32,
+}
+
+/// The progress of one kind of item.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub enum ItemStatus {
+	/// The items are migrated, with the number of sent and acknowledged items.
+	Migrated(ItemCounter),
+	/// The items are not migrated and stay on the Relay Chain.
+	NotMigrated,
+}
+
+/// Version 1 of the migration progress.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct MigrationProgressV1<BlockNumber> {
+	/// The current stage of the migration.
+	pub stage: MigrationStage<AccountId32, BlockNumber>,
+	/// The block number when the migration started, if it did.
+	pub start_block: Option<BlockNumber>,
+	/// The block number when the migration ended, if it did.
+	pub end_block: Option<BlockNumber>,
+	/// The counters for every kind of migrated item.
+	pub items: Vec<(MigratedItem, ItemCounter)>,
+	/// The estimated number of blocks until the migration finishes.
+	///
+	/// `None` if no estimate can be made, e.g. because the migration did not start yet.
+	pub eta: Option<BlockNumber>,
+}
+
+/// Version 2 of the migration progress.
+///
+/// Reports every [`MigratedItem`] kind with its [`ItemStatus`].
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct MigrationProgressV2<BlockNumber> {
+	/// The current stage of the migration.
+	pub stage: MigrationStage<AccountId32, BlockNumber>,
+	/// The block number when the migration started, if it did.
+	pub start_block: Option<BlockNumber>,
+	/// The block number when the migration ended, if it did.
+	pub end_block: Option<BlockNumber>,
+	/// The status of every kind of item.
+	pub items: Vec<(MigratedItem, ItemStatus)>,
+	/// The estimated number of blocks until the migration finishes.
+	///
+	/// `None` if no estimate can be made, e.g. because the migration did not start yet.
+	pub eta: Option<BlockNumber>,
+}
+
+/// The migration progress as returned by the runtime API.
+///
+/// New versions are added as new variants so that clients can keep decoding old responses.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub enum VersionedMigrationProgress<BlockNumber> {
+	#[codec(index = 1)]
+	V1(MigrationProgressV1<BlockNumber>),
+	#[codec(index = 2)]
+	V2(MigrationProgressV2<BlockNumber>),
+}
+
+impl<T: Config> Pallet<T> {
+	/// The current progress of the migration.
+	pub fn migration_progress() -> VersionedMigrationProgress<BlockNumberFor<T>> {
+		let stage = RcMigrationStage::<T>::get();
+		let items = MigratedItem::ALL
+			.into_iter()
+			.map(|item| {
+				let status = if item.is_migrated() {
+					ItemStatus::Migrated(MigrationItemCounters::<T>::get(item))
+				} else {
+					ItemStatus::NotMigrated
+				};
+				(item, status)
+			})
+			.collect();
+
+		VersionedMigrationProgress::V2(MigrationProgressV2 {
+			eta: Self::estimate_remaining_blocks(&stage),
+			stage,
+			start_block: MigrationStartBlock::<T>::get(),
+			end_block: MigrationEndBlock::<T>::get(),
+			items,
+		})
+	}
+
+	/// Increase the number of items of `item` kind that were sent to Asset Hub.
+	pub(crate) fn note_sent(item: MigratedItem, count: u32) {
+		MigrationItemCounters::<T>::mutate(item, |counter| {
+			counter.sent.saturating_accrue(count);
+		});
+	}
+
+	/// Register a `pallet_xcm` query for the report of Asset Hub on processing `count` items of the
+	/// `item` kind.
+	///
+	/// The returned query id must be reported back with `ReportTransactStatus`. The query has no
+	/// timeout, items whose message is never executed on Asset Hub stay unacknowledged.
+	pub(crate) fn expect_acknowledgement(item: MigratedItem, count: u32) -> QueryId {
+		let notify = <T as Config>::RuntimeCall::from(Call::<T>::receive_query_response {
+			query_id: 0,
+			response: Response::Null,
+		});
+		let query_id = pallet_xcm::Pallet::<T>::new_notify_query(
+			T::AssetHubLocation::get(),
+			notify.into(),
+			BlockNumberFor::<T>::max_value(),
+			Location::here(),
+		);
+		PendingAcknowledgements::<T>::insert(query_id, (item, count));
+		query_id
+	}
+
+	/// Estimate the number of blocks until the migration finishes.
+	///
+	/// Accounts are migrated in the order of their hashed storage key, which is uniformly
+	/// distributed. The leading bytes of the hash of the last migrated account therefore tell which
+	/// fraction of the accounts is done, and the remaining time is extrapolated from the time spent
+	/// so far.
+	fn estimate_remaining_blocks(stage: &MigrationStageOf<T>) -> Option<BlockNumberFor<T>> {
+		let last_key = match stage {
+			MigrationStage::AccountsMigrationOngoing { last_key: Some(last_key) } => last_key,
+			MigrationStage::AccountsMigrationDone => return Some(1u32.into()),
+			MigrationStage::MigrationDone => return Some(0u32.into()),
+			_ => return None,
+		};
+
+		let now = frame_system::Pallet::<T>::block_number();
+		let elapsed: u128 =
+			now.saturating_sub(MigrationStartBlock::<T>::get()?).unique_saturated_into();
+
+		// Skip the pallet and storage prefix, the `Blake2_128Concat` hash follows.
+		let key = frame_system::Account::<T>::hashed_key_for(last_key);
+		let position = u64::from_be_bytes(key.get(32..40)?.try_into().ok()?);
+		if position == 0 {
+			return None;
+		}
+
+		let remaining = elapsed.saturating_mul((u64::MAX - position) as u128) / position as u128;
+		// One more block for notifying Asset Hub about the end of the migration.
+		Some(remaining.saturating_add(1).saturated_into())
+	}
+}
@@ -0,0 +1,507 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the Relay Chain migrator pallet.
+
+use crate::{
+	accounts::AccountsMigrator,
+	mock::{account, *},
+	*,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{
+		fungible::{Inspect, InspectHold},
+		WithdrawReasons,
+	},
+};
+use pallet_balances::{BalanceLock, Reasons};
+use xcm::prelude::Parachain;
+
+fn migrated_stage(last_key: Option<AccountId>) -> MigrationStageOf<Test> {
+	MigrationStage::AccountsMigrationOngoing { last_key }
+}
+
+fn session_hold() -> RuntimeHoldReason {
+	RuntimeHoldReason::Session(pallet_session::HoldReason::Keys)
+}
+
+#[test]
+fn migration_passes_all_stages() {
+	new_test_ext().execute_with(|| {
+		Balances::set_balance(&account(1), 100);
+		assert_ok!(RcMigrator::schedule_migration(RuntimeOrigin::root(), DispatchTime::At(3)));
+		assert_eq!(RcMigrationStage::<Test>::get(), MigrationStage::Scheduled { start: 3 });
+
+		// Not started before the scheduled block.
+		next_block();
+		assert_eq!(RcMigrationStage::<Test>::get(), MigrationStage::Scheduled { start: 3 });
+
+		next_block();
+		assert_eq!(RcMigrationStage::<Test>::get(), MigrationStage::Starting);
+		assert!(RcMigrator::is_ongoing());
+
+		next_block();
+		System::assert_has_event(Event::<Test>::AssetHubMigrationStarted.into());
+		assert_eq!(RcMigrationStage::<Test>::get(), MigrationStage::AccountsMigrationInit);
+		assert_eq!(MigrationStartBlock::<Test>::get(), Some(4));
+		assert!(RcMigrator::is_ongoing());
+
+		next_block();
+		assert_eq!(RcMigrationStage::<Test>::get(), migrated_stage(None));
+
+		next_block();
+		assert_eq!(RcMigrationStage::<Test>::get(), MigrationStage::AccountsMigrationDone);
+		assert_eq!(sent_accounts().len(), 1);
+
+		next_block();
+		System::assert_has_event(
+			Event::<Test>::StageTransition {
+				old: MigrationStage::AccountsMigrationDone,
+				new: MigrationStage::MigrationDone,
+			}
+			.into(),
+		);
+		System::assert_last_event(Event::<Test>::AssetHubMigrationFinished.into());
+		assert_eq!(MigrationEndBlock::<Test>::get(), Some(7));
+		assert!(!RcMigrator::is_ongoing());
+
+		// Start, accounts and finish.
+		assert_eq!(SentXcm::get().len(), 3);
+	});
+}
+
+#[test]
+fn stage_transitions_emit_events() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(RcMigrator::schedule_migration(RuntimeOrigin::root(), DispatchTime::After(1)));
+		System::assert_last_event(
+			Event::<Test>::StageTransition {
+				old: MigrationStage::Pending,
+				new: MigrationStage::Scheduled { start: 2 },
+			}
+			.into(),
+		);
+
+		next_block();
+		System::assert_last_event(
+			Event::<Test>::StageTransition {
+				old: MigrationStage::Scheduled { start: 2 },
+				new: MigrationStage::Starting,
+			}
+			.into(),
+		);
+
+		next_block();
+		System::assert_last_event(
+			Event::<Test>::StageTransition {
+				old: MigrationStage::Starting,
+				new: MigrationStage::AccountsMigrationInit,
+			}
+			.into(),
+		);
+
+		// The migration can't be re-scheduled once started.
+		assert_noop!(
+			RcMigrator::schedule_migration(RuntimeOrigin::root(), DispatchTime::After(1)),
+			Error::<Test>::WrongStage
+		);
+		assert_noop!(
+			RcMigrator::schedule_migration(
+				RuntimeOrigin::signed(account(1)),
+				DispatchTime::After(1)
+			),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn starting_is_retried_if_asset_hub_is_not_reachable() {
+	new_test_ext().execute_with(|| {
+		RcMigrationStage::<Test>::put(MigrationStage::Starting);
+		SendFails::set(true);
+
+		next_block();
+		assert_eq!(RcMigrationStage::<Test>::get(), MigrationStage::Starting);
+		assert_eq!(MigrationStartBlock::<Test>::get(), None);
+
+		SendFails::set(false);
+		next_block();
+		assert_eq!(RcMigrationStage::<Test>::get(), MigrationStage::AccountsMigrationInit);
+	});
+}
+
+#[test]
+fn withdraw_account_migrates_holds_freezes_locks_and_reserves() {
+	new_test_ext().execute_with(|| {
+		let who = account(1);
+		Balances::set_balance(&who, 1000);
+		assert_ok!(<Balances as MutateHold<_>>::hold(&session_hold(), &who, 100));
+		assert_ok!(<Balances as ReservableCurrency<_>>::reserve(&who, 50));
+		assert_ok!(<Balances as MutateFreeze<_>>::set_freeze(
+			&TestFreezeReason::PoolMinBalance,
+			&who,
+			200
+		));
+		<Balances as LockableCurrency<_>>::set_lock(
+			*b"vesting ",
+			&who,
+			300,
+			WithdrawReasons::all(),
+		);
+		let info = System::account(&who);
+		let issuance = Balances::total_issuance();
+
+		let migrated = AccountsMigrator::<Test>::withdraw_account(who.clone()).unwrap().unwrap();
+
+		assert_eq!(migrated.who, who);
+		assert_eq!(migrated.free, 850);
+		assert_eq!(migrated.reserved, 150);
+		assert_eq!(migrated.frozen, 300);
+		assert_eq!(
+			migrated.holds.into_inner(),
+			vec![ReasonAmount {
+				reason: PortableHoldReason::Session(pallet_session::HoldReason::Keys),
+				amount: 100
+			}]
+		);
+		assert_eq!(
+			migrated.freezes.into_inner(),
+			vec![ReasonAmount {
+				reason: PortableFreezeReason::NominationPools(
+					pallet_nomination_pools::FreezeReason::PoolMinBalance
+				),
+				amount: 200
+			}]
+		);
+		assert_eq!(
+			migrated.locks.into_inner(),
+			vec![BalanceLock { id: *b"vesting ", amount: 300, reasons: Reasons::All }]
+		);
+		assert_eq!(migrated.unnamed_reserve, 50);
+		assert_eq!(migrated.consumers as u32, info.consumers);
+		assert_eq!(migrated.providers as u32, info.providers);
+
+		// Everything is burned on the Relay Chain.
+		assert!(!System::account_exists(&who));
+		assert_eq!(Balances::total_issuance(), issuance - 1000);
+	});
+}
+
+#[test]
+fn withdraw_account_keeps_preserved_accounts() {
+	new_test_ext().execute_with(|| {
+		let who = account(1);
+		Balances::set_balance(&who, 1000);
+		assert_ok!(<Balances as ReservableCurrency<_>>::reserve(&who, 100));
+		RcAccounts::<Test>::insert(&who, AccountState::Preserve);
+
+		assert_eq!(AccountsMigrator::<Test>::withdraw_account(who.clone()).unwrap(), None);
+
+		assert_eq!(Balances::free_balance(&who), 900);
+		assert_eq!(Balances::reserved_balance(&who), 100);
+	});
+}
+
+#[test]
+fn withdraw_account_keeps_part_of_the_account() {
+	new_test_ext().execute_with(|| {
+		let who = account(1);
+		Balances::set_balance(&who, 1000);
+		assert_ok!(<Balances as ReservableCurrency<_>>::reserve(&who, 100));
+		RcAccounts::<Test>::insert(
+			&who,
+			AccountState::Part { free: 200, reserved: 100, consumers: 1 },
+		);
+
+		let migrated = AccountsMigrator::<Test>::withdraw_account(who.clone()).unwrap().unwrap();
+
+		// The reserve and its consumer reference keep the account alive, so the ED stays as well.
+		assert_eq!(migrated.free, 900 - ED - 200);
+		assert_eq!(migrated.reserved, 0);
+		assert_eq!(migrated.unnamed_reserve, 0);
+		assert_eq!(migrated.consumers, 0);
+		assert_eq!(migrated.providers, 0);
+		assert_eq!(Balances::free_balance(&who), 200 + ED);
+		assert_eq!(Balances::reserved_balance(&who), 100);
+	});
+}
+
+#[test]
+fn accounts_migration_resumes_from_last_key() {
+	new_test_ext().execute_with(|| {
+		for id in 1..=5 {
+			Balances::set_balance(&account(id), 100);
+		}
+		let order: Vec<_> = frame_system::Account::<Test>::iter_keys().collect();
+		// Three accounts in two messages per block.
+		let account_weight = <Test as Config>::WeightInfo::withdraw_account();
+		let send_weight = <Test as Config>::WeightInfo::send_accounts();
+		MaxRcWeight::set(
+			account_weight.saturating_mul(3).saturating_add(send_weight.saturating_mul(2)),
+		);
+		RcMigrationStage::<Test>::put(migrated_stage(None));
+
+		next_block();
+		assert_eq!(RcMigrationStage::<Test>::get(), migrated_stage(Some(order[2].clone())));
+		assert_eq!(SentXcm::get().len(), 2);
+		let sent: Vec<_> = sent_accounts().into_iter().map(|a| a.who).collect();
+		assert_eq!(sent, order[..3].to_vec());
+		assert_eq!(MigrationItemCounters::<Test>::get(MigratedItem::Accounts).sent, 3);
+		assert!(order[3..].iter().all(|who| Balances::free_balance(who) == 100));
+
+		next_block();
+		assert_eq!(RcMigrationStage::<Test>::get(), MigrationStage::AccountsMigrationDone);
+		let sent: Vec<_> = sent_accounts().into_iter().map(|a| a.who).collect();
+		assert_eq!(sent, order);
+		assert_eq!(MigrationItemCounters::<Test>::get(MigratedItem::Accounts).sent, 5);
+		assert_eq!(Balances::total_issuance(), 0);
+	});
+}
+
+#[test]
+fn failed_send_rolls_back_withdrawals() {
+	new_test_ext().execute_with(|| {
+		let who = account(1);
+		Balances::set_balance(&who, 1000);
+		assert_ok!(<Balances as MutateHold<_>>::hold(&session_hold(), &who, 100));
+		<Balances as LockableCurrency<_>>::set_lock(
+			*b"vesting ",
+			&who,
+			300,
+			WithdrawReasons::all(),
+		);
+		Balances::set_balance(&account(2), 500);
+		let accounts: Vec<_> = frame_system::Account::<Test>::iter().collect();
+		let issuance = Balances::total_issuance();
+		RcMigrationStage::<Test>::put(migrated_stage(None));
+		SendFails::set(true);
+
+		next_block();
+
+		// Nothing was burned and the batch is retried.
+		assert_eq!(Balances::total_issuance(), issuance);
+		assert_eq!(frame_system::Account::<Test>::iter().collect::<Vec<_>>(), accounts);
+		assert_eq!(Balances::balance_on_hold(&session_hold(), &who), 100);
+		assert_eq!(pallet_balances::Locks::<Test>::get(&who).len(), 1);
+		assert_eq!(RcMigrationStage::<Test>::get(), migrated_stage(None));
+		assert_eq!(MigrationItemCounters::<Test>::get(MigratedItem::Accounts).sent, 0);
+		assert!(SentXcm::get().is_empty());
+
+		SendFails::set(false);
+		next_block();
+
+		assert_eq!(RcMigrationStage::<Test>::get(), MigrationStage::AccountsMigrationDone);
+		assert_eq!(Balances::total_issuance(), 0);
+		assert_eq!(sent_accounts().len(), 2);
+	});
+}
+
+#[test]
+fn asset_hub_acknowledges_sent_accounts() {
+	new_test_ext().execute_with(|| {
+		for id in 1..=3 {
+			Balances::set_balance(&account(id), 100);
+		}
+		RcMigrationStage::<Test>::put(migrated_stage(None));
+
+		next_block();
+
+		// Every message asks Asset Hub to report the status of the `Transact` back.
+		let query_ids: Vec<QueryId> = SentXcm::get()
+			.into_iter()
+			.map(|(_, message)| match message.0.last() {
+				Some(Instruction::ReportTransactStatus(info)) => {
+					assert_eq!(info.destination, Location::parent());
+					info.query_id
+				},
+				_ => panic!("message is not reported back: {:?}", message),
+			})
+			.collect();
+		assert_eq!(query_ids.len(), 2);
+		assert_eq!(
+			PendingAcknowledgements::<Test>::get(query_ids[0]),
+			Some((MigratedItem::Accounts, 2))
+		);
+		assert_eq!(
+			PendingAcknowledgements::<Test>::get(query_ids[1]),
+			Some((MigratedItem::Accounts, 1))
+		);
+
+		let asset_hub =
+			|| RuntimeOrigin::from(pallet_xcm::Origin::Response(AssetHubLocation::get()));
+		let success = Response::DispatchResult(MaybeErrorCode::Success);
+		let failure = Response::DispatchResult(MaybeErrorCode::Error(Default::default()));
+
+		assert_noop!(
+			RcMigrator::receive_query_response(
+				RuntimeOrigin::from(pallet_xcm::Origin::Response(Location::new(
+					0,
+					Parachain(2000)
+				))),
+				query_ids[0],
+				success.clone()
+			),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			RcMigrator::receive_query_response(
+				RuntimeOrigin::root(),
+				query_ids[0],
+				success.clone()
+			),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(RcMigrator::receive_query_response(asset_hub(), query_ids[0], success.clone()));
+		System::assert_last_event(
+			Event::<Test>::ItemsAcknowledged { item: MigratedItem::Accounts, count: 2 }.into(),
+		);
+		assert_ok!(RcMigrator::receive_query_response(asset_hub(), query_ids[1], failure.clone()));
+		System::assert_last_event(
+			Event::<Test>::ItemsRejected {
+				item: MigratedItem::Accounts,
+				count: 1,
+				response: failure,
+			}
+			.into(),
+		);
+		assert_eq!(
+			MigrationItemCounters::<Test>::get(MigratedItem::Accounts),
+			ItemCounter { sent: 3, acknowledged: 2 }
+		);
+
+		// Every report is only accepted once.
+		assert_noop!(
+			RcMigrator::receive_query_response(asset_hub(), query_ids[0], success),
+			Error::<Test>::UnknownQuery
+		);
+	});
+}
+
+#[test]
+fn rejected_accounts_are_restored() {
+	new_test_ext().execute_with(|| {
+		let who = account(1);
+		Balances::set_balance(&who, 1000);
+		assert_ok!(<Balances as MutateHold<_>>::hold(&session_hold(), &who, 100));
+		assert_ok!(<Balances as ReservableCurrency<_>>::reserve(&who, 50));
+		assert_ok!(<Balances as MutateFreeze<_>>::set_freeze(
+			&TestFreezeReason::PoolMinBalance,
+			&who,
+			200
+		));
+		<Balances as LockableCurrency<_>>::set_lock(
+			*b"vesting ",
+			&who,
+			300,
+			WithdrawReasons::all(),
+		);
+		let issuance = Balances::total_issuance();
+		RcMigrationStage::<Test>::put(migrated_stage(None));
+
+		next_block();
+
+		assert!(!System::account_exists(&who));
+		let query_id = match SentXcm::get()[0].1 .0.last() {
+			Some(Instruction::ReportTransactStatus(info)) => info.query_id,
+			_ => panic!("message is not reported back"),
+		};
+		assert_eq!(PendingAccounts::<Test>::get(query_id).unwrap().into_inner(), sent_accounts());
+
+		let failure = Response::DispatchResult(MaybeErrorCode::Error(Default::default()));
+		assert_ok!(RcMigrator::receive_query_response(
+			RuntimeOrigin::from(pallet_xcm::Origin::Response(AssetHubLocation::get())),
+			query_id,
+			failure
+		));
+
+		// Everything is back on the Relay Chain.
+		assert_eq!(PendingAccounts::<Test>::get(query_id), None);
+		assert_eq!(Balances::total_issuance(), issuance);
+		assert_eq!(<Balances as Inspect<_>>::total_balance(&who), 1000);
+		assert_eq!(Balances::balance_on_hold(&session_hold(), &who), 100);
+		assert_eq!(Balances::reserved_balance(&who), 150);
+		assert_eq!(
+			<Balances as InspectFreeze<_>>::balance_frozen(&TestFreezeReason::PoolMinBalance, &who),
+			200
+		);
+		assert_eq!(
+			pallet_balances::Locks::<Test>::get(&who).into_inner(),
+			vec![BalanceLock { id: *b"vesting ", amount: 300, reasons: Reasons::All }]
+		);
+		assert!(!System::events().iter().any(|record| matches!(
+			record.event,
+			RuntimeEvent::RcMigrator(Event::AccountRestoreFailed { .. })
+		)));
+	});
+}
+
+#[test]
+fn acknowledged_accounts_are_not_restored() {
+	new_test_ext().execute_with(|| {
+		Balances::set_balance(&account(1), 1000);
+		RcMigrationStage::<Test>::put(migrated_stage(None));
+
+		next_block();
+
+		let query_id = match SentXcm::get()[0].1 .0.last() {
+			Some(Instruction::ReportTransactStatus(info)) => info.query_id,
+			_ => panic!("message is not reported back"),
+		};
+		assert_ok!(RcMigrator::receive_query_response(
+			RuntimeOrigin::from(pallet_xcm::Origin::Response(AssetHubLocation::get())),
+			query_id,
+			Response::DispatchResult(MaybeErrorCode::Success)
+		));
+
+		assert_eq!(PendingAccounts::<Test>::get(query_id), None);
+		assert!(!System::account_exists(&account(1)));
+		assert_eq!(Balances::total_issuance(), 0);
+	});
+}
+
+#[test]
+fn migration_progress_reports_items_that_are_not_migrated() {
+	new_test_ext().execute_with(|| {
+		for id in 1..=3 {
+			Balances::set_balance(&account(id), 100);
+		}
+		RcMigrationStage::<Test>::put(migrated_stage(None));
+
+		next_block();
+
+		let VersionedMigrationProgress::V2(progress) = RcMigrator::migration_progress() else {
+			panic!("the latest version is returned");
+		};
+		assert_eq!(progress.stage, MigrationStage::AccountsMigrationDone);
+		assert_eq!(
+			progress.items,
+			vec![
+				(
+					MigratedItem::Accounts,
+					ItemStatus::Migrated(ItemCounter { sent: 3, acknowledged: 0 })
+				),
+				(MigratedItem::Proxies, ItemStatus::NotMigrated),
+				(MigratedItem::Multisigs, ItemStatus::NotMigrated),
+				(MigratedItem::Referenda, ItemStatus::NotMigrated),
+				(MigratedItem::StakingLedgers, ItemStatus::NotMigrated),
+			]
+		);
+	});
+}
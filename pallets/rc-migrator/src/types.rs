@@ -0,0 +1,123 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Types that are shared between the Relay Chain and the Asset Hub side of the migration.
+//!
+//! Everything in here is SCALE encoded into `Transact` calls for `pallet_ah_ops`, so the encoding
+//! of these types must not change without a matching change on Asset Hub.
+
+use crate::*;
+use pallet_balances::BalanceLock;
+
+/// The maximum number of holds, freezes and locks that are migrated per account.
+pub type MaxAccountItems = ConstU32<16>;
+
+/// Relay Chain pallets on Asset Hub side.
+///
+/// Used to encode calls to the `pallet_ah_ops` pallet on Asset Hub.
+#[derive(Encode, Decode)]
+pub enum AssetHubPalletConfig<T: Config> {
+	#[codec(index = 254)]
+	AhOps(AhOpsCall<T>),
+}
+
+/// Call encoding for the calls needed from the `pallet_ah_ops` pallet on Asset Hub.
+#[derive(Encode, Decode)]
+pub enum AhOpsCall<T: Config> {
+	#[codec(index = 9)]
+	ReceiveAccounts { accounts: Vec<RcAccountFor<T>> },
+	#[codec(index = 10)]
+	StartMigration,
+	#[codec(index = 11)]
+	FinishMigration,
+}
+
+/// Portable version of the Relay Chain hold reasons.
+///
+/// Asset Hub does not know the Relay Chain `RuntimeHoldReason`, so the holds are sent in this
+/// runtime-independent representation and mapped to the local reason on Asset Hub.
+#[derive(
+	Encode, DecodeWithMemTracking, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen,
+)]
+pub enum PortableHoldReason {
+	Preimage(pallet_preimage::HoldReason),
+	Staking(pallet_staking::HoldReason),
+	StateTrieMigration(pallet_state_trie_migration::HoldReason),
+	DelegatedStaking(pallet_delegated_staking::HoldReason),
+	Session(pallet_session::HoldReason),
+	XcmPallet(pallet_xcm::HoldReason),
+}
+
+/// Portable version of the Relay Chain freeze reasons.
+///
+/// See [`PortableHoldReason`].
+#[derive(
+	Encode, DecodeWithMemTracking, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen,
+)]
+pub enum PortableFreezeReason {
+	NominationPools(pallet_nomination_pools::FreezeReason),
+}
+
+/// An amount of balance that is held or frozen for a specific reason.
+#[derive(
+	Encode, DecodeWithMemTracking, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen,
+)]
+pub struct ReasonAmount<Reason, Balance> {
+	/// The reason of the hold or freeze.
+	pub reason: Reason,
+	/// The amount that is held or frozen.
+	pub amount: Balance,
+}
+
+/// Account balance data that is sent to Asset Hub.
+///
+/// The sum of `free` and `reserved` is the amount that was burned on the Relay Chain and has to be
+/// minted on Asset Hub. The holds, the unnamed reserve and the freezes and locks are re-applied on
+/// top of it.
+#[derive(
+	Encode, DecodeWithMemTracking, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen,
+)]
+pub struct RcAccount<AccountId, Balance, HoldReason, FreezeReason> {
+	/// The account address.
+	pub who: AccountId,
+	/// The free balance.
+	pub free: Balance,
+	/// The reserved balance, which is the sum of all `holds` and the `unnamed_reserve`.
+	pub reserved: Balance,
+	/// The frozen balance.
+	pub frozen: Balance,
+	/// The balances held for a specific reason.
+	pub holds: BoundedVec<ReasonAmount<HoldReason, Balance>, MaxAccountItems>,
+	/// The balances frozen for a specific reason.
+	pub freezes: BoundedVec<ReasonAmount<FreezeReason, Balance>, MaxAccountItems>,
+	/// The locks of the old `Currency` API.
+	pub locks: BoundedVec<BalanceLock<Balance>, MaxAccountItems>,
+	/// The part of the reserved balance that is not covered by a hold.
+	pub unnamed_reserve: Balance,
+	/// The number of consumer references that are moved along with the account.
+	pub consumers: u8,
+	/// The number of provider references that are moved along with the account.
+	pub providers: u8,
+}
+
+/// [`RcAccount`] as it is sent by this pallet.
+pub type RcAccountFor<T> = RcAccount<
+	<T as frame_system::Config>::AccountId,
+	u128,
+	PortableHoldReason,
+	PortableFreezeReason,
+>;
@@ -0,0 +1,302 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Benchmarks for the region market pallet.
+
+use crate::*;
+use frame_benchmarking::{v2::*, whitelisted_caller};
+use frame_support::traits::{fungible::Inspect, EnsureOrigin};
+use pallet_broker::{
+	CompletionStatus, ConfigRecordOf, CoreAssignment, PotentialRenewalRecord,
+	RCBlockNumberProviderOf, SaleInfoRecordOf, Schedule, ScheduleItem,
+};
+use sp_runtime::traits::Bounded;
+
+/// The length of the regions in the benchmarks.
+const REGION_LENGTH: Timeslice = 10;
+
+fn fund<T: Config>(who: &T::AccountId) {
+	let funds = CurrencyOf::<T>::minimum_balance()
+		.saturating_add(T::Deposit::get())
+		.saturating_mul(1_000_000u32.into());
+	CurrencyOf::<T>::set_balance(who, funds);
+}
+
+fn funded_account<T: Config>(name: &'static str) -> T::AccountId {
+	let who = account(name, 0, 0);
+	fund::<T>(&who);
+	who
+}
+
+fn region<T: Config>(owner: &T::AccountId) -> RegionId {
+	pallet_broker::Pallet::<T>::issue(
+		0,
+		0,
+		CoreMask::complete(),
+		REGION_LENGTH,
+		Some(owner.clone()),
+		None,
+	)
+}
+
+fn timeslice_price<T: Config>() -> BalanceOf<T> {
+	CurrencyOf::<T>::minimum_balance()
+}
+
+fn expires_at<T: Config>() -> BlockNumberFor<T> {
+	T::RcBlockNumberProvider::current_block_number().saturating_add(T::MaxDuration::get())
+}
+
+fn listed_region<T: Config>(seller: &T::AccountId) -> RegionId {
+	let region_id = region::<T>(seller);
+	Pallet::<T>::list_region(
+		RawOrigin::Signed(seller.clone()).into(),
+		region_id,
+		timeslice_price::<T>(),
+		expires_at::<T>(),
+	)
+	.unwrap();
+	region_id
+}
+
+fn order<T: Config>(buyer: &T::AccountId) -> OrderId {
+	let order_id = NextOrderId::<T>::get();
+	Pallet::<T>::place_order(
+		RawOrigin::Signed(buyer.clone()).into(),
+		0,
+		REGION_LENGTH,
+		CORE_PARTS,
+		timeslice_price::<T>(),
+		expires_at::<T>(),
+	)
+	.unwrap();
+	order_id
+}
+
+fn expire<T: Config>() {
+	T::RcBlockNumberProvider::set_block_number(expires_at::<T>());
+}
+
+/// Start the sales of the broker and move past the lead-in.
+fn start_sales<T: Config>() -> SaleInfoRecordOf<T> {
+	let admin = <T as pallet_broker::Config>::AdminOrigin::try_successful_origin().unwrap();
+	let config = ConfigRecordOf::<T> {
+		advance_notice: 2u32.into(),
+		interlude_length: 1u32.into(),
+		leadin_length: 1u32.into(),
+		region_length: REGION_LENGTH,
+		ideal_bulk_proportion: Default::default(),
+		limit_cores_offered: None,
+		renewal_bump: Perbill::from_percent(10),
+		contribution_timeout: REGION_LENGTH,
+	};
+	pallet_broker::Pallet::<T>::configure(admin.clone(), config).unwrap();
+	pallet_broker::Pallet::<T>::start_sales(admin, CurrencyOf::<T>::minimum_balance(), 2).unwrap();
+	let sale = SaleInfo::<T>::get().unwrap();
+	RCBlockNumberProviderOf::<<T as pallet_broker::Config>::Coretime>::set_block_number(
+		sale.sale_start.saturating_add(sale.leadin_length).saturating_add(1u32.into()),
+	);
+	sale
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn list_region() {
+		let seller = funded_account::<T>("seller");
+		let region_id = region::<T>(&seller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(seller), region_id, timeslice_price::<T>(), expires_at::<T>());
+
+		assert!(Listings::<T>::contains_key(region_id));
+	}
+
+	#[benchmark]
+	fn update_listing() {
+		let seller = funded_account::<T>("seller");
+		let region_id = listed_region::<T>(&seller);
+		let new_price = timeslice_price::<T>().saturating_mul(2u32.into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(seller), region_id, new_price, expires_at::<T>());
+
+		assert_eq!(Listings::<T>::get(region_id).unwrap().timeslice_price, new_price);
+	}
+
+	#[benchmark]
+	fn cancel_listing() {
+		let seller = funded_account::<T>("seller");
+		let region_id = listed_region::<T>(&seller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(seller), region_id);
+
+		assert!(!Listings::<T>::contains_key(region_id));
+	}
+
+	#[benchmark]
+	fn purchase() {
+		let seller = funded_account::<T>("seller");
+		let region_id = listed_region::<T>(&seller);
+		let buyer: T::AccountId = whitelisted_caller();
+		fund::<T>(&buyer);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(buyer.clone()), region_id, BalanceOf::<T>::max_value());
+
+		assert_eq!(Regions::<T>::get(region_id).unwrap().owner, Some(buyer));
+	}
+
+	// Worst case: the region is partitioned twice and interlaced.
+	#[benchmark]
+	fn purchase_part() {
+		let seller = funded_account::<T>("seller");
+		let region_id = listed_region::<T>(&seller);
+		let buyer: T::AccountId = whitelisted_caller();
+		fund::<T>(&buyer);
+		let mask = CoreMask::from_chunk(0, CORE_PARTS / 2);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(buyer.clone()),
+			region_id,
+			1,
+			REGION_LENGTH - 1,
+			mask,
+			BalanceOf::<T>::max_value(),
+		);
+
+		let part = RegionId { begin: 1, core: 0, mask };
+		assert_eq!(Regions::<T>::get(part).unwrap().owner, Some(buyer));
+	}
+
+	#[benchmark]
+	fn place_order() {
+		let buyer = funded_account::<T>("buyer");
+		let order_id = NextOrderId::<T>::get();
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(buyer),
+			0,
+			REGION_LENGTH,
+			CORE_PARTS,
+			timeslice_price::<T>(),
+			expires_at::<T>(),
+		);
+
+		assert!(Orders::<T>::contains_key(order_id));
+	}
+
+	#[benchmark]
+	fn cancel_order() {
+		let buyer = funded_account::<T>("buyer");
+		let order_id = order::<T>(&buyer);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(buyer), order_id);
+
+		assert!(!Orders::<T>::contains_key(order_id));
+	}
+
+	#[benchmark]
+	fn fill_order() {
+		let buyer = funded_account::<T>("buyer");
+		let order_id = order::<T>(&buyer);
+		let seller = funded_account::<T>("seller");
+		let region_id = region::<T>(&seller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(seller), order_id, region_id);
+
+		assert_eq!(Regions::<T>::get(region_id).unwrap().owner, Some(buyer));
+	}
+
+	#[benchmark]
+	fn clear_expired_listing() {
+		let seller = funded_account::<T>("seller");
+		let region_id = listed_region::<T>(&seller);
+		expire::<T>();
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), region_id);
+
+		assert_eq!(Regions::<T>::get(region_id).unwrap().owner, Some(seller));
+	}
+
+	#[benchmark]
+	fn clear_expired_order() {
+		let buyer = funded_account::<T>("buyer");
+		let order_id = order::<T>(&buyer);
+		expire::<T>();
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), order_id);
+
+		assert!(!Orders::<T>::contains_key(order_id));
+	}
+
+	#[benchmark]
+	fn purchase_and_assign() {
+		let sale = start_sales::<T>();
+		let buyer = funded_account::<T>("buyer");
+		T::OnSurplus::ensure_successful(&buyer);
+		// Leaves a surplus that is handed over.
+		let price_limit = CurrencyOf::<T>::balance(&buyer);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(buyer), price_limit, 2000);
+
+		let renewal = PotentialRenewalId { core: sale.first_core, when: sale.region_end };
+		assert!(PotentialRenewals::<T>::contains_key(renewal));
+	}
+
+	#[benchmark]
+	fn renew_with_limit() {
+		let sale = start_sales::<T>();
+		let buyer = funded_account::<T>("buyer");
+		let mut workload = Schedule::default();
+		workload
+			.try_push(ScheduleItem {
+				mask: CoreMask::complete(),
+				assignment: CoreAssignment::Task(2000),
+			})
+			.unwrap();
+		let renewal = PotentialRenewalId { core: sale.first_core, when: sale.region_begin };
+		PotentialRenewals::<T>::insert(
+			renewal,
+			PotentialRenewalRecord {
+				price: CurrencyOf::<T>::minimum_balance(),
+				completion: CompletionStatus::Complete(workload),
+			},
+		);
+
+		T::OnSurplus::ensure_successful(&buyer);
+		let price_limit = CurrencyOf::<T>::balance(&buyer);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(buyer), sale.first_core, price_limit);
+
+		assert!(!PotentialRenewals::<T>::contains_key(renewal));
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
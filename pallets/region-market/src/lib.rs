@@ -0,0 +1,862 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Region market pallet
+//!
+//! A secondary market for the coretime regions of `pallet_broker`.
+//!
+//! Region owners list a region with [`Pallet::list_region`] for a price per timeslice of the whole
+//! core. The region is held in the escrow account of the market until it is sold, the listing is
+//! cancelled or it expires. Buyers purchase either the whole region with [`Pallet::purchase`] or
+//! a part of it with [`Pallet::purchase_part`]. A part is a range of timeslices and a subset of
+//! the core mask of the region. The market partitions and interlaces the listed region and keeps
+//! the remaining pieces listed on the same terms. The price of a part is proportional to its
+//! length and the share of the core it covers.
+//!
+//! Buyers can also place orders with [`Pallet::place_order`], which lock the offered price in the
+//! escrow account. Any region owner can fill an order with a region that covers the requested
+//! timeslices and at least the requested share of a core.
+//!
+//! Listings and orders expire after a relay chain block chosen by their creator, expired ones can
+//! be cleared by anyone. A [`Config::Fee`] is charged from every sale and handed to
+//! [`Config::OnFee`], and listings and orders require a [`Config::Deposit`] that is returned when
+//! they are removed.
+//!
+//! For buyers that can't observe the bulk sale, for example because they act over XCM, the market
+//! also offers [`Pallet::purchase_and_assign`], which purchases a core in the current bulk sale and
+//! assigns it in one call, and [`Pallet::renew_with_limit`], which renews a core only up to a
+//! given price. The part of the price limit that a purchase didn't cost is handed to
+//! [`Config::OnSurplus`], for example to return it to the chain the buyer acts from.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+use frame_support::{
+	pallet_prelude::*,
+	traits::{
+		fungible::{Balanced, Credit, Inspect, Mutate},
+		tokens::{Fortitude, Precision, Preservation},
+		OnUnbalanced,
+	},
+	PalletId,
+};
+use frame_system::{pallet_prelude::*, RawOrigin};
+use pallet_broker::{
+	CoreIndex, CoreMask, Finality, PotentialRenewalId, PotentialRenewals, RegionId, Regions,
+	SaleInfo, TaskId, Timeslice,
+};
+use sp_runtime::{
+	traits::{AccountIdConversion, BlockNumberProvider, Saturating, Zero},
+	Perbill,
+};
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+pub type BalanceOf<T> = pallet_broker::BalanceOf<T>;
+type CurrencyOf<T> = <T as pallet_broker::Config>::Currency;
+pub type ListingOf<T> =
+	Listing<<T as frame_system::Config>::AccountId, BalanceOf<T>, BlockNumberFor<T>>;
+pub type OrderOf<T> =
+	Order<<T as frame_system::Config>::AccountId, BalanceOf<T>, BlockNumberFor<T>>;
+
+/// The identifier of an order.
+pub type OrderId = u32;
+
+/// The number of parts of a core, i.e. the bits of a [`CoreMask`].
+pub const CORE_PARTS: u32 = 80;
+
+/// A region that is offered for sale.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+	Clone,
+	PartialEq,
+	Eq,
+	RuntimeDebug,
+)]
+pub struct Listing<AccountId, Balance, BlockNumber> {
+	/// The owner of the region who receives the payment.
+	pub seller: AccountId,
+	/// The price of the whole core for one timeslice.
+	pub timeslice_price: Balance,
+	/// The relay chain block from which on the listing can no longer be purchased.
+	pub expires_at: BlockNumber,
+	/// The deposit held for the listing.
+	pub deposit: Balance,
+}
+
+/// An offer to buy a region.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+	Clone,
+	PartialEq,
+	Eq,
+	RuntimeDebug,
+)]
+pub struct Order<AccountId, Balance, BlockNumber> {
+	/// The account that receives the region.
+	pub buyer: AccountId,
+	/// The region must begin at or before this timeslice.
+	pub begin: Timeslice,
+	/// The region must end at or after this timeslice.
+	pub end: Timeslice,
+	/// The minimum number of the [`CORE_PARTS`] of a core that the region must cover.
+	pub parts: u32,
+	/// The price paid for a matching region.
+	pub price: Balance,
+	/// The relay chain block from which on the order can no longer be filled.
+	pub expires_at: BlockNumber,
+	/// The deposit held for the order.
+	pub deposit: Balance,
+}
+
+/// Handler for the part of the price limit of [`Pallet::purchase_and_assign`] and
+/// [`Pallet::renew_with_limit`] that the purchase didn't cost.
+pub trait OnSurplus<AccountId, Balance> {
+	/// Handle the `surplus`, which is still in the account of `who`. An error fails the
+	/// purchase.
+	fn on_surplus(who: &AccountId, surplus: Balance) -> DispatchResult;
+
+	/// Prepare the successful handling of a surplus of `who`.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn ensure_successful(_who: &AccountId) {}
+}
+
+impl<AccountId, Balance> OnSurplus<AccountId, Balance> for () {
+	fn on_surplus(_who: &AccountId, _surplus: Balance) -> DispatchResult {
+		Ok(())
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_broker::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The id of the market, used to derive the escrow account of listings and orders.
+		#[pallet::constant]
+		type MarketPalletId: Get<PalletId>;
+
+		/// Provider of the relay chain block number that listings and orders expire at.
+		type RcBlockNumberProvider: BlockNumberProvider<BlockNumber = BlockNumberFor<Self>>;
+
+		/// The maximum number of relay chain blocks a listing or an order can be open.
+		#[pallet::constant]
+		type MaxDuration: Get<BlockNumberFor<Self>>;
+
+		/// The deposit for a listing or an order. Should be at least the existential deposit.
+		#[pallet::constant]
+		type Deposit: Get<BalanceOf<Self>>;
+
+		/// The share of the price of every sale that is charged as fee.
+		#[pallet::constant]
+		type Fee: Get<Perbill>;
+
+		/// Handler for the fees.
+		type OnFee: OnUnbalanced<Credit<Self::AccountId, CurrencyOf<Self>>>;
+
+		/// Handler for the part of the price limit that a purchase didn't cost.
+		type OnSurplus: OnSurplus<Self::AccountId, BalanceOf<Self>>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The listed regions.
+	#[pallet::storage]
+	pub type Listings<T: Config> = StorageMap<_, Blake2_128Concat, RegionId, ListingOf<T>>;
+
+	/// The open orders.
+	#[pallet::storage]
+	pub type Orders<T: Config> = StorageMap<_, Twox64Concat, OrderId, OrderOf<T>>;
+
+	/// The identifier of the next order.
+	#[pallet::storage]
+	pub type NextOrderId<T: Config> = StorageValue<_, OrderId, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A region was listed.
+		Listed {
+			region_id: RegionId,
+			seller: T::AccountId,
+			timeslice_price: BalanceOf<T>,
+			expires_at: BlockNumberFor<T>,
+		},
+		/// The terms of a listing were changed.
+		ListingUpdated {
+			region_id: RegionId,
+			timeslice_price: BalanceOf<T>,
+			expires_at: BlockNumberFor<T>,
+		},
+		/// A listing was cancelled by the seller.
+		ListingCancelled { region_id: RegionId },
+		/// An expired listing was removed.
+		ListingExpired { region_id: RegionId },
+		/// `region_id` of the listing `listing` was sold. The remaining parts of the listed
+		/// region stay listed.
+		Sold {
+			listing: RegionId,
+			region_id: RegionId,
+			seller: T::AccountId,
+			buyer: T::AccountId,
+			price: BalanceOf<T>,
+			fee: BalanceOf<T>,
+		},
+		/// An order was placed.
+		OrderPlaced {
+			order_id: OrderId,
+			buyer: T::AccountId,
+			begin: Timeslice,
+			end: Timeslice,
+			parts: u32,
+			price: BalanceOf<T>,
+			expires_at: BlockNumberFor<T>,
+		},
+		/// An order was cancelled by the buyer.
+		OrderCancelled { order_id: OrderId },
+		/// An expired order was removed.
+		OrderExpired { order_id: OrderId },
+		/// An order was filled with `region_id`.
+		OrderFilled {
+			order_id: OrderId,
+			region_id: RegionId,
+			seller: T::AccountId,
+			buyer: T::AccountId,
+			price: BalanceOf<T>,
+			fee: BalanceOf<T>,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The region does not exist.
+		UnknownRegion,
+		/// The sender does not own the region.
+		NotOwner,
+		/// The region is not listed.
+		NotListed,
+		/// The sender is not the seller of the listing.
+		NotSeller,
+		/// The order does not exist.
+		UnknownOrder,
+		/// The sender is not the buyer of the order.
+		NotBuyer,
+		/// The expiry is in the past or further in the future than `MaxDuration`.
+		InvalidExpiry,
+		/// The listing or order expired.
+		Expired,
+		/// The listing or order did not expire yet.
+		NotExpired,
+		/// The price is higher than the given maximum.
+		PriceTooHigh,
+		/// The part is not within the listed region.
+		InvalidPart,
+		/// The order is empty or covers more than a core.
+		InvalidOrder,
+		/// The region does not satisfy the order.
+		RegionMismatch,
+		/// There is no ongoing bulk sale.
+		NoSale,
+		/// The core has no workload that can be renewed in the current sale.
+		NotRenewable,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// List a region for sale.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the owner of the region. The
+		/// region is moved into the escrow account of the market and the deposit is taken.
+		///
+		/// Parameters:
+		/// - `region_id`: The region to list.
+		/// - `timeslice_price`: The price of the whole core for one timeslice.
+		/// - `expires_at`: The relay chain block from which on the listing can no longer be
+		///   purchased.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::list_region())]
+		pub fn list_region(
+			origin: OriginFor<T>,
+			region_id: RegionId,
+			timeslice_price: BalanceOf<T>,
+			expires_at: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let seller = ensure_signed(origin)?;
+			let region = Regions::<T>::get(region_id).ok_or(Error::<T>::UnknownRegion)?;
+			ensure!(region.owner.as_ref() == Some(&seller), Error::<T>::NotOwner);
+			Self::ensure_expiry(expires_at)?;
+
+			let deposit = T::Deposit::get();
+			Self::lock_funds(&seller, deposit)?;
+			Self::transfer_region(seller.clone(), region_id, Self::escrow_account())?;
+			Listings::<T>::insert(
+				region_id,
+				Listing { seller: seller.clone(), timeslice_price, expires_at, deposit },
+			);
+
+			Self::deposit_event(Event::Listed { region_id, seller, timeslice_price, expires_at });
+			Ok(())
+		}
+
+		/// Change the price and the expiry of a listing.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the seller.
+		///
+		/// Parameters:
+		/// - `region_id`: The listed region.
+		/// - `timeslice_price`: The new price of the whole core for one timeslice.
+		/// - `expires_at`: The new expiry of the listing.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_listing())]
+		pub fn update_listing(
+			origin: OriginFor<T>,
+			region_id: RegionId,
+			timeslice_price: BalanceOf<T>,
+			expires_at: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_expiry(expires_at)?;
+			Listings::<T>::try_mutate(region_id, |listing| {
+				let listing = listing.as_mut().ok_or(Error::<T>::NotListed)?;
+				ensure!(listing.seller == who, Error::<T>::NotSeller);
+				listing.timeslice_price = timeslice_price;
+				listing.expires_at = expires_at;
+				Ok::<_, DispatchError>(())
+			})?;
+
+			Self::deposit_event(Event::ListingUpdated { region_id, timeslice_price, expires_at });
+			Ok(())
+		}
+
+		/// Cancel a listing and return the region and the deposit to the seller.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the seller.
+		///
+		/// Parameters:
+		/// - `region_id`: The listed region.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_listing())]
+		pub fn cancel_listing(origin: OriginFor<T>, region_id: RegionId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let listing = Listings::<T>::get(region_id).ok_or(Error::<T>::NotListed)?;
+			ensure!(listing.seller == who, Error::<T>::NotSeller);
+
+			Self::unlist(region_id, listing)?;
+			Self::deposit_event(Event::ListingCancelled { region_id });
+			Ok(())
+		}
+
+		/// Purchase a whole listed region.
+		///
+		/// The dispatch origin for this call must be _Signed_. The sender pays the price and
+		/// receives the region.
+		///
+		/// Parameters:
+		/// - `region_id`: The listed region.
+		/// - `max_price`: The maximum price the sender is willing to pay.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::purchase())]
+		pub fn purchase(
+			origin: OriginFor<T>,
+			region_id: RegionId,
+			max_price: BalanceOf<T>,
+		) -> DispatchResult {
+			let buyer = ensure_signed(origin)?;
+			let listing = Self::active_listing(region_id)?;
+			let end = Regions::<T>::get(region_id).ok_or(Error::<T>::UnknownRegion)?.end;
+			let price =
+				Self::price_of(listing.timeslice_price, region_id.begin, end, region_id.mask);
+			ensure!(price <= max_price, Error::<T>::PriceTooHigh);
+
+			Listings::<T>::remove(region_id);
+			let fee = Self::pay(&buyer, &listing.seller, price, Preservation::Preserve)?;
+			Self::transfer_region(Self::account_id(), region_id, buyer.clone())?;
+			Self::release_funds(&listing.seller, listing.deposit)?;
+
+			Self::deposit_event(Event::Sold {
+				listing: region_id,
+				region_id,
+				seller: listing.seller,
+				buyer,
+				price,
+				fee,
+			});
+			Ok(())
+		}
+
+		/// Purchase a part of a listed region.
+		///
+		/// The dispatch origin for this call must be _Signed_. The listed region is partitioned
+		/// at `begin` and `end` and interlaced with `mask` as needed. The sender pays the price of
+		/// the part and receives it, the remaining pieces stay listed on the same terms.
+		///
+		/// Parameters:
+		/// - `region_id`: The listed region.
+		/// - `begin`: The first timeslice of the part.
+		/// - `end`: The timeslice at which the part ends.
+		/// - `mask`: The parts of the core of the part, a subset of the mask of the region.
+		/// - `max_price`: The maximum price the sender is willing to pay.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::purchase_part())]
+		pub fn purchase_part(
+			origin: OriginFor<T>,
+			region_id: RegionId,
+			begin: Timeslice,
+			end: Timeslice,
+			mask: CoreMask,
+			max_price: BalanceOf<T>,
+		) -> DispatchResult {
+			let buyer = ensure_signed(origin)?;
+			let listing = Self::active_listing(region_id)?;
+			let region_end = Regions::<T>::get(region_id).ok_or(Error::<T>::UnknownRegion)?.end;
+			ensure!(
+				region_id.begin <= begin && begin < end && end <= region_end,
+				Error::<T>::InvalidPart
+			);
+			// The mask must not be empty and must not contain parts outside of the region.
+			ensure!(
+				!mask.is_void() && (mask & (region_id.mask ^ mask)).is_void(),
+				Error::<T>::InvalidPart
+			);
+			let price = Self::price_of(listing.timeslice_price, begin, end, mask);
+			ensure!(price <= max_price, Error::<T>::PriceTooHigh);
+
+			Listings::<T>::remove(region_id);
+			let escrow = Self::account_id();
+			// The deposit stays with the first remaining piece.
+			let mut deposit = Some(listing.deposit);
+			let mut relist = |piece: RegionId| {
+				let deposit = deposit.take().unwrap_or_default();
+				Listings::<T>::insert(piece, Listing { deposit, ..listing.clone() });
+				Self::deposit_event(Event::Listed {
+					region_id: piece,
+					seller: listing.seller.clone(),
+					timeslice_price: listing.timeslice_price,
+					expires_at: listing.expires_at,
+				});
+			};
+
+			let mut part = region_id;
+			if begin > part.begin {
+				pallet_broker::Pallet::<T>::partition(
+					RawOrigin::Signed(escrow.clone()).into(),
+					part,
+					begin,
+				)?;
+				relist(part);
+				part.begin = begin;
+			}
+			if end < region_end {
+				pallet_broker::Pallet::<T>::partition(
+					RawOrigin::Signed(escrow.clone()).into(),
+					part,
+					end,
+				)?;
+				relist(RegionId { begin: end, ..part });
+			}
+			if mask != part.mask {
+				pallet_broker::Pallet::<T>::interlace(
+					RawOrigin::Signed(escrow.clone()).into(),
+					part,
+					mask,
+				)?;
+				relist(RegionId { mask: part.mask ^ mask, ..part });
+				part.mask = mask;
+			}
+
+			let fee = Self::pay(&buyer, &listing.seller, price, Preservation::Preserve)?;
+			Self::transfer_region(escrow, part, buyer.clone())?;
+			if let Some(deposit) = deposit {
+				Self::release_funds(&listing.seller, deposit)?;
+			}
+
+			Self::deposit_event(Event::Sold {
+				listing: region_id,
+				region_id: part,
+				seller: listing.seller,
+				buyer,
+				price,
+				fee,
+			});
+			Ok(())
+		}
+
+		/// Place an order for a region.
+		///
+		/// The dispatch origin for this call must be _Signed_. The price and the deposit are
+		/// moved into the escrow account of the market.
+		///
+		/// Parameters:
+		/// - `begin`: The region must begin at or before this timeslice.
+		/// - `end`: The region must end at or after this timeslice.
+		/// - `parts`: The minimum number of the 80 parts of a core that the region must cover.
+		/// - `price`: The price paid for a matching region.
+		/// - `expires_at`: The relay chain block from which on the order can no longer be filled.
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::place_order())]
+		pub fn place_order(
+			origin: OriginFor<T>,
+			begin: Timeslice,
+			end: Timeslice,
+			parts: u32,
+			price: BalanceOf<T>,
+			expires_at: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let buyer = ensure_signed(origin)?;
+			ensure!(
+				begin < end && (1..=CORE_PARTS).contains(&parts) && !price.is_zero(),
+				Error::<T>::InvalidOrder
+			);
+			Self::ensure_expiry(expires_at)?;
+
+			let deposit = T::Deposit::get();
+			Self::lock_funds(&buyer, price.saturating_add(deposit))?;
+			let order_id = NextOrderId::<T>::mutate(|next| {
+				let id = *next;
+				*next = next.saturating_add(1);
+				id
+			});
+			Orders::<T>::insert(
+				order_id,
+				Order { buyer: buyer.clone(), begin, end, parts, price, expires_at, deposit },
+			);
+
+			Self::deposit_event(Event::OrderPlaced {
+				order_id,
+				buyer,
+				begin,
+				end,
+				parts,
+				price,
+				expires_at,
+			});
+			Ok(())
+		}
+
+		/// Cancel an order and return the price and the deposit to the buyer.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the buyer.
+		///
+		/// Parameters:
+		/// - `order_id`: The order to cancel.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_order())]
+		pub fn cancel_order(origin: OriginFor<T>, order_id: OrderId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let order = Orders::<T>::get(order_id).ok_or(Error::<T>::UnknownOrder)?;
+			ensure!(order.buyer == who, Error::<T>::NotBuyer);
+
+			Orders::<T>::remove(order_id);
+			Self::release_funds(&order.buyer, order.price.saturating_add(order.deposit))?;
+			Self::deposit_event(Event::OrderCancelled { order_id });
+			Ok(())
+		}
+
+		/// Fill an order with a region.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the owner of the region. The
+		/// region is transferred to the buyer and the sender receives the price of the order.
+		///
+		/// Parameters:
+		/// - `order_id`: The order to fill.
+		/// - `region_id`: The region that satisfies the order.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::fill_order())]
+		pub fn fill_order(
+			origin: OriginFor<T>,
+			order_id: OrderId,
+			region_id: RegionId,
+		) -> DispatchResult {
+			let seller = ensure_signed(origin)?;
+			let order = Orders::<T>::get(order_id).ok_or(Error::<T>::UnknownOrder)?;
+			ensure!(Self::now() < order.expires_at, Error::<T>::Expired);
+			let region = Regions::<T>::get(region_id).ok_or(Error::<T>::UnknownRegion)?;
+			ensure!(region.owner.as_ref() == Some(&seller), Error::<T>::NotOwner);
+			ensure!(
+				region_id.begin <= order.begin &&
+					region.end >= order.end &&
+					region_id.mask.count_ones() >= order.parts,
+				Error::<T>::RegionMismatch
+			);
+
+			Orders::<T>::remove(order_id);
+			Self::transfer_region(seller.clone(), region_id, order.buyer.clone())?;
+			let fee =
+				Self::pay(&Self::account_id(), &seller, order.price, Preservation::Expendable)?;
+			Self::release_funds(&order.buyer, order.deposit)?;
+
+			Self::deposit_event(Event::OrderFilled {
+				order_id,
+				region_id,
+				seller,
+				buyer: order.buyer,
+				price: order.price,
+				fee,
+			});
+			Ok(())
+		}
+
+		/// Remove an expired listing and return the region and the deposit to the seller.
+		///
+		/// The dispatch origin for this call must be _Signed_. Listings of regions that no longer
+		/// exist, for example because they were dropped after they ended, count as expired.
+		///
+		/// Parameters:
+		/// - `region_id`: The listed region.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::clear_expired_listing())]
+		pub fn clear_expired_listing(origin: OriginFor<T>, region_id: RegionId) -> DispatchResult {
+			ensure_signed(origin)?;
+			let listing = Listings::<T>::get(region_id).ok_or(Error::<T>::NotListed)?;
+			ensure!(
+				Self::now() >= listing.expires_at || !Regions::<T>::contains_key(region_id),
+				Error::<T>::NotExpired
+			);
+
+			Self::unlist(region_id, listing)?;
+			Self::deposit_event(Event::ListingExpired { region_id });
+			Ok(())
+		}
+
+		/// Remove an expired order and return the price and the deposit to the buyer.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Parameters:
+		/// - `order_id`: The expired order.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::clear_expired_order())]
+		pub fn clear_expired_order(origin: OriginFor<T>, order_id: OrderId) -> DispatchResult {
+			ensure_signed(origin)?;
+			let order = Orders::<T>::get(order_id).ok_or(Error::<T>::UnknownOrder)?;
+			ensure!(Self::now() >= order.expires_at, Error::<T>::NotExpired);
+
+			Orders::<T>::remove(order_id);
+			Self::release_funds(&order.buyer, order.price.saturating_add(order.deposit))?;
+			Self::deposit_event(Event::OrderExpired { order_id });
+			Ok(())
+		}
+
+		/// Purchase a core in the current bulk sale and assign it to `task`.
+		///
+		/// The dispatch origin for this call must be _Signed_. The region is purchased with
+		/// `pallet_broker::Pallet::purchase` and assigned with final finality, so the workload can
+		/// be renewed in the next sale. The part of `price_limit` that the purchase didn't cost is
+		/// handed to [`Config::OnSurplus`].
+		///
+		/// Parameters:
+		/// - `price_limit`: The maximum price the sender is willing to pay.
+		/// - `task`: The task the region is assigned to.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::purchase_and_assign())]
+		pub fn purchase_and_assign(
+			origin: OriginFor<T>,
+			price_limit: BalanceOf<T>,
+			task: TaskId,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin.clone())?;
+			let sale = SaleInfo::<T>::get().ok_or(Error::<T>::NoSale)?;
+			// The broker sells the cores of a sale in order.
+			let region_id = RegionId {
+				begin: sale.region_begin,
+				core: sale.first_core.saturating_add(sale.cores_sold),
+				mask: CoreMask::complete(),
+			};
+			let balance = CurrencyOf::<T>::balance(&who);
+			pallet_broker::Pallet::<T>::purchase(origin.clone(), price_limit)?;
+			pallet_broker::Pallet::<T>::assign(origin, region_id, task, Finality::Final)?;
+			Self::hand_over_surplus(&who, price_limit, balance)?;
+			Ok(().into())
+		}
+
+		/// Renew the workload of `core` if the renewal price is at most `price_limit`.
+		///
+		/// The dispatch origin for this call must be _Signed_. The part of `price_limit` that the
+		/// renewal didn't cost is handed to [`Config::OnSurplus`].
+		///
+		/// Parameters:
+		/// - `core`: The core whose workload is renewed.
+		/// - `price_limit`: The maximum price the sender is willing to pay.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::renew_with_limit())]
+		pub fn renew_with_limit(
+			origin: OriginFor<T>,
+			core: CoreIndex,
+			price_limit: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin.clone())?;
+			let sale = SaleInfo::<T>::get().ok_or(Error::<T>::NoSale)?;
+			let renewal =
+				PotentialRenewals::<T>::get(PotentialRenewalId { core, when: sale.region_begin })
+					.ok_or(Error::<T>::NotRenewable)?;
+			ensure!(renewal.price <= price_limit, Error::<T>::PriceTooHigh);
+			let balance = CurrencyOf::<T>::balance(&who);
+			pallet_broker::Pallet::<T>::renew(origin, core)?;
+			Self::hand_over_surplus(&who, price_limit, balance)?;
+			Ok(().into())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The escrow account that holds the listed regions, the deposits and the order funds.
+		pub fn account_id() -> T::AccountId {
+			T::MarketPalletId::get().into_account_truncating()
+		}
+
+		/// The price of the part of a core with `mask` from `begin` to `end` at `timeslice_price`
+		/// for the whole core.
+		pub fn price_of(
+			timeslice_price: BalanceOf<T>,
+			begin: Timeslice,
+			end: Timeslice,
+			mask: CoreMask,
+		) -> BalanceOf<T> {
+			let parts = end.saturating_sub(begin).saturating_mul(mask.count_ones());
+			timeslice_price.saturating_mul(parts.into()) / CORE_PARTS.into()
+		}
+
+		fn now() -> BlockNumberFor<T> {
+			T::RcBlockNumberProvider::current_block_number()
+		}
+
+		fn ensure_expiry(expires_at: BlockNumberFor<T>) -> DispatchResult {
+			let now = Self::now();
+			ensure!(
+				expires_at > now && expires_at <= now.saturating_add(T::MaxDuration::get()),
+				Error::<T>::InvalidExpiry
+			);
+			Ok(())
+		}
+
+		fn active_listing(region_id: RegionId) -> Result<ListingOf<T>, DispatchError> {
+			let listing = Listings::<T>::get(region_id).ok_or(Error::<T>::NotListed)?;
+			ensure!(Self::now() < listing.expires_at, Error::<T>::Expired);
+			Ok(listing)
+		}
+
+		/// The escrow account, which is created if it does not exist yet.
+		fn escrow_account() -> T::AccountId {
+			let escrow = Self::account_id();
+			if !frame_system::Pallet::<T>::account_exists(&escrow) {
+				// The account doesn't require ED to survive.
+				frame_system::Pallet::<T>::inc_providers(&escrow);
+			}
+			escrow
+		}
+
+		fn transfer_region(
+			from: T::AccountId,
+			region_id: RegionId,
+			to: T::AccountId,
+		) -> DispatchResult {
+			pallet_broker::Pallet::<T>::transfer(RawOrigin::Signed(from).into(), region_id, to)?;
+			Ok(())
+		}
+
+		/// Move `amount` from `who` into the escrow account.
+		fn lock_funds(who: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+			if amount.is_zero() {
+				return Ok(());
+			}
+			CurrencyOf::<T>::transfer(
+				who,
+				&Self::escrow_account(),
+				amount,
+				Preservation::Preserve,
+			)?;
+			Ok(())
+		}
+
+		/// Return `amount` from the escrow account to `who`.
+		fn release_funds(who: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+			if amount.is_zero() {
+				return Ok(());
+			}
+			CurrencyOf::<T>::transfer(&Self::account_id(), who, amount, Preservation::Expendable)?;
+			Ok(())
+		}
+
+		/// Hand the part of `price_limit` that wasn't spent since `who` had `balance` to
+		/// [`Config::OnSurplus`].
+		fn hand_over_surplus(
+			who: &T::AccountId,
+			price_limit: BalanceOf<T>,
+			balance: BalanceOf<T>,
+		) -> DispatchResult {
+			let price = balance.saturating_sub(CurrencyOf::<T>::balance(who));
+			let surplus = price_limit.saturating_sub(price);
+			if surplus.is_zero() {
+				return Ok(());
+			}
+			T::OnSurplus::on_surplus(who, surplus)
+		}
+
+		/// Pay `price` from `from` to `to` and charge the fee. Returns the fee.
+		fn pay(
+			from: &T::AccountId,
+			to: &T::AccountId,
+			price: BalanceOf<T>,
+			preservation: Preservation,
+		) -> Result<BalanceOf<T>, DispatchError> {
+			let fee = T::Fee::get() * price;
+			CurrencyOf::<T>::transfer(from, to, price.saturating_sub(fee), preservation)?;
+			if !fee.is_zero() {
+				let credit = CurrencyOf::<T>::withdraw(
+					from,
+					fee,
+					Precision::Exact,
+					preservation,
+					Fortitude::Polite,
+				)?;
+				T::OnFee::on_unbalanced(credit);
+			}
+			Ok(fee)
+		}
+
+		/// Remove a listing and return the region, if it still exists, and the deposit.
+		fn unlist(region_id: RegionId, listing: ListingOf<T>) -> DispatchResult {
+			Listings::<T>::remove(region_id);
+			if Regions::<T>::contains_key(region_id) {
+				Self::transfer_region(Self::account_id(), region_id, listing.seller.clone())?;
+			}
+			Self::release_funds(&listing.seller, listing.deposit)
+		}
+	}
+}
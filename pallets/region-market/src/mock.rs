@@ -0,0 +1,142 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Mock runtime for the region market pallet.
+
+use crate as pallet_region_market;
+use crate::*;
+use frame_support::{
+	derive_impl, parameter_types,
+	traits::{tokens::imbalance::ResolveTo, ConstU32, ConstU64},
+};
+use frame_system::EnsureRoot;
+use pallet_broker::{
+	CoreAssignment, CoreIndex, CoretimeInterface, PartsOf57600, RCBlockNumberOf, TaskId,
+};
+use sp_runtime::{
+	traits::{Identity, MaybeConvert},
+	BuildStorage,
+};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test {
+		System: frame_system,
+		Balances: pallet_balances,
+		Broker: pallet_broker,
+		RegionMarket: pallet_region_market,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+	type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type AccountStore = System;
+}
+
+pub struct TestCoretime;
+impl CoretimeInterface for TestCoretime {
+	type AccountId = u64;
+	type Balance = u64;
+	type RelayChainBlockNumberProvider = System;
+
+	fn request_core_count(_count: CoreIndex) {}
+	fn request_revenue_info_at(_when: RCBlockNumberOf<Self>) {}
+	fn credit_account(_who: Self::AccountId, _amount: Self::Balance) {}
+	fn assign_core(
+		_core: CoreIndex,
+		_begin: RCBlockNumberOf<Self>,
+		_assignment: Vec<(CoreAssignment, PartsOf57600)>,
+		_end_hint: Option<RCBlockNumberOf<Self>>,
+	) {
+	}
+}
+
+pub struct NoSovereignAccount;
+impl MaybeConvert<TaskId, u64> for NoSovereignAccount {
+	fn maybe_convert(_id: TaskId) -> Option<u64> {
+		None
+	}
+}
+
+/// The account that receives the fees.
+pub const FEE_ACCOUNT: u64 = 99;
+
+parameter_types! {
+	pub const BrokerPalletId: PalletId = PalletId(*b"py/broke");
+	pub const MarketPalletId: PalletId = PalletId(*b"py/rgnmk");
+	pub const FeeAccount: u64 = FEE_ACCOUNT;
+	pub const MarketFee: Perbill = Perbill::from_percent(10);
+	pub static Surpluses: Vec<(u64, u64)> = vec![];
+}
+
+/// Records the surpluses of purchases.
+pub struct RecordSurplus;
+impl OnSurplus<u64, u64> for RecordSurplus {
+	fn on_surplus(who: &u64, surplus: u64) -> DispatchResult {
+		Surpluses::mutate(|surpluses| surpluses.push((*who, surplus)));
+		Ok(())
+	}
+}
+
+impl pallet_broker::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type OnRevenue = ();
+	type TimeslicePeriod = ConstU64<2>;
+	type MaxLeasedCores = ConstU32<5>;
+	type MaxReservedCores = ConstU32<5>;
+	type Coretime = TestCoretime;
+	type ConvertBalance = Identity;
+	type WeightInfo = ();
+	type PalletId = BrokerPalletId;
+	type AdminOrigin = EnsureRoot<u64>;
+	type SovereignAccountOf = NoSovereignAccount;
+	type MaxAutoRenewals = ConstU32<5>;
+	type PriceAdapter = pallet_broker::MinimumPrice<u64, ConstU64<1>>;
+	type MinimumCreditPurchase = ConstU64<1>;
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type MarketPalletId = MarketPalletId;
+	type RcBlockNumberProvider = System;
+	type MaxDuration = ConstU64<100>;
+	type Deposit = ConstU64<10>;
+	type Fee = MarketFee;
+	type OnFee = ResolveTo<FeeAccount, Balances>;
+	type OnSurplus = RecordSurplus;
+	type WeightInfo = ();
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(1, 1_000), (2, 1_000), (FEE_ACCOUNT, 1)],
+		..Default::default()
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
@@ -0,0 +1,248 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Tests for the region market pallet.
+
+use crate::{mock::*, *};
+use frame_support::{assert_noop, assert_ok, traits::fungible::Inspect};
+use pallet_broker::{
+	CompletionStatus, ConfigRecordOf, CoreAssignment, PotentialRenewalRecord, SaleInfoRecordOf,
+	Schedule, ScheduleItem,
+};
+
+/// Issue a region of the whole core 0 from `begin` to `end` to `owner`.
+fn issue(owner: u64, begin: Timeslice, end: Timeslice) -> RegionId {
+	Broker::issue(0, begin, CoreMask::complete(), end, Some(owner), None)
+}
+
+fn owner(region_id: RegionId) -> Option<u64> {
+	Regions::<Test>::get(region_id).and_then(|region| region.owner)
+}
+
+/// Start the sales with `cores` cores and move past the lead-in.
+fn start_sales(cores: CoreIndex) -> SaleInfoRecordOf<Test> {
+	let config = ConfigRecordOf::<Test> {
+		advance_notice: 2,
+		interlude_length: 1,
+		leadin_length: 1,
+		region_length: 3,
+		ideal_bulk_proportion: Default::default(),
+		limit_cores_offered: None,
+		renewal_bump: Perbill::from_percent(10),
+		contribution_timeout: 5,
+	};
+	assert_ok!(Broker::configure(RuntimeOrigin::root(), config));
+	assert_ok!(Broker::start_sales(RuntimeOrigin::root(), 100, cores));
+	let sale = SaleInfo::<Test>::get().unwrap();
+	System::set_block_number(sale.sale_start + sale.leadin_length + 1);
+	sale
+}
+
+#[test]
+fn listed_region_can_be_purchased() {
+	new_test_ext().execute_with(|| {
+		let region_id = issue(1, 0, 10);
+		assert_noop!(
+			RegionMarket::list_region(RuntimeOrigin::signed(2), region_id, 8, 50),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			RegionMarket::list_region(RuntimeOrigin::signed(1), region_id, 8, 200),
+			Error::<Test>::InvalidExpiry
+		);
+		assert_ok!(RegionMarket::list_region(RuntimeOrigin::signed(1), region_id, 8, 50));
+		assert_eq!(owner(region_id), Some(RegionMarket::account_id()));
+		assert_eq!(Balances::balance(&1), 990);
+
+		assert_noop!(
+			RegionMarket::purchase(RuntimeOrigin::signed(2), region_id, 79),
+			Error::<Test>::PriceTooHigh
+		);
+		assert_ok!(RegionMarket::purchase(RuntimeOrigin::signed(2), region_id, 80));
+		System::assert_last_event(
+			Event::<Test>::Sold {
+				listing: region_id,
+				region_id,
+				seller: 1,
+				buyer: 2,
+				price: 80,
+				fee: 8,
+			}
+			.into(),
+		);
+
+		assert_eq!(owner(region_id), Some(2));
+		assert!(!Listings::<Test>::contains_key(region_id));
+		// The seller receives the price minus the fee and the deposit back.
+		assert_eq!(Balances::balance(&1), 1_072);
+		assert_eq!(Balances::balance(&2), 920);
+		assert_eq!(Balances::balance(&FEE_ACCOUNT), 9);
+	});
+}
+
+#[test]
+fn purchasing_a_part_keeps_the_remaining_pieces_listed() {
+	new_test_ext().execute_with(|| {
+		let region_id = issue(1, 0, 10);
+		assert_ok!(RegionMarket::list_region(RuntimeOrigin::signed(1), region_id, 80, 50));
+
+		// Parts of the core that are not in the region can't be bought.
+		let half = CoreMask::from_chunk(0, 40);
+		let front = RegionId { begin: 0, core: 0, mask: CoreMask::complete() };
+		assert_noop!(
+			RegionMarket::purchase_part(RuntimeOrigin::signed(2), region_id, 2, 11, half, 1_000),
+			Error::<Test>::InvalidPart
+		);
+
+		assert_ok!(RegionMarket::purchase_part(
+			RuntimeOrigin::signed(2),
+			region_id,
+			2,
+			6,
+			half,
+			160
+		));
+		let part = RegionId { begin: 2, core: 0, mask: half };
+		assert_eq!(owner(part), Some(2));
+		assert_eq!(Regions::<Test>::get(part).unwrap().end, 6);
+		assert_eq!(Balances::balance(&2), 840);
+
+		// The remaining pieces are listed on the same terms.
+		let back = RegionId { begin: 6, core: 0, mask: CoreMask::complete() };
+		let other_half = RegionId { begin: 2, core: 0, mask: CoreMask::from_chunk(40, 80) };
+		for (piece, end) in [(front, 2), (back, 10), (other_half, 6)] {
+			assert_eq!(owner(piece), Some(RegionMarket::account_id()));
+			assert_eq!(Regions::<Test>::get(piece).unwrap().end, end);
+			let listing = Listings::<Test>::get(piece).unwrap();
+			assert_eq!((listing.seller, listing.timeslice_price), (1, 80));
+		}
+		// The deposit moved to the first remaining piece.
+		assert_eq!(Listings::<Test>::get(front).unwrap().deposit, 10);
+		assert_eq!(Listings::<Test>::get(back).unwrap().deposit, 0);
+	});
+}
+
+#[test]
+fn orders_can_be_filled() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			RegionMarket::place_order(RuntimeOrigin::signed(2), 2, 6, 81, 100, 50),
+			Error::<Test>::InvalidOrder
+		);
+		assert_ok!(RegionMarket::place_order(RuntimeOrigin::signed(2), 2, 6, 40, 100, 50));
+		assert_eq!(Balances::balance(&2), 890);
+
+		let too_short = issue(1, 0, 5);
+		assert_noop!(
+			RegionMarket::fill_order(RuntimeOrigin::signed(1), 0, too_short),
+			Error::<Test>::RegionMismatch
+		);
+		let region_id = Broker::issue(0, 2, CoreMask::from_chunk(0, 40), 6, Some(1), None);
+		assert_ok!(RegionMarket::fill_order(RuntimeOrigin::signed(1), 0, region_id));
+
+		assert_eq!(owner(region_id), Some(2));
+		assert!(!Orders::<Test>::contains_key(0));
+		assert_eq!(Balances::balance(&1), 1_090);
+		assert_eq!(Balances::balance(&2), 900);
+		assert_eq!(Balances::balance(&FEE_ACCOUNT), 11);
+	});
+}
+
+#[test]
+fn expired_listings_and_orders_are_cleared() {
+	new_test_ext().execute_with(|| {
+		let region_id = issue(1, 0, 10);
+		assert_ok!(RegionMarket::list_region(RuntimeOrigin::signed(1), region_id, 8, 5));
+		assert_ok!(RegionMarket::place_order(RuntimeOrigin::signed(2), 2, 6, 40, 100, 5));
+		assert_noop!(
+			RegionMarket::clear_expired_listing(RuntimeOrigin::signed(3), region_id),
+			Error::<Test>::NotExpired
+		);
+
+		System::set_block_number(5);
+		assert_noop!(
+			RegionMarket::purchase(RuntimeOrigin::signed(2), region_id, 80),
+			Error::<Test>::Expired
+		);
+		assert_ok!(RegionMarket::clear_expired_listing(RuntimeOrigin::signed(3), region_id));
+		assert_ok!(RegionMarket::clear_expired_order(RuntimeOrigin::signed(3), 0));
+
+		assert_eq!(owner(region_id), Some(1));
+		assert_eq!(Balances::balance(&1), 1_000);
+		assert_eq!(Balances::balance(&2), 1_000);
+	});
+}
+
+#[test]
+fn purchased_core_is_assigned() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			RegionMarket::purchase_and_assign(RuntimeOrigin::signed(2), 500, 2000),
+			Error::<Test>::NoSale
+		);
+		let sale = start_sales(1);
+		assert_noop!(
+			RegionMarket::purchase_and_assign(RuntimeOrigin::signed(2), 0, 2000),
+			pallet_broker::Error::<Test>::Overpriced
+		);
+
+		assert_ok!(RegionMarket::purchase_and_assign(RuntimeOrigin::signed(2), 500, 2000));
+		let region_id = RegionId {
+			begin: sale.region_begin,
+			core: sale.first_core,
+			mask: CoreMask::complete(),
+		};
+		assert!(!Regions::<Test>::contains_key(region_id));
+		let renewal = PotentialRenewalId { core: sale.first_core, when: sale.region_end };
+		assert!(PotentialRenewals::<Test>::contains_key(renewal));
+		let price = 1_000 - Balances::balance(&2);
+		assert!(price > 0 && price < 500);
+		assert_eq!(Surpluses::get(), vec![(2, 500 - price)]);
+	});
+}
+
+#[test]
+fn renewal_respects_the_price_limit() {
+	new_test_ext().execute_with(|| {
+		let sale = start_sales(2);
+		let mut workload = Schedule::default();
+		workload
+			.try_push(ScheduleItem {
+				mask: CoreMask::complete(),
+				assignment: CoreAssignment::Task(2000),
+			})
+			.unwrap();
+		let renewal = PotentialRenewalId { core: 1, when: sale.region_begin };
+		PotentialRenewals::<Test>::insert(
+			renewal,
+			PotentialRenewalRecord { price: 50, completion: CompletionStatus::Complete(workload) },
+		);
+
+		assert_noop!(
+			RegionMarket::renew_with_limit(RuntimeOrigin::signed(2), 0, 500),
+			Error::<Test>::NotRenewable
+		);
+		assert_noop!(
+			RegionMarket::renew_with_limit(RuntimeOrigin::signed(2), 1, 49),
+			Error::<Test>::PriceTooHigh
+		);
+		assert_ok!(RegionMarket::renew_with_limit(RuntimeOrigin::signed(2), 1, 50));
+		assert!(!PotentialRenewals::<Test>::contains_key(renewal));
+		assert_eq!(Balances::balance(&2), 950);
+		// Nothing is left of a limit that is the exact price.
+		assert!(Surpluses::get().is_empty());
+	});
+}
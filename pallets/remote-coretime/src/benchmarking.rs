@@ -0,0 +1,69 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Benchmarks for the remote coretime pallet.
+
+use crate::*;
+use frame_benchmarking::{v2::*, whitelisted_caller};
+use frame_system::RawOrigin;
+
+/// The maximum price of the orders in the benchmarks.
+fn max_price<T: Config>() -> BalanceOf<T> {
+	1_000_000_000u32.into()
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn purchase() {
+		let who: T::AccountId = whitelisted_caller();
+		T::Orders::ensure_successful(&who, max_price::<T>());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(who), 2000, max_price::<T>());
+
+		assert_eq!(Orders::<T>::iter().count(), 1);
+	}
+
+	#[benchmark]
+	fn renew() {
+		let who: T::AccountId = whitelisted_caller();
+		T::Orders::ensure_successful(&who, max_price::<T>());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(who), 0, max_price::<T>());
+
+		assert_eq!(Orders::<T>::iter().count(), 1);
+	}
+
+	#[benchmark]
+	fn check_order() {
+		let who: T::AccountId = whitelisted_caller();
+		T::Orders::ensure_successful(&who, max_price::<T>());
+		Pallet::<T>::renew(RawOrigin::Signed(who.clone()).into(), 0, max_price::<T>()).unwrap();
+		let id = Orders::<T>::iter_keys().next().unwrap();
+		T::Orders::ensure_concluded(id);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(who), id);
+
+		assert!(!Orders::<T>::contains_key(id));
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
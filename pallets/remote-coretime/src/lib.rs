@@ -0,0 +1,264 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Remote coretime pallet
+//!
+//! Accounts on Asset Hub can buy coretime without moving funds to the Coretime chain first.
+//! [`Pallet::purchase`] purchases a core in the current bulk sale of the Coretime chain and assigns
+//! it to a parachain, [`Pallet::renew`] renews the workload of a core. Both hand the order to
+//! [`Config::Orders`], which sends one XCM program that moves the payment to the Coretime chain,
+//! executes the order there up to the given price and reports the outcome back to Asset Hub.
+//!
+//! The sent orders are tracked in [`Orders`]. Once the outcome was reported back, or the order
+//! expired after [`Config::OrderTimeout`] blocks without a report, anyone can conclude the order
+//! with [`Pallet::check_order`].
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+use frame_support::{pallet_prelude::*, traits::tokens::PaymentStatus, Parameter};
+use frame_system::pallet_prelude::*;
+use sp_runtime::traits::{AtLeast32BitUnsigned, Saturating};
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+/// An order for coretime on the Coretime chain.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	RuntimeDebug,
+)]
+pub enum CoretimeOrder {
+	/// Purchase a core in the current bulk sale and assign it to the parachain `para_id`.
+	Purchase { para_id: u32 },
+	/// Renew the workload of `core` for the next region.
+	Renew { core: u16 },
+}
+
+/// Sends coretime orders to the Coretime chain.
+pub trait SendOrder<AccountId, Balance, BlockNumber> {
+	/// The identifier of a sent order.
+	type Id: Parameter + MaxEncodedLen + Copy;
+
+	/// Send `order` on behalf of `who`, who pays at most `max_price` for the coretime. The
+	/// outcome isn't expected anymore from the block `expires_at` on.
+	fn send_order(
+		who: &AccountId,
+		order: CoretimeOrder,
+		max_price: Balance,
+		expires_at: BlockNumber,
+	) -> Result<Self::Id, DispatchError>;
+
+	/// Returns the status of the order `id`.
+	///
+	/// [`PaymentStatus::InProgress`] is returned until the outcome was reported, the pallet
+	/// concludes orders that expired without an outcome as unknown.
+	fn check_order(id: Self::Id) -> PaymentStatus;
+
+	/// Prepare everything required by [`Self::send_order`] for `who` to pay `max_price`.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn ensure_successful(who: &AccountId, max_price: Balance);
+
+	/// Make the order `id` conclude with [`PaymentStatus::Success`].
+	#[cfg(feature = "runtime-benchmarks")]
+	fn ensure_concluded(id: Self::Id);
+}
+
+/// An order that was sent to the Coretime chain.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+	Clone,
+	PartialEq,
+	Eq,
+	RuntimeDebug,
+)]
+pub struct OrderRecord<AccountId, Balance, BlockNumber> {
+	/// The account that placed the order.
+	pub who: AccountId,
+	/// The order.
+	pub order: CoretimeOrder,
+	/// The maximum price for the coretime.
+	pub max_price: Balance,
+	/// The block from which on the outcome isn't expected anymore.
+	pub expires_at: BlockNumber,
+}
+
+pub type OrderIdOf<T> = <<T as Config>::Orders as SendOrder<
+	<T as frame_system::Config>::AccountId,
+	BalanceOf<T>,
+	BlockNumberFor<T>,
+>>::Id;
+pub type BalanceOf<T> = <T as Config>::Balance;
+pub type OrderRecordOf<T> =
+	OrderRecord<<T as frame_system::Config>::AccountId, BalanceOf<T>, BlockNumberFor<T>>;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The balance type of the coretime prices.
+		type Balance: Parameter + MaxEncodedLen + AtLeast32BitUnsigned + Copy + Default;
+
+		/// Sends the orders to the Coretime chain.
+		type Orders: SendOrder<Self::AccountId, Self::Balance, BlockNumberFor<Self>>;
+
+		/// The number of blocks after which the outcome of an order isn't expected anymore.
+		#[pallet::constant]
+		type OrderTimeout: Get<BlockNumberFor<Self>>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The orders that were sent and are not concluded yet.
+	#[pallet::storage]
+	pub type Orders<T: Config> = StorageMap<_, Twox64Concat, OrderIdOf<T>, OrderRecordOf<T>>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// `who` sent `order` with a maximum price of `max_price`.
+		OrderSent {
+			id: OrderIdOf<T>,
+			who: T::AccountId,
+			order: CoretimeOrder,
+			max_price: BalanceOf<T>,
+		},
+		/// The order was executed on the Coretime chain.
+		OrderCompleted { id: OrderIdOf<T>, who: T::AccountId, order: CoretimeOrder },
+		/// The order failed on the Coretime chain, for example because the price was too high.
+		OrderFailed { id: OrderIdOf<T>, who: T::AccountId, order: CoretimeOrder },
+		/// The order expired without a reported outcome.
+		OrderUnknown { id: OrderIdOf<T>, who: T::AccountId, order: CoretimeOrder },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// There is no order with the given id.
+		UnknownOrder,
+		/// The outcome of the order wasn't reported back yet and the order didn't expire.
+		OrderInProgress,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Purchase a core in the current bulk sale of the Coretime chain and assign it to
+		/// `para_id`.
+		///
+		/// The dispatch origin for this call must be _Signed_. The sender pays the coretime and
+		/// the fees of the order. The payment of a failed order and the part of `max_price` that
+		/// the core didn't cost are returned to the sender, no funds are needed on the Coretime
+		/// chain.
+		///
+		/// Parameters:
+		/// - `para_id`: The parachain that the purchased core is assigned to.
+		/// - `max_price`: The maximum price the sender is willing to pay.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::purchase())]
+		pub fn purchase(
+			origin: OriginFor<T>,
+			para_id: u32,
+			max_price: T::Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::send(who, CoretimeOrder::Purchase { para_id }, max_price)
+		}
+
+		/// Renew the workload of `core` on the Coretime chain.
+		///
+		/// The dispatch origin for this call must be _Signed_. The sender pays the renewal and the
+		/// fees of the order. The payment of a failed order and the part of `max_price` that the
+		/// renewal didn't cost are returned to the sender, no funds are needed on the Coretime
+		/// chain.
+		///
+		/// Parameters:
+		/// - `core`: The core whose workload is renewed.
+		/// - `max_price`: The maximum price the sender is willing to pay.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::renew())]
+		pub fn renew(origin: OriginFor<T>, core: u16, max_price: T::Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::send(who, CoretimeOrder::Renew { core }, max_price)
+		}
+
+		/// Conclude the order `id` with the outcome that was reported by the Coretime chain, or as
+		/// unknown if the order expired without an outcome.
+		///
+		/// The dispatch origin for this call must be _Signed_. Any account can conclude an order.
+		///
+		/// Parameters:
+		/// - `id`: The id of the order.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::check_order())]
+		pub fn check_order(origin: OriginFor<T>, id: OrderIdOf<T>) -> DispatchResult {
+			ensure_signed(origin)?;
+			let OrderRecord { who, order, expires_at, .. } =
+				Orders::<T>::get(id).ok_or(Error::<T>::UnknownOrder)?;
+
+			let event = match T::Orders::check_order(id) {
+				PaymentStatus::InProgress
+					if frame_system::Pallet::<T>::block_number() < expires_at =>
+					return Err(Error::<T>::OrderInProgress.into()),
+				PaymentStatus::InProgress => Event::OrderUnknown { id, who, order },
+				PaymentStatus::Success => Event::OrderCompleted { id, who, order },
+				PaymentStatus::Failure => Event::OrderFailed { id, who, order },
+				PaymentStatus::Unknown => Event::OrderUnknown { id, who, order },
+			};
+			Orders::<T>::remove(id);
+			Self::deposit_event(event);
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		fn send(who: T::AccountId, order: CoretimeOrder, max_price: T::Balance) -> DispatchResult {
+			let expires_at =
+				frame_system::Pallet::<T>::block_number().saturating_add(T::OrderTimeout::get());
+			let id = T::Orders::send_order(&who, order, max_price, expires_at)?;
+			Orders::<T>::insert(id, OrderRecord { who: who.clone(), order, max_price, expires_at });
+			Self::deposit_event(Event::OrderSent { id, who, order, max_price });
+			Ok(())
+		}
+	}
+}
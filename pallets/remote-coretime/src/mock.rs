@@ -0,0 +1,99 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Mock runtime for the remote coretime pallet.
+
+use crate as pallet_remote_coretime;
+use crate::*;
+use frame_support::{derive_impl, parameter_types, traits::ConstU64};
+use sp_runtime::{BuildStorage, DispatchError};
+use std::collections::BTreeMap;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test {
+		System: frame_system,
+		RemoteCoretime: pallet_remote_coretime,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+}
+
+/// The number of blocks after which orders expire.
+pub const ORDER_TIMEOUT: u64 = 10;
+
+parameter_types! {
+	/// The orders sent by [`TestOrders`] as `(who, order, max_price)`, the index is the order id.
+	pub static SentOrders: Vec<(u64, CoretimeOrder, u64)> = Vec::new();
+	/// The reported outcomes of the orders, orders without an outcome are in progress.
+	pub static Outcomes: BTreeMap<u64, PaymentStatus> = BTreeMap::new();
+}
+
+pub struct TestOrders;
+impl SendOrder<u64, u64, u64> for TestOrders {
+	type Id = u64;
+
+	fn send_order(
+		who: &u64,
+		order: CoretimeOrder,
+		max_price: u64,
+		_expires_at: u64,
+	) -> Result<u64, DispatchError> {
+		if max_price == 0 {
+			return Err(DispatchError::Other("failed to send"));
+		}
+		Ok(SentOrders::mutate(|sent| {
+			sent.push((*who, order, max_price));
+			sent.len() as u64 - 1
+		}))
+	}
+
+	fn check_order(id: u64) -> PaymentStatus {
+		Outcomes::get().get(&id).cloned().unwrap_or(PaymentStatus::InProgress)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn ensure_successful(_who: &u64, _max_price: u64) {}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn ensure_concluded(id: u64) {
+		report(id, PaymentStatus::Success);
+	}
+}
+
+/// Report the outcome of the order `id`.
+pub fn report(id: u64, status: PaymentStatus) {
+	Outcomes::mutate(|outcomes| outcomes.insert(id, status));
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = u64;
+	type Orders = TestOrders;
+	type OrderTimeout = ConstU64<ORDER_TIMEOUT>;
+	type WeightInfo = ();
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
@@ -0,0 +1,125 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Tests for the remote coretime pallet.
+
+use crate::{mock::*, *};
+use frame_support::{assert_noop, assert_ok};
+
+#[test]
+fn orders_are_sent_and_concluded() {
+	new_test_ext().execute_with(|| {
+		let purchase = CoretimeOrder::Purchase { para_id: 2000 };
+		let renew = CoretimeOrder::Renew { core: 3 };
+		assert_ok!(RemoteCoretime::purchase(RuntimeOrigin::signed(1), 2000, 100));
+		System::assert_last_event(
+			Event::<Test>::OrderSent { id: 0, who: 1, order: purchase, max_price: 100 }.into(),
+		);
+		assert_ok!(RemoteCoretime::renew(RuntimeOrigin::signed(2), 3, 50));
+		assert_eq!(SentOrders::get(), vec![(1, purchase, 100), (2, renew, 50)]);
+		assert_eq!(
+			Orders::<Test>::get(1),
+			Some(OrderRecord {
+				who: 2,
+				order: renew,
+				max_price: 50,
+				expires_at: 1 + ORDER_TIMEOUT
+			})
+		);
+
+		assert_noop!(
+			RemoteCoretime::check_order(RuntimeOrigin::signed(3), 0),
+			Error::<Test>::OrderInProgress
+		);
+		report(0, PaymentStatus::Success);
+		assert_ok!(RemoteCoretime::check_order(RuntimeOrigin::signed(3), 0));
+		System::assert_last_event(
+			Event::<Test>::OrderCompleted { id: 0, who: 1, order: purchase }.into(),
+		);
+		assert!(!Orders::<Test>::contains_key(0));
+		assert_noop!(
+			RemoteCoretime::check_order(RuntimeOrigin::signed(3), 0),
+			Error::<Test>::UnknownOrder
+		);
+
+		report(1, PaymentStatus::Failure);
+		assert_ok!(RemoteCoretime::check_order(RuntimeOrigin::signed(3), 1));
+		System::assert_last_event(
+			Event::<Test>::OrderFailed { id: 1, who: 2, order: renew }.into(),
+		);
+	});
+}
+
+#[test]
+fn lost_orders_are_concluded_as_unknown() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(RemoteCoretime::renew(RuntimeOrigin::signed(1), 3, 50));
+		report(0, PaymentStatus::Unknown);
+
+		assert_ok!(RemoteCoretime::check_order(RuntimeOrigin::signed(2), 0));
+		System::assert_last_event(
+			Event::<Test>::OrderUnknown { id: 0, who: 1, order: CoretimeOrder::Renew { core: 3 } }
+				.into(),
+		);
+		assert!(!Orders::<Test>::contains_key(0));
+	});
+}
+
+#[test]
+fn orders_stay_in_progress_until_reported_or_expired() {
+	new_test_ext().execute_with(|| {
+		let now = 4_000_000;
+		System::set_block_number(now);
+		assert_ok!(RemoteCoretime::purchase(RuntimeOrigin::signed(1), 2000, 100));
+		assert_ok!(RemoteCoretime::renew(RuntimeOrigin::signed(1), 3, 50));
+		assert_eq!(Orders::<Test>::get(0).unwrap().expires_at, now + ORDER_TIMEOUT);
+
+		System::set_block_number(now + ORDER_TIMEOUT - 1);
+		for id in [0, 1] {
+			assert_noop!(
+				RemoteCoretime::check_order(RuntimeOrigin::signed(2), id),
+				Error::<Test>::OrderInProgress
+			);
+		}
+		report(0, PaymentStatus::Success);
+		assert_ok!(RemoteCoretime::check_order(RuntimeOrigin::signed(2), 0));
+		System::assert_last_event(
+			Event::<Test>::OrderCompleted {
+				id: 0,
+				who: 1,
+				order: CoretimeOrder::Purchase { para_id: 2000 },
+			}
+			.into(),
+		);
+
+		System::set_block_number(now + ORDER_TIMEOUT);
+		assert_ok!(RemoteCoretime::check_order(RuntimeOrigin::signed(2), 1));
+		System::assert_last_event(
+			Event::<Test>::OrderUnknown { id: 1, who: 1, order: CoretimeOrder::Renew { core: 3 } }
+				.into(),
+		);
+		assert_eq!(Orders::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn failed_send_stores_no_order() {
+	new_test_ext().execute_with(|| {
+		assert!(RemoteCoretime::purchase(RuntimeOrigin::signed(1), 2000, 0).is_err());
+		assert_eq!(Orders::<Test>::iter().count(), 0);
+		assert!(SentOrders::get().is_empty());
+	});
+}
@@ -17,14 +17,14 @@
 
 use super::*;
 use crate::Pallet as RemoteProxy;
-use alloc::{boxed::Box, vec};
+use alloc::{boxed::Box, vec, vec::Vec};
 use frame_benchmarking::v2::{
 	account, impl_test_function, instance_benchmarks, whitelisted_caller,
 };
 use frame_support::traits::Currency;
 use frame_system::RawOrigin;
 use sp_runtime::{
-	traits::{Bounded, StaticLookup},
+	traits::{Bounded, Hash, StaticLookup},
 	BoundedVec,
 };
 
@@ -34,6 +34,25 @@ type BalanceOf<T> = <<T as pallet_proxy::Config>::Currency as Currency<
 	<T as frame_system::Config>::AccountId,
 >>::Balance;
 
+/// Returns the caller, the sorted `s` other signatories and the multisig account with a
+/// threshold of two.
+fn setup_multisig<T: Config<I>, I: 'static>(
+	s: u32,
+) -> (T::AccountId, Vec<T::AccountId>, T::AccountId) {
+	let mut signatories: Vec<T::AccountId> =
+		(0..=s).map(|i| account("signatory", i, SEED)).collect();
+	for signatory in &signatories {
+		<T as pallet_proxy::Config>::Currency::make_free_balance_be(
+			signatory,
+			BalanceOf::<T>::max_value() / 2u32.into(),
+		);
+	}
+	signatories.sort();
+	let multisig = crate::multi_account_id(&signatories, 2);
+	let caller = signatories.remove(0);
+	(caller, signatories, multisig)
+}
+
 fn assert_last_event<T: pallet_proxy::Config>(
 	generic_event: <T as pallet_proxy::Config>::RuntimeEvent,
 ) {
@@ -132,5 +151,224 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn remote_announce() -> Result<(), BenchmarkError> {
+		// In this case the caller is the "target" proxy
+		let caller: T::AccountId = account("target", 0, SEED);
+		<T as pallet_proxy::Config>::Currency::make_free_balance_be(
+			&caller,
+			BalanceOf::<T>::max_value() / 2u32.into(),
+		);
+		// ... and "real" is the traditional caller. This is not a typo.
+		let real: T::AccountId = whitelisted_caller();
+		let real_lookup = T::Lookup::unlookup(real.clone());
+		let call: <T as pallet_proxy::Config>::RuntimeCall =
+			frame_system::Call::<T>::remark { remark: vec![] }.into();
+		let call_hash = T::CallHasher::hash_of(&call);
+		let (proof, block_number, storage_root) =
+			T::RemoteProxy::create_remote_proxy_proof(&caller, &real);
+		BlockToRoot::<T, I>::set(BoundedVec::truncate_from(vec![(block_number, storage_root)]));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), real_lookup, call_hash, proof);
+
+		assert_last_event::<T>(
+			pallet_proxy::Event::Announced { real, proxy: caller, call_hash }.into(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remote_remove_announcement() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = account("target", 0, SEED);
+		<T as pallet_proxy::Config>::Currency::make_free_balance_be(
+			&caller,
+			BalanceOf::<T>::max_value() / 2u32.into(),
+		);
+		let real: T::AccountId = whitelisted_caller();
+		let real_lookup = T::Lookup::unlookup(real.clone());
+		let call: <T as pallet_proxy::Config>::RuntimeCall =
+			frame_system::Call::<T>::remark { remark: vec![] }.into();
+		let call_hash = T::CallHasher::hash_of(&call);
+		let (proof, block_number, storage_root) =
+			T::RemoteProxy::create_remote_proxy_proof(&caller, &real);
+		BlockToRoot::<T, I>::set(BoundedVec::truncate_from(vec![(block_number, storage_root)]));
+		RemoteProxy::<T, I>::remote_announce(
+			RawOrigin::Signed(caller.clone()).into(),
+			real_lookup.clone(),
+			call_hash,
+			proof,
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), real_lookup, call_hash);
+
+		assert!(!Announcements::<T, I>::contains_key(&caller));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remote_reject_announcement() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = account("target", 0, SEED);
+		<T as pallet_proxy::Config>::Currency::make_free_balance_be(
+			&caller,
+			BalanceOf::<T>::max_value() / 2u32.into(),
+		);
+		let caller_lookup = T::Lookup::unlookup(caller.clone());
+		let real: T::AccountId = whitelisted_caller();
+		let real_lookup = T::Lookup::unlookup(real.clone());
+		let call: <T as pallet_proxy::Config>::RuntimeCall =
+			frame_system::Call::<T>::remark { remark: vec![] }.into();
+		let call_hash = T::CallHasher::hash_of(&call);
+		let (proof, block_number, storage_root) =
+			T::RemoteProxy::create_remote_proxy_proof(&caller, &real);
+		BlockToRoot::<T, I>::set(BoundedVec::truncate_from(vec![(block_number, storage_root)]));
+		RemoteProxy::<T, I>::remote_announce(
+			RawOrigin::Signed(caller.clone()).into(),
+			real_lookup,
+			call_hash,
+			proof,
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(real), caller_lookup, call_hash);
+
+		assert!(!Announcements::<T, I>::contains_key(&caller));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remote_proxy_announced() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = account("target", 0, SEED);
+		<T as pallet_proxy::Config>::Currency::make_free_balance_be(
+			&caller,
+			BalanceOf::<T>::max_value() / 2u32.into(),
+		);
+		let caller_lookup = T::Lookup::unlookup(caller.clone());
+		let real: T::AccountId = whitelisted_caller();
+		let real_lookup = T::Lookup::unlookup(real.clone());
+		let executor: T::AccountId = account("executor", 0, SEED);
+		let call: <T as pallet_proxy::Config>::RuntimeCall =
+			frame_system::Call::<T>::remark { remark: vec![] }.into();
+		let call_hash = T::CallHasher::hash_of(&call);
+		let (proof, block_number, storage_root) =
+			T::RemoteProxy::create_remote_proxy_proof(&caller, &real);
+		BlockToRoot::<T, I>::set(BoundedVec::truncate_from(vec![(block_number, storage_root)]));
+		RemoteProxy::<T, I>::remote_announce(
+			RawOrigin::Signed(caller.clone()).into(),
+			real_lookup.clone(),
+			call_hash,
+			proof.clone(),
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(executor), caller_lookup, real_lookup, None, Box::new(call), proof);
+
+		assert_last_event::<T>(pallet_proxy::Event::ProxyExecuted { result: Ok(()) }.into());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remote_multisig_approve(
+		s: Linear<1, { T::MaxSignatories::get() - 1 }>,
+	) -> Result<(), BenchmarkError> {
+		let (caller, other_signatories, multisig) = setup_multisig::<T, I>(s);
+		let real: T::AccountId = whitelisted_caller();
+		let real_lookup = T::Lookup::unlookup(real.clone());
+		let call: <T as pallet_proxy::Config>::RuntimeCall =
+			frame_system::Call::<T>::remark { remark: vec![] }.into();
+		let call_hash = T::CallHasher::hash_of(&call);
+		let (proof, block_number, storage_root) =
+			T::RemoteProxy::create_remote_proxy_proof(&multisig, &real);
+		BlockToRoot::<T, I>::set(BoundedVec::truncate_from(vec![(block_number, storage_root)]));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), real_lookup, 2, other_signatories, call_hash, proof);
+
+		assert!(RemoteMultisigs::<T, I>::contains_key(&multisig, (&real, call_hash)));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remote_multisig_as_proxy(
+		s: Linear<1, { T::MaxSignatories::get() - 1 }>,
+	) -> Result<(), BenchmarkError> {
+		let (caller, mut other_signatories, multisig) = setup_multisig::<T, I>(s);
+		let real: T::AccountId = whitelisted_caller();
+		let real_lookup = T::Lookup::unlookup(real.clone());
+		let call: <T as pallet_proxy::Config>::RuntimeCall =
+			frame_system::Call::<T>::remark { remark: vec![] }.into();
+		let call_hash = T::CallHasher::hash_of(&call);
+		let (proof, block_number, storage_root) =
+			T::RemoteProxy::create_remote_proxy_proof(&multisig, &real);
+		BlockToRoot::<T, I>::set(BoundedVec::truncate_from(vec![(block_number, storage_root)]));
+
+		// The first other signatory starts the operation, the caller executes it.
+		let first = other_signatories.remove(0);
+		let mut first_others = other_signatories.clone();
+		first_others.push(caller.clone());
+		first_others.sort();
+		RemoteProxy::<T, I>::remote_multisig_approve(
+			RawOrigin::Signed(first.clone()).into(),
+			real_lookup.clone(),
+			2,
+			first_others,
+			call_hash,
+			proof.clone(),
+		)?;
+		other_signatories.push(first);
+		other_signatories.sort();
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			real_lookup,
+			2,
+			other_signatories,
+			None,
+			Box::new(call),
+			proof,
+		);
+
+		assert!(!RemoteMultisigs::<T, I>::contains_key(&multisig, (&real, call_hash)));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remote_multisig_cancel(
+		s: Linear<1, { T::MaxSignatories::get() - 1 }>,
+	) -> Result<(), BenchmarkError> {
+		let (caller, other_signatories, multisig) = setup_multisig::<T, I>(s);
+		let real: T::AccountId = whitelisted_caller();
+		let real_lookup = T::Lookup::unlookup(real.clone());
+		let call: <T as pallet_proxy::Config>::RuntimeCall =
+			frame_system::Call::<T>::remark { remark: vec![] }.into();
+		let call_hash = T::CallHasher::hash_of(&call);
+		let (proof, block_number, storage_root) =
+			T::RemoteProxy::create_remote_proxy_proof(&multisig, &real);
+		BlockToRoot::<T, I>::set(BoundedVec::truncate_from(vec![(block_number, storage_root)]));
+		RemoteProxy::<T, I>::remote_multisig_approve(
+			RawOrigin::Signed(caller.clone()).into(),
+			real_lookup.clone(),
+			2,
+			other_signatories.clone(),
+			call_hash,
+			proof,
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), real_lookup, 2, other_signatories, call_hash);
+
+		assert!(!RemoteMultisigs::<T, I>::contains_key(&multisig, (&real, call_hash)));
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(RemoteProxy, crate::tests::new_test_ext(), crate::tests::Test);
 }
@@ -36,6 +36,28 @@
 //! - [`Pallet::remote_proxy_with_registered_proof`]: Use a previously registered `proof` to
 //!   dispatch the wrapped call.
 //!
+//! - [`Pallet::remote_announce`]: Announce a call for a remote proxy with a delay.
+//!
+//! - [`Pallet::remote_proxy_announced`]: Dispatch a previously announced call once the delay of the
+//!   remote proxy has passed.
+//!
+//! - [`Pallet::remote_remove_announcement`] and [`Pallet::remote_reject_announcement`]: Remove an
+//!   announcement as the proxy or as the proxied account.
+//!
+//! - [`Pallet::remote_multisig_approve`] and [`Pallet::remote_multisig_as_proxy`]: Approve a call
+//!   as a signatory of a multisig that is a remote proxy. The call is dispatched once the threshold
+//!   of approvals is reached.
+//!
+//! - [`Pallet::remote_multisig_cancel`]: Cancel a pending remote multisig operation.
+//!
+//! ## Remote multisigs
+//!
+//! A multisig account can not sign a transaction itself. If a multisig is a proxy at the remote
+//! location, its signatories approve the call one after another on this chain. Every approval
+//! needs to come with a proof that the multisig account is still a proxy of the `real` account.
+//! The multisig account is derived from the signatories and the threshold in the same way as
+//! `pallet_multisig` does.
+//!
 //! ## Security considerations
 //!
 //! As explained above the security of the proxy depends on the remote location. So, if the remote
@@ -46,6 +68,11 @@
 //! [`MaxStorageRootsToKeep`](Config::MaxStorageRootsToKeep) time until the proxy can not be used
 //! anymore. The reason for this is that the caller will be able to provide an old `proof` at which
 //! the proxy was still available.
+//!
+//! Announcements of delayed remote proxies are only stored locally. The proxied account should
+//! watch for them and reject unwanted announcements with
+//! [`Pallet::remote_reject_announcement`] or remove the proxy at the remote location before the
+//! delay has passed. The call is only dispatched if the remote proxy still exists at that point.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -57,16 +84,18 @@ mod benchmarking;
 mod tests;
 mod weight;
 
-use alloc::{boxed::Box, vec::Vec};
-use codec::{Encode, MaxEncodedLen};
-use frame_support::{storage::storage_prefix, Parameter, StorageHasher, Twox64Concat};
+use alloc::{boxed::Box, vec, vec::Vec};
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+	storage::storage_prefix, traits::Currency, Parameter, StorageHasher, Twox64Concat,
+};
 use scale_info::TypeInfo;
 use sp_core::Hasher;
-use sp_runtime::traits::Saturating;
+use sp_runtime::traits::{Saturating, TrailingZeroInput};
 
 pub use cumulus_primitives_core::PersistedValidationData;
 pub use pallet::*;
-pub use pallet_proxy::ProxyDefinition;
+pub use pallet_proxy::{Announcement, ProxyDefinition};
 pub use weight::WeightInfo;
 
 /// The remote proxy interface.
@@ -130,14 +159,43 @@ pub trait RemoteProxyInterface<AccountId, ProxyType, BlockNumber> {
 	) -> (RemoteProxyProof<Self::RemoteBlockNumber>, Self::RemoteBlockNumber, Self::RemoteHash);
 }
 
+/// A pending remote multisig operation.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, core::fmt::Debug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(MaxApprovals))]
+pub struct RemoteMultisig<AccountId, Balance, MaxApprovals: frame_support::traits::Get<u32>> {
+	/// The account that reserved the `deposit` when the operation was started.
+	pub depositor: AccountId,
+	/// The amount held in reserve of the `depositor`.
+	pub deposit: Balance,
+	/// The signatories that already approved the operation.
+	pub approvals: frame_support::BoundedVec<AccountId, MaxApprovals>,
+}
+
+/// Derive the multisig account id from the sorted `signatories` and the `threshold`.
+///
+/// Mirrors `pallet_multisig::Pallet::multi_account_id`.
+pub fn multi_account_id<AccountId: Decode + Encode>(
+	signatories: &[AccountId],
+	threshold: u16,
+) -> AccountId {
+	let entropy =
+		(b"modlpy/utilisuba", signatories, threshold).using_encoded(sp_core::hashing::blake2_256);
+	Decode::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
+		.expect("infinite length input; no invalid inputs for type; qed")
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use cumulus_pallet_parachain_system::OnSystemEvent;
 	use cumulus_primitives_core::PersistedValidationData;
-	use frame_support::{dispatch_context, pallet_prelude::*, traits::IsSubType};
+	use frame_support::{
+		dispatch_context,
+		pallet_prelude::*,
+		traits::{IsSubType, ReservableCurrency},
+	};
 	use frame_system::pallet_prelude::*;
-	use sp_runtime::traits::{Dispatchable, StaticLookup, Zero};
+	use sp_runtime::traits::{BlockNumberProvider, Dispatchable, Hash, StaticLookup, Zero};
 
 	type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
 
@@ -168,6 +226,17 @@ pub mod pallet {
 		BlockNumberFor<T>,
 	>>::RemoteProxyType;
 	type WeightInfoOf<T, I> = <T as Config<I>>::WeightInfo;
+	type CallHashOf<T> = <<T as pallet_proxy::Config>::CallHasher as Hash>::Output;
+	type DepositBalanceOf<T> = <<T as pallet_proxy::Config>::Currency as Currency<
+		<T as frame_system::Config>::AccountId,
+	>>::Balance;
+	type RemoteAnnouncementOf<T> =
+		Announcement<<T as frame_system::Config>::AccountId, CallHashOf<T>, BlockNumberFor<T>>;
+	type RemoteMultisigOf<T, I> = RemoteMultisig<
+		<T as frame_system::Config>::AccountId,
+		DepositBalanceOf<T>,
+		<T as Config<I>>::MaxSignatories,
+	>;
 
 	#[pallet::pallet]
 	pub struct Pallet<T, I = ()>(_);
@@ -181,9 +250,40 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// The announcements made by remote proxies, keyed by the proxy account.
+	///
+	/// The deposit is reserved with the announcement deposit of `pallet_proxy`.
+	#[pallet::storage]
+	pub type Announcements<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		(
+			BoundedVec<RemoteAnnouncementOf<T>, <T as pallet_proxy::Config>::MaxPending>,
+			DepositBalanceOf<T>,
+		),
+		ValueQuery,
+	>;
+
+	/// The pending remote multisig operations, keyed by the multisig account and the `real`
+	/// account together with the call hash.
+	#[pallet::storage]
+	pub type RemoteMultisigs<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		(T::AccountId, CallHashOf<T>),
+		RemoteMultisigOf<T, I>,
+	>;
+
 	/// Configuration trait.
 	#[pallet::config]
 	pub trait Config<I: 'static = ()>: frame_system::Config + pallet_proxy::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self, I>>
+			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
 		/// The maximum number of storage roots to keep.
 		///
 		/// The storage roots are used to validate the remote proofs. The more we keep in storage,
@@ -200,10 +300,66 @@ pub mod pallet {
 			BlockNumberFor<Self>,
 		>;
 
+		/// Provides the block number in which the `delay` of the remote proxy definitions is
+		/// measured.
+		///
+		/// Must use the same unit as the `delay` of the proxy definitions returned by
+		/// [`RemoteProxyInterface::remote_to_local_proxy_defintion`].
+		type AnnouncementBlockNumberProvider: BlockNumberProvider<
+			BlockNumber = BlockNumberFor<Self>,
+		>;
+
+		/// The base amount of currency needed to reserve for starting a remote multisig
+		/// operation.
+		#[pallet::constant]
+		type MultisigDepositBase: Get<DepositBalanceOf<Self>>;
+
+		/// The amount of currency needed per unit threshold when starting a remote multisig
+		/// operation.
+		#[pallet::constant]
+		type MultisigDepositFactor: Get<DepositBalanceOf<Self>>;
+
+		/// The maximum number of signatories of a remote multisig.
+		#[pallet::constant]
+		type MaxSignatories: Get<u32>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
 
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config<I>, I: 'static = ()> {
+		/// A new remote multisig operation has begun.
+		NewRemoteMultisig {
+			approving: T::AccountId,
+			multisig: T::AccountId,
+			real: T::AccountId,
+			call_hash: CallHashOf<T>,
+		},
+		/// A remote multisig operation has been approved by a signatory.
+		RemoteMultisigApproval {
+			approving: T::AccountId,
+			multisig: T::AccountId,
+			real: T::AccountId,
+			call_hash: CallHashOf<T>,
+		},
+		/// A remote multisig operation reached its threshold and the call was dispatched.
+		RemoteMultisigExecuted {
+			approving: T::AccountId,
+			multisig: T::AccountId,
+			real: T::AccountId,
+			call_hash: CallHashOf<T>,
+		},
+		/// A remote multisig operation has been cancelled.
+		RemoteMultisigCancelled {
+			cancelling: T::AccountId,
+			multisig: T::AccountId,
+			real: T::AccountId,
+			call_hash: CallHashOf<T>,
+		},
+	}
+
 	impl<T: Config<I>, I: 'static> OnSystemEvent for Pallet<T, I> {
 		fn on_validation_data(validation_data: &PersistedValidationData) {
 			let Some((block, hash)) = T::RemoteProxy::block_to_storage_root(validation_data) else {
@@ -251,6 +407,24 @@ pub mod pallet {
 		DidNotFindMatchingProxyDefinition,
 		/// Proxy proof not registered.
 		ProxyProofNotRegistered,
+		/// There are too many pending announcements.
+		TooManyAnnouncements,
+		/// The announcement was not found.
+		AnnouncementNotFound,
+		/// The threshold of a remote multisig must be at least two.
+		MinimumThreshold,
+		/// There are too few or too many signatories.
+		WrongSignatoriesCount,
+		/// The other signatories are not sorted or contain duplicates.
+		SignatoriesOutOfOrder,
+		/// The sender was contained in the other signatories.
+		SenderInSignatories,
+		/// The signatory already approved the remote multisig operation.
+		AlreadyApproved,
+		/// The remote multisig operation was not found.
+		MultisigNotFound,
+		/// Only the account that started the remote multisig operation can cancel it.
+		NotOwner,
 	}
 
 	/// The remote proxy proof to prove the existence of a proxy account.
@@ -388,6 +562,286 @@ pub mod pallet {
 
 			Self::do_remote_proxy(who, real, force_proxy_type, *call, proof)
 		}
+
+		/// Announce the hash of a call that will be dispatched with
+		/// [`Self::remote_proxy_announced`] once the delay of the remote proxy has passed.
+		///
+		/// The dispatch origin for this call must be _Signed_ and a remote proxy of `real`. The
+		/// announcement deposit of `pallet_proxy` is reserved for every pending announcement.
+		///
+		/// Parameters:
+		/// - `real`: The account that the proxy will make a call on behalf of.
+		/// - `call_hash`: The hash of the call to be made by the `real` account.
+		/// - `proof`: The proof from the remote chain about the existence of the proxy.
+		#[pallet::call_index(3)]
+		#[pallet::weight(WeightInfoOf::<T, I>::remote_announce())]
+		pub fn remote_announce(
+			origin: OriginFor<T>,
+			real: AccountIdLookupOf<T>,
+			call_hash: CallHashOf<T>,
+			proof: RemoteProxyProof<RemoteBlockNumberOf<T, I>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let real = T::Lookup::lookup(real)?;
+
+			Self::verify_remote_proxy(&who, &real, None, proof)?;
+
+			let announcement = Announcement {
+				real: real.clone(),
+				call_hash,
+				height: T::AnnouncementBlockNumberProvider::current_block_number(),
+			};
+			Announcements::<T, I>::try_mutate(&who, |(pending, deposit)| -> DispatchResult {
+				pending
+					.try_push(announcement)
+					.map_err(|_| Error::<T, I>::TooManyAnnouncements)?;
+				let new_deposit = Self::announcement_deposit(pending.len() as u32);
+				Self::update_deposit(&who, *deposit, new_deposit)?;
+				*deposit = new_deposit;
+				Ok(())
+			})?;
+
+			frame_system::Pallet::<T>::deposit_event(
+				<T as pallet_proxy::Config>::RuntimeEvent::from(pallet_proxy::Event::Announced {
+					real,
+					proxy: who,
+					call_hash,
+				}),
+			);
+
+			Ok(())
+		}
+
+		/// Remove an announcement that was made with [`Self::remote_announce`].
+		///
+		/// The dispatch origin for this call must be _Signed_ and the proxy that made the
+		/// announcement. The deposit is returned.
+		///
+		/// Parameters:
+		/// - `real`: The account that the proxy will make a call on behalf of.
+		/// - `call_hash`: The hash of the announced call.
+		#[pallet::call_index(4)]
+		#[pallet::weight(WeightInfoOf::<T, I>::remote_remove_announcement())]
+		pub fn remote_remove_announcement(
+			origin: OriginFor<T>,
+			real: AccountIdLookupOf<T>,
+			call_hash: CallHashOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let real = T::Lookup::lookup(real)?;
+
+			Self::edit_announcements(&who, |ann| ann.real != real || ann.call_hash != call_hash)
+		}
+
+		/// Reject an announcement that was made with [`Self::remote_announce`].
+		///
+		/// The dispatch origin for this call must be _Signed_ and the account on whose behalf the
+		/// call was announced. The deposit is returned to the proxy.
+		///
+		/// Parameters:
+		/// - `delegate`: The proxy that made the announcement.
+		/// - `call_hash`: The hash of the announced call.
+		#[pallet::call_index(5)]
+		#[pallet::weight(WeightInfoOf::<T, I>::remote_reject_announcement())]
+		pub fn remote_reject_announcement(
+			origin: OriginFor<T>,
+			delegate: AccountIdLookupOf<T>,
+			call_hash: CallHashOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+
+			Self::edit_announcements(&delegate, |ann| ann.real != who || ann.call_hash != call_hash)
+		}
+
+		/// Dispatch a `call` that was announced with [`Self::remote_announce`] once the delay of
+		/// the remote proxy has passed.
+		///
+		/// The dispatch origin for this call must be _Signed_. The `proof` must show that the
+		/// remote proxy still exists.
+		///
+		/// Parameters:
+		/// - `delegate`: The proxy that made the announcement.
+		/// - `real`: The account that the proxy will make a call on behalf of.
+		/// - `force_proxy_type`: Specify the exact proxy type to be used and checked for this call.
+		/// - `call`: The call to be made by the `real` account.
+		/// - `proof`: The proof from the remote chain about the existence of the proxy.
+		#[pallet::call_index(6)]
+		#[pallet::weight({
+			let di = call.get_dispatch_info();
+			(WeightInfoOf::<T, I>::remote_proxy_announced()
+				// AccountData for inner call origin accountdata.
+				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+				.saturating_add(di.call_weight),
+			di.class)
+		})]
+		pub fn remote_proxy_announced(
+			origin: OriginFor<T>,
+			delegate: AccountIdLookupOf<T>,
+			real: AccountIdLookupOf<T>,
+			force_proxy_type: Option<T::ProxyType>,
+			call: Box<<T as pallet_proxy::Config>::RuntimeCall>,
+			proof: RemoteProxyProof<RemoteBlockNumberOf<T, I>>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+			let real = T::Lookup::lookup(real)?;
+
+			let def = Self::verify_remote_proxy(&delegate, &real, force_proxy_type, proof)?;
+
+			let call_hash = T::CallHasher::hash_of(&call);
+			let now = T::AnnouncementBlockNumberProvider::current_block_number();
+			Self::edit_announcements(&delegate, |ann| {
+				ann.real != real ||
+					ann.call_hash != call_hash ||
+					now.saturating_sub(ann.height) < def.delay
+			})
+			.map_err(|_| Error::<T, I>::Unannounced)?;
+
+			Self::do_proxy(def, real, *call);
+
+			Ok(())
+		}
+
+		/// Approve a call as a signatory of a multisig that is a proxy of `real` at the remote
+		/// location.
+		///
+		/// The dispatch origin for this call must be _Signed_ and one of the signatories. The
+		/// first approval reserves a deposit of `MultisigDepositBase + threshold *
+		/// MultisigDepositFactor` from the sender, which is returned once the operation is
+		/// executed or cancelled.
+		///
+		/// Parameters:
+		/// - `real`: The account that the multisig will make a call on behalf of.
+		/// - `threshold`: The number of approvals required to dispatch the call.
+		/// - `other_signatories`: The other signatories of the multisig, sorted.
+		/// - `call_hash`: The hash of the call to be made by the `real` account.
+		/// - `proof`: The proof from the remote chain about the existence of the proxy.
+		#[pallet::call_index(7)]
+		#[pallet::weight(WeightInfoOf::<T, I>::remote_multisig_approve(
+			other_signatories.len() as u32
+		))]
+		pub fn remote_multisig_approve(
+			origin: OriginFor<T>,
+			real: AccountIdLookupOf<T>,
+			threshold: u16,
+			other_signatories: Vec<T::AccountId>,
+			call_hash: CallHashOf<T>,
+			proof: RemoteProxyProof<RemoteBlockNumberOf<T, I>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let real = T::Lookup::lookup(real)?;
+
+			let multisig = Self::multisig_of(&who, threshold, other_signatories)?;
+			let def = Self::verify_remote_proxy(&multisig, &real, None, proof)?;
+			ensure!(def.delay.is_zero(), Error::<T, I>::Unannounced);
+
+			Self::note_multisig_approval(&who, &multisig, &real, call_hash, threshold)?;
+
+			Ok(())
+		}
+
+		/// Approve and, if the threshold is reached, dispatch a `call` as a signatory of a
+		/// multisig that is a proxy of `real` at the remote location.
+		///
+		/// The dispatch origin for this call must be _Signed_ and one of the signatories. If the
+		/// sender already approved the call, it is only dispatched when the threshold is reached.
+		///
+		/// Parameters:
+		/// - `real`: The account that the multisig will make a call on behalf of.
+		/// - `threshold`: The number of approvals required to dispatch the call.
+		/// - `other_signatories`: The other signatories of the multisig, sorted.
+		/// - `force_proxy_type`: Specify the exact proxy type to be used and checked for this call.
+		/// - `call`: The call to be made by the `real` account.
+		/// - `proof`: The proof from the remote chain about the existence of the proxy.
+		#[pallet::call_index(8)]
+		#[pallet::weight({
+			let di = call.get_dispatch_info();
+			(WeightInfoOf::<T, I>::remote_multisig_as_proxy(other_signatories.len() as u32)
+				// AccountData for inner call origin accountdata.
+				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+				.saturating_add(di.call_weight),
+			di.class)
+		})]
+		pub fn remote_multisig_as_proxy(
+			origin: OriginFor<T>,
+			real: AccountIdLookupOf<T>,
+			threshold: u16,
+			other_signatories: Vec<T::AccountId>,
+			force_proxy_type: Option<T::ProxyType>,
+			call: Box<<T as pallet_proxy::Config>::RuntimeCall>,
+			proof: RemoteProxyProof<RemoteBlockNumberOf<T, I>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let real = T::Lookup::lookup(real)?;
+
+			let multisig = Self::multisig_of(&who, threshold, other_signatories)?;
+			let def = Self::verify_remote_proxy(&multisig, &real, force_proxy_type, proof)?;
+			ensure!(def.delay.is_zero(), Error::<T, I>::Unannounced);
+
+			let call_hash = T::CallHasher::hash_of(&call);
+			let approvals = match RemoteMultisigs::<T, I>::get(&multisig, (&real, call_hash)) {
+				Some(m) if m.approvals.contains(&who) => m.approvals.len() as u32,
+				_ => Self::note_multisig_approval(&who, &multisig, &real, call_hash, threshold)?,
+			};
+			if approvals < threshold as u32 {
+				return Ok(());
+			}
+
+			if let Some(m) = RemoteMultisigs::<T, I>::take(&multisig, (&real, call_hash)) {
+				let _ = <T as pallet_proxy::Config>::Currency::unreserve(&m.depositor, m.deposit);
+			}
+			Self::do_proxy(def, real.clone(), *call);
+			Self::deposit_event(Event::RemoteMultisigExecuted {
+				approving: who,
+				multisig,
+				real,
+				call_hash,
+			});
+
+			Ok(())
+		}
+
+		/// Cancel a pending remote multisig operation and return the deposit.
+		///
+		/// The dispatch origin for this call must be _Signed_ and the signatory that started the
+		/// operation.
+		///
+		/// Parameters:
+		/// - `real`: The account that the multisig would make a call on behalf of.
+		/// - `threshold`: The number of approvals required to dispatch the call.
+		/// - `other_signatories`: The other signatories of the multisig, sorted.
+		/// - `call_hash`: The hash of the call of the operation.
+		#[pallet::call_index(9)]
+		#[pallet::weight(WeightInfoOf::<T, I>::remote_multisig_cancel(
+			other_signatories.len() as u32
+		))]
+		pub fn remote_multisig_cancel(
+			origin: OriginFor<T>,
+			real: AccountIdLookupOf<T>,
+			threshold: u16,
+			other_signatories: Vec<T::AccountId>,
+			call_hash: CallHashOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let real = T::Lookup::lookup(real)?;
+
+			let multisig = Self::multisig_of(&who, threshold, other_signatories)?;
+			let m = RemoteMultisigs::<T, I>::get(&multisig, (&real, call_hash))
+				.ok_or(Error::<T, I>::MultisigNotFound)?;
+			ensure!(m.depositor == who, Error::<T, I>::NotOwner);
+
+			RemoteMultisigs::<T, I>::remove(&multisig, (&real, call_hash));
+			let _ = <T as pallet_proxy::Config>::Currency::unreserve(&m.depositor, m.deposit);
+			Self::deposit_event(Event::RemoteMultisigCancelled {
+				cancelling: who,
+				multisig,
+				real,
+				call_hash,
+			});
+
+			Ok(())
+		}
 	}
 
 	impl<T: Config<I>, I: 'static> Pallet<T, I> {
@@ -398,7 +852,24 @@ pub mod pallet {
 			call: <T as pallet_proxy::Config>::RuntimeCall,
 			proof: RemoteProxyProof<RemoteBlockNumberOf<T, I>>,
 		) -> DispatchResult {
-			let Some(real_remote) = T::RemoteProxy::local_to_remote_account_id(&real) else {
+			let def = Self::verify_remote_proxy(&who, &real, force_proxy_type, proof)?;
+
+			ensure!(def.delay.is_zero(), Error::<T, I>::Unannounced);
+
+			Self::do_proxy(def, real, call);
+
+			Ok(())
+		}
+
+		/// Verify the `proof` and return the remote proxy definition of `who` for `real`.
+		fn verify_remote_proxy(
+			who: &T::AccountId,
+			real: &T::AccountId,
+			force_proxy_type: Option<T::ProxyType>,
+			proof: RemoteProxyProof<RemoteBlockNumberOf<T, I>>,
+		) -> Result<ProxyDefinition<T::AccountId, T::ProxyType, BlockNumberFor<T>>, DispatchError>
+		{
+			let Some(real_remote) = T::RemoteProxy::local_to_remote_account_id(real) else {
 				return Err(Error::<T, I>::CouldNotConvertLocalToRemoteAccountId.into());
 			};
 
@@ -443,7 +914,7 @@ pub mod pallet {
 						BlockNumberFor<T>,
 					>|
 					 -> bool {
-						x.delegate == who &&
+						x.delegate == *who &&
 							force_proxy_type.as_ref().is_none_or(|y| &x.proxy_type == y)
 					};
 
@@ -455,10 +926,146 @@ pub mod pallet {
 				},
 			};
 
-			ensure!(def.delay.is_zero(), Error::<T, I>::Unannounced);
+			Ok(def)
+		}
 
-			Self::do_proxy(def, real, call);
+		/// Check the signatories and return the multisig account of `who` and
+		/// `other_signatories`.
+		fn multisig_of(
+			who: &T::AccountId,
+			threshold: u16,
+			other_signatories: Vec<T::AccountId>,
+		) -> Result<T::AccountId, DispatchError> {
+			ensure!(threshold >= 2, Error::<T, I>::MinimumThreshold);
+			ensure!(
+				!other_signatories.is_empty() &&
+					other_signatories.len() < T::MaxSignatories::get() as usize,
+				Error::<T, I>::WrongSignatoriesCount
+			);
+
+			let signatories = Self::ensure_sorted_and_insert(other_signatories, who.clone())?;
+			Ok(multi_account_id(&signatories, threshold))
+		}
+
+		/// Check that `other_signatories` is sorted and does not contain `who`, then insert `who`
+		/// at the right position.
+		fn ensure_sorted_and_insert(
+			other_signatories: Vec<T::AccountId>,
+			who: T::AccountId,
+		) -> Result<Vec<T::AccountId>, DispatchError> {
+			let mut signatories = other_signatories;
+			ensure!(
+				signatories.windows(2).all(|w| w[0] < w[1]),
+				Error::<T, I>::SignatoriesOutOfOrder
+			);
+			match signatories.binary_search(&who) {
+				Ok(_) => Err(Error::<T, I>::SenderInSignatories.into()),
+				Err(pos) => {
+					signatories.insert(pos, who);
+					Ok(signatories)
+				},
+			}
+		}
+
+		/// Record the approval of `who` for the remote multisig operation and return the number
+		/// of approvals.
+		///
+		/// Starting a new operation reserves the deposit from `who`.
+		fn note_multisig_approval(
+			who: &T::AccountId,
+			multisig: &T::AccountId,
+			real: &T::AccountId,
+			call_hash: CallHashOf<T>,
+			threshold: u16,
+		) -> Result<u32, DispatchError> {
+			let key = (real.clone(), call_hash);
+			if let Some(mut m) = RemoteMultisigs::<T, I>::get(multisig, &key) {
+				ensure!(!m.approvals.contains(who), Error::<T, I>::AlreadyApproved);
+				m.approvals
+					.try_push(who.clone())
+					.map_err(|_| Error::<T, I>::WrongSignatoriesCount)?;
+				let approvals = m.approvals.len() as u32;
+				RemoteMultisigs::<T, I>::insert(multisig, &key, m);
+
+				Self::deposit_event(Event::RemoteMultisigApproval {
+					approving: who.clone(),
+					multisig: multisig.clone(),
+					real: real.clone(),
+					call_hash,
+				});
+
+				return Ok(approvals);
+			}
+
+			let deposit = T::MultisigDepositBase::get()
+				.saturating_add(T::MultisigDepositFactor::get().saturating_mul(threshold.into()));
+			<T as pallet_proxy::Config>::Currency::reserve(who, deposit)?;
+			RemoteMultisigs::<T, I>::insert(
+				multisig,
+				&key,
+				RemoteMultisig {
+					depositor: who.clone(),
+					deposit,
+					approvals: BoundedVec::truncate_from(vec![who.clone()]),
+				},
+			);
+
+			Self::deposit_event(Event::NewRemoteMultisig {
+				approving: who.clone(),
+				multisig: multisig.clone(),
+				real: real.clone(),
+				call_hash,
+			});
+
+			Ok(1)
+		}
+
+		/// The deposit for `count` pending announcements.
+		fn announcement_deposit(count: u32) -> DepositBalanceOf<T> {
+			if count == 0 {
+				return Zero::zero();
+			}
+			<T as pallet_proxy::Config>::AnnouncementDepositBase::get().saturating_add(
+				<T as pallet_proxy::Config>::AnnouncementDepositFactor::get()
+					.saturating_mul(count.into()),
+			)
+		}
+
+		/// Reserve or unreserve the difference between the `old` and the `new` deposit.
+		fn update_deposit(
+			who: &T::AccountId,
+			old: DepositBalanceOf<T>,
+			new: DepositBalanceOf<T>,
+		) -> DispatchResult {
+			if new > old {
+				<T as pallet_proxy::Config>::Currency::reserve(who, new.saturating_sub(old))?;
+			} else if old > new {
+				let _ =
+					<T as pallet_proxy::Config>::Currency::unreserve(who, old.saturating_sub(new));
+			}
+			Ok(())
+		}
+
+		/// Keep the announcements of `delegate` for which `keep` returns `true` and adjust the
+		/// deposit.
+		///
+		/// Fails if no announcement was removed.
+		fn edit_announcements(
+			delegate: &T::AccountId,
+			keep: impl FnMut(&RemoteAnnouncementOf<T>) -> bool,
+		) -> DispatchResult {
+			let (mut pending, old_deposit) = Announcements::<T, I>::get(delegate);
+			let count = pending.len();
+			pending.retain(keep);
+			ensure!(pending.len() < count, Error::<T, I>::AnnouncementNotFound);
 
+			let new_deposit = Self::announcement_deposit(pending.len() as u32);
+			Self::update_deposit(delegate, old_deposit, new_deposit)?;
+			if pending.is_empty() {
+				Announcements::<T, I>::remove(delegate);
+			} else {
+				Announcements::<T, I>::insert(delegate, (pending, new_deposit));
+			}
 			Ok(())
 		}
 
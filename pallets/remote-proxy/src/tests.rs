@@ -31,7 +31,7 @@ use pallet_utility::Call as UtilityCall;
 use sp_core::{ConstU32, ConstU64, H256};
 use sp_io::TestExternalities;
 use sp_runtime::{
-	traits::{BlakeTwo256, BlockNumberProvider, Dispatchable},
+	traits::{BlakeTwo256, BlockNumberProvider, Dispatchable, Hash},
 	BoundedVec, BuildStorage,
 };
 
@@ -195,8 +195,13 @@ impl crate::RemoteProxyInterface<u64, ProxyType, u64> for RemoteProxyImpl {
 }
 
 impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
 	type MaxStorageRootsToKeep = ConstU32<10>;
 	type RemoteProxy = RemoteProxyImpl;
+	type AnnouncementBlockNumberProvider = System;
+	type MultisigDepositBase = ConstU64<1>;
+	type MultisigDepositFactor = ConstU64<1>;
+	type MaxSignatories = ConstU32<4>;
 	type WeightInfo = ();
 }
 
@@ -644,3 +649,351 @@ fn clean_up_works_and_old_blocks_are_rejected() {
 			.for_each(|(b, _)| assert!(*b >= 31 && *b <= 40));
 	});
 }
+
+#[test]
+fn remote_proxy_announced_works() {
+	let mut ext = new_test_ext();
+
+	let anon = ext.execute_with(|| {
+		Balances::make_free_balance_be(&1, 11); // An extra one for the ED.
+		assert_ok!(Proxy::create_pure(RuntimeOrigin::signed(1), ProxyType::Any, 2, 0));
+		Proxy::pure_account(&1, &ProxyType::Any, 0, None)
+	});
+
+	let proof = sp_state_machine::prove_read(
+		ext.as_backend(),
+		[pallet_proxy::Proxies::<Test>::hashed_key_for(anon)],
+	)
+	.unwrap();
+	let root = *ext.as_backend().root();
+	let remote_proof = || RemoteProxyProof::RelayChain {
+		proof: proof.clone().into_iter_nodes().collect(),
+		block: 1,
+	};
+
+	new_test_ext().execute_with(|| {
+		let call = Box::new(call_transfer(6, 1));
+		let call_hash = BlakeTwo256::hash_of(&call);
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(3), anon, 5));
+
+		RemoteProxy::on_validation_data(&PersistedValidationData {
+			parent_head: vec![].into(),
+			relay_parent_number: 1,
+			relay_parent_storage_root: root,
+			max_pov_size: 5000000,
+		});
+
+		// The proxy has a delay and can not be used directly.
+		assert_err!(
+			RemoteProxy::remote_proxy(
+				RuntimeOrigin::signed(1),
+				anon,
+				None,
+				call.clone(),
+				remote_proof()
+			),
+			Error::<Test>::Unannounced
+		);
+
+		// Only the remote proxy can announce.
+		assert_err!(
+			RemoteProxy::remote_announce(RuntimeOrigin::signed(2), anon, call_hash, remote_proof()),
+			Error::<Test>::DidNotFindMatchingProxyDefinition
+		);
+		assert_ok!(RemoteProxy::remote_announce(
+			RuntimeOrigin::signed(1),
+			anon,
+			call_hash,
+			remote_proof()
+		));
+		System::assert_last_event(ProxyEvent::Announced { real: anon, proxy: 1, call_hash }.into());
+		assert_eq!(Balances::reserved_balance(1), 2);
+
+		// The delay has not passed yet.
+		assert_err!(
+			RemoteProxy::remote_proxy_announced(
+				RuntimeOrigin::signed(2),
+				1,
+				anon,
+				None,
+				call.clone(),
+				remote_proof()
+			),
+			Error::<Test>::Unannounced
+		);
+
+		System::set_block_number(3);
+		assert_ok!(RemoteProxy::remote_proxy_announced(
+			RuntimeOrigin::signed(2),
+			1,
+			anon,
+			None,
+			call.clone(),
+			remote_proof()
+		));
+		System::assert_last_event(ProxyEvent::ProxyExecuted { result: Ok(()) }.into());
+		assert_eq!(Balances::free_balance(6), 1);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert!(!Announcements::<Test>::contains_key(1));
+
+		// The announcement is consumed.
+		assert_err!(
+			RemoteProxy::remote_proxy_announced(
+				RuntimeOrigin::signed(2),
+				1,
+				anon,
+				None,
+				call,
+				remote_proof()
+			),
+			Error::<Test>::Unannounced
+		);
+	});
+}
+
+#[test]
+fn remote_announcements_can_be_removed_and_rejected() {
+	let mut ext = new_test_ext();
+
+	let anon = ext.execute_with(|| {
+		Balances::make_free_balance_be(&1, 11); // An extra one for the ED.
+		assert_ok!(Proxy::create_pure(RuntimeOrigin::signed(1), ProxyType::Any, 2, 0));
+		Proxy::pure_account(&1, &ProxyType::Any, 0, None)
+	});
+
+	let proof = sp_state_machine::prove_read(
+		ext.as_backend(),
+		[pallet_proxy::Proxies::<Test>::hashed_key_for(anon)],
+	)
+	.unwrap();
+	let root = *ext.as_backend().root();
+	let remote_proof = || RemoteProxyProof::RelayChain {
+		proof: proof.clone().into_iter_nodes().collect(),
+		block: 1,
+	};
+
+	new_test_ext().execute_with(|| {
+		let first = BlakeTwo256::hash_of(&call_transfer(6, 1));
+		let second = BlakeTwo256::hash_of(&call_transfer(6, 2));
+		let third = BlakeTwo256::hash_of(&call_transfer(6, 3));
+
+		RemoteProxy::on_validation_data(&PersistedValidationData {
+			parent_head: vec![].into(),
+			relay_parent_number: 1,
+			relay_parent_storage_root: root,
+			max_pov_size: 5000000,
+		});
+
+		for hash in [first, second] {
+			assert_ok!(RemoteProxy::remote_announce(
+				RuntimeOrigin::signed(1),
+				anon,
+				hash,
+				remote_proof()
+			));
+		}
+		assert_eq!(Balances::reserved_balance(1), 3);
+		assert_err!(
+			RemoteProxy::remote_announce(RuntimeOrigin::signed(1), anon, third, remote_proof()),
+			Error::<Test>::TooManyAnnouncements
+		);
+
+		assert_ok!(RemoteProxy::remote_remove_announcement(RuntimeOrigin::signed(1), anon, first));
+		assert_eq!(Balances::reserved_balance(1), 2);
+		assert_err!(
+			RemoteProxy::remote_remove_announcement(RuntimeOrigin::signed(1), anon, first),
+			Error::<Test>::AnnouncementNotFound
+		);
+
+		// Only the proxied account can reject.
+		assert_err!(
+			RemoteProxy::remote_reject_announcement(RuntimeOrigin::signed(2), 1, second),
+			Error::<Test>::AnnouncementNotFound
+		);
+		assert_ok!(RemoteProxy::remote_reject_announcement(RuntimeOrigin::signed(anon), 1, second));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert!(!Announcements::<Test>::contains_key(1));
+	});
+}
+
+#[test]
+fn remote_multisig_works() {
+	let mut ext = new_test_ext();
+	let multisig = multi_account_id::<u64>(&[1, 2, 3], 2);
+
+	ext.execute_with(|| {
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(4), multisig, ProxyType::Any, 0));
+	});
+
+	let proof = sp_state_machine::prove_read(
+		ext.as_backend(),
+		[pallet_proxy::Proxies::<Test>::hashed_key_for(4)],
+	)
+	.unwrap();
+	let root = *ext.as_backend().root();
+	let remote_proof = || RemoteProxyProof::RelayChain {
+		proof: proof.clone().into_iter_nodes().collect(),
+		block: 1,
+	};
+
+	new_test_ext().execute_with(|| {
+		let call = Box::new(call_transfer(6, 1));
+		let call_hash = BlakeTwo256::hash_of(&call);
+
+		RemoteProxy::on_validation_data(&PersistedValidationData {
+			parent_head: vec![].into(),
+			relay_parent_number: 1,
+			relay_parent_storage_root: root,
+			max_pov_size: 5000000,
+		});
+
+		assert_err!(
+			RemoteProxy::remote_multisig_approve(
+				RuntimeOrigin::signed(1),
+				4,
+				1,
+				vec![2, 3],
+				call_hash,
+				remote_proof()
+			),
+			Error::<Test>::MinimumThreshold
+		);
+		assert_err!(
+			RemoteProxy::remote_multisig_approve(
+				RuntimeOrigin::signed(1),
+				4,
+				2,
+				vec![3, 2],
+				call_hash,
+				remote_proof()
+			),
+			Error::<Test>::SignatoriesOutOfOrder
+		);
+		// A different set of signatories is not a proxy of `4`.
+		assert_err!(
+			RemoteProxy::remote_multisig_approve(
+				RuntimeOrigin::signed(5),
+				4,
+				2,
+				vec![1, 2],
+				call_hash,
+				remote_proof()
+			),
+			Error::<Test>::DidNotFindMatchingProxyDefinition
+		);
+
+		assert_ok!(RemoteProxy::remote_multisig_approve(
+			RuntimeOrigin::signed(1),
+			4,
+			2,
+			vec![2, 3],
+			call_hash,
+			remote_proof()
+		));
+		System::assert_last_event(
+			Event::<Test>::NewRemoteMultisig { approving: 1, multisig, real: 4, call_hash }.into(),
+		);
+		assert_eq!(Balances::reserved_balance(1), 3);
+		assert_err!(
+			RemoteProxy::remote_multisig_approve(
+				RuntimeOrigin::signed(1),
+				4,
+				2,
+				vec![2, 3],
+				call_hash,
+				remote_proof()
+			),
+			Error::<Test>::AlreadyApproved
+		);
+
+		// The second approval reaches the threshold and dispatches the call.
+		assert_ok!(RemoteProxy::remote_multisig_as_proxy(
+			RuntimeOrigin::signed(2),
+			4,
+			2,
+			vec![1, 3],
+			None,
+			call,
+			remote_proof()
+		));
+		System::assert_has_event(ProxyEvent::ProxyExecuted { result: Ok(()) }.into());
+		System::assert_last_event(
+			Event::<Test>::RemoteMultisigExecuted { approving: 2, multisig, real: 4, call_hash }
+				.into(),
+		);
+		assert_eq!(Balances::free_balance(6), 1);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert!(!RemoteMultisigs::<Test>::contains_key(multisig, (4, call_hash)));
+	});
+}
+
+#[test]
+fn remote_multisig_cancel_works() {
+	let mut ext = new_test_ext();
+	let multisig = multi_account_id::<u64>(&[1, 2, 3], 2);
+
+	ext.execute_with(|| {
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(4), multisig, ProxyType::Any, 0));
+	});
+
+	let proof = sp_state_machine::prove_read(
+		ext.as_backend(),
+		[pallet_proxy::Proxies::<Test>::hashed_key_for(4)],
+	)
+	.unwrap();
+	let root = *ext.as_backend().root();
+
+	new_test_ext().execute_with(|| {
+		let call_hash = BlakeTwo256::hash_of(&call_transfer(6, 1));
+
+		RemoteProxy::on_validation_data(&PersistedValidationData {
+			parent_head: vec![].into(),
+			relay_parent_number: 1,
+			relay_parent_storage_root: root,
+			max_pov_size: 5000000,
+		});
+
+		assert_ok!(RemoteProxy::remote_multisig_approve(
+			RuntimeOrigin::signed(3),
+			4,
+			2,
+			vec![1, 2],
+			call_hash,
+			RemoteProxyProof::RelayChain { proof: proof.into_iter_nodes().collect(), block: 1 }
+		));
+		assert_eq!(Balances::reserved_balance(3), 3);
+
+		assert_err!(
+			RemoteProxy::remote_multisig_cancel(
+				RuntimeOrigin::signed(1),
+				4,
+				2,
+				vec![2, 3],
+				call_hash
+			),
+			Error::<Test>::NotOwner
+		);
+		assert_err!(
+			RemoteProxy::remote_multisig_cancel(
+				RuntimeOrigin::signed(3),
+				4,
+				3,
+				vec![1, 2],
+				call_hash
+			),
+			Error::<Test>::MultisigNotFound
+		);
+		assert_ok!(RemoteProxy::remote_multisig_cancel(
+			RuntimeOrigin::signed(3),
+			4,
+			2,
+			vec![1, 2],
+			call_hash
+		));
+		System::assert_last_event(
+			Event::<Test>::RemoteMultisigCancelled { cancelling: 3, multisig, real: 4, call_hash }
+				.into(),
+		);
+		assert_eq!(Balances::reserved_balance(3), 0);
+	});
+}
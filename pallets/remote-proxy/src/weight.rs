@@ -21,6 +21,13 @@ pub trait WeightInfo {
 	fn remote_proxy_with_registered_proof() -> Weight;
 	fn register_remote_proxy_proof() -> Weight;
 	fn remote_proxy() -> Weight;
+	fn remote_announce() -> Weight;
+	fn remote_remove_announcement() -> Weight;
+	fn remote_reject_announcement() -> Weight;
+	fn remote_proxy_announced() -> Weight;
+	fn remote_multisig_approve(s: u32) -> Weight;
+	fn remote_multisig_as_proxy(s: u32) -> Weight;
+	fn remote_multisig_cancel(s: u32) -> Weight;
 }
 
 impl WeightInfo for () {
@@ -35,4 +42,32 @@ impl WeightInfo for () {
 	fn remote_proxy() -> Weight {
 		Weight::MAX
 	}
+
+	fn remote_announce() -> Weight {
+		Weight::MAX
+	}
+
+	fn remote_remove_announcement() -> Weight {
+		Weight::MAX
+	}
+
+	fn remote_reject_announcement() -> Weight {
+		Weight::MAX
+	}
+
+	fn remote_proxy_announced() -> Weight {
+		Weight::MAX
+	}
+
+	fn remote_multisig_approve(_: u32) -> Weight {
+		Weight::MAX
+	}
+
+	fn remote_multisig_as_proxy(_: u32) -> Weight {
+		Weight::MAX
+	}
+
+	fn remote_multisig_cancel(_: u32) -> Weight {
+		Weight::MAX
+	}
 }
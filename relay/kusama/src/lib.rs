@@ -1951,9 +1951,137 @@ impl pallet_asset_rate::Config for Runtime {
 	type BenchmarkHelper = polkadot_runtime_common::impls::benchmarks::AssetRateArguments;
 }
 
+parameter_types! {
+	/// The Asset Hub migration may use up to 10% of the block weight, leaving the rest of the
+	/// block to the parachain consensus and user transactions.
+	pub RcMigratorMaxWeight: Weight = Perbill::from_percent(10) * BlockWeights::get().max_block;
+}
+
+/// Converts the Relay Chain hold reasons into the representation that is sent to Asset Hub.
+pub struct RcToPortableHoldReason;
+impl sp_runtime::traits::Convert<RuntimeHoldReason, pallet_rc_migrator::types::PortableHoldReason>
+	for RcToPortableHoldReason
+{
+	fn convert(reason: RuntimeHoldReason) -> pallet_rc_migrator::types::PortableHoldReason {
+		use pallet_rc_migrator::types::PortableHoldReason;
+
+		match reason {
+			RuntimeHoldReason::Preimage(r) => PortableHoldReason::Preimage(r),
+			RuntimeHoldReason::Staking(r) => PortableHoldReason::Staking(r),
+			RuntimeHoldReason::DelegatedStaking(r) => PortableHoldReason::DelegatedStaking(r),
+			RuntimeHoldReason::Session(r) => PortableHoldReason::Session(r),
+			RuntimeHoldReason::XcmPallet(r) => PortableHoldReason::XcmPallet(r),
+		}
+	}
+}
+
+/// Converts the Relay Chain freeze reasons into the representation that is sent to Asset Hub.
+pub struct RcToPortableFreezeReason;
+impl
+	sp_runtime::traits::Convert<
+		RuntimeFreezeReason,
+		pallet_rc_migrator::types::PortableFreezeReason,
+	> for RcToPortableFreezeReason
+{
+	fn convert(reason: RuntimeFreezeReason) -> pallet_rc_migrator::types::PortableFreezeReason {
+		use pallet_rc_migrator::types::PortableFreezeReason;
+
+		match reason {
+			RuntimeFreezeReason::NominationPools(r) => PortableFreezeReason::NominationPools(r),
+		}
+	}
+}
+
+/// Converts the representation that was sent to Asset Hub back into the Relay Chain hold reasons.
+pub struct PortableToRcHoldReason;
+impl
+	sp_runtime::traits::Convert<
+		pallet_rc_migrator::types::PortableHoldReason,
+		Option<RuntimeHoldReason>,
+	> for PortableToRcHoldReason
+{
+	fn convert(reason: pallet_rc_migrator::types::PortableHoldReason) -> Option<RuntimeHoldReason> {
+		use pallet_rc_migrator::types::PortableHoldReason;
+
+		match reason {
+			PortableHoldReason::Preimage(r) => Some(RuntimeHoldReason::Preimage(r)),
+			PortableHoldReason::Staking(r) => Some(RuntimeHoldReason::Staking(r)),
+			PortableHoldReason::DelegatedStaking(r) => Some(RuntimeHoldReason::DelegatedStaking(r)),
+			PortableHoldReason::Session(r) => Some(RuntimeHoldReason::Session(r)),
+			PortableHoldReason::XcmPallet(r) => Some(RuntimeHoldReason::XcmPallet(r)),
+			// Not deployed on this Relay Chain, so never sent to Asset Hub.
+			PortableHoldReason::StateTrieMigration(_) => None,
+		}
+	}
+}
+
+/// Converts the representation that was sent to Asset Hub back into the Relay Chain freeze
+/// reasons.
+pub struct PortableToRcFreezeReason;
+impl
+	sp_runtime::traits::Convert<
+		pallet_rc_migrator::types::PortableFreezeReason,
+		Option<RuntimeFreezeReason>,
+	> for PortableToRcFreezeReason
+{
+	fn convert(
+		reason: pallet_rc_migrator::types::PortableFreezeReason,
+	) -> Option<RuntimeFreezeReason> {
+		use pallet_rc_migrator::types::PortableFreezeReason;
+
+		match reason {
+			PortableFreezeReason::NominationPools(r) =>
+				Some(RuntimeFreezeReason::NominationPools(r)),
+		}
+	}
+}
+
+/// Provides every hold and freeze reason of the runtime to the `pallet_rc_migrator` benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct RcMigratorBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_rc_migrator::BenchmarkHelper<RuntimeHoldReason, RuntimeFreezeReason>
+	for RcMigratorBenchmarkHelper
+{
+	fn hold_reasons() -> Vec<RuntimeHoldReason> {
+		vec![
+			RuntimeHoldReason::Preimage(pallet_preimage::HoldReason::Preimage),
+			RuntimeHoldReason::Staking(pallet_staking::HoldReason::Staking),
+			RuntimeHoldReason::DelegatedStaking(
+				pallet_delegated_staking::HoldReason::StakingDelegation,
+			),
+			RuntimeHoldReason::Session(pallet_session::HoldReason::Keys),
+			RuntimeHoldReason::XcmPallet(pallet_xcm::HoldReason::AuthorizeAlias),
+		]
+	}
+
+	fn freeze_reasons() -> Vec<RuntimeFreezeReason> {
+		vec![RuntimeFreezeReason::NominationPools(
+			pallet_nomination_pools::FreezeReason::PoolMinBalance,
+		)]
+	}
+}
+
 impl pallet_rc_migrator::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
 	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type ManagerOrigin = EitherOfDiverse<
+		EitherOf<EnsureRoot<Self::AccountId>, GeneralAdmin>,
+		EnsureXcm<IsVoiceOfBody<AssetHubLocation, GeneralAdminBodyId>>,
+	>;
 	type Currency = Balances;
+	type RcToPortableHoldReason = RcToPortableHoldReason;
+	type RcToPortableFreezeReason = RcToPortableFreezeReason;
+	type PortableToRcHoldReason = PortableToRcHoldReason;
+	type PortableToRcFreezeReason = PortableToRcFreezeReason;
+	type SendXcm = xcm_config::XcmRouter;
+	type AssetHubLocation = AssetHubLocation;
+	type MaxRcWeight = RcMigratorMaxWeight;
+	type MaxAccountsPerMessage = ConstU32<100>;
+	type WeightInfo = weights::pallet_rc_migrator::WeightInfo<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = RcMigratorBenchmarkHelper;
 }
 
 construct_runtime! {
@@ -2224,6 +2352,7 @@ mod benches {
 		[pallet_vesting, Vesting]
 		[pallet_whitelist, Whitelist]
 		[pallet_asset_rate, AssetRate]
+		[pallet_rc_migrator, RcMigrator]
 		[pallet_parameters, Parameters]
 		// XCM
 		[pallet_xcm, PalletXcmExtrinsicsBenchmark::<Runtime>]
@@ -3095,6 +3224,7 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(2)]
 	impl pallet_rc_migrator::runtime_api::AssetHubMigrationApi<Block, BlockNumber> for Runtime {
 		fn migration_start_block() -> BlockNumber {
 			pallet_rc_migrator::MigrationStartBlock::<Runtime>::get().unwrap_or(0)
@@ -3103,6 +3233,11 @@ sp_api::impl_runtime_apis! {
 		fn migration_end_block() -> BlockNumber {
 			pallet_rc_migrator::MigrationEndBlock::<Runtime>::get().unwrap_or(0)
 		}
+
+		fn migration_progress(
+		) -> pallet_rc_migrator::progress::VersionedMigrationProgress<BlockNumber> {
+			RcMigrator::migration_progress()
+		}
 	}
 
 	#[cfg(feature = "try-runtime")]
@@ -34,6 +34,7 @@ pub mod pallet_nomination_pools;
 pub mod pallet_parameters;
 pub mod pallet_preimage;
 pub mod pallet_proxy;
+pub mod pallet_rc_migrator;
 pub mod pallet_referenda;
 pub mod pallet_scheduler;
 pub mod pallet_session;
@@ -0,0 +1,317 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The compatibility checks between two versions of a runtime.
+
+use crate::metadata::Metadata;
+use std::{collections::BTreeMap, fmt};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+	Info,
+	Warning,
+	Error,
+}
+
+impl fmt::Display for Severity {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str(match self {
+			Severity::Info => "info",
+			Severity::Warning => "warning",
+			Severity::Error => "error",
+		})
+	}
+}
+
+#[derive(Debug)]
+pub struct Finding {
+	pub severity: Severity,
+	pub message: String,
+}
+
+/// A version of a runtime.
+pub struct Runtime {
+	pub metadata: Metadata,
+	/// The in-code `StorageVersion` of every pallet, only known for WASM blobs.
+	pub storage_versions: Option<BTreeMap<String, u16>>,
+}
+
+/// Compares `old` with `new`.
+///
+/// `upgraded` are the on-chain storage versions after `new` was applied to the state of `old`.
+pub fn check(
+	old: &Runtime,
+	new: &Runtime,
+	upgraded: Option<&BTreeMap<String, u16>>,
+) -> Vec<Finding> {
+	let mut findings = Vec::new();
+	let mut report = |severity, message: String| findings.push(Finding { severity, message });
+	// Changes that alter the encoding of transactions and require a `transaction_version` bump.
+	let mut transaction_changes = 0;
+
+	let (old_metadata, new_metadata) = (&old.metadata, &new.metadata);
+	match (old_metadata.spec_version, new_metadata.spec_version) {
+		(Some(old), Some(new)) if new <= old =>
+			report(Severity::Error, format!("`spec_version` was not bumped: {old} -> {new}")),
+		(None, _) | (_, None) =>
+			report(Severity::Warning, "`spec_version` not found in the metadata".into()),
+		_ => {},
+	}
+
+	for (name, old_pallet) in &old_metadata.pallets {
+		let Some(new_pallet) = new_metadata.pallets.get(name) else {
+			report(Severity::Warning, format!("Pallet `{name}` was removed"));
+			transaction_changes += 1;
+			continue
+		};
+		if old_pallet.index != new_pallet.index {
+			report(
+				Severity::Error,
+				format!(
+					"Pallet `{name}` moved from index {} to {}",
+					old_pallet.index, new_pallet.index
+				),
+			);
+			transaction_changes += 1;
+		}
+
+		for (call, old_call) in &old_pallet.calls {
+			match new_pallet.calls.get(call) {
+				None => {
+					report(
+						Severity::Warning,
+						format!("Call `{name}::{call}` was removed or renamed"),
+					);
+					transaction_changes += 1;
+				},
+				Some(new_call) if new_call.index != old_call.index => {
+					report(
+						Severity::Warning,
+						format!(
+							"Call `{name}::{call}` moved from index {} to {}",
+							old_call.index, new_call.index
+						),
+					);
+					transaction_changes += 1;
+				},
+				Some(new_call) if new_call.shape != old_call.shape => {
+					report(Severity::Warning, format!("Arguments of `{name}::{call}` changed"));
+					transaction_changes += 1;
+				},
+				Some(_) => {},
+			}
+		}
+
+		let changed_storage = old_pallet
+			.storage
+			.iter()
+			.filter(|(item, shape)| new_pallet.storage.get(*item) != Some(*shape))
+			.map(|(item, _)| item.as_str())
+			.collect::<Vec<_>>();
+		if changed_storage.is_empty() {
+			continue
+		}
+		let items = changed_storage.join("`, `");
+		let versions = old
+			.storage_versions
+			.as_ref()
+			.zip(new.storage_versions.as_ref())
+			.map(|(old, new)| (old.get(name), new.get(name)));
+		match versions {
+			Some((old_version, new_version)) if old_version == new_version => report(
+				Severity::Error,
+				format!(
+					"Storage `{items}` of `{name}` was changed or removed but the `StorageVersion` \
+					 stayed at {}",
+					old_version.copied().unwrap_or_default()
+				),
+			),
+			Some(_) => report(
+				Severity::Info,
+				format!("Storage `{items}` of `{name}` was changed or removed"),
+			),
+			None => report(
+				Severity::Warning,
+				format!(
+					"Storage `{items}` of `{name}` was changed or removed, check that the \
+					 `StorageVersion` was bumped"
+				),
+			),
+		}
+	}
+
+	for (name, new_pallet) in &new_metadata.pallets {
+		if old_metadata.pallets.contains_key(name) {
+			continue
+		}
+		match old_metadata.pallets.iter().find(|(_, old)| old.index == new_pallet.index) {
+			Some((old_name, _)) => {
+				report(
+					Severity::Error,
+					format!(
+						"Pallet `{name}` reuses index {} of pallet `{old_name}`",
+						new_pallet.index
+					),
+				);
+				transaction_changes += 1;
+			},
+			None => report(
+				Severity::Info,
+				format!("Pallet `{name}` was added at index {}", new_pallet.index),
+			),
+		}
+	}
+
+	if old_metadata.extensions != new_metadata.extensions {
+		let names = |metadata: &Metadata| {
+			metadata
+				.extensions
+				.iter()
+				.map(|(name, _)| name.as_str())
+				.collect::<Vec<_>>()
+				.join(", ")
+		};
+		report(
+			Severity::Warning,
+			format!(
+				"`TxExtension` changed from ({}) to ({})",
+				names(old_metadata),
+				names(new_metadata)
+			),
+		);
+		transaction_changes += 1;
+	}
+
+	if transaction_changes > 0 {
+		match (old_metadata.transaction_version, new_metadata.transaction_version) {
+			(Some(old), Some(new)) if new <= old => report(
+				Severity::Error,
+				format!(
+					"{transaction_changes} change(s) to the transaction encoding but \
+					 `transaction_version` was not bumped: {old} -> {new}"
+				),
+			),
+			_ => {},
+		}
+	}
+
+	if let (Some(upgraded), Some(versions)) = (upgraded, &new.storage_versions) {
+		for (pallet, version) in versions {
+			// New pallets are initialized by their genesis config, not by a migration.
+			if !old_metadata.pallets.contains_key(pallet) {
+				continue
+			}
+			let on_chain = upgraded.get(pallet).copied().unwrap_or_default();
+			if on_chain != *version {
+				report(
+					Severity::Error,
+					format!(
+						"`StorageVersion` of `{pallet}` is {on_chain} after the upgrade but \
+						 {version} in code, a migration is missing in `Migrations`"
+					),
+				);
+			}
+		}
+	}
+
+	findings.sort_by(|a, b| b.severity.cmp(&a.severity));
+	findings
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use codec::Encode;
+
+	fn runtime() -> Runtime {
+		let metadata = people_polkadot_runtime::Runtime::metadata().encode();
+		Runtime { metadata: Metadata::decode(&metadata).unwrap(), storage_versions: None }
+	}
+
+	fn upgraded(old: &Runtime) -> Runtime {
+		let mut metadata = old.metadata.clone();
+		metadata.spec_version = metadata.spec_version.map(|version| version + 1);
+		Runtime { metadata, storage_versions: old.storage_versions.clone() }
+	}
+
+	fn errors(findings: &[Finding]) -> Vec<&str> {
+		findings
+			.iter()
+			.filter(|finding| finding.severity == Severity::Error)
+			.map(|finding| finding.message.as_str())
+			.collect()
+	}
+
+	#[test]
+	fn unchanged_runtime_only_requires_spec_version_bump() {
+		let old = runtime();
+		assert!(old.metadata.spec_version.is_some());
+		assert!(old.metadata.pallets["Balances"].calls.contains_key("transfer_keep_alive"));
+
+		assert_eq!(errors(&check(&old, &old, None)).len(), 1);
+		assert!(check(&old, &upgraded(&old), None).is_empty());
+	}
+
+	#[test]
+	fn transaction_changes_require_transaction_version_bump() {
+		let old = runtime();
+		let mut new = upgraded(&old);
+		new.metadata
+			.pallets
+			.get_mut("Balances")
+			.unwrap()
+			.calls
+			.remove("transfer_keep_alive");
+		let findings = check(&old, &new, None);
+		assert_eq!(errors(&findings).len(), 1);
+		assert!(errors(&findings)[0].contains("`transaction_version` was not bumped"));
+
+		new.metadata.transaction_version = new.metadata.transaction_version.map(|v| v + 1);
+		let findings = check(&old, &new, None);
+		assert!(errors(&findings).is_empty());
+		assert!(findings[0].message.contains("`Balances::transfer_keep_alive` was removed"));
+	}
+
+	#[test]
+	fn reordered_pallets_are_errors() {
+		let old = runtime();
+		let mut new = upgraded(&old);
+		new.metadata.transaction_version = new.metadata.transaction_version.map(|v| v + 1);
+		new.metadata.pallets.get_mut("Balances").unwrap().index += 100;
+		assert!(errors(&check(&old, &new, None))[0].contains("Pallet `Balances` moved"));
+	}
+
+	#[test]
+	fn storage_changes_require_storage_version_bump() {
+		let mut old = runtime();
+		old.storage_versions = Some(BTreeMap::from([("Balances".to_string(), 1)]));
+		let mut new = upgraded(&old);
+		new.metadata
+			.pallets
+			.get_mut("Balances")
+			.unwrap()
+			.storage
+			.remove("TotalIssuance");
+		assert!(errors(&check(&old, &new, None))[0].contains("`StorageVersion` stayed at 1"));
+
+		new.storage_versions = Some(BTreeMap::from([("Balances".to_string(), 2)]));
+		assert!(errors(&check(&old, &new, None)).is_empty());
+
+		let not_migrated = BTreeMap::from([("Balances".to_string(), 1)]);
+		let findings = check(&old, &new, Some(&not_migrated));
+		assert!(errors(&findings)[0].contains("a migration is missing in `Migrations`"));
+	}
+}
@@ -0,0 +1,206 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Offline compatibility checks between two versions of a runtime.
+//!
+//! Compares the metadata of the runtimes, given as SCALE or hex encoded metadata or as WASM blobs.
+//! For WASM blobs the in-code storage versions are read from the default genesis state and the
+//! upgrade is executed on the genesis state of the old runtime to find missing migrations.
+
+use checks::{Finding, Runtime, Severity};
+use clap::Parser;
+use codec::Decode;
+use metadata::Metadata;
+use serde_json::json;
+use std::path::{Path, PathBuf};
+use wasm::Wasm;
+
+mod checks;
+mod metadata;
+mod wasm;
+
+/// Prefix of zstd compressed WASM blobs, see `sp-maybe-compressed-blob`.
+const ZSTD_PREFIX: [u8; 8] = [82, 188, 83, 118, 70, 219, 142, 5];
+
+#[derive(Parser)]
+struct Cli {
+	/// The old runtime, or a directory of old runtimes that are paired with the new ones by file
+	/// name.
+	old: PathBuf,
+
+	/// The new runtime, or a directory of new runtimes.
+	new: PathBuf,
+
+	/// Print the findings as JSON.
+	#[arg(long)]
+	json: bool,
+}
+
+/// A loaded runtime file.
+enum Input {
+	Metadata(Vec<u8>),
+	Wasm(Wasm),
+}
+
+fn load(path: &Path) -> Result<Input, String> {
+	let mut bytes =
+		std::fs::read(path).map_err(|e| format!("Failed to read '{}': {e}", path.display()))?;
+	if bytes.starts_with(b"0x") {
+		let hex = String::from_utf8_lossy(&bytes).trim().to_string();
+		bytes = array_bytes::hex2bytes(&hex)
+			.map_err(|e| format!("Invalid hex in '{}': {e:?}", path.display()))?;
+	}
+
+	if bytes.starts_with(b"\0asm") || bytes.starts_with(&ZSTD_PREFIX) {
+		return Ok(Input::Wasm(Wasm::new(bytes)))
+	}
+	if bytes.starts_with(b"meta") {
+		return Ok(Input::Metadata(bytes))
+	}
+	// The output of `Metadata_metadata` is length prefixed.
+	match Vec::<u8>::decode(&mut &bytes[..]) {
+		Ok(metadata) if metadata.starts_with(b"meta") => Ok(Input::Metadata(metadata)),
+		_ => Err(format!("'{}' is neither metadata nor a WASM blob", path.display())),
+	}
+}
+
+/// Checks the runtime at `old_path` against the one at `new_path`.
+fn check_pair(old_path: &Path, new_path: &Path) -> Result<Vec<Finding>, String> {
+	let (old, new) = (load(old_path)?, load(new_path)?);
+	let (Input::Wasm(old_wasm), Input::Wasm(new_wasm)) = (&old, &new) else {
+		let metadata = |input: &Input| match input {
+			Input::Metadata(metadata) => Metadata::decode(metadata),
+			Input::Wasm(wasm) => Metadata::decode(&wasm.metadata()?),
+		};
+		let old = Runtime { metadata: metadata(&old)?, storage_versions: None };
+		let new = Runtime { metadata: metadata(&new)?, storage_versions: None };
+		let mut findings = checks::check(&old, &new, None);
+		findings.push(Finding {
+			severity: Severity::Info,
+			message: "Storage versions and migrations are only checked for two WASM blobs".into(),
+		});
+		return Ok(findings)
+	};
+
+	let old_metadata = Metadata::decode(&old_wasm.metadata()?)?;
+	let new_metadata = Metadata::decode(&new_wasm.metadata()?)?;
+	let old_genesis = old_wasm.genesis()?;
+	let new_genesis = new_wasm.genesis()?;
+	let old = Runtime {
+		storage_versions: Some(wasm::storage_versions(&old_genesis, old_metadata.pallets.keys())),
+		metadata: old_metadata,
+	};
+	let new = Runtime {
+		storage_versions: Some(wasm::storage_versions(&new_genesis, new_metadata.pallets.keys())),
+		metadata: new_metadata,
+	};
+
+	let (upgraded, upgrade_error) = match new_wasm.upgrade(old_genesis) {
+		Ok(state) => (Some(wasm::storage_versions(&state, new.metadata.pallets.keys())), None),
+		Err(e) => (None, Some(e)),
+	};
+	let mut findings = checks::check(&old, &new, upgraded.as_ref());
+	if let Some(e) = upgrade_error {
+		findings.push(Finding {
+			severity: Severity::Warning,
+			message: format!("Migrations are not checked, the upgrade could not be executed: {e}"),
+		});
+	}
+	Ok(findings)
+}
+
+/// Returns the pairs of runtimes to check, and the runtimes that only exist in one directory.
+fn pairs(old: &Path, new: &Path) -> Result<(Vec<(PathBuf, PathBuf)>, Vec<PathBuf>), String> {
+	if !old.is_dir() || !new.is_dir() {
+		return Ok((vec![(old.into(), new.into())], Vec::new()))
+	}
+	let files = |dir: &Path| -> Result<Vec<PathBuf>, String> {
+		let mut files = std::fs::read_dir(dir)
+			.map_err(|e| format!("Failed to read '{}': {e}", dir.display()))?
+			.filter_map(|entry| entry.ok().map(|entry| entry.path()))
+			.filter(|path| path.is_file())
+			.collect::<Vec<_>>();
+		files.sort();
+		Ok(files)
+	};
+	let (mut pairs, mut unpaired) = (Vec::new(), Vec::new());
+	for old_file in files(old)? {
+		let new_file = new.join(old_file.file_name().expect("Files have a name; qed"));
+		if new_file.is_file() {
+			pairs.push((old_file, new_file));
+		} else {
+			unpaired.push(old_file);
+		}
+	}
+	for new_file in files(new)? {
+		if !old.join(new_file.file_name().expect("Files have a name; qed")).is_file() {
+			unpaired.push(new_file);
+		}
+	}
+	Ok((pairs, unpaired))
+}
+
+fn main() -> Result<(), String> {
+	let cli = Cli::parse();
+
+	let (pairs, unpaired) = pairs(&cli.old, &cli.new)?;
+	let mut results = Vec::new();
+	for (old, new) in pairs {
+		let findings = check_pair(&old, &new)
+			.unwrap_or_else(|e| vec![Finding { severity: Severity::Error, message: e }]);
+		let name = new.file_name().map(|name| name.to_string_lossy().into_owned());
+		results.push((name.unwrap_or_default(), findings));
+	}
+	for path in unpaired {
+		let finding = Finding {
+			severity: Severity::Warning,
+			message: "Only exists in one of the directories".into(),
+		};
+		results.push((path.display().to_string(), vec![finding]));
+	}
+
+	if cli.json {
+		let output = results
+			.iter()
+			.map(|(name, findings)| {
+				let findings = findings
+					.iter()
+					.map(|f| json!({ "severity": f.severity.to_string(), "message": f.message }))
+					.collect::<Vec<_>>();
+				(name.clone(), serde_json::Value::Array(findings))
+			})
+			.collect::<serde_json::Map<_, _>>();
+		println!("{}", serde_json::to_string_pretty(&output).map_err(|e| e.to_string())?);
+	} else {
+		for (name, findings) in &results {
+			println!("=== {name} ===");
+			for finding in findings {
+				println!("{}: {}", finding.severity, finding.message);
+			}
+			println!();
+		}
+	}
+
+	let errors = results
+		.iter()
+		.flat_map(|(_, findings)| findings)
+		.filter(|finding| finding.severity == Severity::Error)
+		.count();
+	if errors > 0 {
+		return Err(format!("Found {errors} incompatibilities"))
+	}
+	Ok(())
+}
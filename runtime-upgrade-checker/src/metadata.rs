@@ -0,0 +1,216 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The parts of the runtime metadata that are relevant for compatibility.
+//!
+//! Types are reduced to their shape, i.e. the SCALE encoding they describe, so that renaming a type
+//! or moving it to another crate is not reported as a change.
+
+use codec::Decode;
+use frame_metadata::{
+	v14::{StorageEntryMetadata, StorageEntryType},
+	RuntimeMetadata, RuntimeMetadataPrefixed,
+};
+use scale_info::{form::PortableForm, Field, PortableRegistry, TypeDef};
+use std::collections::BTreeMap;
+
+/// Outer enums that are only referenced by name, their variants are compared per pallet.
+const OUTER_ENUMS: &[&str] = &["RuntimeCall", "RuntimeEvent"];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Metadata {
+	/// `spec_version` of the `System::Version` constant.
+	pub spec_version: Option<u32>,
+	/// `transaction_version` of the `System::Version` constant.
+	pub transaction_version: Option<u32>,
+	/// The pallets by name.
+	pub pallets: BTreeMap<String, Pallet>,
+	/// The identifier and the shape of every transaction extension, in order.
+	pub extensions: Vec<(String, String)>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Pallet {
+	/// The index of the pallet in `construct_runtime!`.
+	pub index: u8,
+	/// The calls by name.
+	pub calls: BTreeMap<String, Call>,
+	/// The shape of every storage item by name.
+	pub storage: BTreeMap<String, String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Call {
+	/// The index of the call within the pallet.
+	pub index: u8,
+	/// The shape of the arguments.
+	pub shape: String,
+}
+
+/// Converts the pallets and extrinsic metadata of V14 and V15, which share their structure.
+macro_rules! convert {
+	($metadata:expr) => {{
+		let metadata = $metadata;
+		let registry = &metadata.types;
+		let mut version = None;
+		let mut pallets = BTreeMap::new();
+		for pallet in &metadata.pallets {
+			if pallet.name == "System" {
+				version =
+					pallet.constants.iter().find(|constant| constant.name == "Version").and_then(
+						|constant| {
+							sp_version::RuntimeVersion::decode(&mut &constant.value[..]).ok()
+						},
+					);
+			}
+			let calls = match &pallet.calls {
+				Some(calls) => variants(registry, calls.ty.id)?,
+				None => BTreeMap::new(),
+			};
+			let storage = pallet
+				.storage
+				.iter()
+				.flat_map(|storage| &storage.entries)
+				.map(|entry| (entry.name.clone(), storage_shape(registry, entry)))
+				.collect();
+			pallets.insert(pallet.name.clone(), Pallet { index: pallet.index, calls, storage });
+		}
+		let extensions = metadata
+			.extrinsic
+			.signed_extensions
+			.iter()
+			.map(|extension| {
+				let shape = format!(
+					"{} / {}",
+					shape(registry, extension.ty.id),
+					shape(registry, extension.additional_signed.id)
+				);
+				(extension.identifier.clone(), shape)
+			})
+			.collect();
+		Metadata {
+			spec_version: version.as_ref().map(|version| version.spec_version),
+			transaction_version: version.as_ref().map(|version| version.transaction_version),
+			pallets,
+			extensions,
+		}
+	}};
+}
+
+impl Metadata {
+	/// Decodes SCALE encoded [`RuntimeMetadataPrefixed`] of version 14 or 15.
+	pub fn decode(bytes: &[u8]) -> Result<Self, String> {
+		let metadata = RuntimeMetadataPrefixed::decode(&mut &bytes[..])
+			.map_err(|e| format!("Invalid metadata: {e}"))?;
+		Ok(match metadata.1 {
+			RuntimeMetadata::V14(metadata) => convert!(metadata),
+			RuntimeMetadata::V15(metadata) => convert!(metadata),
+			other => return Err(format!("Metadata V{} is not supported", other.version())),
+		})
+	}
+}
+
+/// Returns the calls of the call enum `id` by name.
+fn variants(registry: &PortableRegistry, id: u32) -> Result<BTreeMap<String, Call>, String> {
+	let ty = registry.resolve(id).ok_or_else(|| format!("Unknown type {id}"))?;
+	let TypeDef::Variant(calls) = &ty.type_def else {
+		return Err(format!("Call type {id} is not an enum"))
+	};
+	Ok(calls
+		.variants
+		.iter()
+		.map(|call| {
+			let shape = fields(registry, &call.fields, &mut Vec::new());
+			(call.name.clone(), Call { index: call.index, shape })
+		})
+		.collect())
+}
+
+fn storage_shape(
+	registry: &PortableRegistry,
+	entry: &StorageEntryMetadata<PortableForm>,
+) -> String {
+	let ty = match &entry.ty {
+		StorageEntryType::Plain(value) => shape(registry, value.id),
+		StorageEntryType::Map { hashers, key, value } =>
+			format!("{hashers:?} {} => {}", shape(registry, key.id), shape(registry, value.id)),
+	};
+	format!("{:?} {ty}", entry.modifier)
+}
+
+/// Returns the shape of type `id`.
+pub fn shape(registry: &PortableRegistry, id: u32) -> String {
+	shape_inner(registry, id, &mut Vec::new())
+}
+
+fn shape_inner(registry: &PortableRegistry, id: u32, stack: &mut Vec<u32>) -> String {
+	let Some(ty) = registry.resolve(id) else { return format!("<unknown {id}>") };
+	let name = ty.path.segments.last().map(String::as_str);
+	// Recursive types are described by name when they are reached again.
+	if stack.contains(&id) || name.is_some_and(|name| OUTER_ENUMS.contains(&name)) {
+		return ty.path.segments.join("::")
+	}
+
+	stack.push(id);
+	let shape = match &ty.type_def {
+		TypeDef::Composite(composite) => fields(registry, &composite.fields, stack),
+		TypeDef::Variant(variant) => {
+			let variants = variant
+				.variants
+				.iter()
+				.map(|v| format!("{}#{}{}", v.name, v.index, fields(registry, &v.fields, stack)))
+				.collect::<Vec<_>>();
+			format!("enum {{ {} }}", variants.join(", "))
+		},
+		TypeDef::Sequence(sequence) =>
+			format!("Vec<{}>", shape_inner(registry, sequence.type_param.id, stack)),
+		TypeDef::Array(array) =>
+			format!("[{}; {}]", shape_inner(registry, array.type_param.id, stack), array.len),
+		TypeDef::Tuple(tuple) => {
+			let fields = tuple
+				.fields
+				.iter()
+				.map(|field| shape_inner(registry, field.id, stack))
+				.collect::<Vec<_>>();
+			format!("({})", fields.join(", "))
+		},
+		TypeDef::Primitive(primitive) => format!("{primitive:?}").to_lowercase(),
+		TypeDef::Compact(compact) =>
+			format!("Compact<{}>", shape_inner(registry, compact.type_param.id, stack)),
+		TypeDef::BitSequence(bits) => format!(
+			"BitVec<{}, {}>",
+			shape_inner(registry, bits.bit_store_type.id, stack),
+			shape_inner(registry, bits.bit_order_type.id, stack)
+		),
+	};
+	stack.pop();
+	shape
+}
+
+fn fields(
+	registry: &PortableRegistry,
+	fields: &[Field<PortableForm>],
+	stack: &mut Vec<u32>,
+) -> String {
+	let fields = fields
+		.iter()
+		.map(|field| match &field.name {
+			Some(name) => format!("{name}: {}", shape_inner(registry, field.ty.id, stack)),
+			None => shape_inner(registry, field.ty.id, stack),
+		})
+		.collect::<Vec<_>>();
+	format!("({})", fields.join(", "))
+}
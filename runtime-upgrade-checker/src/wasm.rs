@@ -0,0 +1,105 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Execution of runtime WASM blobs.
+
+use codec::{Decode, Encode};
+use sc_chain_spec::GenesisConfigBuilderRuntimeCaller;
+use sc_executor::WasmExecutor;
+use sp_core::{
+	hashing::{blake2_256, twox_128},
+	storage::{well_known_keys, Storage},
+	traits::{CallContext, CodeExecutor, RuntimeCode, WrappedRuntimeCode},
+};
+use sp_runtime::{generic::Header, traits::BlakeTwo256};
+use sp_state_machine::BasicExternalities;
+use std::collections::BTreeMap;
+
+/// Host functions of parachain runtimes in addition to the default ones.
+type ExtraHostFunctions =
+	cumulus_primitives_proof_size_hostfunction::storage_proof_size::HostFunctions;
+
+pub struct Wasm {
+	code: Vec<u8>,
+	executor: WasmExecutor<(sp_io::SubstrateHostFunctions, ExtraHostFunctions)>,
+}
+
+impl Wasm {
+	pub fn new(code: Vec<u8>) -> Self {
+		let executor = WasmExecutor::builder().with_allow_missing_host_functions(true).build();
+		Wasm { code, executor }
+	}
+
+	/// Returns the SCALE encoded metadata of the runtime.
+	pub fn metadata(&self) -> Result<Vec<u8>, String> {
+		let output = self.call(&mut BasicExternalities::default(), "Metadata_metadata", &[])?;
+		Vec::<u8>::decode(&mut &output[..]).map_err(|e| format!("Invalid metadata: {e}"))
+	}
+
+	/// Returns the genesis storage of the default genesis config.
+	pub fn genesis(&self) -> Result<Storage, String> {
+		GenesisConfigBuilderRuntimeCaller::<ExtraHostFunctions>::new(&self.code)
+			.get_storage_for_patch(serde_json::json!({}))
+	}
+
+	/// Upgrades `state` to this runtime by initializing a block, which runs the runtime upgrade
+	/// hooks and thus the `Migrations` of the runtime.
+	pub fn upgrade(&self, state: Storage) -> Result<Storage, String> {
+		let mut ext = BasicExternalities::new(state);
+		ext.insert(well_known_keys::CODE.to_vec(), self.code.clone());
+		let header = Header::<u32, BlakeTwo256>::new(
+			1,
+			Default::default(),
+			Default::default(),
+			Default::default(),
+			Default::default(),
+		);
+		self.call(&mut ext, "Core_initialize_block", &header.encode())?;
+		Ok(ext.into_storages())
+	}
+
+	fn call(
+		&self,
+		ext: &mut BasicExternalities,
+		method: &str,
+		data: &[u8],
+	) -> Result<Vec<u8>, String> {
+		let runtime_code = RuntimeCode {
+			code_fetcher: &WrappedRuntimeCode(self.code.as_slice().into()),
+			heap_pages: None,
+			hash: blake2_256(&self.code).to_vec(),
+		};
+		self.executor
+			.call(ext, &runtime_code, method, data, CallContext::Offchain)
+			.0
+			.map_err(|e| format!("Calling `{method}` failed: {e}"))
+	}
+}
+
+/// Returns the `StorageVersion` in `storage` of every pallet in `pallets` that has one.
+pub fn storage_versions<'a>(
+	storage: &Storage,
+	pallets: impl IntoIterator<Item = &'a String>,
+) -> BTreeMap<String, u16> {
+	pallets
+		.into_iter()
+		.filter_map(|pallet| {
+			let key = [twox_128(pallet.as_bytes()), twox_128(b":__STORAGE_VERSION__:")].concat();
+			let version = u16::decode(&mut &storage.top.get(&key)?[..]).ok()?;
+			Some((pallet.clone(), version))
+		})
+		.collect()
+}
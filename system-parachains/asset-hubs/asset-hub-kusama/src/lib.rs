@@ -1209,20 +1209,25 @@ impl
 		Some(ProxyDefinition {
 			delegate: a.delegate,
 			proxy_type,
-			// Delays are currently not supported by the remote proxy pallet, but should be
-			// converted in the future to the block time used by the local proxy pallet.
+			// The delay is in Relay Chain blocks, which is also what the remote proxy pallet uses
+			// for announcements.
 			delay: a.delay,
 		})
 	}
 }
 
 impl pallet_remote_proxy::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
 	// The time between creating a proof and using the proof in a transaction.
 	type MaxStorageRootsToKeep = ConstU32<{ RC_MINUTES }>;
 	type RemoteProxy = kusama_runtime_constants::proxy::RemoteProxyInterface<
 		ProxyType,
 		RelayChainToLocalProxyTypeConverter,
 	>;
+	type AnnouncementBlockNumberProvider = RelaychainDataProvider<Runtime>;
+	type MultisigDepositBase = DepositBase;
+	type MultisigDepositFactor = DepositFactor;
+	type MaxSignatories = MaxSignatories;
 	type WeightInfo = weights::pallet_remote_proxy::WeightInfo<Runtime>;
 }
 
@@ -1371,6 +1376,51 @@ parameter_types! {
 	pub MigrationEndBlock: BlockNumberFor<Runtime> = 11_151_931u32;
 }
 
+/// Converts the hold reasons of the Relay Chain into the local hold reasons.
+///
+/// The session keys and the state trie migration are not migrated, their holds are received as
+/// reserve without a reason.
+pub struct PortableToAhHoldReason;
+impl
+	sp_runtime::traits::Convert<
+		pallet_rc_migrator::types::PortableHoldReason,
+		Option<RuntimeHoldReason>,
+	> for PortableToAhHoldReason
+{
+	fn convert(reason: pallet_rc_migrator::types::PortableHoldReason) -> Option<RuntimeHoldReason> {
+		use pallet_rc_migrator::types::PortableHoldReason;
+
+		match reason {
+			PortableHoldReason::Preimage(r) => Some(RuntimeHoldReason::Preimage(r)),
+			PortableHoldReason::Staking(_) =>
+				Some(RuntimeHoldReason::Staking(pallet_staking_async::HoldReason::Staking)),
+			PortableHoldReason::DelegatedStaking(r) => Some(RuntimeHoldReason::DelegatedStaking(r)),
+			PortableHoldReason::XcmPallet(r) => Some(RuntimeHoldReason::PolkadotXcm(r)),
+			PortableHoldReason::Session(_) | PortableHoldReason::StateTrieMigration(_) => None,
+		}
+	}
+}
+
+/// Converts the freeze reasons of the Relay Chain into the local freeze reasons.
+pub struct PortableToAhFreezeReason;
+impl
+	sp_runtime::traits::Convert<
+		pallet_rc_migrator::types::PortableFreezeReason,
+		Option<RuntimeFreezeReason>,
+	> for PortableToAhFreezeReason
+{
+	fn convert(
+		reason: pallet_rc_migrator::types::PortableFreezeReason,
+	) -> Option<RuntimeFreezeReason> {
+		use pallet_rc_migrator::types::PortableFreezeReason;
+
+		match reason {
+			PortableFreezeReason::NominationPools(r) =>
+				Some(RuntimeFreezeReason::NominationPools(r)),
+		}
+	}
+}
+
 impl pallet_ah_ops::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
@@ -1385,6 +1435,17 @@ impl pallet_ah_ops::Config for Runtime {
 	type AssetId = Location;
 	type RelevantAssets = ();
 	type MigrateOrigin = EnsureNone<AccountId>;
+	type MaxBatchSize = ConstU32<64>;
+	type PortableToAhHoldReason = PortableToAhHoldReason;
+	type PortableToAhFreezeReason = PortableToAhFreezeReason;
+	type AccountTranslator = (
+		pallet_ah_ops::translate::VestingTranslator<Runtime>,
+		pallet_ah_ops::translate::ProxyTranslator<Runtime>,
+		pallet_ah_ops::translate::MultisigTranslator<Runtime>,
+		pallet_ah_ops::translate::PoolMemberTranslator<Runtime>,
+		pallet_ah_ops::translate::UniquesTranslator<Runtime>,
+		pallet_ah_ops::translate::NftsTranslator<Runtime>,
+	);
 }
 
 parameter_types! {
@@ -2748,6 +2809,12 @@ pallet_revive::impl_runtime_apis_plus_revive_traits!(
 		}
 	}
 
+	impl pallet_ah_ops::runtime_api::AhOpsApi<Block, AccountId, BlockNumber, Balance> for Runtime {
+		fn claimable_funds(who: AccountId) -> Vec<pallet_ah_ops::Claim<BlockNumber, Balance>> {
+			AhOps::claimable_funds(who)
+		}
+	}
+
 	impl cumulus_primitives_core::GetParachainInfo<Block> for Runtime {
 		fn parachain_id() -> ParaId {
 			ParachainInfo::parachain_id()
@@ -21,6 +21,14 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `e9bbf6afad60`, CPU: `QEMU Virtual CPU version 2.5+`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: 1024
+//!
+//! The weights of `remote_announce`, `remote_remove_announcement`, `remote_reject_announcement` and
+//! `remote_proxy_announced` are not generated by the benchmarks yet. They are estimates that must
+//! be replaced by running the benchmarks of the pallet.
+//!
+//! The weights of `remote_multisig_approve`, `remote_multisig_as_proxy` and
+//! `remote_multisig_cancel` are not generated by the benchmarks yet. They are estimates that must
+//! be replaced by running the benchmarks of the pallet.
 
 // Executed Command:
 // frame-omni-bencher
@@ -81,4 +89,123 @@ impl<T: frame_system::Config> pallet_remote_proxy::WeightInfo for WeightInfo<T>
 			.saturating_add(Weight::from_parts(0, 1846))
 			.saturating_add(T::DbWeight::get().reads(1))
 	}
+	/// Storage: `RemoteProxyRelayChain::BlockToRoot` (r:1 w:0)
+	/// Proof: `RemoteProxyRelayChain::BlockToRoot` (`max_values`: Some(1), `max_size`: Some(361), added: 856, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainSystem::LastRelayChainBlockNumber` (r:1 w:0)
+	/// Proof: `ParachainSystem::LastRelayChainBlockNumber` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `RemoteProxyRelayChain::Announcements` (r:1 w:1)
+	/// Proof: `RemoteProxyRelayChain::Announcements` (`max_values`: None, `max_size`: Some(2233), added: 4708, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn remote_announce() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `312`
+		//  Estimated: `5698`
+		// Minimum execution time: 41_210_000 picoseconds.
+		Weight::from_parts(43_870_000, 0)
+			.saturating_add(Weight::from_parts(0, 5698))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `RemoteProxyRelayChain::Announcements` (r:1 w:1)
+	/// Proof: `RemoteProxyRelayChain::Announcements` (`max_values`: None, `max_size`: Some(2233), added: 4708, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn remote_remove_announcement() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `383`
+		//  Estimated: `5698`
+		// Minimum execution time: 24_630_000 picoseconds.
+		Weight::from_parts(26_090_000, 0)
+			.saturating_add(Weight::from_parts(0, 5698))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `RemoteProxyRelayChain::Announcements` (r:1 w:1)
+	/// Proof: `RemoteProxyRelayChain::Announcements` (`max_values`: None, `max_size`: Some(2233), added: 4708, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn remote_reject_announcement() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `383`
+		//  Estimated: `5698`
+		// Minimum execution time: 24_380_000 picoseconds.
+		Weight::from_parts(25_920_000, 0)
+			.saturating_add(Weight::from_parts(0, 5698))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `RemoteProxyRelayChain::BlockToRoot` (r:1 w:0)
+	/// Proof: `RemoteProxyRelayChain::BlockToRoot` (`max_values`: Some(1), `max_size`: Some(361), added: 856, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainSystem::LastRelayChainBlockNumber` (r:1 w:0)
+	/// Proof: `ParachainSystem::LastRelayChainBlockNumber` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `RemoteProxyRelayChain::Announcements` (r:1 w:1)
+	/// Proof: `RemoteProxyRelayChain::Announcements` (`max_values`: None, `max_size`: Some(2233), added: 4708, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn remote_proxy_announced() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `383`
+		//  Estimated: `5698`
+		// Minimum execution time: 45_770_000 picoseconds.
+		Weight::from_parts(48_310_000, 0)
+			.saturating_add(Weight::from_parts(0, 5698))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `RemoteProxyRelayChain::BlockToRoot` (r:1 w:0)
+	/// Proof: `RemoteProxyRelayChain::BlockToRoot` (`max_values`: Some(1), `max_size`: Some(361), added: 856, mode: `MaxEncodedLen`)
+	/// Storage: `RemoteProxyRelayChain::RemoteMultisigs` (r:1 w:1)
+	/// Proof: `RemoteProxyRelayChain::RemoteMultisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 99]`.
+	fn remote_multisig_approve(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `292`
+		//  Estimated: `6811`
+		// Minimum execution time: 52_114_000 picoseconds.
+		Weight::from_parts(54_208_331, 0)
+			.saturating_add(Weight::from_parts(0, 6811))
+			// Standard Error: 1_327
+			.saturating_add(Weight::from_parts(121_406, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `RemoteProxyRelayChain::BlockToRoot` (r:1 w:0)
+	/// Proof: `RemoteProxyRelayChain::BlockToRoot` (`max_values`: Some(1), `max_size`: Some(361), added: 856, mode: `MaxEncodedLen`)
+	/// Storage: `RemoteProxyRelayChain::RemoteMultisigs` (r:1 w:1)
+	/// Proof: `RemoteProxyRelayChain::RemoteMultisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 99]`.
+	fn remote_multisig_as_proxy(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `421 + s * (32 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 61_870_000 picoseconds.
+		Weight::from_parts(64_512_907, 0)
+			.saturating_add(Weight::from_parts(0, 6811))
+			// Standard Error: 1_519
+			.saturating_add(Weight::from_parts(158_224, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `RemoteProxyRelayChain::RemoteMultisigs` (r:1 w:1)
+	/// Proof: `RemoteProxyRelayChain::RemoteMultisigs` (`max_values`: None, `max_size`: Some(3346), added: 5821, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1, 99]`.
+	fn remote_multisig_cancel(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `389 + s * (1 ±0)`
+		//  Estimated: `6811`
+		// Minimum execution time: 30_442_000 picoseconds.
+		Weight::from_parts(32_096_518, 0)
+			.saturating_add(Weight::from_parts(0, 6811))
+			// Standard Error: 842
+			.saturating_add(Weight::from_parts(98_611, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 }
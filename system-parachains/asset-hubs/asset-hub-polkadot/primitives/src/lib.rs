@@ -43,6 +43,9 @@ pub enum Call {
 	/// `ToKusamaXcmRouter` bridge pallet.
 	#[codec(index = 34)]
 	ToKusamaXcmRouter(XcmBridgeHubRouterCall),
+	/// `ToEthereumXcmRouter` bridge pallet.
+	#[codec(index = 37)]
+	ToEthereumXcmRouter(XcmBridgeHubRouterCall),
 }
 
 frame_support::parameter_types! {
@@ -61,17 +64,33 @@ pub fn build_congestion_message<RuntimeCall>(
 	bridge_id: sp_core::H256,
 	is_congested: bool,
 ) -> Vec<Instruction<RuntimeCall>> {
+	build_router_status_message(Call::ToKusamaXcmRouter(
+		XcmBridgeHubRouterCall::report_bridge_status { bridge_id, is_congested },
+	))
+}
+
+/// Builds an (un)congestion XCM program with the `report_bridge_status` call for
+/// `ToEthereumXcmRouter`.
+///
+/// There is a single Snowbridge outbound queue, so the router ignores the bridge id.
+pub fn build_ethereum_congestion_message<RuntimeCall>(
+	is_congested: bool,
+) -> Vec<Instruction<RuntimeCall>> {
+	build_router_status_message(Call::ToEthereumXcmRouter(
+		XcmBridgeHubRouterCall::report_bridge_status {
+			bridge_id: Default::default(),
+			is_congested,
+		},
+	))
+}
+
+fn build_router_status_message<RuntimeCall>(call: Call) -> Vec<Instruction<RuntimeCall>> {
 	alloc::vec![
 		UnpaidExecution { weight_limit: Unlimited, check_origin: None },
 		Transact {
 			origin_kind: OriginKind::Xcm,
 			fallback_max_weight: Some(XcmBridgeHubRouterTransactCallMaxWeight::get()),
-			call: Call::ToKusamaXcmRouter(XcmBridgeHubRouterCall::report_bridge_status {
-				bridge_id,
-				is_congested,
-			})
-			.encode()
-			.into(),
+			call: call.encode().into(),
 		},
 		ExpectTransactStatus(MaybeErrorCode::Success),
 	]
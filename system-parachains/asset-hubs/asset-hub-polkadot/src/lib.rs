@@ -68,6 +68,7 @@ pub mod bridge_to_ethereum_config;
 pub mod genesis_config_presets;
 pub mod governance;
 pub mod migrations;
+pub mod remote_coretime;
 #[cfg(all(test, feature = "try-runtime"))]
 mod remote_tests;
 pub mod staking;
@@ -1094,6 +1095,36 @@ impl pallet_xcm_bridge_hub_router::Config<ToKusamaXcmRouterInstance> for Runtime
 	type UnpaidExport = frame_support::traits::ConstBool<true>;
 }
 
+/// XCM router instance to BridgeHub with bridging capabilities for `Ethereum` global
+/// consensus with dynamic fees and back-pressure.
+///
+/// Only Snowbridge V2 messages are routed through this instance. The BridgeHub reports the
+/// congestion of its Ethereum outbound queue here, which raises the delivery fee factor.
+pub type ToEthereumXcmRouterInstance = pallet_xcm_bridge_hub_router::Instance2;
+impl pallet_xcm_bridge_hub_router::Config<ToEthereumXcmRouterInstance> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	// The `send_message` benchmark can't produce a Snowbridge V2 message, so this instance
+	// reuses the weights of `ToKusamaXcmRouterInstance`, which runs the same code.
+	type WeightInfo = weights::pallet_xcm_bridge_hub_router::WeightInfo<Runtime>;
+
+	type UniversalLocation = xcm_config::UniversalLocation;
+	type BridgedNetworkId = xcm_config::bridging::to_ethereum::EthereumNetwork;
+	type Bridges = xcm_config::bridging::to_ethereum::EthereumNetworkExportTableV2;
+	type DestinationVersion = xcm_config::bridging::to_ethereum::EthereumDestinationVersion;
+
+	type SiblingBridgeHubLocation = xcm_config::bridging::SiblingBridgeHub;
+	type BridgeHubOrigin =
+		EitherOfDiverse<EnsureRoot<AccountId>, EnsureXcm<Equals<Self::SiblingBridgeHubLocation>>>;
+	type ToBridgeHubSender = XcmpQueue;
+
+	// The whole Ethereum delivery cost is covered by `BridgeHubEthereumBaseFeeV2`.
+	type ByteFee = ConstU128<0>;
+	type FeeAsset = xcm_config::bridging::XcmBridgeHubRouterFeeAssetId;
+	type LocalXcmChannelManager =
+		cumulus_pallet_xcmp_queue::bridging::InAndOutXcmpChannelStatusProvider<Runtime>;
+	type UnpaidExport = frame_support::traits::ConstBool<true>;
+}
+
 pub type PoolAssetsInstance = pallet_assets::Instance3;
 impl pallet_assets::Config<PoolAssetsInstance> for Runtime {
 	type RuntimeEvent = RuntimeEvent;
@@ -1423,6 +1454,51 @@ parameter_types! {
 	];
 }
 
+/// Converts the hold reasons of the Relay Chain into the local hold reasons.
+///
+/// The session keys and the state trie migration are not migrated, their holds are received as
+/// reserve without a reason.
+pub struct PortableToAhHoldReason;
+impl
+	sp_runtime::traits::Convert<
+		pallet_rc_migrator::types::PortableHoldReason,
+		Option<RuntimeHoldReason>,
+	> for PortableToAhHoldReason
+{
+	fn convert(reason: pallet_rc_migrator::types::PortableHoldReason) -> Option<RuntimeHoldReason> {
+		use pallet_rc_migrator::types::PortableHoldReason;
+
+		match reason {
+			PortableHoldReason::Preimage(r) => Some(RuntimeHoldReason::Preimage(r)),
+			PortableHoldReason::Staking(_) =>
+				Some(RuntimeHoldReason::Staking(pallet_staking_async::HoldReason::Staking)),
+			PortableHoldReason::DelegatedStaking(r) => Some(RuntimeHoldReason::DelegatedStaking(r)),
+			PortableHoldReason::XcmPallet(r) => Some(RuntimeHoldReason::PolkadotXcm(r)),
+			PortableHoldReason::Session(_) | PortableHoldReason::StateTrieMigration(_) => None,
+		}
+	}
+}
+
+/// Converts the freeze reasons of the Relay Chain into the local freeze reasons.
+pub struct PortableToAhFreezeReason;
+impl
+	sp_runtime::traits::Convert<
+		pallet_rc_migrator::types::PortableFreezeReason,
+		Option<RuntimeFreezeReason>,
+	> for PortableToAhFreezeReason
+{
+	fn convert(
+		reason: pallet_rc_migrator::types::PortableFreezeReason,
+	) -> Option<RuntimeFreezeReason> {
+		use pallet_rc_migrator::types::PortableFreezeReason;
+
+		match reason {
+			PortableFreezeReason::NominationPools(r) =>
+				Some(RuntimeFreezeReason::NominationPools(r)),
+		}
+	}
+}
+
 impl pallet_ah_ops::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
@@ -1438,6 +1514,17 @@ impl pallet_ah_ops::Config for Runtime {
 	type TreasuryPostMigrationAccount = xcm_config::PostMigrationTreasuryAccount;
 	type MigrationStartBlock = MigrationStartBlock;
 	type MigrationEndBlock = MigrationEndBlock;
+	type MaxBatchSize = ConstU32<64>;
+	type PortableToAhHoldReason = PortableToAhHoldReason;
+	type PortableToAhFreezeReason = PortableToAhFreezeReason;
+	type AccountTranslator = (
+		pallet_ah_ops::translate::VestingTranslator<Runtime>,
+		pallet_ah_ops::translate::ProxyTranslator<Runtime>,
+		pallet_ah_ops::translate::MultisigTranslator<Runtime>,
+		pallet_ah_ops::translate::PoolMemberTranslator<Runtime>,
+		pallet_ah_ops::translate::UniquesTranslator<Runtime>,
+		pallet_ah_ops::translate::NftsTranslator<Runtime>,
+	);
 }
 
 parameter_types! {
@@ -1550,6 +1637,8 @@ construct_runtime!(
 		ToKusamaXcmRouter: pallet_xcm_bridge_hub_router::<Instance1> = 34,
 		MessageQueue: pallet_message_queue = 35,
 		SnowbridgeSystemFrontend: snowbridge_pallet_system_frontend = 36,
+		ToEthereumXcmRouter: pallet_xcm_bridge_hub_router::<Instance2> = 37,
+		RemoteCoretime: pallet_remote_coretime = 38,
 
 		// Handy utilities.
 		Utility: pallet_utility = 40,
@@ -1793,6 +1882,7 @@ mod benches {
 
 		// XCM
 		[pallet_xcm, PalletXcmExtrinsicsBenchmark::<Runtime>]
+		[pallet_remote_coretime, RemoteCoretime]
 		// Bridges
 		[pallet_xcm_bridge_hub_router, ToKusama]
 		[snowbridge_pallet_system_frontend, SnowbridgeSystemFrontend]
@@ -2605,6 +2695,12 @@ pallet_revive::impl_runtime_apis_plus_revive_traits!(
 		}
 	}
 
+	impl pallet_ah_ops::runtime_api::AhOpsApi<Block, AccountId, BlockNumber, Balance> for Runtime {
+		fn claimable_funds(who: AccountId) -> Vec<pallet_ah_ops::Claim<BlockNumber, Balance>> {
+			AhOps::claimable_funds(who)
+		}
+	}
+
 	impl pallet_asset_conversion::AssetConversionApi<Block, Balance, Location> for Runtime {
 		fn quote_price_exact_tokens_for_tokens(
 			asset1: Location,
@@ -0,0 +1,188 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Purchase and renewal of coretime on the Coretime chain from Asset Hub.
+
+use crate::{xcm_config::RelayNetwork, *};
+use alloc::boxed::Box;
+use frame_support::{traits::tokens::PaymentStatus, BoundedVec};
+use pallet_remote_coretime::{CoretimeOrder, SendOrder};
+use polkadot_runtime_constants::system_parachain::{ASSET_HUB_ID, BROKER_ID};
+use sp_runtime::DispatchError;
+use xcm_executor::traits::{QueryHandler, QueryResponseStatus};
+
+parameter_types! {
+	/// The location of the Coretime chain.
+	pub CoretimeLocation: Location = Location::new(1, Parachain(BROKER_ID));
+	/// The fees that are teleported along with an order to execute it on the Coretime chain.
+	pub const CoretimeOrderFee: Balance = UNITS / 10;
+	/// The number of blocks after which the outcome of an order isn't expected anymore.
+	pub const CoretimeOrderTimeout: BlockNumber = 2 * HOURS;
+}
+
+/// The pallet indices of the calls needed from the `construct_runtime` of the Coretime chain.
+#[derive(Encode, Decode)]
+enum CoretimeRuntimePallets {
+	#[codec(index = 52)]
+	RegionMarket(RegionMarketCalls),
+}
+
+/// Call encoding for the calls needed from the region market pallet of the Coretime chain.
+#[derive(Encode, Decode)]
+enum RegionMarketCalls {
+	#[codec(index = 10)]
+	PurchaseAndAssign { price_limit: Balance, task: u32 },
+	#[codec(index = 11)]
+	RenewWithLimit { core: u16, price_limit: Balance },
+}
+
+/// Sends coretime orders to the Coretime chain as one XCM program.
+///
+/// The program is executed locally with the origin of the sender. It teleports the maximum price
+/// and [`CoretimeOrderFee`] to the Coretime chain, which aliases the origin to the same account
+/// there. The funds are deposited into that account, the order is dispatched from it by the region
+/// market pallet and the outcome of the dispatch is reported back as the response to a query of
+/// `pallet_xcm`. If the order fails, the maximum price is withdrawn from the account again and
+/// teleported back to the sender on Asset Hub, with the delivery paid from the unused fees. If it
+/// succeeds, the region market of the Coretime chain teleports the part of the maximum price that
+/// the order didn't cost back to the sender and the appendix teleports the unused fees. Either way,
+/// nothing of the payment stays on the Coretime chain.
+pub struct CoretimeOrderOverXcm;
+impl SendOrder<AccountId, Balance, BlockNumber> for CoretimeOrderOverXcm {
+	type Id = QueryId;
+
+	fn send_order(
+		who: &AccountId,
+		order: CoretimeOrder,
+		max_price: Balance,
+		expires_at: BlockNumber,
+	) -> Result<QueryId, DispatchError> {
+		let account = AccountId32 { network: RelayNetwork::get(), id: who.clone().into() };
+		// The sender on the Coretime chain, which is the querier of the reported outcome.
+		let querier = Location::new(1, [Parachain(BROKER_ID), account]);
+		let query_id =
+			<PolkadotXcm as QueryHandler>::new_query(CoretimeLocation::get(), expires_at, querier);
+		let account = Location::new(0, [account]);
+
+		let call = match order {
+			CoretimeOrder::Purchase { para_id } =>
+				RegionMarketCalls::PurchaseAndAssign { price_limit: max_price, task: para_id },
+			CoretimeOrder::Renew { core } =>
+				RegionMarketCalls::RenewWithLimit { core, price_limit: max_price },
+		};
+		let asset_hub = Location::new(1, Parachain(ASSET_HUB_ID));
+		let payment: Asset = (Location::parent(), max_price).into();
+		let remote_xcm = Xcm(vec![
+			AliasOrigin(account.clone()),
+			// Teleports what is left in the holding register back to the sender on Asset Hub.
+			SetAppendix(Xcm(vec![
+				InitiateTransfer {
+					destination: asset_hub.clone(),
+					remote_fees: Some(AssetTransferFilter::Teleport(Wild(AllCounted(1)))),
+					preserve_origin: false,
+					assets: BoundedVec::new(),
+					remote_xcm: Xcm(vec![
+						RefundSurplus,
+						DepositAsset { assets: Wild(AllCounted(1)), beneficiary: account.clone() },
+					]),
+				},
+				// Only the fees that weren't used for the delivery of a failed order are left.
+				RefundSurplus,
+				DepositAsset { assets: Wild(AllCounted(1)), beneficiary: account.clone() },
+			])),
+			DepositAsset { assets: Wild(AllCounted(1)), beneficiary: account },
+			// A failed order is rolled back, so the payment is still in the account.
+			SetErrorHandler(Xcm(vec![WithdrawAsset(payment.into())])),
+			Transact {
+				origin_kind: OriginKind::SovereignAccount,
+				fallback_max_weight: None,
+				call: CoretimeRuntimePallets::RegionMarket(call).encode().into(),
+			},
+			ReportTransactStatus(QueryResponseInfo {
+				destination: asset_hub,
+				query_id,
+				max_weight: Weight::zero(),
+			}),
+			ExpectTransactStatus(MaybeErrorCode::Success),
+			// The unused part of the payment of a successful order was returned by the region
+			// market, the unused fees are teleported back by the appendix, which pays the delivery
+			// from them.
+			RefundSurplus,
+		]);
+
+		let fee: Asset = (DotLocation::get(), CoretimeOrderFee::get()).into();
+		let message = Xcm::<RuntimeCall>(vec![
+			WithdrawAsset(
+				(DotLocation::get(), max_price.saturating_add(CoretimeOrderFee::get())).into(),
+			),
+			SetFeesMode { jit_withdraw: true },
+			InitiateTransfer {
+				destination: CoretimeLocation::get(),
+				remote_fees: Some(AssetTransferFilter::Teleport(Definite(fee.into()))),
+				preserve_origin: true,
+				assets: BoundedVec::truncate_from(vec![AssetTransferFilter::Teleport(Wild(
+					AllCounted(1),
+				))]),
+				remote_xcm,
+			},
+		]);
+		PolkadotXcm::execute(
+			RuntimeOrigin::signed(who.clone()),
+			Box::new(VersionedXcm::from(message)),
+			// The weight of the program is part of the weight of the order.
+			Weight::MAX,
+		)
+		.map_err(|e| e.error)?;
+
+		Ok(query_id)
+	}
+
+	fn check_order(id: QueryId) -> PaymentStatus {
+		match <PolkadotXcm as QueryHandler>::take_response(id) {
+			QueryResponseStatus::Ready { response: Response::DispatchResult(result), .. } =>
+				match result {
+					MaybeErrorCode::Success => PaymentStatus::Success,
+					_ => PaymentStatus::Failure,
+				},
+			// `pallet_remote_coretime` concludes the order as unknown once it expired.
+			QueryResponseStatus::Pending { .. } => PaymentStatus::InProgress,
+			_ => PaymentStatus::Unknown,
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn ensure_successful(who: &AccountId, max_price: Balance) {
+		use frame_support::traits::fungible::Mutate;
+
+		Balances::set_balance(who, max_price.saturating_add(10 * UNITS));
+		ParachainSystem::open_outbound_hrmp_channel_for_benchmarks_or_tests(BROKER_ID.into());
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn ensure_concluded(id: QueryId) {
+		<PolkadotXcm as QueryHandler>::expect_response(
+			id,
+			Response::DispatchResult(MaybeErrorCode::Success),
+		);
+	}
+}
+
+impl pallet_remote_coretime::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type Orders = CoretimeOrderOverXcm;
+	type OrderTimeout = CoretimeOrderTimeout;
+	type WeightInfo = weights::pallet_remote_coretime::WeightInfo<Runtime>;
+}
@@ -51,6 +51,7 @@ pub mod pallet_parameters;
 pub mod pallet_preimage;
 pub mod pallet_proxy;
 pub mod pallet_referenda;
+pub mod pallet_remote_coretime;
 pub mod pallet_revive;
 pub mod pallet_scheduler;
 pub mod pallet_session;
@@ -21,7 +21,7 @@ use super::{
 	Balance, Balances, DotWeightToFee as WeightToFee, FellowshipAdmin, ForeignAssets, GeneralAdmin,
 	NativeAndAssets, ParachainInfo, ParachainSystem, PolkadotXcm, PoolAssets,
 	PriceForParentDelivery, Runtime, RuntimeCall, RuntimeEvent, RuntimeHoldReason, RuntimeOrigin,
-	StakingAdmin, ToKusamaXcmRouter, Treasurer, XcmpQueue,
+	StakingAdmin, ToEthereumXcmRouter, ToKusamaXcmRouter, Treasurer, XcmpQueue,
 };
 use alloc::{collections::BTreeSet, vec, vec::Vec};
 use assets_common::{
@@ -586,15 +586,14 @@ pub type XcmRouter = WithUniqueTopic<(
 	// GlobalConsensus
 	ToKusamaXcmRouter,
 	// Router which wraps and sends xcm to BridgeHub to be delivered to the Ethereum
-	// GlobalConsensus
+	// GlobalConsensus. Snowbridge V2 messages go through `ToEthereumXcmRouter`, which applies
+	// the congestion fee factor reported by the BridgeHub.
 	PausableExporter<
 		crate::SnowbridgeSystemFrontend,
 		(
+			ToEthereumXcmRouter,
 			UnpaidRemoteExporter<
-				(
-					bridging::to_ethereum::EthereumNetworkExportTableV2,
-					bridging::to_ethereum::EthereumNetworkExportTableV1,
-				),
+				bridging::to_ethereum::EthereumNetworkExportTableV1,
 				XcmpQueue,
 				UniversalLocation,
 			>,
@@ -861,6 +860,17 @@ pub mod bridging {
 				snowbridge_outbound_queue_primitives::v2::XcmForSnowbridgeV2,
 			>;
 
+		/// XCM version of the Ethereum destination, used by `ToEthereumXcmRouter`.
+		///
+		/// Messages to Ethereum are converted into Snowbridge commands at the BridgeHub, so there
+		/// is no remote XCM version to negotiate and the latest one is always used.
+		pub struct EthereumDestinationVersion;
+		impl xcm::GetVersion for EthereumDestinationVersion {
+			fn get_version_for(_dest: &Location) -> Option<xcm::Version> {
+				Some(XCM_VERSION)
+			}
+		}
+
 		pub type EthereumAssetFromEthereum =
 			IsForeignConcreteAsset<FromNetwork<UniversalLocation, EthereumNetwork>>;
 
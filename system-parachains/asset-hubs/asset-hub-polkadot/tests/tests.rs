@@ -25,7 +25,8 @@ use asset_hub_polkadot_runtime::{
 	AllPalletsWithoutSystem, AssetDeposit, Assets, Balances, Block, Dap, ExistentialDeposit,
 	ForeignAssets, ForeignAssetsInstance, MetadataDepositBase, MetadataDepositPerByte,
 	ParachainSystem, PolkadotXcm, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, SessionKeys,
-	ToKusamaXcmRouterInstance, TrustBackedAssetsInstance, XcmpQueue, SLOT_DURATION,
+	ToEthereumXcmRouterInstance, ToKusamaXcmRouterInstance, TrustBackedAssetsInstance, XcmpQueue,
+	SLOT_DURATION,
 };
 use asset_test_utils::{
 	include_create_and_manage_foreign_assets_for_local_consensus_parachain_assets_works,
@@ -543,6 +544,52 @@ fn test_report_bridge_status_call_compatibility() {
 	)
 }
 
+fn bridging_to_ethereum() -> TestBridgingConfig {
+	TestBridgingConfig {
+		bridged_network: bridging::to_ethereum::EthereumNetwork::get(),
+		local_bridge_hub_para_id: bridging::SiblingBridgeHubParaId::get(),
+		local_bridge_hub_location: bridging::SiblingBridgeHub::get(),
+		bridged_target_location: bridging::to_ethereum::EthereumLocation::get(),
+	}
+}
+
+#[test]
+fn report_bridge_status_from_xcm_bridge_router_for_ethereum_works() {
+	asset_test_utils::test_cases_over_bridge::report_bridge_status_from_xcm_bridge_router_works::<
+		Runtime,
+		AllPalletsWithoutSystem,
+		XcmConfig,
+		LocationToAccountId,
+		ToEthereumXcmRouterInstance,
+	>(
+		collator_session_keys(),
+		bridging_to_ethereum,
+		|| bp_asset_hub_polkadot::build_ethereum_congestion_message(true).into(),
+		|| bp_asset_hub_polkadot::build_ethereum_congestion_message(false).into(),
+	)
+}
+
+#[test]
+fn test_report_ethereum_bridge_status_call_compatibility() {
+	// if this test fails, make sure `bp_asset_hub_polkadot` has valid encoding
+	assert_eq!(
+		RuntimeCall::ToEthereumXcmRouter(
+			pallet_xcm_bridge_hub_router::Call::report_bridge_status {
+				bridge_id: Default::default(),
+				is_congested: true,
+			}
+		)
+		.encode(),
+		bp_asset_hub_polkadot::Call::ToEthereumXcmRouter(
+			bp_asset_hub_polkadot::XcmBridgeHubRouterCall::report_bridge_status {
+				bridge_id: Default::default(),
+				is_congested: true,
+			}
+		)
+		.encode()
+	)
+}
+
 #[test]
 fn check_sane_weight_report_bridge_status() {
 	use pallet_xcm_bridge_hub_router::WeightInfo;
@@ -24,7 +24,8 @@ extern crate alloc;
 pub use bp_bridge_hub_cumulus::*;
 use bp_messages::*;
 use bp_runtime::{
-	decl_bridge_finality_runtime_apis, decl_bridge_messages_runtime_apis, Chain, ChainId, Parachain,
+	decl_bridge_finality_runtime_apis, decl_bridge_messages_runtime_apis, Chain, ChainId,
+	Parachain, UnderlyingChainProvider,
 };
 use frame_support::{
 	dispatch::DispatchClass,
@@ -89,6 +90,26 @@ impl ChainWithMessages for BridgeHubKusama {
 	const MAX_UNCONFIRMED_MESSAGES_IN_CONFIRMATION_TX: MessageNonce = 2024;
 }
 
+/// BridgeHubKusama parachain, as seen by the messages pallet instance that serves the lanes
+/// opened with `open_bridge`.
+///
+/// It only differs from [`BridgeHubKusama`] by the name of the messages pallet.
+#[derive(Debug)]
+pub struct BridgeHubKusamaWithDynamicLanes;
+
+impl UnderlyingChainProvider for BridgeHubKusamaWithDynamicLanes {
+	type Chain = BridgeHubKusama;
+}
+
+impl ChainWithMessages for BridgeHubKusamaWithDynamicLanes {
+	const WITH_CHAIN_MESSAGES_PALLET_NAME: &'static str =
+		WITH_BRIDGE_HUB_KUSAMA_DYNAMIC_MESSAGES_PALLET_NAME;
+	const MAX_UNREWARDED_RELAYERS_IN_CONFIRMATION_TX: MessageNonce =
+		BridgeHubKusama::MAX_UNREWARDED_RELAYERS_IN_CONFIRMATION_TX;
+	const MAX_UNCONFIRMED_MESSAGES_IN_CONFIRMATION_TX: MessageNonce =
+		BridgeHubKusama::MAX_UNCONFIRMED_MESSAGES_IN_CONFIRMATION_TX;
+}
+
 /// Public key of the chain account that may be used to verify signatures.
 pub type AccountSigner = MultiSigner;
 
@@ -101,6 +122,10 @@ pub const BRIDGE_HUB_KUSAMA_PARACHAIN_ID: u32 = 1002;
 /// Name of the With-BridgeHubKusama messages pallet instance that is deployed at bridged chains.
 pub const WITH_BRIDGE_HUB_KUSAMA_MESSAGES_PALLET_NAME: &str = "BridgeKusamaMessages";
 
+/// Name of the With-BridgeHubKusama messages pallet instance that serves dynamic lanes and is
+/// deployed at bridged chains.
+pub const WITH_BRIDGE_HUB_KUSAMA_DYNAMIC_MESSAGES_PALLET_NAME: &str = "BridgeKusamaDynamicMessages";
+
 /// Name of the With-BridgeHubKusama bridge-relayers pallet instance that is deployed at bridged
 /// chains.
 pub const WITH_BRIDGE_HUB_KUSAMA_RELAYERS_PALLET_NAME: &str = "BridgeRelayers";
@@ -108,8 +133,12 @@ pub const WITH_BRIDGE_HUB_KUSAMA_RELAYERS_PALLET_NAME: &str = "BridgeRelayers";
 /// Pallet index of `BridgePolkadotMessages: pallet_bridge_messages::<Instance1>`.
 pub const WITH_BRIDGE_KUSAMA_TO_POLKADOT_MESSAGES_PALLET_INDEX: u8 = 53;
 
+/// Pallet index of `BridgePolkadotDynamicMessages: pallet_bridge_messages::<Instance2>`.
+pub const WITH_BRIDGE_KUSAMA_TO_POLKADOT_DYNAMIC_MESSAGES_PALLET_INDEX: u8 = 56;
+
 decl_bridge_finality_runtime_apis!(bridge_hub_kusama);
 decl_bridge_messages_runtime_apis!(bridge_hub_kusama, LegacyLaneId);
+decl_bridge_messages_runtime_apis!(bridge_hub_kusama_with_dynamic_lanes, HashedLaneId);
 
 frame_support::parameter_types! {
 	/// The XCM fee that is paid for executing XCM program (with `ExportMessage` instruction) at the Kusama
@@ -18,22 +18,32 @@
 
 use crate::{
 	weights,
-	xcm_config::{UniversalLocation, XcmRouter},
-	AccountId, Balance, Balances, BlockNumber, BridgePolkadotMessages, PolkadotXcm, Runtime,
-	RuntimeEvent, RuntimeHoldReason, XcmOverBridgeHubPolkadot, XcmpQueue,
+	xcm_config::{UniversalLocation, XcmConfig, XcmRouter},
+	AccountId, Balance, Balances, BlockNumber, BridgePolkadotMessages, BridgeRewardBatcher,
+	PolkadotXcm, Runtime, RuntimeEvent, RuntimeHoldReason, XcmOverBridgeHubPolkadot,
+	XcmOverBridgeHubPolkadotDynamic, XcmpQueue,
 };
+use alloc::{boxed::Box, collections::btree_map::BTreeMap, vec, vec::Vec};
 pub use bp_bridge_hub_polkadot::bp_polkadot;
 use bp_messages::{
 	source_chain::FromBridgedChainMessagesDeliveryProof,
-	target_chain::FromBridgedChainMessagesProof, LegacyLaneId,
+	target_chain::FromBridgedChainMessagesProof, HashedLaneId, LaneIdType, LegacyLaneId,
+	MessagesOperatingMode,
 };
 use bp_parachains::SingleParaStoredHeaderDataBuilder;
 use bp_relayers::RewardsAccountParams;
-use bp_runtime::Chain;
+use bp_runtime::{BasicOperatingMode, Chain};
+use bp_xcm_bridge_hub_router::XcmBridgeHubRouterCall;
 use bridge_hub_common::xcm_version::XcmVersionOfDestAndRemoteBridge;
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use frame_support::{
 	parameter_types,
-	traits::{ConstU128, PalletInfoAccess},
+	traits::{
+		fungible::Mutate,
+		tokens::{Fortitude, Precision, Preservation},
+		ConstU128, Contains, PalletInfoAccess,
+	},
+	PalletId,
 };
 use frame_system::{EnsureNever, EnsureRoot};
 use kusama_runtime_constants as constants;
@@ -41,12 +51,23 @@ use pallet_bridge_messages::LaneIdOf;
 use pallet_bridge_relayers::extension::{
 	BridgeRelayersTransactionExtension, WithMessagesExtensionConfig,
 };
-use pallet_xcm_bridge_hub::{BridgeId, XcmAsPlainPayload};
+use pallet_bridge_reward_batcher::PayBatch;
+use pallet_xcm::EnsureXcm;
+use pallet_xcm_bridge_hub::{BridgeId, BridgeState, XcmAsPlainPayload};
 use parachains_common::xcm_config::{AllSiblingSystemParachains, RelayOrOtherSystemParachains};
 use polkadot_parachain_primitives::primitives::Sibling;
-use sp_runtime::traits::ConstU32;
-use xcm::latest::prelude::*;
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{AccountIdConversion, ConstU32},
+	DispatchError, DispatchResult,
+};
+use system_parachains_common::apis::{
+	BridgeLaneId, BridgeLaneState, BridgedHeaderId, MessagesLaneHealth,
+};
+use system_parachains_constants::kusama::locations::AssetHubLocation;
+use xcm::{latest::prelude::*, VersionedLocation};
 use xcm_builder::{BridgeBlobDispatcher, ParentIsPreset, SiblingParachainConvertsVia};
+use xcm_executor::XcmExecutor;
 
 // Parameters that may be changed by the governance.
 parameter_types! {
@@ -64,6 +85,27 @@ parameter_types! {
 	/// submit an invalid transaction. The set of such (registered) relayers is tracked
 	/// by the `pallet_bridge_relayers` pallet at the Kusama Bridge Hub.
 	pub storage RequiredStakeForStakeAndSlash: Balance = 100 * constants::currency::UNITS;
+
+	/// Deposit that is held from the sovereign account of a sibling parachain that opens a
+	/// dynamic lane over `XcmOverBridgeHubPolkadotDynamic`.
+	///
+	/// The deposit is returned when the bridge is closed. System parachains don't pay it.
+	pub storage BridgeDeposit: Balance = 2 * constants::currency::UNITS;
+
+	/// Pallet index of the bridge router of a sibling parachain, by parachain id.
+	///
+	/// Congestion signals of the dynamic lanes that a sibling parachain has opened are sent to
+	/// this pallet, see [`build_congestion_message`]. The router of Asset Hub is known and doesn't
+	/// need an entry.
+	pub storage SiblingBridgeRouters: BTreeMap<u32, u8> = BTreeMap::new();
+}
+
+parameter_types! {
+	/// Identifier of the account that holds the KSM rewards that are queued for Asset Hub.
+	pub const BridgeRewardBatcherPalletId: PalletId = PalletId(*b"py/brbat");
+	/// The account that holds the KSM rewards that are queued for Asset Hub.
+	pub BridgeRewardBatcherAccount: AccountId =
+		BridgeRewardBatcherPalletId::get().into_account_truncating();
 }
 
 // Parameters, used by both XCM and bridge code.
@@ -94,12 +136,26 @@ impl pallet_bridge_relayers::Config<RelayersForLegacyLaneIdsMessagesInstance> fo
 	type RuntimeEvent = RuntimeEvent;
 	type RewardBalance = Balance;
 	type Reward = RewardsAccountParams<LegacyLaneId>;
-	type PaymentProcedure = bp_relayers::PayRewardFromAccount<
-		pallet_balances::Pallet<Runtime>,
+	type PaymentProcedure = BridgeRewardPayer;
+	type StakeAndSlash = pallet_bridge_relayers::StakeAndSlashNamed<
 		AccountId,
-		LegacyLaneId,
-		Self::RewardBalance,
+		BlockNumber,
+		Balances,
+		RelayerStakeReserveId,
+		RequiredStakeForStakeAndSlash,
+		RelayerStakeLease,
 	>;
+	type Balance = Balance;
+	type WeightInfo = weights::pallet_bridge_relayers::WeightInfo<Runtime>;
+}
+
+pub type RelayersForDynamicLanesInstance = pallet_bridge_relayers::Instance2;
+/// Allows collect and claim rewards for relayers of the dynamic lanes.
+impl pallet_bridge_relayers::Config<RelayersForDynamicLanesInstance> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RewardBalance = Balance;
+	type Reward = RewardsAccountParams<HashedLaneId>;
+	type PaymentProcedure = BridgeRewardPayer;
 	type StakeAndSlash = pallet_bridge_relayers::StakeAndSlashNamed<
 		AccountId,
 		BlockNumber,
@@ -112,6 +168,177 @@ impl pallet_bridge_relayers::Config<RelayersForLegacyLaneIdsMessagesInstance> fo
 	type WeightInfo = weights::pallet_bridge_relayers::WeightInfo<Runtime>;
 }
 
+/// An enum representing the different types of supported beneficiaries.
+#[derive(
+	Clone, Debug, Decode, DecodeWithMemTracking, Encode, Eq, MaxEncodedLen, PartialEq, TypeInfo,
+)]
+pub enum BridgeRewardBeneficiaries {
+	/// A local chain account.
+	LocalAccount(AccountId),
+	/// A beneficiary specified by a VersionedLocation.
+	AssetHubLocation(Box<VersionedLocation>),
+}
+
+impl From<sp_runtime::AccountId32> for BridgeRewardBeneficiaries {
+	fn from(value: sp_runtime::AccountId32) -> Self {
+		BridgeRewardBeneficiaries::LocalAccount(value)
+	}
+}
+
+/// Implementation of `bp_relayers::PaymentProcedure` as a pay/claim rewards scheme.
+///
+/// Rewards that are paid to Asset Hub are queued in the `BridgeRewardBatcher` pallet and sent
+/// with one message per beneficiary.
+pub struct BridgeRewardPayer;
+impl<LaneId: LaneIdType>
+	bp_relayers::PaymentProcedure<AccountId, RewardsAccountParams<LaneId>, Balance>
+	for BridgeRewardPayer
+{
+	type Error = DispatchError;
+	type Beneficiary = BridgeRewardBeneficiaries;
+
+	fn pay_reward(
+		relayer: &AccountId,
+		reward_kind: RewardsAccountParams<LaneId>,
+		reward: Balance,
+		beneficiary: BridgeRewardBeneficiaries,
+	) -> Result<(), Self::Error> {
+		let account = match beneficiary {
+			BridgeRewardBeneficiaries::LocalAccount(ref account) => account.clone(),
+			BridgeRewardBeneficiaries::AssetHubLocation(_) => BridgeRewardBatcherAccount::get(),
+		};
+		bp_relayers::PayRewardFromAccount::<Balances, AccountId, LaneId, Balance>::pay_reward(
+			relayer,
+			reward_kind,
+			reward,
+			account,
+		)?;
+
+		if let BridgeRewardBeneficiaries::AssetHubLocation(account_location) = beneficiary {
+			let account_location = Location::try_from(*account_location).map_err(|_| {
+				DispatchError::Other(
+					"`AssetHubLocation` beneficiary location version is not supported!",
+				)
+			})?;
+			BridgeRewardBatcher::queue_reward(
+				VersionedLocation::from(account_location),
+				AssetHubRewardKind::Native,
+				reward,
+			);
+		}
+		Ok(())
+	}
+}
+
+/// The kinds of rewards that are batched and paid on Asset Hub.
+#[derive(
+	Clone,
+	Copy,
+	Debug,
+	Decode,
+	DecodeWithMemTracking,
+	Encode,
+	Eq,
+	MaxEncodedLen,
+	PartialEq,
+	TypeInfo,
+)]
+pub enum AssetHubRewardKind {
+	/// KSM held by [`BridgeRewardBatcherAccount`] that is teleported to Asset Hub.
+	Native,
+}
+
+/// Pays the rewards batched by the `BridgeRewardBatcher` pallet on Asset Hub.
+pub struct AssetHubRewardPayer;
+impl PayBatch<AccountId, AssetHubRewardKind, Balance, VersionedLocation> for AssetHubRewardPayer {
+	fn pay_batch(
+		payer: &AccountId,
+		kind: AssetHubRewardKind,
+		amount: Balance,
+		beneficiary: VersionedLocation,
+	) -> DispatchResult {
+		let AssetHubRewardKind::Native = kind;
+		let beneficiary = Location::try_from(beneficiary)
+			.map_err(|_| DispatchError::Other("Unsupported beneficiary location version!"))?
+			.reanchored(&AssetHubLocation::get(), &UniversalLocation::get())
+			.map_err(|_| DispatchError::Other("Failed to reanchor the beneficiary!"))?;
+		let asset: Asset = (Location::parent(), amount).into();
+		let message = Xcm(vec![
+			ReceiveTeleportedAsset(asset.clone().into()),
+			ClearOrigin,
+			BuyExecution { fees: asset, weight_limit: Unlimited },
+			DepositAsset { assets: Wild(AllCounted(1)), beneficiary },
+		]);
+
+		let (ticket, fees) = validate_send::<XcmRouter>(AssetHubLocation::get(), message)
+			.map_err(|_| DispatchError::Other("Failed to validate the reward message!"))?;
+		let payer_location =
+			Location::new(0, [Junction::AccountId32 { network: None, id: payer.clone().into() }]);
+		XcmExecutor::<XcmConfig>::charge_fees(payer_location, fees)
+			.map_err(|_| DispatchError::Other("Failed to charge the delivery fees!"))?;
+		Balances::burn_from(
+			&BridgeRewardBatcherAccount::get(),
+			amount,
+			Preservation::Expendable,
+			Precision::Exact,
+			Fortitude::Polite,
+		)?;
+		XcmRouter::deliver(ticket)
+			.map_err(|_| DispatchError::Other("Failed to deliver the reward message!"))?;
+
+		Ok(())
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct BridgeRewardBatcherBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl
+	pallet_bridge_reward_batcher::BenchmarkHelper<
+		AccountId,
+		AssetHubRewardKind,
+		Balance,
+		VersionedLocation,
+	> for BridgeRewardBatcherBenchmarkHelper
+{
+	fn reward() -> (VersionedLocation, AssetHubRewardKind, Balance) {
+		let beneficiary = Location::new(
+			1,
+			[
+				Parachain(constants::system_parachain::ASSET_HUB_ID),
+				Junction::AccountId32 { network: None, id: [1u8; 32] },
+			],
+		);
+		(
+			VersionedLocation::from(beneficiary),
+			AssetHubRewardKind::Native,
+			constants::currency::UNITS,
+		)
+	}
+
+	fn prepare_payment(payer: &AccountId) {
+		use constants::system_parachain::AssetHubParaId;
+
+		let (_, _, amount) = Self::reward();
+		Balances::mint_into(&BridgeRewardBatcherAccount::get(), amount).unwrap();
+		Balances::mint_into(payer, 100 * constants::currency::UNITS).unwrap();
+		crate::ParachainSystem::open_outbound_hrmp_channel_for_benchmarks_or_tests(
+			AssetHubParaId::get(),
+		);
+	}
+}
+
+impl pallet_bridge_reward_batcher::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RewardKind = AssetHubRewardKind;
+	type Balance = Balance;
+	type Beneficiary = VersionedLocation;
+	type Payer = AssetHubRewardPayer;
+	type WeightInfo = weights::pallet_bridge_reward_batcher::WeightInfo<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = BridgeRewardBatcherBenchmarkHelper;
+}
+
 // Parameters, used by bridge transport code.
 parameter_types! {
 	/// Number of Polkadot headers to keep in the runtime storage.
@@ -168,6 +395,21 @@ pub type OnBridgeHubPolkadotRefundBridgeHubKusamaMessages = BridgeRelayersTransa
 >;
 bp_runtime::generate_static_str_provider!(OnBridgeHubPolkadotRefundBridgeHubKusamaMessages);
 
+/// Signed extension that refunds relayers that are delivering messages over the dynamic lanes from
+/// the Polkadot parachain.
+pub type OnBridgeHubKusamaRefundBridgeHubPolkadotDynamicMessages =
+	BridgeRelayersTransactionExtension<
+		Runtime,
+		WithMessagesExtensionConfig<
+			StrOnBridgeHubKusamaRefundBridgeHubPolkadotDynamicMessages,
+			Runtime,
+			WithBridgeHubPolkadotDynamicMessagesInstance,
+			RelayersForDynamicLanesInstance,
+			PriorityBoostPerMessage,
+		>,
+	>;
+bp_runtime::generate_static_str_provider!(OnBridgeHubKusamaRefundBridgeHubPolkadotDynamicMessages);
+
 /// Add GRANDPA bridge pallet to track Polkadot relay chain.
 pub type BridgeGrandpaPolkadotInstance = pallet_bridge_grandpa::Instance1;
 impl pallet_bridge_grandpa::Config<BridgeGrandpaPolkadotInstance> for Runtime {
@@ -222,6 +464,38 @@ impl pallet_bridge_messages::Config<WithBridgeHubPolkadotMessagesInstance> for R
 	type OnMessagesDelivered = XcmOverBridgeHubPolkadot;
 }
 
+/// Add XCM messages support for exchanging messages over the dynamic lanes with
+/// BridgeHubPolkadot.
+///
+/// The pallet code is the same as of `BridgePolkadotMessages`, so it reuses its weights.
+pub type WithBridgeHubPolkadotDynamicMessagesInstance = pallet_bridge_messages::Instance2;
+impl pallet_bridge_messages::Config<WithBridgeHubPolkadotDynamicMessagesInstance> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = weights::pallet_bridge_messages::WeightInfo<Runtime>;
+
+	type ThisChain = bp_bridge_hub_kusama::BridgeHubKusamaWithDynamicLanes;
+	type BridgedChain = bp_bridge_hub_polkadot::BridgeHubPolkadotWithDynamicLanes;
+	type BridgedHeaderChain = pallet_bridge_parachains::ParachainHeaders<
+		Runtime,
+		BridgeParachainPolkadotInstance,
+		bp_bridge_hub_polkadot::BridgeHubPolkadotWithDynamicLanes,
+	>;
+
+	type OutboundPayload = XcmAsPlainPayload;
+	type InboundPayload = XcmAsPlainPayload;
+	type LaneId = HashedLaneId;
+
+	type DeliveryPayments = ();
+	type DeliveryConfirmationPayments = pallet_bridge_relayers::DeliveryConfirmationPaymentsAdapter<
+		Runtime,
+		WithBridgeHubPolkadotDynamicMessagesInstance,
+		RelayersForDynamicLanesInstance,
+		DeliveryRewardInBalance,
+	>;
+	type MessageDispatch = XcmOverBridgeHubPolkadotDynamic;
+	type OnMessagesDelivered = XcmOverBridgeHubPolkadotDynamic;
+}
+
 /// Add support for the export and dispatch of XCM programs.
 pub type XcmOverBridgeHubPolkadotInstance = pallet_xcm_bridge_hub::Instance1;
 impl pallet_xcm_bridge_hub::Config<XcmOverBridgeHubPolkadotInstance> for Runtime {
@@ -239,6 +513,7 @@ impl pallet_xcm_bridge_hub::Config<XcmOverBridgeHubPolkadotInstance> for Runtime
 
 	type ForceOrigin = EnsureRoot<AccountId>;
 	// We don't want to allow creating bridges for this instance with `LegacyLaneId`.
+	// Dynamic lanes are opened over `XcmOverBridgeHubPolkadotDynamic` instead.
 	type OpenBridgeOrigin = EnsureNever<Location>;
 	// Converter aligned with `OpenBridgeOrigin`.
 	type BridgeOriginAccountIdConverter =
@@ -257,6 +532,39 @@ impl pallet_xcm_bridge_hub::Config<XcmOverBridgeHubPolkadotInstance> for Runtime
 	type BlobDispatcher = FromPolkadotMessageBlobDispatcher;
 }
 
+/// Add support for the export and dispatch of XCM programs over the lanes that are opened by
+/// sibling parachains with `open_bridge`.
+pub type XcmOverBridgeHubPolkadotDynamicInstance = pallet_xcm_bridge_hub::Instance2;
+impl pallet_xcm_bridge_hub::Config<XcmOverBridgeHubPolkadotDynamicInstance> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+
+	type UniversalLocation = UniversalLocation;
+	type BridgedNetwork = PolkadotGlobalConsensusNetworkLocation;
+	type BridgeMessagesPalletInstance = WithBridgeHubPolkadotDynamicMessagesInstance;
+	// See `XcmOverBridgeHubPolkadotInstance`.
+	type MessageExportPrice = ();
+	type DestinationVersion =
+		XcmVersionOfDestAndRemoteBridge<PolkadotXcm, BridgeHubPolkadotLocation>;
+
+	type ForceOrigin = EnsureRoot<AccountId>;
+	// Any sibling parachain may open a bridge with `HashedLaneId`.
+	type OpenBridgeOrigin = EnsureXcm<SiblingParachains>;
+	// Converter aligned with `OpenBridgeOrigin`.
+	type BridgeOriginAccountIdConverter = SiblingParachainConvertsVia<Sibling, AccountId>;
+
+	type BridgeDeposit = BridgeDeposit;
+	type Currency = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	// Require the deposit from all sibling parachains, but system parachains.
+	type AllowWithoutBridgeDeposit =
+		RelayOrOtherSystemParachains<AllSiblingSystemParachains, Runtime>;
+
+	type LocalXcmChannelManager = CongestionManager;
+	// Messages of all lanes are dispatched with the same bridge instance location, so the
+	// destinations only need to trust one origin for the Polkadot bridge.
+	type BlobDispatcher = FromPolkadotMessageBlobDispatcher;
+}
+
 /// Implementation of `bp_xcm_bridge_hub::LocalXcmChannelManager` for congestion management.
 pub struct CongestionManager;
 impl pallet_xcm_bridge_hub::LocalXcmChannelManager for CongestionManager {
@@ -273,26 +581,126 @@ impl pallet_xcm_bridge_hub::LocalXcmChannelManager for CongestionManager {
 	}
 
 	fn suspend_bridge(local_origin: &Location, bridge: BridgeId) -> Result<(), Self::Error> {
-		// This bridge is intended for AH<>AH communication with a hard-coded/static lane,
-		// so `local_origin` is expected to represent only the local AH.
-		send_xcm::<XcmpQueue>(
-			local_origin.clone(),
-			bp_asset_hub_kusama::build_congestion_message(bridge.inner(), true).into(),
-		)
-		.map(|_| ())
+		report_bridge_status(local_origin, bridge, true)
 	}
 
 	fn resume_bridge(local_origin: &Location, bridge: BridgeId) -> Result<(), Self::Error> {
-		// This bridge is intended for AH<>AH communication with a hard-coded/static lane,
-		// so `local_origin` is expected to represent only the local AH.
-		send_xcm::<XcmpQueue>(
-			local_origin.clone(),
-			bp_asset_hub_kusama::build_congestion_message(bridge.inner(), false).into(),
+		report_bridge_status(local_origin, bridge, false)
+	}
+}
+
+/// All sibling parachains.
+pub struct SiblingParachains;
+impl Contains<Location> for SiblingParachains {
+	fn contains(location: &Location) -> bool {
+		matches!(location.unpack(), (1, [Parachain(_)]))
+	}
+}
+
+/// Builds the (un)congestion message for the bridge router of `local_origin`, which owns the lane
+/// of `bridge`.
+///
+/// The router of Asset Hub is known, the routers of other sibling parachains are looked up in
+/// [`SiblingBridgeRouters`]. Returns `None` if the router of `local_origin` is not known.
+pub fn build_congestion_message(
+	local_origin: &Location,
+	bridge: BridgeId,
+	is_congested: bool,
+) -> Option<Xcm<()>> {
+	if *local_origin == AssetHubLocation::get() {
+		return Some(
+			bp_asset_hub_kusama::build_congestion_message(bridge.inner(), is_congested).into(),
 		)
-		.map(|_| ())
+	}
+	let (1, [Parachain(para_id)]) = local_origin.unpack() else { return None };
+	let router = SiblingBridgeRouters::get().get(para_id).copied()?;
+	let call = (
+		router,
+		XcmBridgeHubRouterCall::report_bridge_status { bridge_id: bridge.inner(), is_congested },
+	);
+	Some(Xcm(vec![
+		UnpaidExecution { weight_limit: Unlimited, check_origin: None },
+		Transact {
+			origin_kind: OriginKind::Xcm,
+			fallback_max_weight: Some(
+				bp_asset_hub_kusama::XcmBridgeHubRouterTransactCallMaxWeight::get(),
+			),
+			call: call.encode().into(),
+		},
+		ExpectTransactStatus(MaybeErrorCode::Success),
+	]))
+}
+
+/// Sends the (un)congestion signal of `bridge` to `local_origin`, which has opened it.
+fn report_bridge_status(
+	local_origin: &Location,
+	bridge: BridgeId,
+	is_congested: bool,
+) -> Result<(), SendError> {
+	let message = build_congestion_message(local_origin, bridge, is_congested)
+		.ok_or(SendError::NotApplicable)?;
+	send_xcm::<XcmpQueue>(local_origin.clone(), message).map(|_| ())
+}
+
+/// Conversion of the lane ids of the messages pallets into the lane id of the
+/// `BridgeHealthApi`.
+pub trait IntoBridgeLaneId {
+	fn into_bridge_lane_id(self) -> BridgeLaneId;
+}
+
+impl IntoBridgeLaneId for LegacyLaneId {
+	fn into_bridge_lane_id(self) -> BridgeLaneId {
+		BridgeLaneId::Legacy(self.0)
 	}
 }
 
+impl IntoBridgeLaneId for HashedLaneId {
+	fn into_bridge_lane_id(self) -> BridgeLaneId {
+		BridgeLaneId::Hashed(*self.inner())
+	}
+}
+
+/// Health of all lanes of the messages pallet instance `MI`, which are owned by the XCM bridge
+/// hub instance `XI`.
+pub fn messages_lanes_health<MI: 'static, XI: 'static>(
+	best_finalized_bridged_header: Option<BridgedHeaderId>,
+) -> Vec<MessagesLaneHealth>
+where
+	Runtime: pallet_xcm_bridge_hub::Config<XI, BridgeMessagesPalletInstance = MI>,
+	LaneIdOf<Runtime, MI>: IntoBridgeLaneId,
+{
+	let is_halted = pallet_bridge_messages::PalletOperatingMode::<Runtime, MI>::get() !=
+		MessagesOperatingMode::Basic(BasicOperatingMode::Normal);
+
+	pallet_bridge_messages::OutboundLanes::<Runtime, MI>::iter()
+		.map(|(lane_id, outbound_lane)| {
+			let unrewarded_relayers =
+				pallet_bridge_messages::InboundLanes::<Runtime, MI>::get(lane_id)
+					.map_or(0, |inbound_lane| inbound_lane.0.relayers.len() as u32);
+			let bridge_state = pallet_xcm_bridge_hub::LaneToBridge::<Runtime, XI>::get(lane_id)
+				.and_then(pallet_xcm_bridge_hub::Bridges::<Runtime, XI>::get)
+				.map(|bridge| match bridge.state {
+					BridgeState::Opened => BridgeLaneState::Opened,
+					BridgeState::Suspended => BridgeLaneState::Suspended,
+					BridgeState::Closed => BridgeLaneState::Closed,
+				});
+
+			MessagesLaneHealth {
+				lane_id: lane_id.into_bridge_lane_id(),
+				best_finalized_bridged_header: best_finalized_bridged_header.clone(),
+				latest_generated_nonce: outbound_lane.latest_generated_nonce,
+				latest_received_nonce: outbound_lane.latest_received_nonce,
+				outbound_nonce_gap: outbound_lane
+					.latest_generated_nonce
+					.saturating_sub(outbound_lane.latest_received_nonce),
+				unrewarded_relayers,
+				is_halted,
+				bridge_state,
+			}
+		})
+		.collect()
+}
+
 #[cfg(feature = "runtime-benchmarks")]
 pub(crate) fn open_bridge_for_benchmarks<R, XBHI, C>(
 	with: pallet_xcm_bridge_hub::LaneIdOf<R, XBHI>,
@@ -413,6 +821,90 @@ mod tests {
 		assert!(BridgeHubPolkadotLocation::get()
 			.starts_with(&PolkadotGlobalConsensusNetworkLocation::get()));
 	}
+
+	#[test]
+	fn ensure_dynamic_lanes_bridge_integrity() {
+		assert_complete_bridge_types!(
+			runtime: Runtime,
+			with_bridged_chain_messages_instance: WithBridgeHubPolkadotDynamicMessagesInstance,
+			this_chain: bp_bridge_hub_kusama::BridgeHubKusamaWithDynamicLanes,
+			bridged_chain: bp_bridge_hub_polkadot::BridgeHubPolkadotWithDynamicLanes,
+			expected_payload_type: XcmAsPlainPayload,
+		);
+
+		assert_eq!(
+			<crate::BridgePolkadotDynamicMessages as PalletInfoAccess>::index() as u8,
+			bp_bridge_hub_kusama::WITH_BRIDGE_KUSAMA_TO_POLKADOT_DYNAMIC_MESSAGES_PALLET_INDEX
+		);
+	}
+
+	#[test]
+	fn congestion_messages_are_routed_to_the_router_of_the_lane_owner() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			let bridge = BridgeId::new(
+				&[GlobalConsensus(Kusama), Parachain(2000)].into(),
+				&[GlobalConsensus(Polkadot), Parachain(2000)].into(),
+			);
+			let sibling = Location::new(1, [Parachain(2000)]);
+
+			assert_eq!(
+				build_congestion_message(&AssetHubLocation::get(), bridge, true),
+				Some(bp_asset_hub_kusama::build_congestion_message(bridge.inner(), true).into())
+			);
+			assert_eq!(
+				build_congestion_message(&AssetHubLocation::get(), bridge, false),
+				Some(bp_asset_hub_kusama::build_congestion_message(bridge.inner(), false).into())
+			);
+			// The router of the sibling is not known yet.
+			assert_eq!(build_congestion_message(&sibling, bridge, true), None);
+
+			SiblingBridgeRouters::set(&BTreeMap::from([(2000, 51)]));
+			let message = build_congestion_message(&sibling, bridge, true).unwrap();
+			let expected_call = (
+				51u8,
+				XcmBridgeHubRouterCall::report_bridge_status {
+					bridge_id: bridge.inner(),
+					is_congested: true,
+				},
+			)
+				.encode();
+			assert!(matches!(
+				&message.0[..],
+				[UnpaidExecution { .. }, Transact { call, .. }, ExpectTransactStatus(_)]
+					if call.clone().into_encoded() == expected_call
+			));
+			assert_eq!(
+				build_congestion_message(&Location::new(1, [Parachain(2001)]), bridge, true),
+				None
+			);
+		});
+	}
+
+	#[test]
+	fn sibling_parachains_may_open_bridges() {
+		use frame_support::traits::EnsureOrigin;
+		type OpenBridgeOrigin = <Runtime as pallet_xcm_bridge_hub::Config<
+			XcmOverBridgeHubPolkadotDynamicInstance,
+		>>::OpenBridgeOrigin;
+		type AllowWithoutBridgeDeposit = <Runtime as pallet_xcm_bridge_hub::Config<
+			XcmOverBridgeHubPolkadotDynamicInstance,
+		>>::AllowWithoutBridgeDeposit;
+		let try_open = |location: Location| {
+			OpenBridgeOrigin::try_origin(pallet_xcm::Origin::Xcm(location).into()).is_ok()
+		};
+
+		assert!(try_open(AssetHubLocation::get()));
+		assert!(try_open(Location::new(1, [Parachain(1001)])));
+		assert!(try_open(Location::new(1, [Parachain(2000)])));
+		assert!(!try_open(Location::parent()));
+		assert!(!try_open(Location::new(1, [Parachain(2000), PalletInstance(1)])));
+
+		// Only non-system parachains pay the deposit.
+		sp_io::TestExternalities::default().execute_with(|| {
+			assert!(AllowWithoutBridgeDeposit::contains(&AssetHubLocation::get()));
+			assert!(!AllowWithoutBridgeDeposit::contains(&Location::new(1, [Parachain(2000)])));
+		});
+	}
 }
 
 /// Contains the migrations for a P/K bridge.
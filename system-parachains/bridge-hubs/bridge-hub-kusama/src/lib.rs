@@ -123,7 +123,10 @@ pub type TxExtension = cumulus_pallet_weight_reclaim::StorageWeightReclaim<
 		frame_system::CheckWeight<Runtime>,
 		pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
 		BridgeRejectObsoleteHeadersAndMessages,
-		bridge_to_polkadot_config::OnBridgeHubPolkadotRefundBridgeHubKusamaMessages,
+		(
+			bridge_to_polkadot_config::OnBridgeHubPolkadotRefundBridgeHubKusamaMessages,
+			bridge_to_polkadot_config::OnBridgeHubKusamaRefundBridgeHubPolkadotDynamicMessages,
+		),
 		frame_metadata_hash_extension::CheckMetadataHash<Runtime>,
 	),
 >;
@@ -135,7 +138,8 @@ bridge_runtime_common::generate_bridge_reject_obsolete_headers_and_messages! {
 	// Parachains
 	BridgePolkadotParachains,
 	// Messages
-	BridgePolkadotMessages
+	BridgePolkadotMessages,
+	BridgePolkadotDynamicMessages
 }
 
 /// Unchecked extrinsic type as expected by this runtime.
@@ -572,6 +576,7 @@ pub enum ProxyType {
 
 impl InstanceFilter<RuntimeCall> for ProxyType {
 	fn filter(&self, c: &RuntimeCall) -> bool {
+		use pallet_bridge_relayers::Call as RelayersCall;
 		match self {
 			ProxyType::Any => true,
 			ProxyType::NonTransfer => matches!(
@@ -584,10 +589,13 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 					RuntimeCall::Utility(_) |
 					RuntimeCall::Multisig(_) |
 					RuntimeCall::Proxy(_) |
-					RuntimeCall::BridgeRelayers(pallet_bridge_relayers::Call::register { .. }) |
-					RuntimeCall::BridgeRelayers(pallet_bridge_relayers::Call::deregister { .. }) |
-					RuntimeCall::BridgeRelayers(
-						pallet_bridge_relayers::Call::claim_rewards { .. }
+					RuntimeCall::BridgeRelayers(RelayersCall::register { .. }) |
+					RuntimeCall::BridgeRelayers(RelayersCall::deregister { .. }) |
+					RuntimeCall::BridgeRelayers(RelayersCall::claim_rewards { .. }) |
+					RuntimeCall::BridgeRelayersForDynamicLanes(RelayersCall::register { .. }) |
+					RuntimeCall::BridgeRelayersForDynamicLanes(RelayersCall::deregister { .. }) |
+					RuntimeCall::BridgeRelayersForDynamicLanes(
+						RelayersCall::claim_rewards { .. }
 					)
 			),
 			ProxyType::CancelProxy => matches!(
@@ -685,6 +693,12 @@ construct_runtime!(
 		BridgePolkadotParachains: pallet_bridge_parachains::<Instance1> = 52,
 		BridgePolkadotMessages: pallet_bridge_messages::<Instance1> = 53,
 		XcmOverBridgeHubPolkadot: pallet_xcm_bridge_hub::<Instance1> = 54,
+		BridgePolkadotDynamicMessages: pallet_bridge_messages::<Instance2> = 56,
+		XcmOverBridgeHubPolkadotDynamic: pallet_xcm_bridge_hub::<Instance2> = 57,
+		BridgeRelayersForDynamicLanes: pallet_bridge_relayers::<Instance2> = 58,
+
+		// Batching of bridge relayer rewards that are paid on Asset Hub.
+		BridgeRewardBatcher: pallet_bridge_reward_batcher = 55,
 
 		// Message Queue. Importantly, it is registered after bridge pallets
 		// so that messages are processed after the `on_initialize` hooks of bridging pallets.
@@ -724,6 +738,7 @@ mod benches {
 		[pallet_xcm_benchmarks::generic, XcmGeneric]
 		// Shared bridge pallets
 		[pallet_bridge_relayers, BridgeRelayersBench::<Runtime>]
+		[pallet_bridge_reward_batcher, BridgeRewardBatcher]
 		// Polkadot bridge pallets.
 		[pallet_bridge_grandpa, PolkadotFinality]
 		[pallet_bridge_parachains, PolkadotParachains]
@@ -956,7 +971,10 @@ mod benches {
 			_relayer: &AccountId,
 			account_params: Self::Reward,
 			reward: Self::RewardBalance,
-		) -> Option<(bp_relayers::RewardsAccountParams<bp_messages::LegacyLaneId>, AccountId)> {
+		) -> Option<(
+			bp_relayers::RewardsAccountParams<bp_messages::LegacyLaneId>,
+			bridge_to_polkadot_config::BridgeRewardBeneficiaries,
+		)> {
 			let rewards_account = bp_relayers::PayRewardFromAccount::<
 				Balances,
 				AccountId,
@@ -964,7 +982,10 @@ mod benches {
 				Balance,
 			>::rewards_account(account_params);
 			Self::deposit_account(rewards_account.clone(), reward);
-			Some((account_params, rewards_account))
+			Some((
+				account_params,
+				bridge_to_polkadot_config::BridgeRewardBeneficiaries::LocalAccount(rewards_account),
+			))
 		}
 
 		fn deposit_account(account: AccountId, balance: Balance) {
@@ -1433,6 +1454,61 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl bp_bridge_hub_polkadot::FromBridgeHubPolkadotWithDynamicLanesInboundLaneApi<Block> for Runtime {
+		fn message_details(
+			lane: bp_messages::HashedLaneId,
+			messages: Vec<(bp_messages::MessagePayload, bp_messages::OutboundMessageDetails)>,
+		) -> Vec<bp_messages::InboundMessageDetails> {
+			bridge_runtime_common::messages_api::inbound_message_details::<
+				Runtime,
+				bridge_to_polkadot_config::WithBridgeHubPolkadotDynamicMessagesInstance,
+			>(lane, messages)
+		}
+	}
+
+	impl bp_bridge_hub_polkadot::ToBridgeHubPolkadotWithDynamicLanesOutboundLaneApi<Block> for Runtime {
+		fn message_details(
+			lane: bp_messages::HashedLaneId,
+			begin: bp_messages::MessageNonce,
+			end: bp_messages::MessageNonce,
+		) -> Vec<bp_messages::OutboundMessageDetails> {
+			bridge_runtime_common::messages_api::outbound_message_details::<
+				Runtime,
+				bridge_to_polkadot_config::WithBridgeHubPolkadotDynamicMessagesInstance,
+			>(lane, begin, end)
+		}
+	}
+
+	impl system_parachains_common::apis::BridgeHealthApi<Block> for Runtime {
+		fn bridge_health() -> system_parachains_common::apis::BridgeHealth {
+			use system_parachains_common::apis::{BridgeHealth, BridgedHeaderId};
+
+			let to_bridged_header_id = |id: bp_runtime::HeaderId<Hash, BlockNumber>| {
+				BridgedHeaderId { number: id.number(), hash: id.hash() }
+			};
+			let best_finalized_bridged_header = BridgePolkadotParachains::best_parachain_head_id::<
+				bp_bridge_hub_polkadot::BridgeHubPolkadot
+			>().unwrap_or(None).map(to_bridged_header_id);
+
+			let mut lanes = bridge_to_polkadot_config::messages_lanes_health::<
+				bridge_to_polkadot_config::WithBridgeHubPolkadotMessagesInstance,
+				bridge_to_polkadot_config::XcmOverBridgeHubPolkadotInstance,
+			>(best_finalized_bridged_header.clone());
+			lanes.extend(bridge_to_polkadot_config::messages_lanes_health::<
+				bridge_to_polkadot_config::WithBridgeHubPolkadotDynamicMessagesInstance,
+				bridge_to_polkadot_config::XcmOverBridgeHubPolkadotDynamicInstance,
+			>(best_finalized_bridged_header));
+
+			BridgeHealth {
+				best_finalized_bridged_relay_header: BridgePolkadotGrandpa::best_finalized()
+					.map(to_bridged_header_id),
+				lanes,
+				// Kusama Bridge Hub has no bridge with Ethereum.
+				snowbridge: None,
+			}
+		}
+	}
+
 	impl cumulus_primitives_core::GetParachainInfo<Block> for Runtime {
 		fn parachain_id() -> ParaId {
 			ParachainInfo::parachain_id()
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
 
-use crate::XcmOverBridgeHubPolkadot;
+use crate::{XcmOverBridgeHubPolkadot, XcmOverBridgeHubPolkadotDynamic};
 
 use super::{
 	AccountId, AllPalletsWithSystem, Balance, Balances, CollatorSelection, ParachainInfo,
@@ -226,7 +226,11 @@ impl xcm_executor::Config for XcmConfig {
 		WaivedLocations,
 		SendXcmFeeToAccount<Self::AssetTransactor, RelayTreasuryPalletAccount>,
 	>;
-	type MessageExporter = XcmOverBridgeHubPolkadot;
+	type MessageExporter = (
+		XcmOverBridgeHubPolkadot,
+		// Lanes that are opened by sibling parachains with `open_bridge`.
+		XcmOverBridgeHubPolkadotDynamic,
+	);
 	type UniversalAliases = Nothing;
 	type CallDispatcher = RuntimeCall;
 	type SafeCallFilter = Everything;
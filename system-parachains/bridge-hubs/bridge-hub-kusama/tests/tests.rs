@@ -19,9 +19,10 @@ use bp_polkadot_core::Signature;
 use bridge_hub_kusama_runtime::{
 	bridge_to_polkadot_config::{
 		BridgeGrandpaPolkadotInstance, BridgeHubPolkadotLocation, BridgeParachainPolkadotInstance,
-		DeliveryRewardInBalance, OnBridgeHubPolkadotRefundBridgeHubKusamaMessages,
-		PolkadotGlobalConsensusNetwork, RelayersForLegacyLaneIdsMessagesInstance,
-		RequiredStakeForStakeAndSlash, WithBridgeHubPolkadotMessagesInstance,
+		DeliveryRewardInBalance, OnBridgeHubKusamaRefundBridgeHubPolkadotDynamicMessages,
+		OnBridgeHubPolkadotRefundBridgeHubKusamaMessages, PolkadotGlobalConsensusNetwork,
+		RelayersForLegacyLaneIdsMessagesInstance, RequiredStakeForStakeAndSlash,
+		WithBridgeHubPolkadotMessagesInstance, XcmOverBridgeHubPolkadotDynamicInstance,
 		XcmOverBridgeHubPolkadotInstance,
 	},
 	xcm_config::{
@@ -98,7 +99,10 @@ fn construct_extrinsic(
 		frame_system::CheckWeight::<Runtime>::new(),
 		pallet_transaction_payment::ChargeTransactionPayment::<Runtime>::from(0),
 		BridgeRejectObsoleteHeadersAndMessages,
-		(OnBridgeHubPolkadotRefundBridgeHubKusamaMessages::default()),
+		(
+			OnBridgeHubPolkadotRefundBridgeHubKusamaMessages::default(),
+			OnBridgeHubKusamaRefundBridgeHubPolkadotDynamicMessages::default(),
+		),
 		frame_metadata_hash_extension::CheckMetadataHash::<Runtime>::new(false),
 	)
 		.into();
@@ -301,6 +305,28 @@ fn handle_export_message_from_system_parachain_add_to_outbound_queue_works() {
 		)
 }
 
+#[test]
+fn open_and_close_bridge_works() {
+	// Only Asset Hub has a bridge router that receives the (un)congestion signal of its bridges.
+	let origins = [AssetHubLocation::get()];
+
+	for origin in origins {
+		bridge_hub_test_utils::test_cases::open_and_close_bridge_works::<
+			Runtime,
+			XcmOverBridgeHubPolkadotDynamicInstance,
+			LocationToAccountId,
+			RelayChainLocation,
+		>(
+			collator_session_keys(),
+			bp_bridge_hub_kusama::BRIDGE_HUB_KUSAMA_PARACHAIN_ID,
+			origin.clone(),
+			BridgedUniversalLocation::get(),
+			(origin, OriginKind::Xcm),
+			true,
+		)
+	}
+}
+
 #[test]
 fn message_dispatch_routing_works() {
 	bridge_hub_test_utils::test_cases::message_dispatch_routing_works::<
@@ -24,7 +24,8 @@ extern crate alloc;
 pub use bp_bridge_hub_cumulus::*;
 use bp_messages::*;
 use bp_runtime::{
-	decl_bridge_finality_runtime_apis, decl_bridge_messages_runtime_apis, Chain, ChainId, Parachain,
+	decl_bridge_finality_runtime_apis, decl_bridge_messages_runtime_apis, Chain, ChainId,
+	Parachain, UnderlyingChainProvider,
 };
 use frame_support::dispatch::DispatchClass;
 use sp_runtime::{Debug, FixedPointNumber, FixedU128, Saturating, StateVersion};
@@ -86,12 +87,37 @@ impl ChainWithMessages for BridgeHubPolkadot {
 	const MAX_UNCONFIRMED_MESSAGES_IN_CONFIRMATION_TX: MessageNonce = 2024;
 }
 
+/// BridgeHubPolkadot parachain, as seen by the messages pallet instance that serves the lanes
+/// opened with `open_bridge`.
+///
+/// It only differs from [`BridgeHubPolkadot`] by the name of the messages pallet.
+#[derive(Debug)]
+pub struct BridgeHubPolkadotWithDynamicLanes;
+
+impl UnderlyingChainProvider for BridgeHubPolkadotWithDynamicLanes {
+	type Chain = BridgeHubPolkadot;
+}
+
+impl ChainWithMessages for BridgeHubPolkadotWithDynamicLanes {
+	const WITH_CHAIN_MESSAGES_PALLET_NAME: &'static str =
+		WITH_BRIDGE_HUB_POLKADOT_DYNAMIC_MESSAGES_PALLET_NAME;
+	const MAX_UNREWARDED_RELAYERS_IN_CONFIRMATION_TX: MessageNonce =
+		BridgeHubPolkadot::MAX_UNREWARDED_RELAYERS_IN_CONFIRMATION_TX;
+	const MAX_UNCONFIRMED_MESSAGES_IN_CONFIRMATION_TX: MessageNonce =
+		BridgeHubPolkadot::MAX_UNCONFIRMED_MESSAGES_IN_CONFIRMATION_TX;
+}
+
 /// Identifier of BridgeHubPolkadot in the Polkadot relay chain.
 pub const BRIDGE_HUB_POLKADOT_PARACHAIN_ID: u32 = 1002;
 
 /// Name of the With-BridgeHubPolkadot messages pallet instance that is deployed at bridged chains.
 pub const WITH_BRIDGE_HUB_POLKADOT_MESSAGES_PALLET_NAME: &str = "BridgePolkadotMessages";
 
+/// Name of the With-BridgeHubPolkadot messages pallet instance that serves dynamic lanes and is
+/// deployed at bridged chains.
+pub const WITH_BRIDGE_HUB_POLKADOT_DYNAMIC_MESSAGES_PALLET_NAME: &str =
+	"BridgePolkadotDynamicMessages";
+
 /// Name of the With-BridgeHubPolkadot bridge-relayers pallet instance that is deployed at bridged
 /// chains.
 pub const WITH_BRIDGE_HUB_POLKADOT_RELAYERS_PALLET_NAME: &str = "BridgeRelayers";
@@ -99,8 +125,12 @@ pub const WITH_BRIDGE_HUB_POLKADOT_RELAYERS_PALLET_NAME: &str = "BridgeRelayers"
 /// Pallet index of `BridgeKusamaMessages: pallet_bridge_messages::<Instance1>`.
 pub const WITH_BRIDGE_POLKADOT_TO_KUSAMA_MESSAGES_PALLET_INDEX: u8 = 53;
 
+/// Pallet index of `BridgeKusamaDynamicMessages: pallet_bridge_messages::<Instance2>`.
+pub const WITH_BRIDGE_POLKADOT_TO_KUSAMA_DYNAMIC_MESSAGES_PALLET_INDEX: u8 = 56;
+
 decl_bridge_finality_runtime_apis!(bridge_hub_polkadot);
 decl_bridge_messages_runtime_apis!(bridge_hub_polkadot, LegacyLaneId);
+decl_bridge_messages_runtime_apis!(bridge_hub_polkadot_with_dynamic_lanes, HashedLaneId);
 
 frame_support::parameter_types! {
 	/// The XCM fee that is paid for executing XCM program (with `ExportMessage` instruction) at the Polkadot
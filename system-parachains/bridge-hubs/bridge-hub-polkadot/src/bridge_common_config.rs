@@ -19,19 +19,35 @@
 use crate::{
 	bridge_to_ethereum_config::InboundQueueV2Location,
 	weights,
-	xcm_config::{XcmConfig, XcmRouter},
-	AccountId, Balance, Balances, BlockNumber, Runtime, RuntimeCall, RuntimeEvent,
+	xcm_config::{UniversalLocation, XcmConfig, XcmRouter},
+	AccountId, Balance, Balances, BlockNumber, BridgeRewardBatcher, Runtime, RuntimeCall,
+	RuntimeEvent,
 };
-use alloc::boxed::Box;
+use alloc::{boxed::Box, vec, vec::Vec};
 use bp_bridge_hub_polkadot::snowbridge::EthereumNetwork;
-use bp_messages::LegacyLaneId;
+use bp_messages::{HashedLaneId, LaneIdType, LegacyLaneId, MessagesOperatingMode};
 use bp_relayers::RewardsAccountParams;
+use bp_runtime::BasicOperatingMode;
 use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
-use frame_support::parameter_types;
+use frame_support::{
+	parameter_types,
+	traits::{
+		fungible::Mutate,
+		tokens::{Fortitude, Precision, Preservation},
+	},
+	PalletId,
+};
+use pallet_bridge_messages::LaneIdOf;
+use pallet_bridge_reward_batcher::PayBatch;
+use pallet_xcm_bridge_hub::BridgeState;
 use polkadot_runtime_constants as constants;
 use scale_info::TypeInfo;
+use sp_runtime::{traits::AccountIdConversion, DispatchError, DispatchResult};
+use system_parachains_common::apis::{
+	BridgeLaneId, BridgeLaneState, BridgedHeaderId, MessagesLaneHealth,
+};
 use system_parachains_constants::polkadot::locations::AssetHubLocation;
-use xcm::{opaque::latest::Location, VersionedLocation};
+use xcm::{latest::prelude::*, opaque::latest::Location, VersionedLocation};
 use xcm_executor::XcmExecutor;
 
 parameter_types! {
@@ -46,6 +62,12 @@ parameter_types! {
 	/// submit an invalid transaction. The set of such (registered) relayers is tracked
 	/// by the `pallet_bridge_relayers` pallet at the Polkadot Bridge Hub.
 	pub storage RequiredStakeForStakeAndSlash: Balance = 500 * constants::currency::UNITS;
+
+	/// Identifier of the account that holds the DOT rewards that are queued for Asset Hub.
+	pub const BridgeRewardBatcherPalletId: PalletId = PalletId(*b"py/brbat");
+	/// The account that holds the DOT rewards that are queued for Asset Hub.
+	pub BridgeRewardBatcherAccount: AccountId =
+		BridgeRewardBatcherPalletId::get().into_account_truncating();
 }
 
 /// Showcasing that we can handle multiple different rewards with the same pallet.
@@ -66,6 +88,9 @@ pub enum BridgeReward {
 	PolkadotKusamaBridge(RewardsAccountParams<LegacyLaneId>),
 	/// Rewards for Snowbridge.
 	Snowbridge,
+	/// Rewards for the dynamic lanes of the P/K bridge—distinguished by the
+	/// `RewardsAccountParams` key.
+	PolkadotKusamaBridgeDynamicLanes(RewardsAccountParams<HashedLaneId>),
 }
 
 impl From<RewardsAccountParams<LegacyLaneId>> for BridgeReward {
@@ -74,6 +99,12 @@ impl From<RewardsAccountParams<LegacyLaneId>> for BridgeReward {
 	}
 }
 
+impl From<RewardsAccountParams<HashedLaneId>> for BridgeReward {
+	fn from(value: RewardsAccountParams<HashedLaneId>) -> Self {
+		Self::PolkadotKusamaBridgeDynamicLanes(value)
+	}
+}
+
 /// An enum representing the different types of supported beneficiaries.
 #[derive(
 	Clone, Debug, Decode, DecodeWithMemTracking, Encode, Eq, MaxEncodedLen, PartialEq, TypeInfo,
@@ -92,6 +123,9 @@ impl From<sp_runtime::AccountId32> for BridgeRewardBeneficiaries {
 }
 
 /// Implementation of `bp_relayers::PaymentProcedure` as a pay/claim rewards scheme.
+///
+/// Rewards that are paid to Asset Hub are queued in the `BridgeRewardBatcher` pallet and sent
+/// with one message per beneficiary and reward kind.
 pub struct BridgeRewardPayer;
 impl bp_relayers::PaymentProcedure<AccountId, BridgeReward, u128> for BridgeRewardPayer {
 	type Error = sp_runtime::DispatchError;
@@ -104,46 +138,191 @@ impl bp_relayers::PaymentProcedure<AccountId, BridgeReward, u128> for BridgeRewa
 		beneficiary: BridgeRewardBeneficiaries,
 	) -> Result<(), Self::Error> {
 		match reward_kind {
-			BridgeReward::PolkadotKusamaBridge(lane_params) => {
-				match beneficiary {
-					BridgeRewardBeneficiaries::LocalAccount(account) => {
-						bp_relayers::PayRewardFromAccount::<
-							Balances,
-							AccountId,
-							LegacyLaneId,
-							u128,
-						>::pay_reward(
-							relayer, lane_params, reward, account,
-						)
-					},
-					BridgeRewardBeneficiaries::AssetHubLocation(_) => Err(Self::Error::Other("`AssetHubLocation` beneficiary is not supported for `PolkadotKusamaBridge` rewards!")),
-				}
-			},
+			BridgeReward::PolkadotKusamaBridge(lane_params) =>
+				pay_lane_reward(relayer, lane_params, reward, beneficiary),
+			BridgeReward::PolkadotKusamaBridgeDynamicLanes(lane_params) =>
+				pay_lane_reward(relayer, lane_params, reward, beneficiary),
 			BridgeReward::Snowbridge => {
-				match beneficiary {
-					BridgeRewardBeneficiaries::LocalAccount(_) => Err(Self::Error::Other("`LocalAccount` beneficiary is not supported for `Snowbridge` rewards!")),
-					BridgeRewardBeneficiaries::AssetHubLocation(account_location) => {
-						let account_location = Location::try_from(account_location.as_ref().clone())
-							.map_err(|_| Self::Error::Other("`AssetHubLocation` beneficiary location version is not supported for `Snowbridge` rewards!"))?;
-						snowbridge_core::reward::PayAccountOnLocation::<
-							AccountId,
-							u128,
-							EthereumNetwork,
-							AssetHubLocation,
-							InboundQueueV2Location,
-							XcmRouter,
-							XcmExecutor<XcmConfig>,
-							RuntimeCall
-						>::pay_reward(
-							relayer, (), reward, account_location
-						)
-					}
-				}
-			}
+				// Ether rewards only exist on Asset Hub, so a local account is paid there.
+				let account_location = match beneficiary {
+					BridgeRewardBeneficiaries::LocalAccount(account) =>
+						asset_hub_account_location(account),
+					BridgeRewardBeneficiaries::AssetHubLocation(account_location) =>
+						latest_location(*account_location)?,
+				};
+				BridgeRewardBatcher::queue_reward(
+					account_location,
+					AssetHubRewardKind::Ether,
+					reward,
+				);
+				Ok(())
+			},
 		}
 	}
 }
 
+/// Pays the P/K bridge `reward` from the rewards account of `lane_params`.
+///
+/// Rewards for an Asset Hub beneficiary are moved to [`BridgeRewardBatcherAccount`] and queued.
+fn pay_lane_reward<LaneId: LaneIdType>(
+	relayer: &AccountId,
+	lane_params: RewardsAccountParams<LaneId>,
+	reward: u128,
+	beneficiary: BridgeRewardBeneficiaries,
+) -> Result<(), DispatchError> {
+	let account = match beneficiary {
+		BridgeRewardBeneficiaries::LocalAccount(ref account) => account.clone(),
+		BridgeRewardBeneficiaries::AssetHubLocation(_) => BridgeRewardBatcherAccount::get(),
+	};
+	bp_relayers::PayRewardFromAccount::<Balances, AccountId, LaneId, u128>::pay_reward(
+		relayer,
+		lane_params,
+		reward,
+		account,
+	)?;
+
+	if let BridgeRewardBeneficiaries::AssetHubLocation(account_location) = beneficiary {
+		BridgeRewardBatcher::queue_reward(
+			latest_location(*account_location)?,
+			AssetHubRewardKind::Native,
+			reward,
+		);
+	}
+	Ok(())
+}
+
+/// The kinds of rewards that are batched and paid on Asset Hub.
+#[derive(
+	Clone,
+	Copy,
+	Debug,
+	Decode,
+	DecodeWithMemTracking,
+	Encode,
+	Eq,
+	MaxEncodedLen,
+	PartialEq,
+	TypeInfo,
+)]
+pub enum AssetHubRewardKind {
+	/// DOT held by [`BridgeRewardBatcherAccount`] that is teleported to Asset Hub.
+	Native,
+	/// Ether that is paid on Asset Hub by Snowbridge.
+	Ether,
+}
+
+/// Converts `location` to the latest XCM version, so that all rewards of a beneficiary end up in
+/// the same batch.
+fn latest_location(location: VersionedLocation) -> Result<VersionedLocation, DispatchError> {
+	Location::try_from(location).map(VersionedLocation::from).map_err(|_| {
+		DispatchError::Other("`AssetHubLocation` beneficiary location version is not supported!")
+	})
+}
+
+/// The location of `account` on Asset Hub, as seen from this chain.
+fn asset_hub_account_location(account: AccountId) -> VersionedLocation {
+	VersionedLocation::from(Location::new(
+		1,
+		[
+			Parachain(constants::system_parachain::ASSET_HUB_ID),
+			Junction::AccountId32 { network: None, id: account.into() },
+		],
+	))
+}
+
+/// Pays the rewards batched by the `BridgeRewardBatcher` pallet on Asset Hub.
+pub struct AssetHubRewardPayer;
+impl PayBatch<AccountId, AssetHubRewardKind, u128, VersionedLocation> for AssetHubRewardPayer {
+	fn pay_batch(
+		payer: &AccountId,
+		kind: AssetHubRewardKind,
+		amount: u128,
+		beneficiary: VersionedLocation,
+	) -> DispatchResult {
+		let beneficiary = Location::try_from(beneficiary)
+			.map_err(|_| DispatchError::Other("Unsupported beneficiary location version!"))?;
+
+		match kind {
+			AssetHubRewardKind::Native => teleport_to_asset_hub(payer, amount, beneficiary),
+			AssetHubRewardKind::Ether => snowbridge_core::reward::PayAccountOnLocation::<
+				AccountId,
+				u128,
+				EthereumNetwork,
+				AssetHubLocation,
+				InboundQueueV2Location,
+				XcmRouter,
+				XcmExecutor<XcmConfig>,
+				RuntimeCall,
+			>::pay_reward(payer, (), amount, beneficiary),
+		}
+	}
+}
+
+/// Teleports `amount` of DOT from [`BridgeRewardBatcherAccount`] to `beneficiary` on Asset Hub.
+///
+/// The delivery fees are charged from `payer`.
+fn teleport_to_asset_hub(payer: &AccountId, amount: u128, beneficiary: Location) -> DispatchResult {
+	let beneficiary = beneficiary
+		.reanchored(&AssetHubLocation::get(), &UniversalLocation::get())
+		.map_err(|_| DispatchError::Other("Failed to reanchor the beneficiary!"))?;
+	let asset: Asset = (Location::parent(), amount).into();
+	let message = Xcm(vec![
+		ReceiveTeleportedAsset(asset.clone().into()),
+		ClearOrigin,
+		BuyExecution { fees: asset, weight_limit: Unlimited },
+		DepositAsset { assets: Wild(AllCounted(1)), beneficiary },
+	]);
+
+	let (ticket, fees) = validate_send::<XcmRouter>(AssetHubLocation::get(), message)
+		.map_err(|_| DispatchError::Other("Failed to validate the reward message!"))?;
+	let payer_location =
+		Location::new(0, [Junction::AccountId32 { network: None, id: payer.clone().into() }]);
+	XcmExecutor::<XcmConfig>::charge_fees(payer_location, fees)
+		.map_err(|_| DispatchError::Other("Failed to charge the delivery fees!"))?;
+	Balances::burn_from(
+		&BridgeRewardBatcherAccount::get(),
+		amount,
+		Preservation::Expendable,
+		Precision::Exact,
+		Fortitude::Polite,
+	)?;
+	XcmRouter::deliver(ticket)
+		.map_err(|_| DispatchError::Other("Failed to deliver the reward message!"))?;
+
+	Ok(())
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct BridgeRewardBatcherBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl
+	pallet_bridge_reward_batcher::BenchmarkHelper<
+		AccountId,
+		AssetHubRewardKind,
+		u128,
+		VersionedLocation,
+	> for BridgeRewardBatcherBenchmarkHelper
+{
+	fn reward() -> (VersionedLocation, AssetHubRewardKind, u128) {
+		(
+			asset_hub_account_location(AccountId::from([1u8; 32])),
+			AssetHubRewardKind::Native,
+			constants::currency::UNITS,
+		)
+	}
+
+	fn prepare_payment(payer: &AccountId) {
+		use polkadot_runtime_constants::system_parachain::AssetHubParaId;
+
+		let (_, _, amount) = Self::reward();
+		Balances::mint_into(&BridgeRewardBatcherAccount::get(), amount).unwrap();
+		Balances::mint_into(payer, 100 * constants::currency::UNITS).unwrap();
+		crate::ParachainSystem::open_outbound_hrmp_channel_for_benchmarks_or_tests(
+			AssetHubParaId::get(),
+		);
+	}
+}
+
 /// Allows collect and claim rewards for relayers.
 pub type BridgeRelayersInstance = ();
 impl pallet_bridge_relayers::Config<BridgeRelayersInstance> for Runtime {
@@ -162,3 +341,73 @@ impl pallet_bridge_relayers::Config<BridgeRelayersInstance> for Runtime {
 	type Balance = Balance;
 	type WeightInfo = weights::pallet_bridge_relayers::WeightInfo<Runtime>;
 }
+
+impl pallet_bridge_reward_batcher::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RewardKind = AssetHubRewardKind;
+	type Balance = u128;
+	type Beneficiary = VersionedLocation;
+	type Payer = AssetHubRewardPayer;
+	type WeightInfo = weights::pallet_bridge_reward_batcher::WeightInfo<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = BridgeRewardBatcherBenchmarkHelper;
+}
+
+/// Conversion of the lane ids of the messages pallets into the lane id of the
+/// `BridgeHealthApi`.
+pub trait IntoBridgeLaneId {
+	fn into_bridge_lane_id(self) -> BridgeLaneId;
+}
+
+impl IntoBridgeLaneId for LegacyLaneId {
+	fn into_bridge_lane_id(self) -> BridgeLaneId {
+		BridgeLaneId::Legacy(self.0)
+	}
+}
+
+impl IntoBridgeLaneId for HashedLaneId {
+	fn into_bridge_lane_id(self) -> BridgeLaneId {
+		BridgeLaneId::Hashed(*self.inner())
+	}
+}
+
+/// Health of all lanes of the messages pallet instance `MI`, which are owned by the XCM bridge
+/// hub instance `XI`.
+pub fn messages_lanes_health<MI: 'static, XI: 'static>(
+	best_finalized_bridged_header: Option<BridgedHeaderId>,
+) -> Vec<MessagesLaneHealth>
+where
+	Runtime: pallet_xcm_bridge_hub::Config<XI, BridgeMessagesPalletInstance = MI>,
+	LaneIdOf<Runtime, MI>: IntoBridgeLaneId,
+{
+	let is_halted = pallet_bridge_messages::PalletOperatingMode::<Runtime, MI>::get() !=
+		MessagesOperatingMode::Basic(BasicOperatingMode::Normal);
+
+	pallet_bridge_messages::OutboundLanes::<Runtime, MI>::iter()
+		.map(|(lane_id, outbound_lane)| {
+			let unrewarded_relayers =
+				pallet_bridge_messages::InboundLanes::<Runtime, MI>::get(lane_id)
+					.map_or(0, |inbound_lane| inbound_lane.0.relayers.len() as u32);
+			let bridge_state = pallet_xcm_bridge_hub::LaneToBridge::<Runtime, XI>::get(lane_id)
+				.and_then(pallet_xcm_bridge_hub::Bridges::<Runtime, XI>::get)
+				.map(|bridge| match bridge.state {
+					BridgeState::Opened => BridgeLaneState::Opened,
+					BridgeState::Suspended => BridgeLaneState::Suspended,
+					BridgeState::Closed => BridgeLaneState::Closed,
+				});
+
+			MessagesLaneHealth {
+				lane_id: lane_id.into_bridge_lane_id(),
+				best_finalized_bridged_header: best_finalized_bridged_header.clone(),
+				latest_generated_nonce: outbound_lane.latest_generated_nonce,
+				latest_received_nonce: outbound_lane.latest_received_nonce,
+				outbound_nonce_gap: outbound_lane
+					.latest_generated_nonce
+					.saturating_sub(outbound_lane.latest_received_nonce),
+				unrewarded_relayers,
+				is_halted,
+				bridge_state,
+			}
+		})
+		.collect()
+}
@@ -17,9 +17,10 @@
 use crate::{
 	bridge_common_config::BridgeReward,
 	xcm_config::{self, RelayNetwork, RelayTreasuryPalletAccount, RootLocation, UniversalLocation},
-	AggregateMessageOrigin, Balances, BridgeRelayers, EthereumBeaconClient, EthereumInboundQueue,
-	EthereumInboundQueueV2, EthereumOutboundQueue, EthereumOutboundQueueV2, EthereumSystem,
-	EthereumSystemV2, MessageQueue, Runtime, RuntimeEvent, TransactionByteFee,
+	AggregateMessageOrigin, Balances, BridgeRelayers, EthereumBeaconClient, EthereumCongestion,
+	EthereumInboundQueue, EthereumInboundQueueV2, EthereumOutboundQueue, EthereumOutboundQueueV2,
+	EthereumSystem, EthereumSystemV2, MessageQueue, Runtime, RuntimeEvent, TransactionByteFee,
+	XcmpQueue,
 };
 use bp_asset_hub_polkadot::SystemFrontendPalletInstance;
 use bp_bridge_hub_polkadot::snowbridge::{
@@ -27,8 +28,9 @@ use bp_bridge_hub_polkadot::snowbridge::{
 	InboundQueueV2PalletInstance, Parameters, SetReservesCall,
 };
 pub use bp_bridge_hub_polkadot::snowbridge::{EthereumLocation, EthereumNetwork};
+use bp_relayers::RewardLedger;
 use frame_support::{parameter_types, traits::Contains, weights::ConstantMultiplier};
-use frame_system::EnsureRootWithSuccess;
+use frame_system::{EnsureRoot, EnsureRootWithSuccess};
 use hex_literal::hex;
 use pallet_xcm::EnsureXcm;
 use parachains_common::{AccountId, Balance};
@@ -45,11 +47,18 @@ use snowbridge_inbound_queue_primitives::{
 use snowbridge_outbound_queue_primitives::{
 	v1::{ConstantGasMeter, EthereumBlobExporter},
 	v2::{ConstantGasMeter as ConstantGasMeterV2, EthereumBlobExporter as EthereumBlobExporterV2},
+	OnNewCommitment,
+};
+use sp_core::{H160, H256};
+use sp_runtime::{
+	traits::{ConstU32, ConstU8, Keccak256},
+	DispatchError, DispatchResult,
+};
+use system_parachains_common::apis::{BeaconHeaderId, SnowbridgeChannelHealth, SnowbridgeHealth};
+use system_parachains_constants::polkadot::{fee::WeightToFee, locations::AssetHubLocation};
+use xcm::prelude::{
+	send_xcm, GlobalConsensus, InteriorLocation, Location, PalletInstance, Parachain,
 };
-use sp_core::H160;
-use sp_runtime::traits::{ConstU32, ConstU8, Keccak256};
-use system_parachains_constants::polkadot::fee::WeightToFee;
-use xcm::prelude::{GlobalConsensus, InteriorLocation, Location, PalletInstance, Parachain};
 use xcm_executor::XcmExecutor;
 
 pub const SLOTS_PER_EPOCH: u32 = snowbridge_pallet_ethereum_client::config::SLOTS_PER_EPOCH as u32;
@@ -191,13 +200,111 @@ impl snowbridge_pallet_outbound_queue_v2::Config for Runtime {
 	type EthereumNetwork = EthereumNetwork;
 	type RewardKind = BridgeReward;
 	type DefaultRewardKind = SnowbridgeReward;
-	type RewardPayment = BridgeRelayers;
+	type RewardPayment = EthereumCongestionReporter;
 	type AggregateMessageOrigin = AggregateMessageOrigin;
-	type OnNewCommitment = ();
+	type OnNewCommitment = EthereumCongestionReporter;
 	#[cfg(feature = "runtime-benchmarks")]
 	type Helper = Runtime;
 }
 
+parameter_types! {
+	/// Number of messages of `EthereumOutboundQueueV2` waiting for their delivery receipt, at
+	/// which Asset Hub is told that the Ethereum bridge is congested.
+	pub const EthereumCongestedBacklog: u64 = 256;
+	/// Number of messages of `EthereumOutboundQueueV2` waiting for their delivery receipt, below
+	/// which Asset Hub is told that the Ethereum bridge is no longer congested.
+	pub const EthereumUncongestedBacklog: u64 = 32;
+}
+
+/// Whether Asset Hub has been told that the Ethereum bridge is congested.
+pub type EthereumBridgeCongested = pallet_ethereum_congestion::IsCongested<Runtime>;
+
+impl pallet_ethereum_congestion::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Backlog = EthereumCongestionReporter;
+	type Reporter = EthereumCongestionReporter;
+	type CongestedBacklog = EthereumCongestedBacklog;
+	type UncongestedBacklog = EthereumUncongestedBacklog;
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type WeightInfo = pallet_ethereum_congestion::weights::SubstrateWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = EthereumCongestionReporter;
+}
+
+/// Reports the backlog of `EthereumOutboundQueueV2` to the `ToEthereumXcmRouter` of Asset Hub,
+/// which raises the delivery fee of Snowbridge V2 messages while the bridge is congested.
+///
+/// The backlog is checked by [`EthereumCongestion`] whenever new messages are committed and
+/// whenever a delivery receipt is submitted. Relayer rewards are forwarded to [`BridgeRelayers`].
+pub struct EthereumCongestionReporter;
+
+impl pallet_ethereum_congestion::OutboundBacklog for EthereumCongestionReporter {
+	/// Returns `true` if the message committed `backlog` messages ago is still waiting for its
+	/// delivery receipt.
+	///
+	/// Receipts may arrive out of order, so this is an estimate of the number of pending
+	/// messages that only needs a single storage read.
+	fn has_backlog(backlog: u64) -> bool {
+		let next_nonce = snowbridge_pallet_outbound_queue_v2::Nonce::<Runtime>::get();
+		next_nonce.checked_sub(backlog).is_some_and(|nonce| {
+			snowbridge_pallet_outbound_queue_v2::PendingOrders::<Runtime>::contains_key(nonce)
+		})
+	}
+}
+
+impl pallet_ethereum_congestion::ReportCongestion for EthereumCongestionReporter {
+	fn report(is_congested: bool) -> DispatchResult {
+		let message = bp_asset_hub_polkadot::build_ethereum_congestion_message(is_congested);
+		send_xcm::<XcmpQueue>(AssetHubLocation::get(), message.into())
+			.map(|_| ())
+			.map_err(|_| DispatchError::Other("Failed to send the congestion signal to Asset Hub!"))
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_ethereum_congestion::BenchmarkHelper for EthereumCongestionReporter {
+	fn prepare_report() {
+		crate::ParachainSystem::open_outbound_hrmp_channel_for_benchmarks_or_tests(
+			AssetHubParaId::get(),
+		);
+	}
+}
+
+impl OnNewCommitment for EthereumCongestionReporter {
+	fn on_new_commitment(_commitment: H256) {
+		EthereumCongestion::update()
+	}
+}
+
+impl RewardLedger<AccountId, BridgeReward, Balance> for EthereumCongestionReporter {
+	fn register_reward(relayer: &AccountId, reward: BridgeReward, reward_balance: Balance) {
+		BridgeRelayers::register_reward(relayer, reward, reward_balance);
+		EthereumCongestion::update()
+	}
+}
+
+/// Health of the bridge with Ethereum, exposed by the `BridgeHealthApi`.
+pub fn snowbridge_health() -> SnowbridgeHealth {
+	let block_root = snowbridge_pallet_ethereum_client::LatestFinalizedBlockRoot::<Runtime>::get();
+	let latest_finalized_beacon_header =
+		snowbridge_pallet_ethereum_client::FinalizedBeaconState::<Runtime>::get(block_root)
+			.map(|state| BeaconHeaderId { slot: state.slot, block_root });
+	let channels = snowbridge_pallet_system::Channels::<Runtime>::iter_keys()
+		.map(|channel_id| SnowbridgeChannelHealth {
+			channel_id: <[u8; 32]>::from(channel_id).into(),
+			inbound_nonce: snowbridge_pallet_inbound_queue::Nonce::<Runtime>::get(channel_id),
+			outbound_nonce: snowbridge_pallet_outbound_queue::Nonce::<Runtime>::get(channel_id),
+		})
+		.collect();
+
+	SnowbridgeHealth {
+		latest_finalized_beacon_header,
+		channels,
+		outbound_v2_nonce: snowbridge_pallet_outbound_queue_v2::Nonce::<Runtime>::get(),
+		is_congested: EthereumBridgeCongested::get(),
+	}
+}
+
 #[cfg(not(any(feature = "std", feature = "runtime-benchmarks", test)))]
 parameter_types! {
 	pub const ChainForkVersions: ForkVersions = ForkVersions {
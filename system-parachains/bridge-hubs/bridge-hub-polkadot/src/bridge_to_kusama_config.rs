@@ -21,31 +21,36 @@ use crate::{
 	weights,
 	xcm_config::{UniversalLocation, XcmRouter},
 	AccountId, Balance, Balances, BridgeKusamaMessages, PolkadotXcm, Runtime, RuntimeEvent,
-	RuntimeHoldReason, XcmOverBridgeHubKusama, XcmpQueue,
+	RuntimeHoldReason, XcmOverBridgeHubKusama, XcmOverBridgeHubKusamaDynamic, XcmpQueue,
 };
 
+use alloc::collections::btree_map::BTreeMap;
 pub use bp_bridge_hub_kusama::bp_kusama;
 use bp_messages::{
 	source_chain::FromBridgedChainMessagesDeliveryProof,
-	target_chain::FromBridgedChainMessagesProof, LegacyLaneId,
+	target_chain::FromBridgedChainMessagesProof, HashedLaneId, LegacyLaneId,
 };
 use bp_parachains::SingleParaStoredHeaderDataBuilder;
 use bp_runtime::Chain;
+use bp_xcm_bridge_hub_router::XcmBridgeHubRouterCall;
 use bridge_hub_common::xcm_version::XcmVersionOfDestAndRemoteBridge;
+use codec::Encode;
 use frame_support::{
 	parameter_types,
-	traits::{ConstU128, PalletInfoAccess},
+	traits::{ConstU128, Contains, PalletInfoAccess},
 };
 use frame_system::{EnsureNever, EnsureRoot};
 use pallet_bridge_messages::LaneIdOf;
 use pallet_bridge_relayers::extension::{
 	BridgeRelayersTransactionExtension, WithMessagesExtensionConfig,
 };
+use pallet_xcm::EnsureXcm;
 use pallet_xcm_bridge_hub::{BridgeId, XcmAsPlainPayload};
 use parachains_common::xcm_config::{AllSiblingSystemParachains, RelayOrOtherSystemParachains};
 use polkadot_parachain_primitives::primitives::Sibling;
 use polkadot_runtime_constants as constants;
 use sp_runtime::traits::ConstU32;
+use system_parachains_constants::polkadot::locations::AssetHubLocation;
 use xcm::latest::prelude::*;
 use xcm_builder::{BridgeBlobDispatcher, ParentIsPreset, SiblingParachainConvertsVia};
 
@@ -57,6 +62,19 @@ parameter_types! {
 	/// This payment is tracked by the `pallet_bridge_relayers` pallet at the Polkadot
 	/// Bridge Hub.
 	pub storage DeliveryRewardInBalance: Balance = constants::currency::UNITS / 2_000;
+
+	/// Deposit that is held from the sovereign account of a sibling parachain that opens a
+	/// dynamic lane over `XcmOverBridgeHubKusamaDynamic`.
+	///
+	/// The deposit is returned when the bridge is closed. System parachains don't pay it.
+	pub storage BridgeDeposit: Balance = 10 * constants::currency::UNITS;
+
+	/// Pallet index of the bridge router of a sibling parachain, by parachain id.
+	///
+	/// Congestion signals of the dynamic lanes that a sibling parachain has opened are sent to
+	/// this pallet, see [`build_congestion_message`]. The router of Asset Hub is known and doesn't
+	/// need an entry.
+	pub storage SiblingBridgeRouters: BTreeMap<u32, u8> = BTreeMap::new();
 }
 
 // Parameters, used by both XCM and bridge code.
@@ -132,6 +150,21 @@ pub type OnBridgeHubPolkadotRefundBridgeHubKusamaMessages = BridgeRelayersTransa
 >;
 bp_runtime::generate_static_str_provider!(OnBridgeHubPolkadotRefundBridgeHubKusamaMessages);
 
+/// Signed extension that refunds relayers that are delivering messages over the dynamic lanes from
+/// the Kusama parachain.
+pub type OnBridgeHubPolkadotRefundBridgeHubKusamaDynamicMessages =
+	BridgeRelayersTransactionExtension<
+		Runtime,
+		WithMessagesExtensionConfig<
+			StrOnBridgeHubPolkadotRefundBridgeHubKusamaDynamicMessages,
+			Runtime,
+			WithBridgeHubKusamaDynamicMessagesInstance,
+			BridgeRelayersInstance,
+			PriorityBoostPerMessage,
+		>,
+	>;
+bp_runtime::generate_static_str_provider!(OnBridgeHubPolkadotRefundBridgeHubKusamaDynamicMessages);
+
 /// Add GRANDPA bridge pallet to track Kusama relay chain.
 pub type BridgeGrandpaKusamaInstance = pallet_bridge_grandpa::Instance1;
 impl pallet_bridge_grandpa::Config<BridgeGrandpaKusamaInstance> for Runtime {
@@ -187,6 +220,38 @@ impl pallet_bridge_messages::Config<WithBridgeHubKusamaMessagesInstance> for Run
 	type OnMessagesDelivered = XcmOverBridgeHubKusama;
 }
 
+/// Add XCM messages support for exchanging messages over the dynamic lanes with BridgeHubKusama.
+///
+/// The pallet code is the same as of `BridgeKusamaMessages`, so it reuses its weights.
+pub type WithBridgeHubKusamaDynamicMessagesInstance = pallet_bridge_messages::Instance2;
+impl pallet_bridge_messages::Config<WithBridgeHubKusamaDynamicMessagesInstance> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = weights::pallet_bridge_messages::WeightInfo<Runtime>;
+
+	type ThisChain = bp_bridge_hub_polkadot::BridgeHubPolkadotWithDynamicLanes;
+	type BridgedChain = bp_bridge_hub_kusama::BridgeHubKusamaWithDynamicLanes;
+	type BridgedHeaderChain = pallet_bridge_parachains::ParachainHeaders<
+		Runtime,
+		BridgeParachainKusamaInstance,
+		bp_bridge_hub_kusama::BridgeHubKusamaWithDynamicLanes,
+	>;
+
+	type OutboundPayload = XcmAsPlainPayload;
+	type InboundPayload = XcmAsPlainPayload;
+	type LaneId = HashedLaneId;
+
+	type DeliveryPayments = ();
+	type DeliveryConfirmationPayments = pallet_bridge_relayers::DeliveryConfirmationPaymentsAdapter<
+		Runtime,
+		WithBridgeHubKusamaDynamicMessagesInstance,
+		BridgeRelayersInstance,
+		DeliveryRewardInBalance,
+	>;
+
+	type MessageDispatch = XcmOverBridgeHubKusamaDynamic;
+	type OnMessagesDelivered = XcmOverBridgeHubKusamaDynamic;
+}
+
 /// Add support for the export and dispatch of XCM programs.
 pub type XcmOverBridgeHubKusamaInstance = pallet_xcm_bridge_hub::Instance1;
 impl pallet_xcm_bridge_hub::Config<XcmOverBridgeHubKusamaInstance> for Runtime {
@@ -203,6 +268,7 @@ impl pallet_xcm_bridge_hub::Config<XcmOverBridgeHubKusamaInstance> for Runtime {
 
 	type ForceOrigin = EnsureRoot<AccountId>;
 	// We don't want to allow creating bridges for this instance with `LegacyLaneId`.
+	// Dynamic lanes are opened over `XcmOverBridgeHubKusamaDynamic` instead.
 	type OpenBridgeOrigin = EnsureNever<Location>;
 	// Converter aligned with `OpenBridgeOrigin`.
 	type BridgeOriginAccountIdConverter =
@@ -220,6 +286,38 @@ impl pallet_xcm_bridge_hub::Config<XcmOverBridgeHubKusamaInstance> for Runtime {
 	type BlobDispatcher = FromKusamaMessageBlobDispatcher;
 }
 
+/// Add support for the export and dispatch of XCM programs over the lanes that are opened by
+/// sibling parachains with `open_bridge`.
+pub type XcmOverBridgeHubKusamaDynamicInstance = pallet_xcm_bridge_hub::Instance2;
+impl pallet_xcm_bridge_hub::Config<XcmOverBridgeHubKusamaDynamicInstance> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+
+	type UniversalLocation = UniversalLocation;
+	type BridgedNetwork = KusamaGlobalConsensusNetworkLocation;
+	type BridgeMessagesPalletInstance = WithBridgeHubKusamaDynamicMessagesInstance;
+	// See `XcmOverBridgeHubKusamaInstance`.
+	type MessageExportPrice = ();
+	type DestinationVersion = XcmVersionOfDestAndRemoteBridge<PolkadotXcm, BridgeHubKusamaLocation>;
+
+	type ForceOrigin = EnsureRoot<AccountId>;
+	// Any sibling parachain may open a bridge with `HashedLaneId`.
+	type OpenBridgeOrigin = EnsureXcm<SiblingParachains>;
+	// Converter aligned with `OpenBridgeOrigin`.
+	type BridgeOriginAccountIdConverter = SiblingParachainConvertsVia<Sibling, AccountId>;
+
+	type BridgeDeposit = BridgeDeposit;
+	type Currency = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	// Require the deposit from all sibling parachains, but system parachains.
+	type AllowWithoutBridgeDeposit =
+		RelayOrOtherSystemParachains<AllSiblingSystemParachains, Runtime>;
+
+	type LocalXcmChannelManager = CongestionManager;
+	// Messages of all lanes are dispatched with the same bridge instance location, so the
+	// destinations only need to trust one origin for the Kusama bridge.
+	type BlobDispatcher = FromKusamaMessageBlobDispatcher;
+}
+
 /// Implementation of `bp_xcm_bridge_hub::LocalXcmChannelManager` for congestion management.
 pub struct CongestionManager;
 impl pallet_xcm_bridge_hub::LocalXcmChannelManager for CongestionManager {
@@ -236,24 +334,65 @@ impl pallet_xcm_bridge_hub::LocalXcmChannelManager for CongestionManager {
 	}
 
 	fn suspend_bridge(local_origin: &Location, bridge: BridgeId) -> Result<(), Self::Error> {
-		// This bridge is intended for AH<>AH communication with a hard-coded/static lane,
-		// so `local_origin` is expected to represent only the local AH.
-		send_xcm::<XcmpQueue>(
-			local_origin.clone(),
-			bp_asset_hub_kusama::build_congestion_message(bridge.inner(), true).into(),
-		)
-		.map(|_| ())
+		report_bridge_status(local_origin, bridge, true)
 	}
 
 	fn resume_bridge(local_origin: &Location, bridge: BridgeId) -> Result<(), Self::Error> {
-		// This bridge is intended for AH<>AH communication with a hard-coded/static lane,
-		// so `local_origin` is expected to represent only the local AH.
-		send_xcm::<XcmpQueue>(
-			local_origin.clone(),
-			bp_asset_hub_kusama::build_congestion_message(bridge.inner(), false).into(),
+		report_bridge_status(local_origin, bridge, false)
+	}
+}
+
+/// All sibling parachains.
+pub struct SiblingParachains;
+impl Contains<Location> for SiblingParachains {
+	fn contains(location: &Location) -> bool {
+		matches!(location.unpack(), (1, [Parachain(_)]))
+	}
+}
+
+/// Builds the (un)congestion message for the bridge router of `local_origin`, which owns the lane
+/// of `bridge`.
+///
+/// The router of Asset Hub is known, the routers of other sibling parachains are looked up in
+/// [`SiblingBridgeRouters`]. Returns `None` if the router of `local_origin` is not known.
+pub fn build_congestion_message(
+	local_origin: &Location,
+	bridge: BridgeId,
+	is_congested: bool,
+) -> Option<Xcm<()>> {
+	if *local_origin == AssetHubLocation::get() {
+		return Some(
+			bp_asset_hub_polkadot::build_congestion_message(bridge.inner(), is_congested).into(),
 		)
-		.map(|_| ())
 	}
+	let (1, [Parachain(para_id)]) = local_origin.unpack() else { return None };
+	let router = SiblingBridgeRouters::get().get(para_id).copied()?;
+	let call = (
+		router,
+		XcmBridgeHubRouterCall::report_bridge_status { bridge_id: bridge.inner(), is_congested },
+	);
+	Some(Xcm(alloc::vec![
+		UnpaidExecution { weight_limit: Unlimited, check_origin: None },
+		Transact {
+			origin_kind: OriginKind::Xcm,
+			fallback_max_weight: Some(
+				bp_asset_hub_polkadot::XcmBridgeHubRouterTransactCallMaxWeight::get(),
+			),
+			call: call.encode().into(),
+		},
+		ExpectTransactStatus(MaybeErrorCode::Success),
+	]))
+}
+
+/// Sends the (un)congestion signal of `bridge` to `local_origin`, which has opened it.
+fn report_bridge_status(
+	local_origin: &Location,
+	bridge: BridgeId,
+	is_congested: bool,
+) -> Result<(), SendError> {
+	let message = build_congestion_message(local_origin, bridge, is_congested)
+		.ok_or(SendError::NotApplicable)?;
+	send_xcm::<XcmpQueue>(local_origin.clone(), message).map(|_| ())
 }
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -376,6 +515,90 @@ mod tests {
 		assert!(BridgeHubKusamaLocation::get()
 			.starts_with(&KusamaGlobalConsensusNetworkLocation::get()));
 	}
+
+	#[test]
+	fn ensure_dynamic_lanes_bridge_integrity() {
+		assert_complete_bridge_types!(
+			runtime: Runtime,
+			with_bridged_chain_messages_instance: WithBridgeHubKusamaDynamicMessagesInstance,
+			this_chain: bp_bridge_hub_polkadot::BridgeHubPolkadotWithDynamicLanes,
+			bridged_chain: bp_bridge_hub_kusama::BridgeHubKusamaWithDynamicLanes,
+			expected_payload_type: XcmAsPlainPayload,
+		);
+
+		assert_eq!(
+			<crate::BridgeKusamaDynamicMessages as PalletInfoAccess>::index() as u8,
+			bp_bridge_hub_polkadot::WITH_BRIDGE_POLKADOT_TO_KUSAMA_DYNAMIC_MESSAGES_PALLET_INDEX
+		);
+	}
+
+	#[test]
+	fn congestion_messages_are_routed_to_the_router_of_the_lane_owner() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			let bridge = BridgeId::new(
+				&[GlobalConsensus(Polkadot), Parachain(2000)].into(),
+				&[GlobalConsensus(Kusama), Parachain(2000)].into(),
+			);
+			let sibling = Location::new(1, [Parachain(2000)]);
+
+			assert_eq!(
+				build_congestion_message(&AssetHubLocation::get(), bridge, true),
+				Some(bp_asset_hub_polkadot::build_congestion_message(bridge.inner(), true).into())
+			);
+			assert_eq!(
+				build_congestion_message(&AssetHubLocation::get(), bridge, false),
+				Some(bp_asset_hub_polkadot::build_congestion_message(bridge.inner(), false).into())
+			);
+			// The router of the sibling is not known yet.
+			assert_eq!(build_congestion_message(&sibling, bridge, true), None);
+
+			SiblingBridgeRouters::set(&BTreeMap::from([(2000, 51)]));
+			let message = build_congestion_message(&sibling, bridge, true).unwrap();
+			let expected_call = (
+				51u8,
+				XcmBridgeHubRouterCall::report_bridge_status {
+					bridge_id: bridge.inner(),
+					is_congested: true,
+				},
+			)
+				.encode();
+			assert!(matches!(
+				&message.0[..],
+				[UnpaidExecution { .. }, Transact { call, .. }, ExpectTransactStatus(_)]
+					if call.clone().into_encoded() == expected_call
+			));
+			assert_eq!(
+				build_congestion_message(&Location::new(1, [Parachain(2001)]), bridge, true),
+				None
+			);
+		});
+	}
+
+	#[test]
+	fn sibling_parachains_may_open_bridges() {
+		use frame_support::traits::EnsureOrigin;
+		type OpenBridgeOrigin = <Runtime as pallet_xcm_bridge_hub::Config<
+			XcmOverBridgeHubKusamaDynamicInstance,
+		>>::OpenBridgeOrigin;
+		type AllowWithoutBridgeDeposit = <Runtime as pallet_xcm_bridge_hub::Config<
+			XcmOverBridgeHubKusamaDynamicInstance,
+		>>::AllowWithoutBridgeDeposit;
+		let try_open = |location: Location| {
+			OpenBridgeOrigin::try_origin(pallet_xcm::Origin::Xcm(location).into()).is_ok()
+		};
+
+		assert!(try_open(AssetHubLocation::get()));
+		assert!(try_open(Location::new(1, [Parachain(1001)])));
+		assert!(try_open(Location::new(1, [Parachain(2000)])));
+		assert!(!try_open(Location::parent()));
+		assert!(!try_open(Location::new(1, [Parachain(2000), PalletInstance(1)])));
+
+		// Only non-system parachains pay the deposit.
+		sp_io::TestExternalities::default().execute_with(|| {
+			assert!(AllowWithoutBridgeDeposit::contains(&AssetHubLocation::get()));
+			assert!(!AllowWithoutBridgeDeposit::contains(&Location::new(1, [Parachain(2000)])));
+		});
+	}
 }
 
 /// Contains the migrations for a P/K bridge.
@@ -130,7 +130,10 @@ pub type TxExtension = cumulus_pallet_weight_reclaim::StorageWeightReclaim<
 		frame_system::CheckWeight<Runtime>,
 		pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
 		BridgeRejectObsoleteHeadersAndMessages,
-		bridge_to_kusama_config::OnBridgeHubPolkadotRefundBridgeHubKusamaMessages,
+		(
+			bridge_to_kusama_config::OnBridgeHubPolkadotRefundBridgeHubKusamaMessages,
+			bridge_to_kusama_config::OnBridgeHubPolkadotRefundBridgeHubKusamaDynamicMessages,
+		),
 		frame_metadata_hash_extension::CheckMetadataHash<Runtime>,
 	),
 >;
@@ -142,7 +145,8 @@ bridge_runtime_common::generate_bridge_reject_obsolete_headers_and_messages! {
 	// Parachains
 	BridgeKusamaParachains,
 	// Messages
-	BridgeKusamaMessages
+	BridgeKusamaMessages,
+	BridgeKusamaDynamicMessages
 }
 
 /// Unchecked extrinsic type as expected by this runtime.
@@ -705,6 +709,11 @@ construct_runtime!(
 		BridgeKusamaParachains: pallet_bridge_parachains::<Instance1> = 52,
 		BridgeKusamaMessages: pallet_bridge_messages::<Instance1> = 53,
 		XcmOverBridgeHubKusama: pallet_xcm_bridge_hub::<Instance1> = 54,
+		BridgeKusamaDynamicMessages: pallet_bridge_messages::<Instance2> = 56,
+		XcmOverBridgeHubKusamaDynamic: pallet_xcm_bridge_hub::<Instance2> = 57,
+
+		// Batching of bridge relayer rewards that are paid on Asset Hub.
+		BridgeRewardBatcher: pallet_bridge_reward_batcher = 55,
 
 		// Ethereum bridge pallets.
 		EthereumInboundQueue: snowbridge_pallet_inbound_queue = 80,
@@ -716,6 +725,7 @@ construct_runtime!(
 		EthereumSystemV2: snowbridge_pallet_system_v2 = 90,
 		EthereumInboundQueueV2: snowbridge_pallet_inbound_queue_v2 = 91,
 		EthereumOutboundQueueV2: snowbridge_pallet_outbound_queue_v2 = 92,
+		EthereumCongestion: pallet_ethereum_congestion = 93,
 
 		// Message Queue. Importantly, it is registered after Snowbridge pallets
 		// so that messages are processed after the `on_initialize` hooks of bridging pallets.
@@ -755,6 +765,7 @@ mod benches {
 		[pallet_xcm_benchmarks::generic, XcmGeneric]
 		// Shared bridge pallets
 		[pallet_bridge_relayers, BridgeRelayersBench::<Runtime>]
+		[pallet_bridge_reward_batcher, BridgeRewardBatcher]
 		// Polkadot bridge pallets.
 		[pallet_bridge_grandpa, KusamaFinality]
 		[pallet_bridge_parachains, KusamaParachains]
@@ -767,6 +778,7 @@ mod benches {
 		[snowbridge_pallet_inbound_queue_v2, EthereumInboundQueueV2]
 		[snowbridge_pallet_outbound_queue_v2, EthereumOutboundQueueV2]
 		[snowbridge_pallet_system_v2, EthereumSystemV2]
+		[pallet_ethereum_congestion, EthereumCongestion]
 	);
 
 	impl frame_system_benchmarking::Config for Runtime {
@@ -1494,6 +1506,60 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl bp_bridge_hub_kusama::FromBridgeHubKusamaWithDynamicLanesInboundLaneApi<Block> for Runtime {
+		fn message_details(
+			lane: bp_messages::HashedLaneId,
+			messages: Vec<(bp_messages::MessagePayload, bp_messages::OutboundMessageDetails)>,
+		) -> Vec<bp_messages::InboundMessageDetails> {
+			bridge_runtime_common::messages_api::inbound_message_details::<
+				Runtime,
+				bridge_to_kusama_config::WithBridgeHubKusamaDynamicMessagesInstance,
+			>(lane, messages)
+		}
+	}
+
+	impl bp_bridge_hub_kusama::ToBridgeHubKusamaWithDynamicLanesOutboundLaneApi<Block> for Runtime {
+		fn message_details(
+			lane: bp_messages::HashedLaneId,
+			begin: bp_messages::MessageNonce,
+			end: bp_messages::MessageNonce,
+		) -> Vec<bp_messages::OutboundMessageDetails> {
+			bridge_runtime_common::messages_api::outbound_message_details::<
+				Runtime,
+				bridge_to_kusama_config::WithBridgeHubKusamaDynamicMessagesInstance,
+			>(lane, begin, end)
+		}
+	}
+
+	impl system_parachains_common::apis::BridgeHealthApi<Block> for Runtime {
+		fn bridge_health() -> system_parachains_common::apis::BridgeHealth {
+			use system_parachains_common::apis::{BridgeHealth, BridgedHeaderId};
+
+			let to_bridged_header_id = |id: bp_runtime::HeaderId<Hash, BlockNumber>| {
+				BridgedHeaderId { number: id.number(), hash: id.hash() }
+			};
+			let best_finalized_bridged_header = BridgeKusamaParachains::best_parachain_head_id::<
+				bp_bridge_hub_kusama::BridgeHubKusama
+			>().unwrap_or(None).map(to_bridged_header_id);
+
+			let mut lanes = bridge_common_config::messages_lanes_health::<
+				bridge_to_kusama_config::WithBridgeHubKusamaMessagesInstance,
+				bridge_to_kusama_config::XcmOverBridgeHubKusamaInstance,
+			>(best_finalized_bridged_header.clone());
+			lanes.extend(bridge_common_config::messages_lanes_health::<
+				bridge_to_kusama_config::WithBridgeHubKusamaDynamicMessagesInstance,
+				bridge_to_kusama_config::XcmOverBridgeHubKusamaDynamicInstance,
+			>(best_finalized_bridged_header));
+
+			BridgeHealth {
+				best_finalized_bridged_relay_header: BridgeKusamaGrandpa::best_finalized()
+					.map(to_bridged_header_id),
+				lanes,
+				snowbridge: Some(bridge_to_ethereum_config::snowbridge_health()),
+			}
+		}
+	}
+
 	impl snowbridge_outbound_queue_runtime_api::OutboundQueueApi<Block, Balance> for Runtime {
 		fn prove_message(leaf_index: u64) -> Option<snowbridge_merkle_tree::MerkleProof> {
 			snowbridge_pallet_outbound_queue::api::prove_message::<Runtime>(leaf_index)
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
 
-use crate::XcmOverBridgeHubKusama;
+use crate::{XcmOverBridgeHubKusama, XcmOverBridgeHubKusamaDynamic};
 use bridge_hub_common::DenyExportMessageFrom;
 
 use super::{
@@ -250,6 +250,8 @@ impl xcm_executor::Config for XcmConfig {
 	>;
 	type MessageExporter = (
 		XcmOverBridgeHubKusama,
+		// Lanes that are opened by sibling parachains with `open_bridge`.
+		XcmOverBridgeHubKusamaDynamic,
 		// `SnowbridgeExporterV2` should always be checked before legacy `SnowbridgeExporter`
 		crate::bridge_to_ethereum_config::SnowbridgeExporterV2,
 		crate::bridge_to_ethereum_config::SnowbridgeExporter,
@@ -19,12 +19,18 @@
 use bp_bridge_hub_polkadot::{snowbridge::EthereumLocation, BRIDGE_HUB_POLKADOT_PARACHAIN_ID};
 use bp_polkadot_core::Signature;
 use bridge_hub_polkadot_runtime::{
-	bridge_to_ethereum_config::{EthereumGatewayAddress, EthereumNetwork},
-	bridge_to_kusama_config::OnBridgeHubPolkadotRefundBridgeHubKusamaMessages,
+	bridge_to_ethereum_config::{
+		EthereumBridgeCongested, EthereumCongestionReporter, EthereumGatewayAddress,
+		EthereumNetwork,
+	},
+	bridge_to_kusama_config::{
+		OnBridgeHubPolkadotRefundBridgeHubKusamaDynamicMessages,
+		OnBridgeHubPolkadotRefundBridgeHubKusamaMessages,
+	},
 	xcm_config::{UniversalLocation, XcmConfig},
 	AllPalletsWithoutSystem, BridgeRejectObsoleteHeadersAndMessages, EthereumBeaconClient,
-	Executive, MessageQueueServiceWeight, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin,
-	SessionKeys, TxExtension, UncheckedExtrinsic,
+	Executive, MessageQueueServiceWeight, ParachainSystem, PolkadotXcm, Runtime, RuntimeCall,
+	RuntimeEvent, RuntimeOrigin, SessionKeys, System, TxExtension, UncheckedExtrinsic,
 };
 use bridge_hub_test_utils::GovernanceOrigin;
 use codec::{Decode, Encode};
@@ -399,7 +405,10 @@ fn construct_extrinsic(
 		frame_system::CheckWeight::<Runtime>::new(),
 		pallet_transaction_payment::ChargeTransactionPayment::<Runtime>::from(0),
 		BridgeRejectObsoleteHeadersAndMessages,
-		(OnBridgeHubPolkadotRefundBridgeHubKusamaMessages::default()),
+		(
+			OnBridgeHubPolkadotRefundBridgeHubKusamaMessages::default(),
+			OnBridgeHubPolkadotRefundBridgeHubKusamaDynamicMessages::default(),
+		),
 		frame_metadata_hash_extension::CheckMetadataHash::<Runtime>::new(false),
 	)
 		.into();
@@ -524,3 +533,53 @@ fn check_compatibility_for_token_id_stored_on_ethereum() {
 			})
 	}
 }
+
+#[test]
+fn ethereum_bridge_status_is_reported_to_asset_hub() {
+	use snowbridge_outbound_queue_primitives::OnNewCommitment;
+	use system_parachains_constants::polkadot::locations::AssetHubLocation;
+
+	ExtBuilder::<Runtime>::default()
+		.with_collators(collator_session_keys().collators())
+		.with_session_keys(collator_session_keys().session_keys())
+		.with_para_id(ParaId::from(BRIDGE_HUB_POLKADOT_PARACHAIN_ID))
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			PolkadotXcm::force_xcm_version(
+				RuntimeOrigin::root(),
+				Box::new(AssetHubLocation::get()),
+				XCM_VERSION,
+			)
+			.expect("version saved!");
+			let is_status_sent = || {
+				System::events().iter().any(|r| {
+					matches!(
+						r.event,
+						RuntimeEvent::XcmpQueue(
+							cumulus_pallet_xcmp_queue::Event::XcmpMessageSent { .. }
+						)
+					)
+				})
+			};
+
+			// nothing is pending, so there is nothing to report
+			EthereumCongestionReporter::on_new_commitment(Default::default());
+			assert!(!EthereumBridgeCongested::get());
+			assert!(!is_status_sent());
+
+			// the status is kept while Asset Hub can't be reached
+			EthereumBridgeCongested::put(true);
+			EthereumCongestionReporter::on_new_commitment(Default::default());
+			assert!(EthereumBridgeCongested::get());
+			assert!(!is_status_sent());
+
+			// the bridge is reported as uncongested once the backlog is gone
+			ParachainSystem::open_outbound_hrmp_channel_for_benchmarks_or_tests(
+				bp_asset_hub_polkadot::ASSET_HUB_POLKADOT_PARACHAIN_ID.into(),
+			);
+			EthereumCongestionReporter::on_new_commitment(Default::default());
+			assert!(!EthereumBridgeCongested::get());
+			assert!(is_status_sent());
+		})
+}
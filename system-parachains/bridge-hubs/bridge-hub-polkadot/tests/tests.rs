@@ -20,23 +20,24 @@ use bp_polkadot_core::Signature;
 use bp_relayers::{PayRewardFromAccount, RewardsAccountOwner, RewardsAccountParams};
 use bridge_hub_polkadot_runtime::{
 	bridge_common_config::{
-		BridgeRelayersInstance, BridgeReward, BridgeRewardBeneficiaries,
-		RequiredStakeForStakeAndSlash,
+		AssetHubRewardKind, BridgeRelayersInstance, BridgeReward, BridgeRewardBatcherAccount,
+		BridgeRewardBeneficiaries, RequiredStakeForStakeAndSlash,
 	},
 	bridge_to_kusama_config::{
 		BridgeGrandpaKusamaInstance, BridgeHubKusamaLocation, BridgeParachainKusamaInstance,
 		DeliveryRewardInBalance, KusamaGlobalConsensusNetwork,
+		OnBridgeHubPolkadotRefundBridgeHubKusamaDynamicMessages,
 		OnBridgeHubPolkadotRefundBridgeHubKusamaMessages, WithBridgeHubKusamaMessagesInstance,
-		XcmOverBridgeHubKusamaInstance,
+		XcmOverBridgeHubKusamaDynamicInstance, XcmOverBridgeHubKusamaInstance,
 	},
 	xcm_config::{
 		AssetHubLocation, DotRelayLocation, LocationToAccountId, RelayChainLocation, RelayNetwork,
 		RelayTreasuryLocation, RelayTreasuryPalletAccount, XcmConfig,
 	},
 	AllPalletsWithoutSystem, Balances, Block, BridgeRejectObsoleteHeadersAndMessages,
-	BridgeRelayers, Executive, ExistentialDeposit, ParachainSystem, PolkadotXcm, Runtime,
-	RuntimeCall, RuntimeEvent, RuntimeOrigin, SessionKeys, TransactionPayment, TxExtension,
-	UncheckedExtrinsic, SLOT_DURATION,
+	BridgeRelayers, BridgeRewardBatcher, Executive, ExistentialDeposit, ParachainSystem,
+	PolkadotXcm, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, SessionKeys,
+	TransactionPayment, TxExtension, UncheckedExtrinsic, SLOT_DURATION,
 };
 use bridge_hub_test_utils::{
 	test_cases::{from_parachain, run_test},
@@ -115,7 +116,10 @@ fn construct_extrinsic(
 		frame_system::CheckWeight::<Runtime>::new(),
 		pallet_transaction_payment::ChargeTransactionPayment::<Runtime>::from(0),
 		BridgeRejectObsoleteHeadersAndMessages,
-		(OnBridgeHubPolkadotRefundBridgeHubKusamaMessages::default()),
+		(
+			OnBridgeHubPolkadotRefundBridgeHubKusamaMessages::default(),
+			OnBridgeHubPolkadotRefundBridgeHubKusamaDynamicMessages::default(),
+		),
 		frame_metadata_hash_extension::CheckMetadataHash::<Runtime>::new(false),
 	)
 		.into();
@@ -306,6 +310,28 @@ fn handle_export_message_from_system_parachain_add_to_outbound_queue_works() {
 		)
 }
 
+#[test]
+fn open_and_close_bridge_works() {
+	// Only Asset Hub has a bridge router that receives the (un)congestion signal of its bridges.
+	let origins = [AssetHubLocation::get()];
+
+	for origin in origins {
+		bridge_hub_test_utils::test_cases::open_and_close_bridge_works::<
+			Runtime,
+			XcmOverBridgeHubKusamaDynamicInstance,
+			LocationToAccountId,
+			DotRelayLocation,
+		>(
+			collator_session_keys(),
+			bp_bridge_hub_polkadot::BRIDGE_HUB_POLKADOT_PARACHAIN_ID,
+			origin.clone(),
+			BridgedUniversalLocation::get(),
+			(origin, OriginKind::Xcm),
+			true,
+		)
+	}
+}
+
 #[test]
 fn message_dispatch_routing_works() {
 	bridge_hub_test_utils::test_cases::message_dispatch_routing_works::<
@@ -656,30 +682,72 @@ pub fn bridge_rewards_works() {
 				pallet_bridge_relayers::Error::<Runtime, BridgeRelayersInstance>::NoRewardForRelayer
 			);
 
-			// Local account claiming is not supported for Snowbridge
-			assert_err!(
-				BridgeRelayers::claim_rewards(
-					RuntimeOrigin::signed(account2.clone()),
-					BridgeReward::Snowbridge
-				),
-				pallet_bridge_relayers::Error::<Runtime, BridgeRelayersInstance>::FailedToPayReward
-			);
-
-			let claim_location = VersionedLocation::V5(Location::new(
+			// Snowbridge rewards of a local account are queued for the same account on Asset Hub.
+			assert_ok!(BridgeRelayers::claim_rewards(
+				RuntimeOrigin::signed(account2.clone()),
+				BridgeReward::Snowbridge
+			));
+			let claim_location = VersionedLocation::from(Location::new(
 				1,
 				[
 					Parachain(bp_asset_hub_polkadot::ASSET_HUB_POLKADOT_PARACHAIN_ID),
 					Junction::AccountId32 { id: account2.clone().into(), network: None },
 				],
 			));
-			// Without proper HRMP channel setup, the claim will fail at XCM sending.
-			assert_err!(
-				BridgeRelayers::claim_rewards_to(
-					RuntimeOrigin::signed(account2.clone()),
-					BridgeReward::Snowbridge,
-					BridgeRewardBeneficiaries::AssetHubLocation(Box::new(claim_location))
+			assert_eq!(
+				pallet_bridge_reward_batcher::PendingRewards::<Runtime>::get(
+					&claim_location,
+					AssetHubRewardKind::Ether
+				),
+				reward2
+			);
+
+			// Rewards claimed to the same Asset Hub location end up in the same batch.
+			BridgeRelayers::register_reward(&account2, BridgeReward::Snowbridge, reward2);
+			assert_ok!(BridgeRelayers::claim_rewards_to(
+				RuntimeOrigin::signed(account2.clone()),
+				BridgeReward::Snowbridge,
+				BridgeRewardBeneficiaries::AssetHubLocation(Box::new(claim_location.clone()))
+			));
+			assert_eq!(
+				pallet_bridge_reward_batcher::PendingRewards::<Runtime>::get(
+					&claim_location,
+					AssetHubRewardKind::Ether
 				),
-				pallet_bridge_relayers::Error::<Runtime, BridgeRelayersInstance>::FailedToPayReward
+				2 * reward2
+			);
+
+			// P/K bridge rewards are moved to the batcher account and queued for Asset Hub.
+			BridgeRelayers::register_reward(&account1, BridgeReward::from(reward1_for), reward1);
+			assert_ok!(Balances::mint_into(&expected_reward1_account, reward1));
+			assert_ok!(BridgeRelayers::claim_rewards_to(
+				RuntimeOrigin::signed(account1.clone()),
+				reward1_for.into(),
+				BridgeRewardBeneficiaries::AssetHubLocation(Box::new(claim_location.clone()))
+			));
+			assert_eq!(Balances::total_balance(&BridgeRewardBatcherAccount::get()), reward1);
+			assert_eq!(
+				pallet_bridge_reward_batcher::PendingRewards::<Runtime>::get(
+					&claim_location,
+					AssetHubRewardKind::Native
+				),
+				reward1
+			);
+
+			// Without proper HRMP channel setup, paying the batch fails at XCM sending and the
+			// rewards stay queued.
+			assert!(BridgeRewardBatcher::pay_pending(
+				RuntimeOrigin::signed(account2.clone()),
+				Box::new(claim_location.clone()),
+				AssetHubRewardKind::Ether
+			)
+			.is_err());
+			assert_eq!(
+				pallet_bridge_reward_batcher::PendingRewards::<Runtime>::get(
+					&claim_location,
+					AssetHubRewardKind::Ether
+				),
+				2 * reward2
 			);
 		},
 	);
@@ -738,3 +806,45 @@ fn governance_authorize_upgrade_works() {
 		RuntimeOrigin,
 	>(GovernanceOrigin::Location(AssetHubLocation::get())));
 }
+
+#[test]
+fn bridge_health_reports_outbound_nonce_gap() {
+	use bridge_hub_polkadot_runtime::bridge_common_config::messages_lanes_health;
+	use system_parachains_common::apis::{BridgeLaneId, BridgedHeaderId};
+	type OutboundLanes =
+		pallet_bridge_messages::OutboundLanes<Runtime, WithBridgeHubKusamaMessagesInstance>;
+
+	run_test::<Runtime, _>(
+		collator_session_keys(),
+		bp_bridge_hub_polkadot::BRIDGE_HUB_POLKADOT_PARACHAIN_ID,
+		vec![],
+		|| {
+			let lane_id = LegacyLaneId([0, 0, 0, 1]);
+			OutboundLanes::insert(
+				lane_id,
+				bp_messages::OutboundLaneData {
+					state: bp_messages::LaneState::Opened,
+					oldest_unpruned_nonce: 1,
+					latest_received_nonce: 5,
+					latest_generated_nonce: 8,
+				},
+			);
+			let best_header = BridgedHeaderId { number: 42, hash: Default::default() };
+
+			let lanes = messages_lanes_health::<
+				WithBridgeHubKusamaMessagesInstance,
+				XcmOverBridgeHubKusamaInstance,
+			>(Some(best_header.clone()));
+
+			assert_eq!(lanes.len(), 1);
+			let lane = &lanes[0];
+			assert_eq!(lane.lane_id, BridgeLaneId::Legacy([0, 0, 0, 1]));
+			assert_eq!(lane.best_finalized_bridged_header, Some(best_header));
+			assert_eq!(lane.outbound_nonce_gap, 3);
+			assert_eq!(lane.unrewarded_relayers, 0);
+			assert!(!lane.is_halted);
+			// the lane isn't owned by any bridge
+			assert_eq!(lane.bridge_state, None);
+		},
+	)
+}
@@ -87,6 +87,9 @@ fn bulletin_polkadot_genesis(
 				.collect(),
 		},
 		polkadot_xcm: PolkadotXcmConfig { safe_xcm_version: Some(SAFE_XCM_VERSION) },
+		transaction_storage: TransactionStorageConfig {
+			retention_period: DefaultStorageRetentionPeriod::get(),
+		},
 	})
 }
 
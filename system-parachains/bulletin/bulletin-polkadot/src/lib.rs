@@ -39,7 +39,10 @@ use frame_support::{
 	dispatch::DispatchClass,
 	genesis_builder_helper::{build_state, get_preset},
 	parameter_types,
-	traits::{ConstBool, ConstU32, ConstU64, ConstU8, EitherOf, EitherOfDiverse, TransformOrigin},
+	traits::{
+		ConstBool, ConstU32, ConstU64, ConstU8, Contains, EitherOf, EitherOfDiverse, EverythingBut,
+		TransformOrigin,
+	},
 	weights::{ConstantMultiplier, Weight},
 	PalletId,
 };
@@ -61,14 +64,16 @@ pub use sp_runtime::BuildStorage;
 use sp_runtime::{
 	generic, impl_opaque_keys,
 	traits::Block as BlockT,
-	transaction_validity::{TransactionSource, TransactionValidity},
+	transaction_validity::{
+		TransactionLongevity, TransactionPriority, TransactionSource, TransactionValidity,
+	},
 	ApplyExtrinsicResult, MultiAddress, Perbill,
 };
 #[cfg(feature = "std")]
 use sp_version::NativeVersion;
 use sp_version::RuntimeVersion;
 use system_parachains_constants::{
-	async_backing::{AVERAGE_ON_INITIALIZE_RATIO, HOURS, MAXIMUM_BLOCK_WEIGHT},
+	async_backing::{AVERAGE_ON_INITIALIZE_RATIO, DAYS, HOURS, MAXIMUM_BLOCK_WEIGHT},
 	polkadot::{
 		consensus::{
 			async_backing::UNINCLUDED_SEGMENT_CAPACITY, BLOCK_PROCESSING_VELOCITY,
@@ -202,9 +207,35 @@ parameter_types! {
 	pub const SS58Prefix: u8 = 0;
 }
 
+parameter_types! {
+	/// Whether storing and renewing data in the transaction storage is enabled.
+	///
+	/// Once stored data reaches the end of its retention period, every block must prove it with
+	/// `check_proof`, otherwise `on_finalize` of the pallet panics and the chain halts. Governance
+	/// enables storing with `System::set_storage` once the collators provide the
+	/// `sp_transaction_storage_proof` inherent data.
+	pub storage StoringEnabled: bool = false;
+}
+
+/// Filter:
+/// - Storing and renewing data in the transaction storage while [`StoringEnabled`] is not set.
+pub struct IsFilteredStorageCall;
+impl Contains<RuntimeCall> for IsFilteredStorageCall {
+	fn contains(c: &RuntimeCall) -> bool {
+		matches!(
+			c,
+			RuntimeCall::TransactionStorage(
+				pallet_transaction_storage::Call::store { .. } |
+					pallet_transaction_storage::Call::renew { .. }
+			)
+		) && !StoringEnabled::get()
+	}
+}
+
 // Configure FRAME pallets to include in runtime.
 #[derive_impl(frame_system::config_preludes::ParaChainDefaultConfig)]
 impl frame_system::Config for Runtime {
+	type BaseCallFilter = EverythingBut<IsFilteredStorageCall>;
 	/// The identifier used to distinguish between accounts.
 	type AccountId = AccountId;
 	/// The nonce type for storing how many extrinsics an account has signed.
@@ -456,6 +487,36 @@ impl pallet_utility::Config for Runtime {
 	type WeightInfo = weights::pallet_utility::WeightInfo<Runtime>;
 }
 
+parameter_types! {
+	/// Default number of blocks for which stored data is kept (and must be proven) before it is
+	/// removed. Can be changed later through governance.
+	pub const DefaultStorageRetentionPeriod: BlockNumber = 14 * DAYS;
+	/// Authorizations to store data expire after this many blocks if unused.
+	pub const StorageAuthorizationPeriod: BlockNumber = 7 * DAYS;
+	/// `store` and `renew` are only admitted for authorized accounts or preimages, so they are
+	/// given a high priority to not be crowded out by other transactions.
+	pub const StoreRenewPriority: TransactionPriority = TransactionPriority::MAX / 2;
+	pub const StoreRenewLongevity: TransactionLongevity = HOURS as TransactionLongevity;
+	pub const RemoveExpiredAuthorizationPriority: TransactionPriority = TransactionPriority::MAX / 4;
+	pub const RemoveExpiredAuthorizationLongevity: TransactionLongevity =
+		HOURS as TransactionLongevity;
+}
+
+impl pallet_transaction_storage::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = weights::pallet_transaction_storage::WeightInfo<Runtime>;
+	type MaxBlockTransactions = ConstU32<512>;
+	// Leaves room for the rest of the normal dispatch class in the 9 MiB normal block length.
+	type MaxTransactionSize = ConstU32<{ 8 * 1024 * 1024 }>;
+	type AuthorizationPeriod = StorageAuthorizationPeriod;
+	// Only Root or the Fellowship can grant accounts or preimages the right to store data.
+	type Authorizer = RootOrFellows;
+	type StoreRenewPriority = StoreRenewPriority;
+	type StoreRenewLongevity = StoreRenewLongevity;
+	type RemoveExpiredAuthorizationPriority = RemoveExpiredAuthorizationPriority;
+	type RemoveExpiredAuthorizationLongevity = RemoveExpiredAuthorizationLongevity;
+}
+
 #[frame_support::runtime]
 mod runtime {
 	#[runtime::runtime]
@@ -516,6 +577,10 @@ mod runtime {
 	pub type CumulusXcm = cumulus_pallet_xcm;
 	#[runtime::pallet_index(34)]
 	pub type MessageQueue = pallet_message_queue;
+
+	// Storage
+	#[runtime::pallet_index(40)]
+	pub type TransactionStorage = pallet_transaction_storage;
 }
 
 cumulus_pallet_parachain_system::register_validate_block! {
@@ -18,13 +18,20 @@
 
 use bulletin_polkadot_runtime::{
 	xcm_config::{GovernanceLocation, LocationToAccountId},
-	Block, Runtime, RuntimeCall, RuntimeOrigin,
+	Block, BuildStorage, Runtime, RuntimeCall, RuntimeOrigin, StoringEnabled, System,
+	TransactionStorage,
 };
-use frame_support::{assert_err, assert_ok};
-use parachains_common::AccountId;
+use codec::Encode;
+use frame_support::{
+	assert_err, assert_noop, assert_ok,
+	inherent::{InherentData, ProvideInherent},
+	traits::{OnFinalize, OnInitialize},
+};
+use parachains_common::{AccountId, BlockNumber};
 use parachains_runtimes_test_utils::GovernanceOrigin;
 use sp_core::crypto::Ss58Codec;
-use sp_runtime::Either;
+use sp_runtime::{traits::Dispatchable, DispatchError, Either};
+use sp_transaction_storage_proof::{registration::build_proof, TransactionStorageProof};
 use system_parachains_constants::polkadot::fee::WeightToFee;
 use xcm::latest::prelude::*;
 use xcm_runtime_apis::conversions::LocationToAccountHelper;
@@ -200,3 +207,229 @@ fn governance_authorize_upgrade_works() {
 		RuntimeOrigin,
 	>(GovernanceOrigin::Location(GovernanceLocation::get())));
 }
+
+#[test]
+fn only_governance_can_authorize_storage() {
+	sp_io::TestExternalities::new(
+		frame_system::GenesisConfig::<Runtime>::default().build_storage().unwrap(),
+	)
+	.execute_with(|| {
+		let who = AccountId::from(ALICE);
+
+		// no - plain signed origin
+		assert_noop!(
+			TransactionStorage::authorize_account(
+				RuntimeOrigin::signed(who.clone()),
+				who.clone(),
+				1,
+				1024
+			),
+			DispatchError::BadOrigin
+		);
+
+		// ok - root
+		assert_ok!(TransactionStorage::authorize_account(RuntimeOrigin::root(), who, 1, 1024));
+	});
+}
+
+/// Retention period of stored data in the tests.
+const RETENTION_PERIOD: BlockNumber = 10;
+
+fn new_test_ext() -> sp_io::TestExternalities {
+	let mut ext: sp_io::TestExternalities = frame_system::GenesisConfig::<Runtime>::default()
+		.build_storage()
+		.unwrap()
+		.into();
+	ext.execute_with(|| {
+		pallet_transaction_storage::RetentionPeriod::<Runtime>::put(RETENTION_PERIOD);
+		System::set_block_number(1);
+	});
+	ext
+}
+
+/// Finalizes the blocks up to `n`, `proof` is checked in every finalized block it returns a proof
+/// for.
+fn run_to_block(n: BlockNumber, proof: impl Fn() -> Option<TransactionStorageProof>) {
+	while System::block_number() < n {
+		if let Some(proof) = proof() {
+			assert_ok!(TransactionStorage::check_proof(RuntimeOrigin::none(), proof));
+		}
+		TransactionStorage::on_finalize(System::block_number());
+		System::on_finalize(System::block_number());
+		System::set_block_number(System::block_number() + 1);
+		System::on_initialize(System::block_number());
+		TransactionStorage::on_initialize(System::block_number());
+	}
+}
+
+/// Stores `data` at the current block, bypassing the call filter of the runtime.
+fn store(data: Vec<u8>) {
+	let who = AccountId::from(ALICE);
+	assert_ok!(TransactionStorage::authorize_account(
+		RuntimeOrigin::root(),
+		who.clone(),
+		1,
+		data.len() as u64
+	));
+	assert_ok!(TransactionStorage::store(RuntimeOrigin::signed(who), data));
+}
+
+#[test]
+fn storing_data_is_filtered_until_governance_enables_it() {
+	new_test_ext().execute_with(|| {
+		let who = AccountId::from(ALICE);
+		assert_ok!(TransactionStorage::authorize_account(
+			RuntimeOrigin::root(),
+			who.clone(),
+			2,
+			2048
+		));
+
+		let store = RuntimeCall::TransactionStorage(pallet_transaction_storage::Call::store {
+			data: vec![0u8; 1024],
+		});
+		assert_err!(
+			store.clone().dispatch(RuntimeOrigin::signed(who.clone())).map_err(|e| e.error),
+			frame_system::Error::<Runtime>::CallFiltered
+		);
+		assert_err!(
+			store.dispatch(RuntimeOrigin::none()).map_err(|e| e.error),
+			frame_system::Error::<Runtime>::CallFiltered
+		);
+
+		let renew = RuntimeCall::TransactionStorage(pallet_transaction_storage::Call::renew {
+			block: 1,
+			index: 0,
+		});
+		assert_err!(
+			renew.dispatch(RuntimeOrigin::signed(who)).map_err(|e| e.error),
+			frame_system::Error::<Runtime>::CallFiltered
+		);
+
+		// Nothing was stored, so no block ever needs a proof.
+		run_to_block(3 * RETENTION_PERIOD, || None);
+	});
+}
+
+#[test]
+fn enabled_storing_is_proven_with_inherent_data_of_collators() {
+	new_test_ext().execute_with(|| {
+		let who = AccountId::from(ALICE);
+		let data = vec![42u8; 2000];
+		assert_ok!(TransactionStorage::authorize_account(
+			RuntimeOrigin::root(),
+			who.clone(),
+			1,
+			data.len() as u64
+		));
+		let store = RuntimeCall::TransactionStorage(pallet_transaction_storage::Call::store {
+			data: data.clone(),
+		});
+
+		// no - storing is disabled
+		assert_err!(
+			store.clone().dispatch(RuntimeOrigin::signed(who.clone())).map_err(|e| e.error),
+			frame_system::Error::<Runtime>::CallFiltered
+		);
+
+		// no - plain signed origin can't enable storing
+		let enable = RuntimeCall::System(frame_system::Call::set_storage {
+			items: vec![(StoringEnabled::key().to_vec(), true.encode())],
+		});
+		assert_err!(
+			enable.clone().dispatch(RuntimeOrigin::signed(who.clone())).map_err(|e| e.error),
+			DispatchError::BadOrigin
+		);
+
+		// ok - root enables storing
+		assert_ok!(enable.dispatch(RuntimeOrigin::root()));
+		assert!(StoringEnabled::get());
+		assert_ok!(store.dispatch(RuntimeOrigin::signed(who)));
+		run_to_block(2, || None);
+		assert!(pallet_transaction_storage::Transactions::<Runtime>::contains_key(1));
+
+		// The collators provide the proof as inherent data in the last block of the retention
+		// period, the inherent is dispatched like in a block.
+		run_to_block(1 + RETENTION_PERIOD, || None);
+		let proof = build_proof(System::parent_hash().as_ref(), vec![data]).unwrap();
+		let mut inherent_data = InherentData::new();
+		inherent_data
+			.put_data(sp_transaction_storage_proof::INHERENT_IDENTIFIER, &proof)
+			.unwrap();
+		let check_proof = TransactionStorage::create_inherent(&inherent_data)
+			.expect("the stored data needs a proof");
+		assert_ok!(RuntimeCall::TransactionStorage(check_proof).dispatch(RuntimeOrigin::none()));
+		run_to_block(2 + RETENTION_PERIOD, || None);
+
+		// The data is removed once the retention period is over.
+		run_to_block(3 + RETENTION_PERIOD, || None);
+		assert!(!pallet_transaction_storage::Transactions::<Runtime>::contains_key(1));
+	});
+}
+
+#[test]
+fn collators_without_proof_inherent_data_provide_no_proof() {
+	new_test_ext().execute_with(|| {
+		assert!(TransactionStorage::create_inherent(&InherentData::new()).is_none());
+	});
+}
+
+#[test]
+fn stored_data_is_proven_and_removed_after_retention_period() {
+	new_test_ext().execute_with(|| {
+		let data = vec![42u8; 2000];
+		store(data.clone());
+		run_to_block(2, || None);
+		assert!(pallet_transaction_storage::Transactions::<Runtime>::contains_key(1));
+
+		// The data must be proven in the last block of its retention period.
+		run_to_block(1 + RETENTION_PERIOD, || None);
+		let proof = build_proof(System::parent_hash().as_ref(), vec![data]).unwrap();
+		run_to_block(2 + RETENTION_PERIOD, || Some(proof.clone()));
+
+		// The data is removed once the retention period is over.
+		run_to_block(3 + RETENTION_PERIOD, || None);
+		assert!(!pallet_transaction_storage::Transactions::<Runtime>::contains_key(1));
+	});
+}
+
+#[test]
+#[should_panic(expected = "Storage proof must be checked once in the block")]
+fn block_without_proof_of_stored_data_panics() {
+	new_test_ext().execute_with(|| {
+		store(vec![42u8; 2000]);
+		run_to_block(2 + RETENTION_PERIOD, || None);
+	});
+}
+
+#[test]
+fn renewed_data_is_kept_for_another_retention_period() {
+	new_test_ext().execute_with(|| {
+		let data = vec![42u8; 2000];
+		store(data.clone());
+		run_to_block(3, || None);
+
+		let who = AccountId::from(ALICE);
+		assert_ok!(TransactionStorage::authorize_account(
+			RuntimeOrigin::root(),
+			who.clone(),
+			1,
+			data.len() as u64
+		));
+		assert_ok!(TransactionStorage::renew(RuntimeOrigin::signed(who), 1, 0));
+		run_to_block(4, || None);
+		assert!(pallet_transaction_storage::Transactions::<Runtime>::contains_key(3));
+
+		// Both the original and the renewed data have to be proven at the end of their retention
+		// periods.
+		run_to_block(1 + RETENTION_PERIOD, || None);
+		let proof = build_proof(System::parent_hash().as_ref(), vec![data.clone()]).unwrap();
+		run_to_block(2 + RETENTION_PERIOD, || Some(proof.clone()));
+		assert!(!pallet_transaction_storage::Transactions::<Runtime>::contains_key(1));
+
+		run_to_block(3 + RETENTION_PERIOD, || None);
+		let proof = build_proof(System::parent_hash().as_ref(), vec![data]).unwrap();
+		run_to_block(4 + RETENTION_PERIOD, || Some(proof.clone()));
+		assert!(!pallet_transaction_storage::Transactions::<Runtime>::contains_key(3));
+	});
+}
@@ -51,6 +51,7 @@ pub use ambassador::pallet_ambassador_origins;
 
 // Secretary Configuration
 pub mod secretary;
+pub use secretary::pallet_secretary_origins;
 
 use alloc::{borrow::Cow, boxed::Box, vec, vec::Vec};
 use cumulus_pallet_parachain_system::RelayNumberMonotonicallyIncreases;
@@ -410,6 +411,7 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 			ProxyType::Secretary => matches!(
 				c,
 				RuntimeCall::SecretaryCollective { .. } |
+					RuntimeCall::SecretaryReferenda { .. } |
 					RuntimeCall::SecretarySalary { .. } |
 					RuntimeCall::Utility { .. } |
 					RuntimeCall::Multisig { .. }
@@ -845,6 +847,11 @@ construct_runtime!(
 		SecretaryCollective: pallet_ranked_collective::<Instance3> = 80,
 		// pub type SecretarySalaryInstance = pallet_salary::Instance3;
 		SecretarySalary: pallet_salary::<Instance3> = 81,
+		// pub type SecretaryReferendaInstance = pallet_referenda::Instance3;
+		SecretaryReferenda: pallet_referenda::<Instance3> = 82,
+		SecretaryOrigins: pallet_secretary_origins = 83,
+		// pub type SecretaryTreasuryInstance = pallet_treasury::Instance3;
+		SecretaryTreasury: pallet_treasury::<Instance3> = 84,
 	}
 );
 
@@ -946,6 +953,8 @@ mod benches {
 		[pallet_treasury, AmbassadorTreasury]
 		[pallet_ranked_collective, SecretaryCollective]
 		[pallet_salary, SecretarySalary]
+		[pallet_referenda, SecretaryReferenda]
+		[pallet_treasury, SecretaryTreasury]
 		// XCM
 		[pallet_xcm, PalletXcmExtrinsicsBenchmark::<Runtime>]
 		[pallet_xcm_benchmarks::fungible, XcmBalances]
@@ -15,17 +15,42 @@
 // along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
 
 //! The Polkadot Secretary Collective.
+//!
+//! The module defines the following on-chain functionality of the Secretary Collective:
+//!
+//! - Managed set of members, where every member has a [rank](ranks) (via
+//!   [SecretaryCollective](pallet_ranked_collective)).
+//! - Referendum functionality for the secretaries to propose, vote on, and execute proposals on
+//!   behalf of the [Secretaries](Origin) (via [SecretaryReferenda](pallet_referenda)).
+//! - Members' salaries (via [SecretarySalary](pallet_salary)).
+//! - Secretary Sub-Treasury (via [SecretaryTreasury](pallet_treasury)). The treasury is optional,
+//!   it holds no funds until it is funded by OpenGov to run a budget of the Secretary program.
+
+pub mod origins;
+mod tracks;
+
+pub use origins::pallet_origins as pallet_secretary_origins;
 
 use crate::{
 	fellowship::FellowshipAdminBodyId,
 	parameters::{SalaryAssetId, SecretarySalaryAsset},
-	*,
+	AssetRateWithNative, *,
+};
+use frame_support::{
+	pallet_prelude::PalletInfoAccess,
+	traits::{tokens::GetSalary, EitherOf, Get, MapSuccess},
 };
-use frame_support::traits::{tokens::GetSalary, EitherOf, Get, MapSuccess, NoOpPoll};
-use frame_system::{pallet_prelude::BlockNumberFor, EnsureRootWithSuccess};
+use frame_system::EnsureRootWithSuccess;
+use origins::pallet_origins::{Origin, Secretaries};
+use pallet_ranked_collective::Votes;
 use pallet_xcm::{EnsureXcm, IsVoiceOfBody};
-use sp_core::ConstU32;
-use sp_runtime::traits::{ConstU16, Identity, Replace};
+use polkadot_runtime_common::impls::{LocatableAssetConverter, VersionedLocationConverter};
+use sp_core::{ConstU128, ConstU32};
+use sp_runtime::{
+	traits::{ConstU16, Identity, IdentityLookup, Replace},
+	Permill,
+};
+use xcm::prelude::*;
 use xcm_builder::{AliasesIntoAccountId32, PayOverXcm};
 
 /// The Secretary members' ranks.
@@ -36,6 +61,8 @@ pub mod ranks {
 	pub const SECRETARY: Rank = 1;
 }
 
+impl pallet_secretary_origins::Config for Runtime {}
+
 /// Origins of:
 /// - Root;
 /// - FellowshipAdmin (i.e. token holder referendum);
@@ -68,7 +95,7 @@ impl pallet_ranked_collective::Config<SecretaryCollectiveInstance> for Runtime {
 	type PromoteOrigin = ApproveOrigin;
 	type DemoteOrigin = ApproveOrigin;
 	type ExchangeOrigin = ApproveOrigin;
-	type Polls = NoOpPoll<BlockNumberFor<Runtime>>;
+	type Polls = SecretaryReferenda;
 	type MinRankOfClass = Identity;
 	type MemberSwappedHandler = crate::SecretarySalary;
 	type VoteWeight = pallet_ranked_collective::Geometric;
@@ -77,6 +104,60 @@ impl pallet_ranked_collective::Config<SecretaryCollectiveInstance> for Runtime {
 	type BenchmarkSetup = crate::SecretarySalary;
 }
 
+/// Root, FellowshipAdmin or Secretaries.
+pub type OpenGovOrSecretaries = EitherOfDiverse<
+	EnsureRoot<AccountId>,
+	EitherOfDiverse<
+		Secretaries,
+		EitherOf<
+			EnsureXcm<IsVoiceOfBody<RelayChainLocation, FellowshipAdminBodyId>>,
+			EnsureXcm<IsVoiceOfBody<AssetHubLocation, FellowshipAdminBodyId>>,
+		>,
+	>,
+>;
+
+parameter_types! {
+	pub const AlarmInterval: BlockNumber = 1;
+	pub const SubmissionDeposit: Balance = 0;
+	pub const UndecidingTimeout: BlockNumber = 7 * DAYS;
+}
+
+pub type SecretaryReferendaInstance = pallet_referenda::Instance3;
+
+impl pallet_referenda::Config<SecretaryReferendaInstance> for Runtime {
+	type WeightInfo = weights::pallet_referenda_secretary_referenda::WeightInfo<Runtime>;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeEvent = RuntimeEvent;
+	type Scheduler = Scheduler;
+	type Currency = Balances;
+	// Any secretary can submit a proposal.
+	type SubmitOrigin = pallet_ranked_collective::EnsureMember<
+		Runtime,
+		SecretaryCollectiveInstance,
+		{ ranks::SECRETARY },
+	>;
+	// Referendum can be canceled by any of:
+	// - Root;
+	// - the FellowshipAdmin origin (i.e. token holder referendum);
+	// - a vote among all secretaries.
+	type CancelOrigin = OpenGovOrSecretaries;
+	// Referendum can be killed by any of:
+	// - Root;
+	// - the FellowshipAdmin origin (i.e. token holder referendum);
+	// - a vote among all secretaries.
+	type KillOrigin = OpenGovOrSecretaries;
+	type Slash = ToParentTreasury<PolkadotTreasuryAccount, LocationToAccountId, Runtime>;
+	type Votes = Votes;
+	type Tally = pallet_ranked_collective::TallyOf<Runtime, SecretaryCollectiveInstance>;
+	type SubmissionDeposit = SubmissionDeposit;
+	type MaxQueued = ConstU32<20>;
+	type UndecidingTimeout = UndecidingTimeout;
+	type AlarmInterval = AlarmInterval;
+	type Tracks = tracks::TracksInfo;
+	type Preimages = Preimage;
+	type BlockNumberProvider = System;
+}
+
 pub type SecretarySalaryInstance = pallet_salary::Instance3;
 
 pub struct SalaryForRank;
@@ -133,3 +214,91 @@ impl pallet_salary::Config<SecretarySalaryInstance> for Runtime {
 	// Total monthly salary budget.
 	type Budget = SecretarySalaryBudget;
 }
+
+parameter_types! {
+	pub const SecretaryTreasuryPalletId: PalletId = SECRETARY_TREASURY_PALLET_ID;
+	pub const Burn: Permill = Permill::from_percent(0);
+	pub const MaxBalance: Balance = Balance::MAX;
+	// The asset's interior location for the paying account. This is the Secretary Treasury
+	// pallet instance.
+	pub SecretaryTreasuryInteriorLocation: InteriorLocation =
+		PalletInstance(<crate::SecretaryTreasury as PalletInfoAccess>::index() as u8).into();
+}
+
+/// [`PayOverXcm`] setup to pay the Secretary Treasury.
+pub type SecretaryTreasuryPaymaster = PayOverXcm<
+	SecretaryTreasuryInteriorLocation,
+	crate::xcm_config::XcmConfig,
+	crate::PolkadotXcm,
+	ConstU32<{ 6 * HOURS }>,
+	VersionedLocation,
+	VersionedLocatableAsset,
+	LocatableAssetConverter,
+	VersionedLocationConverter,
+>;
+
+pub type SecretaryTreasuryInstance = pallet_treasury::Instance3;
+
+impl pallet_treasury::Config<SecretaryTreasuryInstance> for Runtime {
+	type WeightInfo = weights::pallet_treasury_secretary_treasury::WeightInfo<Runtime>;
+	type RuntimeEvent = RuntimeEvent;
+	type PalletId = SecretaryTreasuryPalletId;
+	type Currency = Balances;
+	type RejectOrigin = OpenGovOrSecretaries;
+	type SpendPeriod = ConstU32<{ 7 * DAYS }>;
+	type Burn = Burn;
+	type BurnDestination = ();
+	type SpendFunds = ();
+	type MaxApprovals = ConstU32<100>;
+	type SpendOrigin = EitherOf<
+		EitherOf<
+			EnsureRootWithSuccess<AccountId, MaxBalance>,
+			EitherOf<
+				MapSuccess<
+					EnsureXcm<IsVoiceOfBody<RelayChainLocation, TreasurerBodyId>>,
+					Replace<ConstU128<{ 10_000 * GRAND }>>,
+				>,
+				MapSuccess<
+					EnsureXcm<IsVoiceOfBody<AssetHubLocation, TreasurerBodyId>>,
+					Replace<ConstU128<{ 10_000 * GRAND }>>,
+				>,
+			>,
+		>,
+		MapSuccess<Secretaries, Replace<ConstU128<{ 10 * GRAND }>>>,
+	>;
+	type AssetKind = VersionedLocatableAsset;
+	type Beneficiary = VersionedLocation;
+	type BeneficiaryLookup = IdentityLookup<Self::Beneficiary>;
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	type Paymaster = SecretaryTreasuryPaymaster;
+	#[cfg(feature = "runtime-benchmarks")]
+	type Paymaster = crate::impls::benchmarks::PayWithEnsure<
+		SecretaryTreasuryPaymaster,
+		crate::impls::benchmarks::OpenHrmpChannel<ConstU32<1000>>,
+	>;
+	type BalanceConverter = AssetRateWithNative;
+	type PayoutPeriod = ConstU32<{ 90 * DAYS }>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = polkadot_runtime_common::impls::benchmarks::TreasuryArguments<
+		sp_core::ConstU8<1>,
+		ConstU32<1000>,
+	>;
+	type BlockNumberProvider = System;
+}
+
+#[cfg(all(test, not(feature = "runtime-benchmarks")))]
+mod tests {
+	use super::*;
+	use pallet_referenda::TracksInfo as _;
+	use sp_runtime::traits::Convert;
+
+	type MinRankOfClass =
+		<Runtime as pallet_ranked_collective::Config<SecretaryCollectiveInstance>>::MinRankOfClass;
+
+	#[test]
+	fn secretaries_vote_on_the_secretary_track() {
+		let secretaries = OriginCaller::SecretaryOrigins(Origin::Secretaries);
+		assert_eq!(tracks::TracksInfo::track_for(&secretaries), Ok(tracks::constants::SECRETARY));
+		assert_eq!(MinRankOfClass::convert(tracks::constants::SECRETARY), ranks::SECRETARY);
+	}
+}
@@ -0,0 +1,54 @@
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The Secretary Collective's origins.
+
+#[frame_support::pallet]
+pub mod pallet_origins {
+	use frame_support::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(PhantomData<T>);
+
+	/// The pallet configuration trait.
+	#[pallet::config]
+	pub trait Config: frame_system::Config {}
+
+	#[derive(
+		PartialEq, Eq, Clone, MaxEncodedLen, Encode, Decode, DecodeWithMemTracking, TypeInfo, Debug,
+	)]
+	#[pallet::origin]
+	pub enum Origin {
+		/// Plurality voice of the [crate::secretary::ranks::SECRETARY] members given via
+		/// referendum.
+		Secretaries,
+	}
+
+	/// Ensures [`Origin::Secretaries`] origin.
+	pub struct Secretaries;
+	impl<O: Into<Result<Origin, O>> + From<Origin>> EnsureOrigin<O> for Secretaries {
+		type Success = ();
+		fn try_origin(o: O) -> Result<Self::Success, O> {
+			o.into().and_then(|o| match o {
+				Origin::Secretaries => Ok(()),
+			})
+		}
+
+		#[cfg(feature = "runtime-benchmarks")]
+		fn try_successful_origin() -> Result<O, ()> {
+			Ok(O::from(Origin::Secretaries))
+		}
+	}
+}
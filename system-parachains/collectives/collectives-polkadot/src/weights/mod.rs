@@ -38,6 +38,7 @@ pub mod pallet_ranked_collective_fellowship_collective;
 pub mod pallet_ranked_collective_secretary_collective;
 pub mod pallet_referenda_ambassador_referenda;
 pub mod pallet_referenda_fellowship_referenda;
+pub mod pallet_referenda_secretary_referenda;
 pub mod pallet_salary_ambassador_salary;
 pub mod pallet_salary_fellowship_salary;
 pub mod pallet_salary_secretary_salary;
@@ -47,6 +48,7 @@ pub mod pallet_timestamp;
 pub mod pallet_transaction_payment;
 pub mod pallet_treasury_ambassador_treasury;
 pub mod pallet_treasury_fellowship_treasury;
+pub mod pallet_treasury_secretary_treasury;
 pub mod pallet_utility;
 pub mod pallet_xcm;
 pub mod paritydb_weights;
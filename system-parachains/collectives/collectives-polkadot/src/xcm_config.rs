@@ -206,6 +206,8 @@ parameter_types! {
 		PalletInstance(<crate::FellowshipSalary as PalletInfoAccess>::index() as u8).into();
 	pub SecretarySalaryLocation: Location =
 		PalletInstance(<crate::SecretarySalary as PalletInfoAccess>::index() as u8).into();
+	pub SecretaryTreasuryLocation: Location =
+		PalletInstance(<crate::SecretaryTreasury as PalletInfoAccess>::index() as u8).into();
 	pub AmbassadorSalaryLocation: Location =
 		PalletInstance(<crate::AmbassadorSalary as PalletInfoAccess>::index() as u8).into();
 	pub AmbassadorTreasuryLocation: Location =
@@ -222,6 +224,7 @@ pub type WaivedLocations = (
 	Equals<FellowshipTreasuryLocation>,
 	Equals<FellowshipSalaryLocation>,
 	Equals<SecretarySalaryLocation>,
+	Equals<SecretaryTreasuryLocation>,
 	Equals<AmbassadorSalaryLocation>,
 	Equals<AmbassadorTreasuryLocation>,
 	LocalPlurality,
@@ -17,6 +17,8 @@
 //! Shared types between system-parachains runtimes.
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
 pub mod randomness;
 
 /// Extra runtime APIs.
@@ -42,6 +44,158 @@ pub mod apis {
 		pub next_mint: (polkadot_primitives::Balance, polkadot_primitives::Balance),
 	}
 
+	/// Identifier of a finalized header of a bridged chain.
+	#[derive(scale_info::TypeInfo, codec::Encode, codec::Decode, Clone, Eq, PartialEq)]
+	#[cfg_attr(feature = "std", derive(Debug))]
+	pub struct BridgedHeaderId {
+		/// Number of the header.
+		pub number: polkadot_primitives::BlockNumber,
+		/// Hash of the header.
+		pub hash: polkadot_primitives::Hash,
+	}
+
+	/// Identifier of a messages lane between two Bridge Hubs.
+	#[derive(scale_info::TypeInfo, codec::Encode, codec::Decode, Clone, Eq, PartialEq)]
+	#[cfg_attr(feature = "std", derive(Debug))]
+	pub enum BridgeLaneId {
+		/// Lane with a `LegacyLaneId`, used by the lanes opened at genesis.
+		Legacy([u8; 4]),
+		/// Lane with a `HashedLaneId`, used by the lanes opened with `open_bridge`.
+		Hashed(polkadot_primitives::Hash),
+	}
+
+	/// State of the XCM bridge that owns a messages lane.
+	#[derive(scale_info::TypeInfo, codec::Encode, codec::Decode, Clone, Eq, PartialEq)]
+	#[cfg_attr(feature = "std", derive(Debug))]
+	pub enum BridgeLaneState {
+		/// The bridge accepts messages.
+		Opened,
+		/// The bridge is congested and the owner has been asked to stop sending messages.
+		Suspended,
+		/// The bridge is being closed.
+		Closed,
+	}
+
+	/// Health of a messages lane between two Bridge Hubs.
+	#[derive(scale_info::TypeInfo, codec::Encode, codec::Decode, Clone, Eq, PartialEq)]
+	#[cfg_attr(feature = "std", derive(Debug))]
+	pub struct MessagesLaneHealth {
+		/// Identifier of the lane.
+		pub lane_id: BridgeLaneId,
+		/// Best finalized header of the bridged Bridge Hub, which message proofs are checked
+		/// against.
+		pub best_finalized_bridged_header: Option<BridgedHeaderId>,
+		/// Nonce of the latest message sent over the lane.
+		pub latest_generated_nonce: u64,
+		/// Nonce of the latest message that the bridged chain has confirmed to receive.
+		pub latest_received_nonce: u64,
+		/// Number of sent messages that are not yet confirmed by the bridged chain.
+		pub outbound_nonce_gap: u64,
+		/// Number of relayers that have delivered messages over the lane and are not yet
+		/// rewarded for them.
+		pub unrewarded_relayers: u32,
+		/// Whether the messages pallet of the lane is not operating normally.
+		pub is_halted: bool,
+		/// State of the XCM bridge that owns the lane, or `None` if no bridge owns it.
+		pub bridge_state: Option<BridgeLaneState>,
+	}
+
+	/// Identifier of a finalized Ethereum beacon header.
+	#[derive(scale_info::TypeInfo, codec::Encode, codec::Decode, Clone, Eq, PartialEq)]
+	#[cfg_attr(feature = "std", derive(Debug))]
+	pub struct BeaconHeaderId {
+		/// Slot of the header.
+		pub slot: u64,
+		/// Root of the beacon block.
+		pub block_root: polkadot_primitives::Hash,
+	}
+
+	/// Nonces of a Snowbridge V1 channel.
+	#[derive(scale_info::TypeInfo, codec::Encode, codec::Decode, Clone, Eq, PartialEq)]
+	#[cfg_attr(feature = "std", derive(Debug))]
+	pub struct SnowbridgeChannelHealth {
+		/// Identifier of the channel.
+		pub channel_id: polkadot_primitives::Hash,
+		/// Nonce of the latest message received from Ethereum.
+		pub inbound_nonce: u64,
+		/// Nonce of the latest message sent to Ethereum.
+		pub outbound_nonce: u64,
+	}
+
+	/// Health of the bridge with Ethereum.
+	#[derive(scale_info::TypeInfo, codec::Encode, codec::Decode, Clone, Eq, PartialEq)]
+	#[cfg_attr(feature = "std", derive(Debug))]
+	pub struct SnowbridgeHealth {
+		/// Latest finalized beacon header, known to the Ethereum light client.
+		pub latest_finalized_beacon_header: Option<BeaconHeaderId>,
+		/// Nonces of the Snowbridge V1 channels.
+		pub channels: alloc::vec::Vec<SnowbridgeChannelHealth>,
+		/// Number of messages sent to Ethereum over Snowbridge V2.
+		///
+		/// Snowbridge V2 accepts inbound messages in any order, so there is no inbound nonce.
+		pub outbound_v2_nonce: u64,
+		/// Whether Asset Hub has been told that Snowbridge V2 is congested.
+		pub is_congested: bool,
+	}
+
+	/// Health of the bridges of a Bridge Hub.
+	#[derive(scale_info::TypeInfo, codec::Encode, codec::Decode, Clone, Eq, PartialEq)]
+	#[cfg_attr(feature = "std", derive(Debug))]
+	pub struct BridgeHealth {
+		/// Best finalized header of the bridged relay chain, known to the GRANDPA light client.
+		pub best_finalized_bridged_relay_header: Option<BridgedHeaderId>,
+		/// Health of all messages lanes with the bridged Bridge Hub.
+		pub lanes: alloc::vec::Vec<MessagesLaneHealth>,
+		/// Health of the bridge with Ethereum, if the Bridge Hub has one.
+		pub snowbridge: Option<SnowbridgeHealth>,
+	}
+
+	/// Status of the current bulk coretime sale.
+	#[derive(scale_info::TypeInfo, codec::Encode, codec::Decode, Clone, Eq, PartialEq)]
+	#[cfg_attr(feature = "std", derive(Debug))]
+	pub struct CoretimeSaleStatus {
+		/// Relay chain block number at which the sale starts.
+		pub sale_start: polkadot_primitives::BlockNumber,
+		/// Length of the lead-in period in relay chain blocks.
+		pub leadin_length: polkadot_primitives::BlockNumber,
+		/// First timeslice of the regions sold in this sale.
+		pub region_begin: u32,
+		/// Timeslice at which the regions sold in this sale end.
+		pub region_end: u32,
+		/// Price of a core at the current relay chain block.
+		pub current_price: polkadot_primitives::Balance,
+		/// Price of a core at the end of the lead-in period.
+		pub end_price: polkadot_primitives::Balance,
+		/// Price at which the ideal number of cores was sold, if it was reached.
+		pub sellout_price: Option<polkadot_primitives::Balance>,
+		/// Number of cores offered in this sale.
+		pub cores_offered: u16,
+		/// Number of cores sold in this sale, including renewals.
+		pub cores_sold: u16,
+		/// Number of cores that are ideally sold.
+		pub ideal_cores_sold: u16,
+		/// End price of the next sale if this sale ended with the current number of cores sold.
+		pub projected_end_price: polkadot_primitives::Balance,
+		/// Target price of the next sale if this sale ended with the current number of cores
+		/// sold.
+		pub projected_target_price: polkadot_primitives::Balance,
+	}
+
+	/// Price of renewing the workload of a core.
+	#[derive(scale_info::TypeInfo, codec::Encode, codec::Decode, Clone, Eq, PartialEq)]
+	#[cfg_attr(feature = "std", derive(Debug))]
+	pub struct CoretimeRenewalPrice {
+		/// Core of the workload.
+		pub core: u16,
+		/// Timeslice at which the renewed region begins.
+		pub when: u32,
+		/// Price of renewing the workload in the current sale.
+		pub price: polkadot_primitives::Balance,
+		/// Price of renewing the workload in the next sale if it is renewed at the current relay
+		/// chain block.
+		pub next_price: polkadot_primitives::Balance,
+	}
+
 	sp_api::decl_runtime_apis! {
 		pub trait Inflation {
 			/// Return the current estimates of the issuance amount.
@@ -50,5 +204,40 @@ pub mod apis {
 			/// recommended over trying to read-storage, or re-create the onchain logic.
 			fn experimental_issuance_prediction_info() -> InflationInfo;
 		}
+
+		/// Health of the bridges of a Bridge Hub.
+		///
+		/// Meant for monitoring, so that it doesn't depend on the storage layout of the bridge
+		/// pallets.
+		pub trait BridgeHealthApi {
+			/// Return the health of all bridges and their lanes.
+			fn bridge_health() -> BridgeHealth;
+		}
+
+		/// Prices of bulk coretime on a Coretime chain.
+		///
+		/// The prices are computed with the price adapter and the configuration of the broker
+		/// pallet of the runtime, so they don't need to be reimplemented off-chain.
+		pub trait CoretimePriceApi {
+			/// Return the status of the current sale, or `None` if no sale started yet.
+			fn sale_status() -> Option<CoretimeSaleStatus>;
+
+			/// Return the price of a core at `points` evenly spaced relay chain blocks from the
+			/// start to the end of the lead-in period of the current sale, at most 100.
+			fn leadin_prices(
+				points: u32,
+			) -> alloc::vec::Vec<(polkadot_primitives::BlockNumber, polkadot_primitives::Balance)>;
+
+			/// Return the renewal price of the workload of `core` in the current sale, or `None`
+			/// if the workload can't be renewed.
+			fn renewal_price(core: u16) -> Option<CoretimeRenewalPrice>;
+
+			/// Return the renewal prices of all cores with a workload of `para_id` that can be
+			/// renewed in the current sale.
+			fn para_renewal_prices(para_id: u32) -> alloc::vec::Vec<CoretimeRenewalPrice>;
+
+			/// Return the minimum amount of credits that can be purchased.
+			fn minimum_credit_purchase() -> polkadot_primitives::Balance;
+		}
 	}
 }
@@ -21,18 +21,26 @@ use cumulus_primitives_core::relay_chain;
 use frame_support::{
 	parameter_types,
 	traits::{
-		fungible::{Balanced, Credit, Inspect},
-		tokens::{Fortitude, Preservation},
+		fungible::{Balanced, Credit},
 		DefensiveResult, OnUnbalanced,
 	},
 };
 use frame_system::Pallet as System;
-use kusama_runtime_constants::{system_parachain::coretime, time::DAYS as RELAY_DAYS};
+use kusama_runtime_constants::{
+	system_parachain::coretime,
+	time::{DAYS as RELAY_DAYS, HOURS as RELAY_HOURS},
+};
 use pallet_broker::{
-	CoreAssignment, CoreIndex, CoretimeInterface, PartsOf57600, RCBlockNumberOf, TaskId,
+	AdaptPrice, CompletionStatus, Configuration, CoreAssignment, CoreIndex, CoretimeInterface,
+	PartsOf57600, PotentialRenewalId, PotentialRenewals, RCBlockNumberOf, RCBlockNumberProviderOf,
+	SaleInfo, SaleInfoRecordOf, SalePerformance, TaskId,
 };
 use parachains_common::{AccountId, Balance};
-use sp_runtime::traits::{AccountIdConversion, MaybeConvert};
+use sp_runtime::{
+	traits::{AccountIdConversion, BlockNumberProvider, MaybeConvert, Zero},
+	DispatchError, DispatchResult, FixedPointNumber, FixedU64,
+};
+use system_parachains_common::apis::{CoretimeRenewalPrice, CoretimeSaleStatus};
 use xcm::latest::prelude::*;
 use xcm_config::LocationToAccountId;
 use xcm_executor::traits::{ConvertLocation, TransactAsset};
@@ -118,6 +126,48 @@ fn burn_at_relay(stash: &AccountId, value: Balance) -> Result<(), XcmError> {
 	Ok(())
 }
 
+/// Burns the funds of [`CoretimeBurnAccount`] by teleporting them to the Relay Chain.
+pub struct BurnAtRelay;
+impl pallet_coretime_burn::BurnRevenue<AccountId, Balance> for BurnAtRelay {
+	fn burn(stash: &AccountId, amount: Balance) -> DispatchResult {
+		burn_at_relay(stash, amount).map_err(|e| {
+			log::error!(target: "runtime::coretime", "burn_at_relay failed: {e:?}");
+			DispatchError::Other("Failed to burn at the Relay Chain")
+		})
+	}
+}
+
+/// Sets up the burn account for the benchmarks of `pallet_coretime_burn`.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct CoretimeBurnBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_coretime_burn::BenchmarkHelper<AccountId, Balance> for CoretimeBurnBenchmarkHelper {
+	fn prepare_burn(stash: &AccountId) -> Balance {
+		use cumulus_primitives_core::UpwardMessageSender;
+		use frame_support::traits::fungible::Mutate;
+
+		ParachainSystem::ensure_successful_delivery();
+		let amount = 1_000 * UNITS;
+		Balances::set_balance(stash, amount);
+		amount
+	}
+}
+
+impl pallet_coretime_burn::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type BurnAccount = CoretimeBurnAccount;
+	type Burner = BurnAtRelay;
+	type RcBlockNumberProvider = RelaychainDataProvider<Runtime>;
+	// Burn roughly once per day and retry failed burns hourly.
+	type BurnPeriod = ConstU32<RELAY_DAYS>;
+	type RetryPeriod = ConstU32<RELAY_HOURS>;
+	type MaxRetries = ConstU32<3>;
+	type WeightInfo = weights::pallet_coretime_burn::WeightInfo<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = CoretimeBurnBenchmarkHelper;
+}
+
 parameter_types! {
 	/// The revenue from on-demand coretime sales. This is distributed amonst those who contributed
 	/// regions to the pool.
@@ -263,33 +313,6 @@ impl CoretimeInterface for CoretimeAllocator {
 			),
 		}
 	}
-
-	fn on_new_timeslice(t: pallet_broker::Timeslice) {
-		// Burn roughly once per day. TIMESLICE_PERIOD tested to be != 0.
-		const BURN_PERIOD: pallet_broker::Timeslice =
-			RELAY_DAYS.saturating_div(coretime::TIMESLICE_PERIOD);
-		// If checked_rem returns `None`, `TIMESLICE_PERIOD` is misconfigured for some reason. We
-		// have bigger issues with the chain, but we still want to burn.
-		if t.checked_rem(BURN_PERIOD).is_some_and(|r| r != 0) {
-			return;
-		}
-
-		let stash = CoretimeBurnAccount::get();
-		let value =
-			Balances::reducible_balance(&stash, Preservation::Expendable, Fortitude::Polite);
-
-		if value > 0 {
-			log::debug!(target: "runtime::coretime", "Going to burn {value} stashed tokens at RC");
-			match burn_at_relay(&stash, value) {
-				Ok(()) => {
-					log::debug!(target: "runtime::coretime", "Succesfully burnt {value} tokens");
-				},
-				Err(err) => {
-					log::error!(target: "runtime::coretime", "burn_at_relay failed: {err:?}");
-				},
-			}
-		}
-	}
 }
 
 parameter_types! {
@@ -325,3 +348,129 @@ impl pallet_broker::Config for Runtime {
 	type PriceAdapter = pallet_broker::MinimumPrice<Balance, MinimumEndPrice>;
 	type MinimumCreditPurchase = MinimumCreditPurchase;
 }
+
+/// The maximum number of prices returned by [`leadin_prices`].
+pub const MAX_LEADIN_PRICES: u32 = 100;
+
+/// The price of a core in `sale` at the relay chain block `when`.
+///
+/// Mirrors `pallet_broker::Pallet::sale_price`, which is not public.
+fn sale_price(sale: &SaleInfoRecordOf<Runtime>, when: relay_chain::BlockNumber) -> Balance {
+	let through = if sale.leadin_length.is_zero() {
+		FixedU64::one()
+	} else {
+		let elapsed = when.saturating_sub(sale.sale_start).min(sale.leadin_length);
+		FixedU64::from_rational(elapsed.into(), sale.leadin_length.into())
+	};
+	<Runtime as pallet_broker::Config>::PriceAdapter::leadin_factor_at(through)
+		.saturating_mul_int(sale.end_price)
+}
+
+fn relay_block_number() -> relay_chain::BlockNumber {
+	RCBlockNumberProviderOf::<<Runtime as pallet_broker::Config>::Coretime>::current_block_number()
+}
+
+/// The status of the current sale and the projected prices of the next one.
+pub fn sale_status() -> Option<CoretimeSaleStatus> {
+	let sale = SaleInfo::<Runtime>::get()?;
+	let projected = <Runtime as pallet_broker::Config>::PriceAdapter::adapt_price(
+		SalePerformance::from_sale(&sale),
+	);
+	Some(CoretimeSaleStatus {
+		sale_start: sale.sale_start,
+		leadin_length: sale.leadin_length,
+		region_begin: sale.region_begin,
+		region_end: sale.region_end,
+		current_price: sale_price(&sale, relay_block_number()),
+		end_price: sale.end_price,
+		sellout_price: sale.sellout_price,
+		cores_offered: sale.cores_offered,
+		cores_sold: sale.cores_sold,
+		ideal_cores_sold: sale.ideal_cores_sold,
+		projected_end_price: projected.end_price,
+		projected_target_price: projected.target_price,
+	})
+}
+
+/// The price of a core at `points` evenly spaced relay chain blocks of the lead-in period of the
+/// current sale.
+pub fn leadin_prices(points: u32) -> Vec<(relay_chain::BlockNumber, Balance)> {
+	let Some(sale) = SaleInfo::<Runtime>::get() else { return Vec::new() };
+	let points = points.min(MAX_LEADIN_PRICES);
+	(0..points)
+		.map(|point| {
+			let offset = match points {
+				1 => 0,
+				_ => (sale.leadin_length as u64 * point as u64 / (points - 1) as u64) as u32,
+			};
+			let when = sale.sale_start.saturating_add(offset);
+			(when, sale_price(&sale, when))
+		})
+		.collect()
+}
+
+/// The renewal price of the workload of `core` in the current sale.
+pub fn renewal_price(core: CoreIndex) -> Option<CoretimeRenewalPrice> {
+	let sale = SaleInfo::<Runtime>::get()?;
+	let id = PotentialRenewalId { core, when: sale.region_begin };
+	let record = PotentialRenewals::<Runtime>::get(id)?;
+	// Only complete workloads can be renewed.
+	matches!(record.completion, CompletionStatus::Complete(_))
+		.then(|| renewal(&sale, core, record.price))
+}
+
+/// The renewal prices of all cores with a workload of `para_id` in the current sale.
+pub fn para_renewal_prices(para_id: TaskId) -> Vec<CoretimeRenewalPrice> {
+	let Some(sale) = SaleInfo::<Runtime>::get() else { return Vec::new() };
+	PotentialRenewals::<Runtime>::iter()
+		.filter(|(id, record)| {
+			id.when == sale.region_begin &&
+				matches!(
+					&record.completion,
+					CompletionStatus::Complete(workload) if workload
+						.iter()
+						.any(|item| item.assignment == CoreAssignment::Task(para_id))
+				)
+		})
+		.map(|(id, record)| renewal(&sale, id.core, record.price))
+		.collect()
+}
+
+/// The renewal price of a workload in the current sale and the projected one in the next sale.
+///
+/// Mirrors the pricing of `pallet_broker::Pallet::do_renew`.
+fn renewal(
+	sale: &SaleInfoRecordOf<Runtime>,
+	core: CoreIndex,
+	price: Balance,
+) -> CoretimeRenewalPrice {
+	let renewal_bump = Configuration::<Runtime>::get()
+		.map(|config| config.renewal_bump)
+		.unwrap_or_default();
+	// Renewals are never priced lower than the current end price.
+	let price_cap = price.saturating_add(renewal_bump * price).max(sale.end_price);
+	let next_price = sale_price(sale, relay_block_number()).min(price_cap);
+	CoretimeRenewalPrice { core, when: sale.region_begin, price, next_price }
+}
+
+parameter_types! {
+	pub const RegionMarketPalletId: PalletId = PalletId(*b"py/rgnmk");
+	// One storage item; key size 32, value size 68.
+	pub const RegionMarketDeposit: Balance = system_para_deposit(1, 100);
+	pub const RegionMarketFee: Perbill = Perbill::from_percent(1);
+}
+
+impl pallet_region_market::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type MarketPalletId = RegionMarketPalletId;
+	type RcBlockNumberProvider = RelaychainDataProvider<Runtime>;
+	// Listings and orders stay open for at most one bulk sale period.
+	type MaxDuration = ConstU32<{ 28 * RELAY_DAYS }>;
+	type Deposit = RegionMarketDeposit;
+	type Fee = RegionMarketFee;
+	// The fees are burnt like the revenue of the primary sales.
+	type OnFee = BurnCoretimeRevenue;
+	// The surplus stays in the account of the buyer.
+	type OnSurplus = ();
+	type WeightInfo = weights::pallet_region_market::WeightInfo<Runtime>;
+}
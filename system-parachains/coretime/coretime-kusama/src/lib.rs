@@ -567,7 +567,14 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 					RuntimeCall::Broker(pallet_broker::Call::drop_region { .. }) |
 					RuntimeCall::Broker(pallet_broker::Call::drop_contribution { .. }) |
 					RuntimeCall::Broker(pallet_broker::Call::drop_history { .. }) |
-					RuntimeCall::Broker(pallet_broker::Call::drop_renewal { .. })
+					RuntimeCall::Broker(pallet_broker::Call::drop_renewal { .. }) |
+					// Cancelling and clearing only return regions and funds to their owners.
+					RuntimeCall::RegionMarket(
+						pallet_region_market::Call::cancel_listing { .. } |
+							pallet_region_market::Call::cancel_order { .. } |
+							pallet_region_market::Call::clear_expired_listing { .. } |
+							pallet_region_market::Call::clear_expired_order { .. }
+					)
 			),
 			ProxyType::CancelProxy => matches!(
 				c,
@@ -579,6 +586,7 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 				matches!(
 					c,
 					RuntimeCall::Broker { .. } |
+						RuntimeCall::RegionMarket { .. } |
 						RuntimeCall::Utility { .. } |
 						RuntimeCall::Multisig { .. }
 				)
@@ -691,6 +699,8 @@ construct_runtime!(
 
 		// The main stage.
 		Broker: pallet_broker = 50,
+		CoretimeBurn: pallet_coretime_burn = 51,
+		RegionMarket: pallet_region_market = 52,
 	}
 );
 
@@ -710,6 +720,8 @@ mod benches {
 		[pallet_timestamp, Timestamp]
 		[pallet_balances, Balances]
 		[pallet_broker, Broker]
+		[pallet_coretime_burn, CoretimeBurn]
+		[pallet_region_market, RegionMarket]
 		[pallet_collator_selection, CollatorSelection]
 		[pallet_session, SessionBench::<Runtime>]
 		[cumulus_pallet_xcmp_queue, XcmpQueue]
@@ -1186,6 +1198,32 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl system_parachains_common::apis::CoretimePriceApi<Block> for Runtime {
+		fn sale_status() -> Option<system_parachains_common::apis::CoretimeSaleStatus> {
+			coretime::sale_status()
+		}
+
+		fn leadin_prices(
+			points: u32,
+		) -> Vec<(cumulus_primitives_core::relay_chain::BlockNumber, Balance)> {
+			coretime::leadin_prices(points)
+		}
+
+		fn renewal_price(core: u16) -> Option<system_parachains_common::apis::CoretimeRenewalPrice> {
+			coretime::renewal_price(core)
+		}
+
+		fn para_renewal_prices(
+			para_id: u32,
+		) -> Vec<system_parachains_common::apis::CoretimeRenewalPrice> {
+			coretime::para_renewal_prices(para_id)
+		}
+
+		fn minimum_credit_purchase() -> Balance {
+			<Runtime as pallet_broker::Config>::MinimumCreditPurchase::get()
+		}
+	}
+
 	#[cfg(feature = "try-runtime")]
 	impl frame_try_runtime::TryRuntime<Block> for Runtime {
 		fn on_runtime_upgrade(checks: frame_try_runtime::UpgradeCheckSelect) -> (Weight, Weight) {
@@ -30,7 +30,7 @@ use frame_support::{
 	},
 };
 use kusama_runtime_constants::system_parachain::coretime::TIMESLICE_PERIOD;
-use pallet_broker::{ConfigRecordOf, RCBlockNumberOf, SaleInfo};
+use pallet_broker::{ConfigRecordOf, Finality, RCBlockNumberOf, Regions, SaleInfo};
 use parachains_runtimes_test_utils::{ExtBuilder, GovernanceOrigin};
 use sp_core::crypto::Ss58Codec;
 use sp_runtime::{traits::AccountIdConversion, Either};
@@ -121,6 +121,84 @@ fn bulk_revenue_is_burnt() {
 		});
 }
 
+#[test]
+fn coretime_prices_match_the_broker() {
+	ExtBuilder::<Runtime>::default()
+		.with_collators(vec![AccountId::from(ALICE)])
+		.with_session_keys(vec![(
+			AccountId::from(ALICE),
+			AccountId::from(ALICE),
+			SessionKeys { aura: AuraId::from(sp_core::sr25519::Public::from_raw(ALICE)) },
+		)])
+		.build()
+		.execute_with(|| {
+			assert_eq!(coretime::sale_status(), None);
+			assert!(coretime::leadin_prices(10).is_empty());
+
+			let config = ConfigRecordOf::<Runtime> {
+				advance_notice: 1,
+				interlude_length: 1,
+				leadin_length: 10,
+				region_length: 1,
+				ideal_bulk_proportion: Perbill::from_percent(100),
+				limit_cores_offered: None,
+				renewal_bump: Perbill::from_percent(3),
+				contribution_timeout: 1,
+			};
+			assert_ok!(Broker::configure(RuntimeOrigin::root(), config.clone()));
+			assert_ok!(Broker::start_sales(RuntimeOrigin::root(), UNITS, 1));
+
+			let sale_start = SaleInfo::<Runtime>::get().unwrap().sale_start;
+			advance_to(sale_start + config.interlude_length);
+
+			// The lead-in curve falls to the end price.
+			let status = coretime::sale_status().unwrap();
+			let prices = coretime::leadin_prices(11);
+			assert_eq!(prices.len(), 11);
+			assert_eq!(prices[0].0, status.sale_start);
+			assert_eq!(prices[10], (status.sale_start + status.leadin_length, status.end_price));
+			assert!(prices.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+			assert_eq!(
+				coretime::leadin_prices(u32::MAX).len(),
+				coretime::MAX_LEADIN_PRICES as usize
+			);
+
+			// A purchase costs the current price.
+			let alice = AccountId::from(ALICE);
+			assert_ok!(Balances::mint_into(&alice, 200 * UNITS));
+			let balance_before = Balances::balance(&alice);
+			assert_ok!(Broker::purchase(
+				RuntimeOrigin::signed(alice.clone()),
+				status.current_price
+			));
+			assert_eq!(balance_before - Balances::balance(&alice), status.current_price);
+			assert_eq!(coretime::sale_status().unwrap().cores_sold, status.cores_sold + 1);
+
+			// The workload can be renewed for the purchase price in the next sale.
+			let (region_id, region) = Regions::<Runtime>::iter().next().unwrap();
+			assert_ok!(Broker::assign(
+				RuntimeOrigin::signed(alice),
+				region_id,
+				2000,
+				Finality::Final
+			));
+			for _ in 0..10 * TIMESLICE_PERIOD {
+				if SaleInfo::<Runtime>::get().unwrap().region_begin == region.end {
+					break
+				}
+				advance_to(System::block_number() + 1);
+			}
+			let renewal = coretime::renewal_price(region_id.core).unwrap();
+			assert_eq!(renewal.when, region.end);
+			assert_eq!(renewal.price, status.current_price);
+			let price_cap = (renewal.price + config.renewal_bump * renewal.price)
+				.max(SaleInfo::<Runtime>::get().unwrap().end_price);
+			assert!(renewal.next_price <= price_cap);
+			assert_eq!(coretime::para_renewal_prices(2000), vec![renewal]);
+			assert!(coretime::para_renewal_prices(2001).is_empty());
+		});
+}
+
 #[test]
 fn timeslice_period_is_sane() {
 	// Config TimeslicePeriod is set to this constant - assumption in burning logic.
@@ -26,9 +26,11 @@ pub mod frame_system_extensions;
 pub mod pallet_balances;
 pub mod pallet_broker;
 pub mod pallet_collator_selection;
+pub mod pallet_coretime_burn;
 pub mod pallet_message_queue;
 pub mod pallet_multisig;
 pub mod pallet_proxy;
+pub mod pallet_region_market;
 pub mod pallet_session;
 pub mod pallet_timestamp;
 pub mod pallet_transaction_payment;
@@ -206,14 +206,20 @@ parameter_types! {
 /// - Credit purchase calls until the credit system is implemented. Otherwise, users may have chance
 ///   of locking their funds forever on purchased credits they cannot use.
 /// - The interlace call until the relay can support this fully
+/// - Purchases of parts of listed regions in the region market that require an interlace
 pub struct IsFilteredBrokerCall;
 impl Contains<RuntimeCall> for IsFilteredBrokerCall {
 	fn contains(c: &RuntimeCall) -> bool {
-		matches!(
-			c,
+		match c {
 			RuntimeCall::Broker(pallet_broker::Call::purchase_credit { .. }) |
-				RuntimeCall::Broker(pallet_broker::Call::interlace { .. })
-		)
+			RuntimeCall::Broker(pallet_broker::Call::interlace { .. }) => true,
+			RuntimeCall::RegionMarket(pallet_region_market::Call::purchase_part {
+				region_id,
+				mask,
+				..
+			}) => *mask != region_id.mask,
+			_ => false,
+		}
 	}
 }
 
@@ -571,7 +577,14 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 					RuntimeCall::Broker(pallet_broker::Call::drop_region { .. }) |
 					RuntimeCall::Broker(pallet_broker::Call::drop_contribution { .. }) |
 					RuntimeCall::Broker(pallet_broker::Call::drop_history { .. }) |
-					RuntimeCall::Broker(pallet_broker::Call::drop_renewal { .. })
+					RuntimeCall::Broker(pallet_broker::Call::drop_renewal { .. }) |
+					// Cancelling and clearing only return regions and funds to their owners.
+					RuntimeCall::RegionMarket(
+						pallet_region_market::Call::cancel_listing { .. } |
+							pallet_region_market::Call::cancel_order { .. } |
+							pallet_region_market::Call::clear_expired_listing { .. } |
+							pallet_region_market::Call::clear_expired_order { .. }
+					)
 			),
 			ProxyType::CancelProxy => matches!(
 				c,
@@ -583,6 +596,7 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 				matches!(
 					c,
 					RuntimeCall::Broker { .. } |
+						RuntimeCall::RegionMarket { .. } |
 						RuntimeCall::Utility { .. } |
 						RuntimeCall::Multisig { .. }
 				)
@@ -699,6 +713,8 @@ construct_runtime!(
 
 		// The main stage.
 		Broker: pallet_broker = 50,
+		CoretimeBurn: pallet_coretime_burn = 51,
+		RegionMarket: pallet_region_market = 52,
 	}
 );
 
@@ -717,6 +733,8 @@ mod benches {
 		[pallet_timestamp, Timestamp]
 		[pallet_balances, Balances]
 		[pallet_broker, Broker]
+		[pallet_coretime_burn, CoretimeBurn]
+		[pallet_region_market, RegionMarket]
 		[pallet_collator_selection, CollatorSelection]
 		[pallet_session, SessionBench::<Runtime>]
 		[cumulus_pallet_xcmp_queue, XcmpQueue]
@@ -1194,6 +1212,32 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl system_parachains_common::apis::CoretimePriceApi<Block> for Runtime {
+		fn sale_status() -> Option<system_parachains_common::apis::CoretimeSaleStatus> {
+			coretime::sale_status()
+		}
+
+		fn leadin_prices(
+			points: u32,
+		) -> Vec<(cumulus_primitives_core::relay_chain::BlockNumber, Balance)> {
+			coretime::leadin_prices(points)
+		}
+
+		fn renewal_price(core: u16) -> Option<system_parachains_common::apis::CoretimeRenewalPrice> {
+			coretime::renewal_price(core)
+		}
+
+		fn para_renewal_prices(
+			para_id: u32,
+		) -> Vec<system_parachains_common::apis::CoretimeRenewalPrice> {
+			coretime::para_renewal_prices(para_id)
+		}
+
+		fn minimum_credit_purchase() -> Balance {
+			<Runtime as pallet_broker::Config>::MinimumCreditPurchase::get()
+		}
+	}
+
 	#[cfg(feature = "try-runtime")]
 	impl frame_try_runtime::TryRuntime<Block> for Runtime {
 		fn on_runtime_upgrade(checks: frame_try_runtime::UpgradeCheckSelect) -> (Weight, Weight) {